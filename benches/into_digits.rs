@@ -5,19 +5,19 @@ macro_rules! create_radix2_bench {
     ( $t:ident ) => {
         #[bench]
         fn bench_into_radix2(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.into_binary_digits());
         }
 
         #[bench]
         fn bench_reverse_radix2(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.reverse_binary_digits());
         }
 
         #[bench]
         fn bench_permutation_radix2(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             let reverse = target.reverse_binary_digits();
             b.iter(|| target.is_binary_permutation(reverse));
         }
@@ -28,38 +28,38 @@ macro_rules! create_radix10_bench {
     ( $t:ident ) => {
         #[bench]
         fn bench_into_radix10(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.into_decimal_digits());
         }
 
         #[bench]
         fn bench_reverse_radix10(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.reverse_decimal_digits());
         }
 
         #[bench]
         fn bench_reverse_radix10_string(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.to_string().chars().rev().collect::<String>())
         }
 
         #[bench]
         fn bench_permutation_radix10(b: &mut Bencher) {
-            let target = core::$t::MAX / 10;
+            let target = $t::MAX / 10;
             let reverse = target.reverse_decimal_digits();
             b.iter(|| target.is_decimal_permutation(reverse));
         }
 
         #[bench]
         fn bench_nbr_decimal_digits(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.nbr_decimal_digits());
         }
 
         #[bench]
         fn bench_nbr_decimal_digits_str_radix(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.to_string().len());
         }
     };
@@ -69,38 +69,260 @@ macro_rules! create_radix16_bench {
     ( $t:ident ) => {
         #[bench]
         fn bench_into_radix16(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.into_digits(16));
         }
 
         #[bench]
         fn bench_reverse_radix16(b: &mut Bencher) {
-            let target = core::$t::MAX >> 1;
+            let target = $t::MAX >> 1;
             b.iter(|| target.reverse_digits(16));
         }
 
         #[bench]
         fn bench_permutation_radix16(b: &mut Bencher) {
-            let target = core::$t::MAX >> 4;
+            let target = $t::MAX >> 4;
             let reverse = target.reverse_binary_digits();
             b.iter(|| target.is_permutation(reverse, 16));
         }
     };
 }
 
+macro_rules! create_iteration_bench {
+    ( $t:ident ) => {
+        #[bench]
+        fn bench_next_back_radix10(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| {
+                let mut digits = black_box(target).into_decimal_digits();
+                let mut sum = 0;
+                while let Some(digit) = digits.next_back() {
+                    sum += digit;
+                }
+                sum
+            });
+        }
+
+        #[bench]
+        fn bench_next_back_radix7(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| {
+                let mut digits = black_box(target).into_digits(7).unwrap();
+                let mut sum = 0;
+                while let Some(digit) = digits.next_back() {
+                    sum += digit;
+                }
+                sum
+            });
+        }
+
+        #[bench]
+        fn bench_reversed_number_radix7(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| {
+                black_box(target)
+                    .into_digits(7)
+                    .unwrap()
+                    .into_reversed_number()
+            });
+        }
+
+        #[bench]
+        fn bench_reversed_number_radix10(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| {
+                black_box(target)
+                    .into_decimal_digits()
+                    .into_reversed_number()
+            });
+        }
+
+        #[bench]
+        fn bench_digit_sum_radix10(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| black_box(target).digit_sum(10));
+        }
+
+        #[bench]
+        fn bench_digit_sum_radix7(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| black_box(target).digit_sum(7));
+        }
+
+        #[bench]
+        fn bench_sum_digits_radix7(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| black_box(target).into_digits(7).unwrap().sum::<$t>());
+        }
+
+        #[bench]
+        fn bench_mixed_directions_radix10(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| {
+                let mut digits = black_box(target).into_decimal_digits();
+                let mut sum = 0;
+                while let Some(digit) = digits.next_back() {
+                    sum += digit;
+                    if let Some(digit) = digits.next() {
+                        sum += digit;
+                    }
+                }
+                sum
+            });
+        }
+
+        #[bench]
+        fn bench_mixed_directions_radix7(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| {
+                let mut digits = black_box(target).into_digits(7).unwrap();
+                let mut sum = 0;
+                while let Some(digit) = digits.next_back() {
+                    sum += digit;
+                    if let Some(digit) = digits.next() {
+                        sum += digit;
+                    }
+                }
+                sum
+            });
+        }
+
+        #[bench]
+        fn bench_next_back_runtime_radix7(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| {
+                let mut digits = black_box(target).into_digits(black_box(7)).unwrap();
+                let mut sum = 0;
+                while let Some(digit) = digits.next_back() {
+                    sum += digit;
+                }
+                sum
+            });
+        }
+
+        #[bench]
+        fn bench_digit_sum_runtime_radix7(b: &mut Bencher) {
+            let target = $t::MAX >> 1;
+            b.iter(|| black_box(target).digit_sum(black_box(7)));
+        }
+    };
+}
+
 macro_rules! create_benches {
     ( $($t:ident)* ) => {
         $(
             mod $t {
                 use radixal::IntoDigits;
-                use test::Bencher;
+                use test::{black_box, Bencher};
 
                 create_radix2_bench!($t);
                 create_radix10_bench!($t);
                 create_radix16_bench!($t);
+                create_iteration_bench!($t);
             }
         )*
     }
 }
 
 create_benches!(u8 u16 u32 u64 u128 usize);
+
+#[cfg(feature = "simd")]
+mod simd {
+    use radixal::simd::DecimalDigits;
+    use radixal::IntoDigits;
+    use test::{black_box, Bencher};
+
+    #[bench]
+    fn bench_sum_decimal_digits(b: &mut Bencher) {
+        let target = u64::MAX >> 1;
+        b.iter(|| DecimalDigits::new(black_box(target)).sum::<u64>());
+    }
+
+    #[bench]
+    fn bench_sum_into_decimal_digits(b: &mut Bencher) {
+        let target = u64::MAX >> 1;
+        b.iter(|| black_box(target).into_decimal_digits().sum::<u64>());
+    }
+}
+
+mod batch {
+    use radixal::batch::{
+        count_digits_of_slice, digit_histogram_of_slice, digit_sums_of_slice, digits_of_slice,
+    };
+    use radixal::IntoDigits;
+    use test::Bencher;
+
+    fn numbers() -> Vec<u64> {
+        (0..1000_u64)
+            .map(|n| n.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> (n % 64))
+            .collect()
+    }
+
+    #[bench]
+    fn bench_digits_of_slice(b: &mut Bencher) {
+        let numbers = numbers();
+        let mut digits = vec![0; count_digits_of_slice(&numbers, 10).unwrap()];
+        let mut offsets = vec![0; numbers.len() + 1];
+        b.iter(|| digits_of_slice(&numbers, 10, &mut digits, &mut offsets));
+    }
+
+    #[bench]
+    fn bench_digit_histogram_of_slice(b: &mut Bencher) {
+        let numbers = numbers();
+        let mut counts = [0; 10];
+        b.iter(|| digit_histogram_of_slice(&numbers, 10, &mut counts));
+    }
+
+    #[bench]
+    fn bench_digit_sums_of_slice_radix16(b: &mut Bencher) {
+        let numbers = numbers();
+        let mut sums = vec![0; numbers.len()];
+        b.iter(|| digit_sums_of_slice(&numbers, 16, &mut sums));
+    }
+
+    #[bench]
+    fn bench_digits_of_each(b: &mut Bencher) {
+        let numbers = numbers();
+        let mut digits = Vec::with_capacity(count_digits_of_slice(&numbers, 10).unwrap());
+        b.iter(|| {
+            digits.clear();
+            for &number in &numbers {
+                digits.extend(number.into_decimal_digits());
+            }
+            digits.len()
+        });
+    }
+}
+
+mod lookup {
+    use radixal::lookup::LookupDigits;
+    use radixal::IntoDigits;
+    use test::{black_box, Bencher};
+
+    #[bench]
+    fn bench_sum_lookup_u8(b: &mut Bencher) {
+        b.iter(|| {
+            (0..=u8::MAX)
+                .map(|n| {
+                    LookupDigits::from_u8(black_box(n))
+                        .map(u32::from)
+                        .sum::<u32>()
+                })
+                .sum::<u32>()
+        });
+    }
+
+    #[bench]
+    fn bench_sum_into_decimal_digits_u8(b: &mut Bencher) {
+        b.iter(|| {
+            (0..=u8::MAX)
+                .map(|n| {
+                    black_box(n)
+                        .into_decimal_digits()
+                        .map(u32::from)
+                        .sum::<u32>()
+                })
+                .sum::<u32>()
+        });
+    }
+}
@@ -0,0 +1,1016 @@
+//! Symbol sets used to format and parse numbers under a given radix.
+use crate::format::DigitsDisplay;
+use crate::parse::{self, ParseError};
+use crate::{IntoDigits, SignedInteger};
+#[cfg(all(feature = "alloc", feature = "alphabets"))]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The largest number of symbols an `Alphabet` may hold, so that its radix fits in any
+/// supported integer type.
+pub const MAX_SYMBOLS: usize = u8::MAX as usize;
+
+/// Errors that can occur when creating an `Alphabet`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlphabetError {
+    /// An alphabet needs at least two symbols.
+    TooFewSymbols,
+    /// An alphabet can hold at most `MAX_SYMBOLS` symbols.
+    TooManySymbols,
+    /// The same symbol appears more than once.
+    DuplicateSymbol(char),
+    /// An alias is already a symbol, or stands for a digit outside of the alphabet.
+    InvalidAlias(char),
+    /// A sign is also a digit, or both signs are the same symbol.
+    InvalidSign(char),
+    /// A balanced alphabet needs an odd number of symbols.
+    EvenSymbols,
+}
+
+impl core::fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            AlphabetError::TooFewSymbols => write!(f, "an alphabet needs at least 2 symbols"),
+            AlphabetError::TooManySymbols => {
+                write!(f, "an alphabet can hold at most {} symbols", MAX_SYMBOLS)
+            }
+            AlphabetError::DuplicateSymbol(c) => write!(f, "duplicate symbol {:?}", c),
+            AlphabetError::InvalidAlias(c) => write!(f, "invalid alias {:?}", c),
+            AlphabetError::InvalidSign(c) => write!(f, "invalid sign {:?}", c),
+            AlphabetError::EvenSymbols => {
+                write!(f, "a balanced alphabet needs an odd number of symbols")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AlphabetError {}
+
+/// An ordered set of symbols, where the symbol at index `i` represents the digit `i`.
+///
+/// The radix of an alphabet is its number of symbols.
+///
+/// When parsing, an alphabet may also accept aliases for some of its digits, and ignore the ASCII
+/// case of symbols.
+///
+/// Each alphabet also has its own plus and minus signs, `+` and `-` unless stated otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Alphabet<'a> {
+    symbols: &'a [char],
+    aliases: &'a [(char, usize)],
+    ignore_case: bool,
+    plus: char,
+    minus: char,
+}
+
+impl<'a> Alphabet<'a> {
+    /// Creates a new `Alphabet` from its `symbols`, in increasing order of digit value.
+    ///
+    /// Returns an `Err(AlphabetError)` if there are fewer than 2 or more than `MAX_SYMBOLS`
+    /// symbols, or if a symbol is repeated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::{Alphabet, AlphabetError};
+    ///
+    /// let alphabet = Alphabet::new(&['o', 'x']).unwrap();
+    /// assert_eq!(alphabet.radix(), 2);
+    ///
+    /// assert_eq!(Alphabet::new(&['o']), Err(AlphabetError::TooFewSymbols));
+    /// assert_eq!(
+    ///     Alphabet::new(&['o', 'x', 'o']),
+    ///     Err(AlphabetError::DuplicateSymbol('o'))
+    /// );
+    /// ```
+    pub fn new(symbols: &'a [char]) -> Result<Self, AlphabetError> {
+        if symbols.len() < 2 {
+            return Err(AlphabetError::TooFewSymbols);
+        } else if symbols.len() > MAX_SYMBOLS {
+            return Err(AlphabetError::TooManySymbols);
+        }
+
+        for (idx, symbol) in symbols.iter().enumerate() {
+            if symbols[..idx].contains(symbol) {
+                return Err(AlphabetError::DuplicateSymbol(*symbol));
+            }
+        }
+
+        Ok(Alphabet::from_symbols(symbols))
+    }
+
+    pub(crate) const fn from_symbols(symbols: &'a [char]) -> Self {
+        Alphabet {
+            symbols,
+            aliases: &[],
+            ignore_case: false,
+            plus: '+',
+            minus: '-',
+        }
+    }
+
+    /// Accepts `aliases` when parsing, each given as a symbol and the digit it stands for.
+    ///
+    /// Returns an `Err(AlphabetError::InvalidAlias)` if an alias is already a symbol or stands
+    /// for a digit outside of the alphabet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::{Alphabet, AlphabetError};
+    ///
+    /// let alphabet = Alphabet::new(&['o', 'x']).unwrap();
+    /// let alphabet = alphabet.with_aliases(&[('0', 0), ('1', 1)]).unwrap();
+    ///
+    /// assert_eq!(alphabet.parse::<u8>("x0x"), Ok(5));
+    /// assert_eq!(alphabet.display(5_u8).to_string(), "xox");
+    ///
+    /// assert_eq!(
+    ///     alphabet.with_aliases(&[('2', 2)]),
+    ///     Err(AlphabetError::InvalidAlias('2'))
+    /// );
+    /// ```
+    pub fn with_aliases(self, aliases: &'a [(char, usize)]) -> Result<Self, AlphabetError> {
+        for &(alias, digit) in aliases {
+            if digit >= self.radix() || self.symbols.contains(&alias) {
+                return Err(AlphabetError::InvalidAlias(alias));
+            }
+        }
+
+        Ok(Alphabet { aliases, ..self })
+    }
+
+    /// Ignores the ASCII case of symbols (and aliases) when parsing.
+    ///
+    /// Returns an `Err(AlphabetError::DuplicateSymbol)` if two symbols only differ by their case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::{Alphabet, AlphabetError};
+    ///
+    /// let alphabet = Alphabet::BASE36.with_ignore_case().unwrap();
+    /// assert_eq!(alphabet.parse::<u32>("zZ"), Ok(1295));
+    ///
+    /// let mixed = Alphabet::new(&['a', 'b', 'A']).unwrap();
+    /// assert_eq!(mixed.with_ignore_case(), Err(AlphabetError::DuplicateSymbol('A')));
+    /// ```
+    pub fn with_ignore_case(self) -> Result<Self, AlphabetError> {
+        for (idx, symbol) in self.symbols.iter().enumerate() {
+            if self.symbols[..idx]
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(symbol))
+            {
+                return Err(AlphabetError::DuplicateSymbol(*symbol));
+            }
+        }
+
+        Ok(Alphabet {
+            ignore_case: true,
+            ..self
+        })
+    }
+
+    /// Uses `plus` and `minus` as the signs of signed numbers.
+    ///
+    /// Returns an `Err(AlphabetError::InvalidSign)` if a sign is also a digit (or an alias), or
+    /// if both signs are the same.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::{Alphabet, AlphabetError};
+    ///
+    /// let alphabet = Alphabet::DECIMAL.with_signs('+', '−').unwrap();
+    /// assert_eq!(alphabet.display_signed(-42_i32).to_string(), "−42");
+    ///
+    /// assert_eq!(
+    ///     Alphabet::BASE36.with_signs('p', 'm'),
+    ///     Err(AlphabetError::InvalidSign('p'))
+    /// );
+    /// ```
+    pub fn with_signs(self, plus: char, minus: char) -> Result<Self, AlphabetError> {
+        for &sign in &[plus, minus] {
+            if self.digit(sign).is_some() {
+                return Err(AlphabetError::InvalidSign(sign));
+            }
+        }
+        if plus == minus {
+            return Err(AlphabetError::InvalidSign(minus));
+        }
+
+        Ok(Alphabet {
+            plus,
+            minus,
+            ..self
+        })
+    }
+
+    /// Returns the radix of the alphabet, i.e. its number of symbols.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::BASE36.radix(), 36);
+    /// ```
+    pub fn radix(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Returns the symbols, in increasing order of digit value.
+    pub fn symbols(&self) -> &'a [char] {
+        self.symbols
+    }
+
+    /// Returns the symbol representing `digit`, or `None` if it is not smaller than the radix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::BASE36.symbol(11), Some('b'));
+    /// assert_eq!(Alphabet::BASE36.symbol(36), None);
+    /// ```
+    pub fn symbol(&self, digit: usize) -> Option<char> {
+        self.symbols.get(digit).copied()
+    }
+
+    /// Returns the aliases accepted when parsing, each with the digit it stands for.
+    pub fn aliases(&self) -> &'a [(char, usize)] {
+        self.aliases
+    }
+
+    /// Returns whether the ASCII case of symbols is ignored when parsing.
+    pub fn ignores_case(&self) -> bool {
+        self.ignore_case
+    }
+
+    /// Returns the plus sign.
+    pub fn plus_sign(&self) -> char {
+        self.plus
+    }
+
+    /// Returns the minus sign.
+    pub fn minus_sign(&self) -> char {
+        self.minus
+    }
+
+    /// Returns the digit represented by `symbol`, or `None` if it is neither a symbol nor an
+    /// alias.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::UPPER_HEX.digit('B'), Some(11));
+    /// assert_eq!(Alphabet::UPPER_HEX.digit('-'), None);
+    /// ```
+    pub fn digit(&self, symbol: char) -> Option<usize> {
+        let matches = |c: char| {
+            if self.ignore_case {
+                c.eq_ignore_ascii_case(&symbol)
+            } else {
+                c == symbol
+            }
+        };
+
+        self.symbols.iter().position(|&c| matches(c)).or_else(|| {
+            self.aliases
+                .iter()
+                .find(|&&(alias, _)| matches(alias))
+                .map(|&(_, digit)| digit)
+        })
+    }
+
+    /// Wraps `number` so that it is displayed using this alphabet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::BASE36.display(1295_u32).to_string(), "zz");
+    /// ```
+    pub fn display<T: IntoDigits>(&self, number: T) -> DigitsDisplay<'a, T> {
+        DigitsDisplay::new(number, *self)
+    }
+
+    /// Wraps the signed `number` so that it is displayed using this alphabet and its signs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::BASE36.display_signed(-1295_i32).to_string(), "-zz");
+    /// # #[cfg(feature = "alphabets")]
+    /// assert_eq!(Alphabet::FULLWIDTH.display_signed(-12_i8).to_string(), "－１２");
+    /// ```
+    pub fn display_signed<S: SignedInteger>(&self, number: S) -> DigitsDisplay<'a, S::Magnitude> {
+        DigitsDisplay::new_signed(number, *self)
+    }
+
+    /// Parses a number written with this alphabet.
+    ///
+    /// Returns an `Err(ParseError)` if the string is empty, contains a symbol outside of the
+    /// alphabet or represents a number too large for `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::parse::ParseError;
+    ///
+    /// assert_eq!(Alphabet::BASE36.parse::<u32>("zz"), Ok(1295));
+    /// assert_eq!(Alphabet::BASE36.parse::<u32>("ZZ"), Err(ParseError::InvalidDigit('Z')));
+    /// assert_eq!(Alphabet::BASE36.parse::<u8>("zz"), Err(ParseError::Overflow));
+    /// ```
+    pub fn parse<T: IntoDigits>(&self, s: &str) -> Result<T, ParseError> {
+        parse::parse(s, self)
+    }
+}
+
+/// Converts ASCII bytes to symbols.
+const fn ascii<const N: usize>(bytes: &[u8; N]) -> [char; N] {
+    let mut symbols = ['\0'; N];
+    let mut idx = 0;
+    while idx < N {
+        symbols[idx] = bytes[idx] as char;
+        idx += 1;
+    }
+    symbols
+}
+
+/// Returns `N` consecutive code points, starting from `first`.
+#[cfg(feature = "alphabets")]
+const fn consecutive<const N: usize>(first: char) -> [char; N] {
+    let mut symbols = ['\0'; N];
+    let mut idx = 0;
+    while idx < N {
+        symbols[idx] = match char::from_u32(first as u32 + idx as u32) {
+            Some(c) => c,
+            None => panic!("invalid code point"),
+        };
+        idx += 1;
+    }
+    symbols
+}
+
+const BASE36_SYMBOLS: [char; 36] = ascii(b"0123456789abcdefghijklmnopqrstuvwxyz");
+#[cfg(feature = "alphabets")]
+const BASE62_SYMBOLS: [char; 62] =
+    ascii(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+const UPPER_HEX_SYMBOLS: [char; 16] = ascii(b"0123456789ABCDEF");
+#[cfg(feature = "alphabets")]
+const BASE58_SYMBOLS: [char; 58] =
+    ascii(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+#[cfg(feature = "alphabets")]
+const BASE32_SYMBOLS: [char; 32] = ascii(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+#[cfg(feature = "alphabets")]
+const BASE32HEX_SYMBOLS: [char; 32] = ascii(b"0123456789ABCDEFGHIJKLMNOPQRSTUV");
+#[cfg(feature = "alphabets")]
+const CROCKFORD_SYMBOLS: [char; 32] = ascii(b"0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+#[cfg(feature = "alphabets")]
+const EASTERN_ARABIC_SYMBOLS: [char; 10] = consecutive('\u{0660}');
+#[cfg(feature = "alphabets")]
+const PERSIAN_SYMBOLS: [char; 10] = consecutive('\u{06F0}');
+#[cfg(feature = "alphabets")]
+const DEVANAGARI_SYMBOLS: [char; 10] = consecutive('\u{0966}');
+#[cfg(feature = "alphabets")]
+const BENGALI_SYMBOLS: [char; 10] = consecutive('\u{09E6}');
+#[cfg(feature = "alphabets")]
+const THAI_SYMBOLS: [char; 10] = consecutive('\u{0E50}');
+#[cfg(feature = "alphabets")]
+const FULLWIDTH_SYMBOLS: [char; 10] = consecutive('\u{FF10}');
+// Superscript one, two and three come from Latin-1, before the rest of the block was added.
+const SUPERSCRIPT_SYMBOLS: [char; 10] = [
+    '\u{2070}', '\u{00B9}', '\u{00B2}', '\u{00B3}', '\u{2074}', '\u{2075}', '\u{2076}', '\u{2077}',
+    '\u{2078}', '\u{2079}',
+];
+/// The superscript digits, available without the `alphabets` feature for the exponents of the
+/// scientific notation.
+pub(crate) const SUPERSCRIPT_DIGITS: Alphabet<'static> = Alphabet {
+    symbols: &SUPERSCRIPT_SYMBOLS,
+    aliases: &[],
+    ignore_case: false,
+    plus: '\u{207A}',
+    minus: '\u{207B}',
+};
+#[cfg(feature = "alphabets")]
+const SUBSCRIPT_SYMBOLS: [char; 10] = consecutive('\u{2080}');
+#[cfg(feature = "alphabets")]
+const Z85_SYMBOLS: [char; 85] =
+    ascii(b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#");
+#[cfg(feature = "alphabets")]
+const ASCII85_SYMBOLS: [char; 85] = ascii(
+    b"!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstu",
+);
+
+impl Alphabet<'static> {
+    /// The binary digits `0` and `1`.
+    pub const BINARY: Alphabet<'static> = Alphabet::from_symbols(BASE36_SYMBOLS.split_at(2).0);
+
+    /// The octal digits `0-7`.
+    pub const OCTAL: Alphabet<'static> = Alphabet::from_symbols(BASE36_SYMBOLS.split_at(8).0);
+
+    /// The decimal digits `0-9`.
+    pub const DECIMAL: Alphabet<'static> = Alphabet::from_symbols(BASE36_SYMBOLS.split_at(10).0);
+
+    /// The hexadecimal digits `0-9` followed by the lowercase letters `a-f`.
+    pub const LOWER_HEX: Alphabet<'static> = Alphabet::from_symbols(BASE36_SYMBOLS.split_at(16).0);
+
+    /// The hexadecimal digits `0-9` followed by the uppercase letters `A-F`.
+    pub const UPPER_HEX: Alphabet<'static> = Alphabet::from_symbols(&UPPER_HEX_SYMBOLS);
+
+    /// The digits `0-9` followed by the lowercase letters `a-z`.
+    pub const BASE36: Alphabet<'static> = Alphabet::from_symbols(&BASE36_SYMBOLS);
+
+    /// The digits `0-9` followed by the uppercase letters `A-Z` and the lowercase letters `a-z`.
+    #[cfg(feature = "alphabets")]
+    pub const BASE62: Alphabet<'static> = Alphabet::from_symbols(&BASE62_SYMBOLS);
+
+    /// The Bitcoin base58 alphabet: `BASE62` without `0`, `O`, `I` and `l`, which are easily
+    /// confused with one another.
+    ///
+    /// See the [`base58`](../base58/index.html) module for encoding byte strings.
+    #[cfg(feature = "alphabets")]
+    pub const BASE58: Alphabet<'static> = Alphabet::from_symbols(&BASE58_SYMBOLS);
+
+    /// The base32 alphabet of RFC 4648: the uppercase letters `A-Z` followed by the digits `2-7`.
+    ///
+    /// Numbers are written positionally, with `A` as the zero digit; there is no padding, unlike
+    /// the byte-oriented encoding of the RFC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::BASE32.display(1234_u32).to_string(), "BGS");
+    /// assert_eq!(Alphabet::BASE32.parse::<u32>("BGS"), Ok(1234));
+    /// ```
+    #[cfg(feature = "alphabets")]
+    pub const BASE32: Alphabet<'static> = Alphabet::from_symbols(&BASE32_SYMBOLS);
+
+    /// The base32hex alphabet of RFC 4648: the digits `0-9` followed by the uppercase letters
+    /// `A-V`, which preserves the sort order of numbers of the same length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::BASE32HEX.display(1234_u32).to_string(), "16I");
+    /// assert_eq!(Alphabet::BASE32HEX.parse::<u32>("16I"), Ok(1234));
+    /// ```
+    #[cfg(feature = "alphabets")]
+    pub const BASE32HEX: Alphabet<'static> = Alphabet::from_symbols(&BASE32HEX_SYMBOLS);
+
+    /// The Z85 alphabet of ZeroMQ, which avoids quotes and backslashes so that it can be
+    /// embedded in source code.
+    ///
+    /// Its digits include `+` and `-`, so signed numbers need other signs (see
+    /// [`with_signs`](#method.with_signs)).
+    ///
+    /// See the [`base85`](../base85/index.html) module for the fixed-width, grouped encoding.
+    #[cfg(feature = "alphabets")]
+    pub const Z85: Alphabet<'static> = Alphabet::from_symbols(&Z85_SYMBOLS);
+
+    /// The Ascii85 alphabet: the 85 consecutive ASCII characters from `!` to `u`.
+    ///
+    /// Like `Z85`, its digits include `+` and `-`.
+    ///
+    /// See the [`base85`](../base85/index.html) module for the grouped encoding.
+    #[cfg(feature = "alphabets")]
+    pub const ASCII85: Alphabet<'static> = Alphabet::from_symbols(&ASCII85_SYMBOLS);
+
+    /// Crockford's base32 alphabet: the digits `0-9` followed by the uppercase letters `A-Z`
+    /// without `I`, `L`, `O` and `U`.
+    ///
+    /// When parsing, the case is ignored, `I` and `L` are read as `1` and `O` as `0`. See the
+    /// [`crockford`](../crockford/index.html) module for check symbols and hyphens.
+    #[cfg(feature = "alphabets")]
+    pub const CROCKFORD: Alphabet<'static> = Alphabet {
+        symbols: &CROCKFORD_SYMBOLS,
+        aliases: &[('I', 1), ('L', 1), ('O', 0)],
+        ignore_case: true,
+        plus: '+',
+        minus: '-',
+    };
+
+    /// The Eastern Arabic digits `٠١٢٣٤٥٦٧٨٩` (U+0660 to U+0669).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::EASTERN_ARABIC.display(2024_u32).to_string(), "٢٠٢٤");
+    /// assert_eq!(Alphabet::EASTERN_ARABIC.parse::<u32>("٢٠٢٤"), Ok(2024));
+    /// ```
+    #[cfg(feature = "alphabets")]
+    pub const EASTERN_ARABIC: Alphabet<'static> = Alphabet::from_symbols(&EASTERN_ARABIC_SYMBOLS);
+
+    /// The Persian digits `۰۱۲۳۴۵۶۷۸۹` (U+06F0 to U+06F9).
+    #[cfg(feature = "alphabets")]
+    pub const PERSIAN: Alphabet<'static> = Alphabet::from_symbols(&PERSIAN_SYMBOLS);
+
+    /// The Devanagari digits `०१२३४५६७८९` (U+0966 to U+096F).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::DEVANAGARI.display(108_u32).to_string(), "१०८");
+    /// ```
+    #[cfg(feature = "alphabets")]
+    pub const DEVANAGARI: Alphabet<'static> = Alphabet::from_symbols(&DEVANAGARI_SYMBOLS);
+
+    /// The Bengali digits `০১২৩৪৫৬৭৮৯` (U+09E6 to U+09EF).
+    #[cfg(feature = "alphabets")]
+    pub const BENGALI: Alphabet<'static> = Alphabet::from_symbols(&BENGALI_SYMBOLS);
+
+    /// The Thai digits `๐๑๒๓๔๕๖๗๘๙` (U+0E50 to U+0E59).
+    #[cfg(feature = "alphabets")]
+    pub const THAI: Alphabet<'static> = Alphabet::from_symbols(&THAI_SYMBOLS);
+
+    /// The fullwidth digits `０１２３４５６７８９` (U+FF10 to U+FF19), used in East Asian
+    /// typography, with the fullwidth signs `＋` and `－`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(Alphabet::FULLWIDTH.display(42_u8).to_string(), "４２");
+    /// assert_eq!(Alphabet::FULLWIDTH.parse::<u8>("４２"), Ok(42));
+    /// ```
+    #[cfg(feature = "alphabets")]
+    pub const FULLWIDTH: Alphabet<'static> = Alphabet {
+        symbols: &FULLWIDTH_SYMBOLS,
+        aliases: &[],
+        ignore_case: false,
+        plus: '\u{FF0B}',
+        minus: '\u{FF0D}',
+    };
+
+    /// The superscript digits `⁰¹²³⁴⁵⁶⁷⁸⁹`, with the superscript signs `⁺` and `⁻`.
+    ///
+    /// `¹`, `²` and `³` (U+00B9, U+00B2 and U+00B3) are not contiguous with the others (U+2070
+    /// and U+2074 to U+2079).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// assert_eq!(format!("x{}", Alphabet::SUPERSCRIPT.display(123_u8)), "x¹²³");
+    /// assert_eq!(Alphabet::SUPERSCRIPT.display_signed(-10_i8).to_string(), "⁻¹⁰");
+    /// assert_eq!(Alphabet::SUPERSCRIPT.parse::<u8>("²⁰"), Ok(20));
+    /// ```
+    #[cfg(feature = "alphabets")]
+    pub const SUPERSCRIPT: Alphabet<'static> = SUPERSCRIPT_DIGITS;
+
+    /// The subscript digits `₀₁₂₃₄₅₆₇₈₉` (U+2080 to U+2089), with the subscript signs `₊` and
+    /// `₋`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// let count = |n: u8| Alphabet::SUBSCRIPT.display(n);
+    /// assert_eq!(format!("C{}H{}O{}", count(6), count(12), count(6)), "C₆H₁₂O₆");
+    /// ```
+    #[cfg(feature = "alphabets")]
+    pub const SUBSCRIPT: Alphabet<'static> = Alphabet {
+        symbols: &SUBSCRIPT_SYMBOLS,
+        aliases: &[],
+        ignore_case: false,
+        plus: '\u{208A}',
+        minus: '\u{208B}',
+    };
+}
+
+/// Formats `number` using the `Alphabet::BASE62` alphabet.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::to_base62;
+///
+/// assert_eq!(to_base62(3843_u32), "zz");
+/// assert_eq!(to_base62(0_u32), "0");
+/// ```
+#[cfg(all(feature = "alloc", feature = "alphabets"))]
+pub fn to_base62<T: IntoDigits>(number: T) -> String {
+    Alphabet::BASE62.display(number).to_string()
+}
+
+/// Parses a number written with the `Alphabet::BASE62` alphabet.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::from_base62;
+///
+/// assert_eq!(from_base62::<u32>("zz"), Ok(3843));
+/// assert!(from_base62::<u32>("z-z").is_err());
+/// ```
+#[cfg(feature = "alphabets")]
+pub fn from_base62<T: IntoDigits>(s: &str) -> Result<T, ParseError> {
+    Alphabet::BASE62.parse(s)
+}
+
+/// An `Alphabet` owning its symbols and aliases, such as an alphabet read from a configuration.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::{Alphabet, OwnedAlphabet};
+///
+/// let owned = OwnedAlphabet::from(Alphabet::new(&['o', 'x']).unwrap());
+/// assert_eq!(owned.as_alphabet().display(5_u8).to_string(), "xox");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OwnedAlphabet {
+    symbols: Vec<char>,
+    aliases: Vec<(char, usize)>,
+    ignore_case: bool,
+    plus: char,
+    minus: char,
+}
+
+#[cfg(feature = "alloc")]
+impl OwnedAlphabet {
+    /// Returns the alphabet, borrowing its symbols and aliases.
+    pub fn as_alphabet(&self) -> Alphabet<'_> {
+        Alphabet {
+            symbols: &self.symbols,
+            aliases: &self.aliases,
+            ignore_case: self.ignore_case,
+            plus: self.plus,
+            minus: self.minus,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<Alphabet<'a>> for OwnedAlphabet {
+    fn from(alphabet: Alphabet<'a>) -> Self {
+        OwnedAlphabet {
+            symbols: alphabet.symbols.to_vec(),
+            aliases: alphabet.aliases.to_vec(),
+            ignore_case: alphabet.ignore_case,
+            plus: alphabet.plus,
+            minus: alphabet.minus,
+        }
+    }
+}
+
+/// The built-in alphabets, whose symbols and aliases a deserialized `Alphabet` borrows.
+#[cfg(feature = "serde")]
+const BUILTIN_ALPHABETS: &[Alphabet<'static>] = &[
+    Alphabet::BINARY,
+    Alphabet::OCTAL,
+    Alphabet::DECIMAL,
+    Alphabet::LOWER_HEX,
+    Alphabet::UPPER_HEX,
+    Alphabet::BASE36,
+    #[cfg(feature = "alphabets")]
+    Alphabet::BASE62,
+    #[cfg(feature = "alphabets")]
+    Alphabet::BASE58,
+    #[cfg(feature = "alphabets")]
+    Alphabet::BASE32,
+    #[cfg(feature = "alphabets")]
+    Alphabet::BASE32HEX,
+    #[cfg(feature = "alphabets")]
+    Alphabet::Z85,
+    #[cfg(feature = "alphabets")]
+    Alphabet::ASCII85,
+    #[cfg(feature = "alphabets")]
+    Alphabet::CROCKFORD,
+    #[cfg(feature = "alphabets")]
+    Alphabet::EASTERN_ARABIC,
+    #[cfg(feature = "alphabets")]
+    Alphabet::PERSIAN,
+    #[cfg(feature = "alphabets")]
+    Alphabet::DEVANAGARI,
+    #[cfg(feature = "alphabets")]
+    Alphabet::BENGALI,
+    #[cfg(feature = "alphabets")]
+    Alphabet::THAI,
+    #[cfg(feature = "alphabets")]
+    Alphabet::FULLWIDTH,
+    #[cfg(feature = "alphabets")]
+    Alphabet::SUPERSCRIPT,
+    #[cfg(feature = "alphabets")]
+    Alphabet::SUBSCRIPT,
+];
+
+/// Serializes the symbols as a string, along with the aliases, the case sensitivity and the
+/// signs.
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for Alphabet<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AlphabetRepr {
+            symbols: self.symbols.iter().collect(),
+            aliases: self.aliases.to_vec(),
+            ignore_case: self.ignore_case,
+            plus: self.plus,
+            minus: self.minus,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes an alphabet made of the symbols and the aliases of built-in alphabets, with any
+/// case sensitivity and signs, validating it as its constructors do.
+///
+/// An `Alphabet` borrows its symbols as `char`s, which cannot be borrowed from the serialized
+/// string, so that only the symbols and the aliases of the built-in alphabets, which live for
+/// `'static`, can be used. Other alphabets are deserialized as an `OwnedAlphabet`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Alphabet<'static> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let repr = AlphabetRepr::deserialize(deserializer)?;
+        let symbols = BUILTIN_ALPHABETS
+            .iter()
+            .map(Alphabet::symbols)
+            .find(|symbols| symbols.iter().copied().eq(repr.symbols.chars()))
+            .ok_or_else(|| D::Error::custom("not the symbols of a built-in alphabet"))?;
+        let aliases = BUILTIN_ALPHABETS
+            .iter()
+            .map(Alphabet::aliases)
+            .find(|&aliases| aliases == repr.aliases.as_slice())
+            .ok_or_else(|| D::Error::custom("not the aliases of a built-in alphabet"))?;
+        repr.build(symbols, aliases).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes the alphabet as an `Alphabet`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for OwnedAlphabet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_alphabet().serialize(serializer)
+    }
+}
+
+/// Deserializes any alphabet, validating it as the constructors of `Alphabet` do.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OwnedAlphabet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let repr = AlphabetRepr::deserialize(deserializer)?;
+        let symbols: Vec<char> = repr.symbols.chars().collect();
+        repr.build(&symbols, &repr.aliases)
+            .map_err(D::Error::custom)?;
+
+        Ok(OwnedAlphabet {
+            symbols,
+            aliases: repr.aliases,
+            ignore_case: repr.ignore_case,
+            plus: repr.plus,
+            minus: repr.minus,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct AlphabetRepr {
+    symbols: String,
+    #[serde(default)]
+    aliases: Vec<(char, usize)>,
+    #[serde(default)]
+    ignore_case: bool,
+    #[serde(default = "plus_sign")]
+    plus: char,
+    #[serde(default = "minus_sign")]
+    minus: char,
+}
+
+#[cfg(feature = "serde")]
+impl AlphabetRepr {
+    /// Builds the alphabet of `symbols` and `aliases` with the other options.
+    fn build<'a>(
+        &self,
+        symbols: &'a [char],
+        aliases: &'a [(char, usize)],
+    ) -> Result<Alphabet<'a>, AlphabetError> {
+        let alphabet = Alphabet::new(symbols)
+            .and_then(|alphabet| alphabet.with_aliases(aliases))
+            .and_then(|alphabet| alphabet.with_signs(self.plus, self.minus))?;
+        if self.ignore_case {
+            alphabet.with_ignore_case()
+        } else {
+            Ok(alphabet)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn plus_sign() -> char {
+    '+'
+}
+
+#[cfg(feature = "serde")]
+fn minus_sign() -> char {
+    '-'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        // Signs which are not symbols of the alphabet.
+        let signed = Alphabet::DECIMAL.with_signs('p', 'm').unwrap();
+        let ignore_case = Alphabet::LOWER_HEX.with_ignore_case().unwrap();
+        for &alphabet in &[Alphabet::DECIMAL, Alphabet::BASE36, signed, ignore_case] {
+            let json = serde_json::to_string(&alphabet).unwrap();
+            assert_eq!(serde_json::from_str::<Alphabet>(&json).unwrap(), alphabet);
+        }
+
+        let custom = r#"{"symbols":"ox"}"#;
+        assert!(serde_json::from_str::<Alphabet>(custom).is_err());
+        let aliased = r#"{"symbols":"0123456789","aliases":[["_",0]]}"#;
+        assert!(serde_json::from_str::<Alphabet>(aliased).is_err());
+    }
+
+    #[cfg(all(feature = "serde", feature = "alphabets"))]
+    #[test]
+    fn test_serde_round_trip_aliases() {
+        for &alphabet in &[Alphabet::FULLWIDTH, Alphabet::CROCKFORD] {
+            let json = serde_json::to_string(&alphabet).unwrap();
+            assert_eq!(serde_json::from_str::<Alphabet>(&json).unwrap(), alphabet);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_owned() {
+        let alphabet = Alphabet::BASE36
+            .with_aliases(&[('_', 0)])
+            .and_then(Alphabet::with_ignore_case)
+            .and_then(|alphabet| alphabet.with_signs('~', '^'))
+            .unwrap();
+        let json = serde_json::to_string(&alphabet).unwrap();
+        let owned: OwnedAlphabet = serde_json::from_str(&json).unwrap();
+        assert_eq!(owned.as_alphabet(), alphabet);
+        assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+
+        let owned: OwnedAlphabet = serde_json::from_str(r#"{"symbols":"ox"}"#).unwrap();
+        assert_eq!(owned.as_alphabet().display(5_u8).to_string(), "xox");
+        assert_eq!(owned.as_alphabet().minus_sign(), '-');
+
+        let duplicate = serde_json::from_str::<OwnedAlphabet>(r#"{"symbols":"oxo"}"#);
+        assert!(duplicate.unwrap_err().to_string().contains("'o'"));
+    }
+
+    #[cfg(feature = "alphabets")]
+    #[test]
+    fn test_builtin_alphabets_are_valid() {
+        for alphabet in &[
+            Alphabet::BINARY,
+            Alphabet::OCTAL,
+            Alphabet::DECIMAL,
+            Alphabet::LOWER_HEX,
+            Alphabet::UPPER_HEX,
+        ] {
+            let signs = (alphabet.plus_sign(), alphabet.minus_sign());
+            assert_eq!(
+                Alphabet::new(alphabet.symbols()).and_then(|a| a.with_signs(signs.0, signs.1)),
+                Ok(*alphabet)
+            );
+        }
+        assert_eq!(
+            Alphabet::new(Alphabet::BASE36.symbols()),
+            Ok(Alphabet::BASE36)
+        );
+        assert_eq!(
+            Alphabet::new(Alphabet::BASE62.symbols()),
+            Ok(Alphabet::BASE62)
+        );
+        assert_eq!(
+            Alphabet::new(Alphabet::BASE58.symbols()),
+            Ok(Alphabet::BASE58)
+        );
+        assert_eq!(
+            Alphabet::new(Alphabet::BASE32.symbols()),
+            Ok(Alphabet::BASE32)
+        );
+        assert_eq!(
+            Alphabet::new(Alphabet::BASE32HEX.symbols()),
+            Ok(Alphabet::BASE32HEX)
+        );
+        assert_eq!(Alphabet::new(Alphabet::Z85.symbols()), Ok(Alphabet::Z85));
+        assert_eq!(
+            Alphabet::new(Alphabet::ASCII85.symbols()),
+            Ok(Alphabet::ASCII85)
+        );
+        assert_eq!(
+            Alphabet::new(Alphabet::CROCKFORD.symbols())
+                .and_then(|alphabet| alphabet.with_aliases(Alphabet::CROCKFORD.aliases()))
+                .and_then(Alphabet::with_ignore_case),
+            Ok(Alphabet::CROCKFORD)
+        );
+    }
+
+    #[test]
+    fn test_too_many_symbols() {
+        let mut symbols = ['\0'; MAX_SYMBOLS + 1];
+        for (n, symbol) in (0..).zip(symbols.iter_mut()) {
+            *symbol = core::char::from_u32(0x100 + n).unwrap();
+        }
+        assert_eq!(Alphabet::new(&symbols), Err(AlphabetError::TooManySymbols));
+        assert!(Alphabet::new(&symbols[1..]).is_ok());
+    }
+
+    #[cfg(feature = "alphabets")]
+    #[test]
+    fn test_unicode_digits_match_char_properties() {
+        for alphabet in &[
+            Alphabet::EASTERN_ARABIC,
+            Alphabet::PERSIAN,
+            Alphabet::DEVANAGARI,
+            Alphabet::BENGALI,
+            Alphabet::THAI,
+            Alphabet::FULLWIDTH,
+            Alphabet::SUPERSCRIPT,
+            Alphabet::SUBSCRIPT,
+        ] {
+            assert_eq!(alphabet.radix(), 10);
+            assert!(alphabet.symbols().iter().all(|c| c.is_numeric()));
+            let signs = (alphabet.plus_sign(), alphabet.minus_sign());
+            assert_eq!(
+                Alphabet::new(alphabet.symbols()).and_then(|a| a.with_signs(signs.0, signs.1)),
+                Ok(*alphabet)
+            );
+        }
+    }
+
+    #[cfg(all(feature = "std", feature = "alphabets"))]
+    #[test]
+    fn test_unicode_round_trip() {
+        let s = Alphabet::THAI.display(u64::MAX).to_string();
+        assert_eq!(s.chars().count(), 20);
+        assert_eq!(Alphabet::THAI.parse::<u64>(&s), Ok(u64::MAX));
+    }
+
+    #[cfg(all(feature = "std", feature = "alphabets"))]
+    #[test]
+    fn test_superscript_gaps() {
+        assert_eq!(
+            Alphabet::SUPERSCRIPT.display(1234567890_u32).to_string(),
+            "¹²³⁴⁵⁶⁷⁸⁹⁰"
+        );
+        assert_eq!(Alphabet::SUPERSCRIPT.digit('\u{2071}'), None);
+        assert_eq!(Alphabet::SUPERSCRIPT.digit('\u{00B9}'), Some(1));
+    }
+
+    #[cfg(feature = "alphabets")]
+    #[test]
+    fn test_ascii85_is_contiguous() {
+        for (idx, &symbol) in Alphabet::ASCII85.symbols().iter().enumerate() {
+            assert_eq!(symbol as usize, '!' as usize + idx);
+        }
+    }
+
+    #[cfg(all(feature = "std", feature = "alphabets"))]
+    #[test]
+    fn test_base32hex_preserves_order() {
+        let a = Alphabet::BASE32HEX.display(1000_u32).to_string();
+        let b = Alphabet::BASE32HEX.display(1023_u32).to_string();
+        assert!(a < b);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "alphabets"))]
+    #[test]
+    fn test_base62_round_trip() {
+        for &n in &[0_u64, 1, 61, 62, 3843, 3844, u64::MAX] {
+            assert_eq!(from_base62::<u64>(&to_base62(n)), Ok(n));
+        }
+    }
+
+    #[cfg(all(feature = "alloc", feature = "alphabets"))]
+    #[test]
+    fn test_base62_max() {
+        assert_eq!(to_base62(u64::MAX), "LygHa16AHYF");
+        assert_eq!(from_base62::<u64>("LygHa16AHYG"), Err(ParseError::Overflow));
+    }
+}
@@ -0,0 +1,334 @@
+//! Balanced representations of signed numbers, where the digits range from `-k` to `k` under an
+//! odd radix `2k + 1`.
+//!
+//! Every integer, positive or negative, is written without a sign: in balanced ternary with the
+//! symbols `T`, `0` and `1` (`T` standing for -1), 5 is `1TT` (9 - 3 - 1) and -5 is `T11`.
+use crate::alphabet::{Alphabet, AlphabetError};
+use crate::parse::ParseError;
+use crate::{IntoDigits, RadixMath, SignedInteger};
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+
+/// The largest number of digits of a `u128` under the smallest balanced radix, 3.
+const MAX_DIGITS: usize = 82;
+
+/// An odd number of symbols, where the middle one represents the digit 0, the ones before it the
+/// negative digits and the ones after it the positive digits.
+///
+/// The radix of a balanced alphabet is its number of symbols.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BalancedAlphabet<'a> {
+    alphabet: Alphabet<'a>,
+}
+
+impl<'a> BalancedAlphabet<'a> {
+    /// Creates a new `BalancedAlphabet` from its `symbols`, in increasing order of digit value.
+    ///
+    /// Returns an `Err(AlphabetError)` if there are fewer than 3 symbols, an even number of
+    /// symbols, or if a symbol is repeated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::AlphabetError;
+    /// use radixal::balanced::BalancedAlphabet;
+    ///
+    /// let alphabet = BalancedAlphabet::new(&['=', '-', '0', '+', '#']).unwrap();
+    /// assert_eq!(alphabet.radix(), 5);
+    /// assert_eq!(alphabet.display(-7_i32).to_string(), "-=");
+    ///
+    /// assert_eq!(BalancedAlphabet::new(&['-', '+']), Err(AlphabetError::EvenSymbols));
+    /// ```
+    pub fn new(symbols: &'a [char]) -> Result<Self, AlphabetError> {
+        let alphabet = Alphabet::new(symbols)?;
+        if symbols.len().is_multiple_of(2) {
+            return Err(AlphabetError::EvenSymbols);
+        }
+
+        Ok(BalancedAlphabet { alphabet })
+    }
+
+    /// Returns the radix of the alphabet, i.e. its number of symbols.
+    pub fn radix(&self) -> usize {
+        self.alphabet.radix()
+    }
+
+    /// Returns the symbols, in increasing order of digit value.
+    pub fn symbols(&self) -> &'a [char] {
+        self.alphabet.symbols()
+    }
+
+    /// Returns the symbol representing `digit`, or `None` if it is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::balanced::BalancedAlphabet;
+    ///
+    /// assert_eq!(BalancedAlphabet::TERNARY.symbol(-1), Some('T'));
+    /// assert_eq!(BalancedAlphabet::TERNARY.symbol(2), None);
+    /// ```
+    pub fn symbol(&self, digit: isize) -> Option<char> {
+        let idx = digit.checked_add(self.max_digit())?;
+        self.alphabet.symbol(usize::try_from(idx).ok()?)
+    }
+
+    /// Returns the digit represented by `symbol`, or `None` if it is not a symbol.
+    pub fn digit(&self, symbol: char) -> Option<isize> {
+        // Alphabets hold at most `u8::MAX` symbols.
+        self.alphabet
+            .digit(symbol)
+            .map(|idx| idx as isize - self.max_digit())
+    }
+
+    /// Wraps `number` so that it is displayed using this alphabet.
+    pub fn display<S: SignedInteger>(&self, number: S) -> BalancedDisplay<'a, S> {
+        BalancedDisplay {
+            number,
+            alphabet: *self,
+        }
+    }
+
+    /// Parses a number written with this alphabet.
+    ///
+    /// Returns an `Err(ParseError)` if the string is empty, contains a symbol outside of the
+    /// alphabet or represents a number out of the range of `S`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::balanced::BalancedAlphabet;
+    /// use radixal::parse::ParseError;
+    ///
+    /// assert_eq!(BalancedAlphabet::TERNARY.parse::<i32>("1TT"), Ok(5));
+    /// assert_eq!(BalancedAlphabet::SIGNS.parse::<i32>("-++"), Ok(-5));
+    /// assert_eq!(BalancedAlphabet::TERNARY.parse::<i8>("11111"), Ok(121));
+    /// assert_eq!(BalancedAlphabet::TERNARY.parse::<i8>("111111"), Err(ParseError::Overflow));
+    /// ```
+    pub fn parse<S: SignedInteger>(&self, s: &str) -> Result<S, ParseError> {
+        let radix = self.radix() as i128;
+        let mut number: Option<i128> = None;
+
+        for symbol in s.chars() {
+            let digit = self.digit(symbol).ok_or(ParseError::InvalidDigit(symbol))?;
+            number = Some(
+                push_digit(number.unwrap_or(0), radix, digit as i128)
+                    .ok_or(ParseError::Overflow)?,
+            );
+        }
+
+        S::from(number.ok_or(ParseError::Empty)?).ok_or(ParseError::Overflow)
+    }
+
+    /// Returns `k`, the largest digit.
+    fn max_digit(&self) -> isize {
+        (self.radix() / 2) as isize
+    }
+}
+
+const TERNARY_SYMBOLS: [char; 3] = ['T', '0', '1'];
+const SIGNS_SYMBOLS: [char; 3] = ['-', '0', '+'];
+
+impl BalancedAlphabet<'static> {
+    /// Balanced ternary, with `T` for the digit -1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::balanced::BalancedAlphabet;
+    ///
+    /// assert_eq!(BalancedAlphabet::TERNARY.display(5_i32).to_string(), "1TT");
+    /// assert_eq!(BalancedAlphabet::TERNARY.display(-5_i32).to_string(), "T11");
+    /// ```
+    pub const TERNARY: BalancedAlphabet<'static> = BalancedAlphabet {
+        alphabet: Alphabet::from_symbols(&TERNARY_SYMBOLS),
+    };
+
+    /// Balanced ternary, with `-`, `0` and `+` for the digits -1, 0 and 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::balanced::BalancedAlphabet;
+    ///
+    /// assert_eq!(BalancedAlphabet::SIGNS.display(8_i32).to_string(), "+0-");
+    /// ```
+    pub const SIGNS: BalancedAlphabet<'static> = BalancedAlphabet {
+        alphabet: Alphabet::from_symbols(&SIGNS_SYMBOLS),
+    };
+}
+
+/// Displays a signed number in a balanced representation.
+///
+/// It honors the width, fill and alignment flags of the formatter; the `0` flag pads with the zero
+/// digit instead.
+///
+/// This struct is created by the [`display`](struct.BalancedAlphabet.html#method.display) method
+/// on `BalancedAlphabet`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::balanced::BalancedAlphabet;
+///
+/// let display = BalancedAlphabet::TERNARY.display(-5_i32);
+/// assert_eq!(format!("{:>5}|{:05}", display, display), "  T11|00T11");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BalancedDisplay<'a, S: SignedInteger> {
+    number: S,
+    alphabet: BalancedAlphabet<'a>,
+}
+
+impl<'a, S: SignedInteger> fmt::Display for BalancedDisplay<'a, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut digits = [0; MAX_DIGITS];
+        let len = balanced_digits(self.number.magnitude(), &self.alphabet, &mut digits)?;
+        let negative = self.number.is_negative();
+
+        let width = f.width().unwrap_or(0);
+        let padding = width.saturating_sub(len);
+        let (zeroes, before, after) = match f.align() {
+            _ if f.sign_aware_zero_pad() => (padding, 0, 0),
+            Some(fmt::Alignment::Left) => (0, 0, padding),
+            Some(fmt::Alignment::Center) => (0, padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (0, padding, 0),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+
+        let zero = self.alphabet.symbol(0).ok_or(fmt::Error)?;
+        for _ in 0..zeroes {
+            f.write_char(zero)?;
+        }
+
+        // Every digit of `-n` is the opposite of the one of `n`.
+        for &digit in digits[..len].iter().rev() {
+            let digit = if negative { -digit } else { digit };
+            f.write_char(self.alphabet.symbol(digit).ok_or(fmt::Error)?)?;
+        }
+
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `number * radix + digit`, or `None` if it overflows.
+fn push_digit(number: i128, radix: i128, digit: i128) -> Option<i128> {
+    match number.checked_mul(radix) {
+        Some(number) => number.checked_add(digit),
+        // The product may overflow by less than the digit when the result is close to the
+        // bounds, e.g. for `i128::MIN`.
+        None => {
+            let sign = number.signum();
+            (number - sign)
+                .checked_mul(radix)?
+                .checked_add(digit + sign * radix)
+        }
+    }
+}
+
+/// Writes the balanced digits of `magnitude` into `digits`, least significant first, and returns
+/// their number.
+fn balanced_digits<T: IntoDigits>(
+    mut magnitude: T,
+    alphabet: &BalancedAlphabet,
+    digits: &mut [isize],
+) -> Result<usize, fmt::Error> {
+    // Alphabets hold at most `u8::MAX` symbols, so the radix fits in any `T`.
+    let radix = T::from_usize(alphabet.radix()).ok_or(fmt::Error)?;
+    let max_digit = alphabet.max_digit();
+
+    // A digit above `k` is written as `digit - radix`, with a carry into the next one.
+    let mut len = 0;
+    while magnitude > T::zero() || len == 0 {
+        let digit = (magnitude % radix).to_usize().ok_or(fmt::Error)? as isize;
+        magnitude = magnitude / radix;
+        digits[len] = if digit > max_digit {
+            magnitude = magnitude + T::one();
+            digit - alphabet.radix() as isize
+        } else {
+            digit
+        };
+        len += 1;
+    }
+
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ternary_small_numbers() {
+        let expected = [
+            "T00", "T01", "T1T", "T10", "T11", "TT", "T0", "T1", "T", "0", "1", "1T",
+        ];
+        for (n, &s) in (-9_i32..=2).zip(&expected) {
+            assert_eq!(BalancedAlphabet::TERNARY.display(n).to_string(), s);
+            assert_eq!(BalancedAlphabet::TERNARY.parse::<i32>(s), Ok(n));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_extremes_round_trip() {
+        for &n in &[i8::MIN, -1, 0, 1, i8::MAX] {
+            let s = BalancedAlphabet::TERNARY.display(n).to_string();
+            assert_eq!(BalancedAlphabet::TERNARY.parse::<i8>(&s), Ok(n));
+        }
+
+        for &n in &[i128::MIN, i128::MIN + 1, i128::MAX] {
+            let s = BalancedAlphabet::SIGNS.display(n).to_string();
+            assert_eq!(BalancedAlphabet::SIGNS.parse::<i128>(&s), Ok(n));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_larger_radix() {
+        let symbols: Vec<char> = "ZYXWVUTSRQPONMLKJIHGFEDCBA0abcdefghijklmnopqrstuvwxyz"
+            .chars()
+            .collect();
+        let alphabet = BalancedAlphabet::new(&symbols).unwrap();
+        assert_eq!(alphabet.radix(), 53);
+
+        for &n in &[i64::MIN, -27, -26, 26, 27, i64::MAX] {
+            let s = alphabet.display(n).to_string();
+            assert_eq!(alphabet.parse::<i64>(&s), Ok(n));
+        }
+        assert_eq!(alphabet.display(27_i64).to_string(), "aZ");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            BalancedAlphabet::TERNARY.parse::<i32>(""),
+            Err(ParseError::Empty)
+        );
+        assert_eq!(
+            BalancedAlphabet::TERNARY.parse::<i32>("12"),
+            Err(ParseError::InvalidDigit('2'))
+        );
+        assert_eq!(
+            BalancedAlphabet::TERNARY.parse::<i8>("TTTTTT"),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_too_few_symbols() {
+        assert_eq!(
+            BalancedAlphabet::new(&['0']),
+            Err(AlphabetError::TooFewSymbols)
+        );
+    }
+}
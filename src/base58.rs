@@ -0,0 +1,405 @@
+//! Base58 encoding of byte strings, as used by Bitcoin, and its Base58Check variant.
+//!
+//! Numbers are formatted and parsed through `Alphabet::BASE58`, like any other alphabet. Byte
+//! strings, on the other hand, keep their leading zero bytes: each one is encoded as a leading
+//! `1` (the zero digit), followed by the base58 digits of the remaining bytes treated as a
+//! big-endian number.
+use crate::alphabet::Alphabet;
+use crate::parse::ParseError;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "base58check")]
+use crate::{IntoDigits, RadixMath};
+
+const RADIX: u32 = 58;
+
+/// The radix of the bytes of a number.
+#[cfg(feature = "base58check")]
+const BYTE_RADIX: usize = 256;
+
+/// Encodes `bytes` in base58.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base58;
+///
+/// assert_eq!(base58::encode(b"bbb"), "a3gV");
+/// assert_eq!(base58::encode(&[0, 0, 1]), "112");
+/// ```
+pub fn encode(bytes: &[u8]) -> String {
+    let zeroes = bytes.iter().take_while(|&&b| b == 0).count();
+
+    // Little endian base58 digits of the remaining bytes.
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &byte in &bytes[zeroes..] {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % RADIX) as u8;
+            carry /= RADIX;
+        }
+
+        while carry > 0 {
+            digits.push((carry % RADIX) as u8);
+            carry /= RADIX;
+        }
+    }
+
+    let symbols = Alphabet::BASE58.symbols();
+    core::iter::repeat_n(symbols[0], zeroes)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|&digit| symbols[usize::from(digit)]),
+        )
+        .collect()
+}
+
+/// Decodes a base58 string into bytes.
+///
+/// Returns an `Err(ParseError::InvalidDigit)` if a symbol is not part of `Alphabet::BASE58`.
+/// Unlike numbers, the empty string is valid and decodes to no bytes.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base58;
+/// use radixal::parse::ParseError;
+///
+/// assert_eq!(base58::decode("a3gV"), Ok(b"bbb".to_vec()));
+/// assert_eq!(base58::decode("112"), Ok(vec![0, 0, 1]));
+/// assert_eq!(base58::decode("0"), Err(ParseError::InvalidDigit('0')));
+/// ```
+pub fn decode(s: &str) -> Result<Vec<u8>, ParseError> {
+    let zero = Alphabet::BASE58.symbols()[0];
+    let zeroes = s.chars().take_while(|&c| c == zero).count();
+
+    // Little endian bytes of the remaining digits.
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len() * 733 / 1000 + 1);
+    for symbol in s.chars().skip(zeroes) {
+        let mut carry = Alphabet::BASE58
+            .digit(symbol)
+            .ok_or(ParseError::InvalidDigit(symbol))? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * RADIX;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    bytes.resize(bytes.len() + zeroes, 0);
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Errors that can occur when decoding a Base58Check string.
+#[cfg(feature = "base58check")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckError {
+    /// The string is not valid base58.
+    Parse(ParseError),
+    /// The decoded bytes are too short to hold a checksum.
+    TooShort,
+    /// The checksum does not match the payload.
+    InvalidChecksum,
+}
+
+#[cfg(feature = "base58check")]
+impl core::fmt::Display for CheckError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            CheckError::Parse(err) => err.fmt(f),
+            CheckError::TooShort => write!(f, "missing checksum"),
+            CheckError::InvalidChecksum => write!(f, "invalid checksum"),
+        }
+    }
+}
+
+#[cfg(all(feature = "base58check", feature = "std"))]
+impl std::error::Error for CheckError {}
+
+#[cfg(feature = "base58check")]
+impl From<ParseError> for CheckError {
+    fn from(err: ParseError) -> Self {
+        CheckError::Parse(err)
+    }
+}
+
+/// Encodes `payload` in Base58Check, appending the 4 bytes returned by `checksum`.
+///
+/// The hash is left to the caller; Bitcoin uses the first 4 bytes of a double SHA-256 of the
+/// payload.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base58;
+///
+/// // A toy checksum, for illustration purposes only.
+/// let checksum = |payload: &[u8]| [payload.len() as u8; 4];
+///
+/// let encoded = base58::encode_check(b"bbb", checksum);
+/// assert_eq!(base58::decode_check(&encoded, checksum), Ok(b"bbb".to_vec()));
+/// ```
+#[cfg(feature = "base58check")]
+pub fn encode_check<F>(payload: &[u8], checksum: F) -> String
+where
+    F: Fn(&[u8]) -> [u8; 4],
+{
+    let mut bytes = Vec::with_capacity(payload.len() + 4);
+    bytes.extend_from_slice(payload);
+    bytes.extend_from_slice(&checksum(payload));
+    encode(&bytes)
+}
+
+/// Decodes a Base58Check string, verifying its trailing 4 bytes against `checksum`.
+///
+/// Returns an `Err(CheckError)` if the string is not valid base58, is too short to hold a
+/// checksum or if the checksum does not match.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base58::{self, CheckError};
+///
+/// let checksum = |payload: &[u8]| [payload.len() as u8; 4];
+///
+/// assert_eq!(base58::decode_check("2g", checksum), Err(CheckError::TooShort));
+/// assert_eq!(
+///     base58::decode_check("1111111", checksum),
+///     Err(CheckError::InvalidChecksum)
+/// );
+/// ```
+#[cfg(feature = "base58check")]
+pub fn decode_check<F>(s: &str, checksum: F) -> Result<Vec<u8>, CheckError>
+where
+    F: Fn(&[u8]) -> [u8; 4],
+{
+    let mut bytes = decode(s)?;
+    if bytes.len() < 4 {
+        return Err(CheckError::TooShort);
+    }
+
+    let split = bytes.len() - 4;
+    if checksum(&bytes[..split])[..] != bytes[split..] {
+        return Err(CheckError::InvalidChecksum);
+    }
+
+    bytes.truncate(split);
+    Ok(bytes)
+}
+
+/// Encodes `number` in Base58Check, using its big-endian bytes without leading zeroes as the
+/// payload.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base58;
+///
+/// let checksum = |payload: &[u8]| [payload.len() as u8; 4];
+///
+/// let encoded = base58::encode_check_number(0x0102_u32, checksum);
+/// assert_eq!(encoded, base58::encode_check(&[1, 2], checksum));
+/// ```
+#[cfg(feature = "base58check")]
+pub fn encode_check_number<T, F>(number: T, checksum: F) -> String
+where
+    T: IntoDigits,
+    F: Fn(&[u8]) -> [u8; 4],
+{
+    // The big-endian bytes are the digits under radix 256, which does not fit in a `u8`, whose
+    // numbers are a single byte.
+    let bytes: Vec<u8> = match T::from_usize(BYTE_RADIX) {
+        Some(radix) => number
+            .into_digits(radix)
+            .unwrap()
+            .map(|byte| byte.to_usize().unwrap_or_default() as u8)
+            .collect(),
+        None => alloc::vec![number.to_usize().unwrap_or_default() as u8],
+    };
+    let zeroes = bytes.iter().take_while(|&&b| b == 0).count();
+    encode_check(&bytes[zeroes..], checksum)
+}
+
+/// Decodes a Base58Check string into a number, reading the payload as big-endian bytes.
+///
+/// Returns an `Err(CheckError::Parse(ParseError::Overflow))` if the payload does not fit in
+/// `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base58::{self, CheckError};
+/// use radixal::parse::ParseError;
+///
+/// let checksum = |payload: &[u8]| [payload.len() as u8; 4];
+///
+/// let encoded = base58::encode_check_number(0x0102_u32, checksum);
+/// assert_eq!(base58::decode_check_number::<u32, _>(&encoded, checksum), Ok(0x0102));
+/// assert_eq!(
+///     base58::decode_check_number::<u8, _>(&encoded, checksum),
+///     Err(CheckError::Parse(ParseError::Overflow))
+/// );
+/// ```
+#[cfg(feature = "base58check")]
+pub fn decode_check_number<T, F>(s: &str, checksum: F) -> Result<T, CheckError>
+where
+    T: IntoDigits,
+    F: Fn(&[u8]) -> [u8; 4],
+{
+    let bytes = decode_check(s, checksum)?;
+    let zeroes = bytes.iter().take_while(|&&b| b == 0).count();
+    bytes[zeroes..]
+        .iter()
+        .try_fold(T::zero(), |number, &byte| {
+            let byte = T::from_usize(usize::from(byte))?;
+            if number.is_zero() {
+                Some(byte)
+            } else {
+                number
+                    .checked_mul(T::from_usize(BYTE_RADIX)?)?
+                    .checked_add(byte)
+            }
+        })
+        .ok_or(CheckError::Parse(ParseError::Overflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from Bitcoin Core's `base58_encode_decode.json`.
+    const VECTORS: &[(&str, &str)] = &[
+        ("", ""),
+        ("61", "2g"),
+        ("626262", "a3gV"),
+        ("636363", "aPEr"),
+        (
+            "73696d706c792061206c6f6e6720737472696e67",
+            "2cFupjhnEsSn59qHXstmK2ffpLv2",
+        ),
+        (
+            "00eb15231dfceb60925886b67d065299925915aeb172c06647",
+            "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+        ),
+        ("516b6fcd0f", "ABnLTmg"),
+        ("bf4f89001e670274dd", "3SEo3LWLoPntC"),
+        ("572e4794", "3EFU7m"),
+        ("ecac89cad93923c02321", "EJDM8drfXA6uyA"),
+        ("10c8511e", "Rt5zm"),
+        ("00000000000000000000", "1111111111"),
+    ];
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&s[idx..idx + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_vectors() {
+        for &(hex, encoded) in VECTORS {
+            assert_eq!(encode(&from_hex(hex)), encoded);
+        }
+    }
+
+    #[test]
+    fn test_decode_vectors() {
+        for &(hex, encoded) in VECTORS {
+            assert_eq!(decode(encoded), Ok(from_hex(hex)));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decode_only_zeroes() {
+        assert_eq!(decode("1"), Ok(vec![0]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_numbers_use_the_same_alphabet() {
+        assert_eq!(
+            Alphabet::BASE58.display(0x10c8511e_u32).to_string(),
+            "Rt5zm"
+        );
+        assert_eq!(Alphabet::BASE58.parse::<u32>("Rt5zm"), Ok(0x10c8511e));
+    }
+
+    #[cfg(feature = "base58check")]
+    fn double_sha256(payload: &[u8]) -> [u8; 4] {
+        use sha2::{Digest, Sha256};
+
+        let hash = Sha256::digest(Sha256::digest(payload));
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    #[cfg(feature = "base58check")]
+    #[test]
+    fn test_bitcoin_address() {
+        let payload = from_hex("00010966776006953d5567439e5e39f86a0d273bee");
+        let address = "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM";
+
+        assert_eq!(encode_check(&payload, double_sha256), address);
+        assert_eq!(decode_check(address, double_sha256), Ok(payload));
+    }
+
+    #[cfg(feature = "base58check")]
+    #[test]
+    fn test_corrupted_address() {
+        let address = "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN";
+        assert_eq!(
+            decode_check(address, double_sha256),
+            Err(CheckError::InvalidChecksum)
+        );
+    }
+
+    #[cfg(feature = "base58check")]
+    #[test]
+    fn test_check_number_round_trip() {
+        for &n in &[0_u128, 1, 255, 256, u128::MAX] {
+            let encoded = encode_check_number(n, double_sha256);
+            assert_eq!(decode_check_number(&encoded, double_sha256), Ok(n));
+        }
+        for &n in &[0_u8, 1, u8::MAX] {
+            let encoded = encode_check_number(n, double_sha256);
+            assert_eq!(
+                encoded,
+                encode_check(&[n][..usize::from(n > 0)], double_sha256)
+            );
+            assert_eq!(decode_check_number(&encoded, double_sha256), Ok(n));
+        }
+    }
+
+    #[cfg(feature = "base58check")]
+    #[test]
+    fn test_check_number_wider_than_u128() {
+        use crate::digital::DigitalNumber;
+        use crate::digits_iterator::tests::Wide256;
+
+        let encoded = encode_check_number(Wide256::MAX, double_sha256);
+        assert_eq!(encoded, encode_check(&[0xff; 32], double_sha256));
+        assert_eq!(
+            decode_check_number(&encoded, double_sha256),
+            Ok(Wide256::MAX)
+        );
+        assert_eq!(
+            decode_check_number::<u128, _>(&encoded, double_sha256),
+            Err(CheckError::Parse(ParseError::Overflow))
+        );
+
+        let number = DigitalNumber(Wide256(1, 0));
+        let encoded = encode_check_number(number, double_sha256);
+        assert_eq!(decode_check_number(&encoded, double_sha256), Ok(number));
+    }
+}
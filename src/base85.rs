@@ -0,0 +1,336 @@
+//! Fixed-width base85 serialization of numbers, following the grouping rules of Z85 and
+//! Ascii85.
+//!
+//! Both encodings split the big-endian bytes of a number into groups of 4 bytes, each written as
+//! exactly 5 symbols. The full width of the type is always encoded, so that a `u128` always
+//! takes 20 symbols. They differ in how they handle the remaining bytes:
+//!
+//! * Z85 only encodes full groups, so narrower types (`u8`, `u16`) are padded to 4 bytes.
+//! * Ascii85 encodes a final group of `k` bytes as `k + 1` symbols, and abbreviates a full group
+//!   of zero bytes as `z`.
+//!
+//! For a plain positional representation in radix 85, use `Alphabet::Z85` or
+//! `Alphabet::ASCII85` directly.
+use crate::alphabet::Alphabet;
+use crate::parse::ParseError;
+use crate::{IntoDigits, RadixMath};
+use core::fmt::{self, Write};
+
+const GROUP_BYTES: usize = 4;
+const GROUP_SYMBOLS: usize = 5;
+const RADIX: u32 = 85;
+const ZERO_GROUP: char = 'z';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Flavor {
+    Z85,
+    Ascii85,
+}
+
+/// Displays a number with one of the grouped base85 encodings.
+///
+/// This struct is created by the [`z85`](fn.z85.html) and [`ascii85`](fn.ascii85.html)
+/// functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Base85Display<T: IntoDigits> {
+    number: T,
+    flavor: Flavor,
+}
+
+/// Displays `number` in Z85, as 5 symbols per 4 bytes of `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base85;
+///
+/// assert_eq!(base85::z85(0x864f_d26f_u32).to_string(), "Hello");
+/// assert_eq!(base85::z85(1_u64).to_string(), "0000000001");
+/// assert_eq!(base85::z85(1_u8).to_string(), "00001");
+/// ```
+pub fn z85<T: IntoDigits>(number: T) -> Base85Display<T> {
+    Base85Display {
+        number,
+        flavor: Flavor::Z85,
+    }
+}
+
+/// Displays `number` in Ascii85, as 5 symbols per 4 bytes of `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base85;
+///
+/// assert_eq!(base85::ascii85(0x4d61_6e20_u32).to_string(), "9jqo^");
+/// assert_eq!(base85::ascii85(1_u64).to_string(), "z!!!!\"");
+/// assert_eq!(base85::ascii85(1_u16).to_string(), "!!*");
+/// ```
+pub fn ascii85<T: IntoDigits>(number: T) -> Base85Display<T> {
+    Base85Display {
+        number,
+        flavor: Flavor::Ascii85,
+    }
+}
+
+impl<T: IntoDigits> fmt::Display for Base85Display<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Every supported type fits in a `u128`.
+        let bytes = self.number.to_u128().ok_or(fmt::Error)?.to_be_bytes();
+        let width = core::mem::size_of::<T>().min(bytes.len());
+
+        match self.flavor {
+            Flavor::Z85 => {
+                let padded = width.div_ceil(GROUP_BYTES) * GROUP_BYTES;
+                for group in bytes[bytes.len() - padded..].chunks(GROUP_BYTES) {
+                    write_group(f, group, &Alphabet::Z85, GROUP_SYMBOLS)?;
+                }
+            }
+            Flavor::Ascii85 => {
+                for group in bytes[bytes.len() - width..].chunks(GROUP_BYTES) {
+                    if group.len() == GROUP_BYTES && group.iter().all(|&b| b == 0) {
+                        f.write_char(ZERO_GROUP)?;
+                    } else {
+                        write_group(f, group, &Alphabet::ASCII85, group.len() + 1)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the first `len` symbols of a group, padded with zero bytes on the right.
+fn write_group(
+    f: &mut fmt::Formatter,
+    group: &[u8],
+    alphabet: &Alphabet,
+    len: usize,
+) -> fmt::Result {
+    let mut padded = [0; GROUP_BYTES];
+    padded[..group.len()].copy_from_slice(group);
+    let mut value = u32::from_be_bytes(padded);
+
+    let mut symbols = [0; GROUP_SYMBOLS];
+    for symbol in symbols.iter_mut().rev() {
+        *symbol = (value % RADIX) as usize;
+        value /= RADIX;
+    }
+
+    for &digit in &symbols[..len] {
+        f.write_char(alphabet.symbol(digit).ok_or(fmt::Error)?)?;
+    }
+
+    Ok(())
+}
+
+/// Parses a number written in Z85.
+///
+/// Returns an `Err(ParseError)` if the string is empty, if its length is not a multiple of 5, if
+/// a symbol is invalid or if the number is too large for `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base85;
+/// use radixal::parse::ParseError;
+///
+/// assert_eq!(base85::decode_z85::<u32>("Hello"), Ok(0x864f_d26f));
+/// assert_eq!(base85::decode_z85::<u8>("00001"), Ok(1));
+/// assert_eq!(base85::decode_z85::<u32>("Hell"), Err(ParseError::InvalidLength));
+/// ```
+pub fn decode_z85<T: IntoDigits>(s: &str) -> Result<T, ParseError> {
+    let mut decoder = Decoder::default();
+    let mut group = Group::default();
+
+    for symbol in s.chars() {
+        let digit = Alphabet::Z85
+            .digit(symbol)
+            .ok_or(ParseError::InvalidDigit(symbol))?;
+        if let Some(value) = group.push(digit)? {
+            decoder.push(&value.to_be_bytes())?;
+        }
+    }
+
+    if group.len != 0 {
+        return Err(ParseError::InvalidLength);
+    }
+
+    decoder.finish()
+}
+
+/// Parses a number written in Ascii85.
+///
+/// Returns an `Err(ParseError)` if the string is empty, if it ends with a group of a single
+/// symbol, if a symbol is invalid or if the number is too large for `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::base85;
+/// use radixal::parse::ParseError;
+///
+/// assert_eq!(base85::decode_ascii85::<u32>("9jqo^"), Ok(0x4d61_6e20));
+/// assert_eq!(base85::decode_ascii85::<u64>("z!!!!\""), Ok(1));
+/// assert_eq!(base85::decode_ascii85::<u16>("!!*"), Ok(1));
+/// assert_eq!(base85::decode_ascii85::<u32>("9"), Err(ParseError::InvalidLength));
+/// ```
+pub fn decode_ascii85<T: IntoDigits>(s: &str) -> Result<T, ParseError> {
+    let mut decoder = Decoder::default();
+    let mut group = Group::default();
+
+    for symbol in s.chars() {
+        if symbol == ZERO_GROUP && group.len == 0 {
+            decoder.push(&[0; GROUP_BYTES])?;
+            continue;
+        }
+
+        let digit = Alphabet::ASCII85
+            .digit(symbol)
+            .ok_or(ParseError::InvalidDigit(symbol))?;
+        if let Some(value) = group.push(digit)? {
+            decoder.push(&value.to_be_bytes())?;
+        }
+    }
+
+    // A final group of `k + 1` symbols holds `k` bytes; it is padded with the highest digit so
+    // that truncation rounds back to the encoded bytes.
+    match group.len {
+        0 => {}
+        1 => return Err(ParseError::InvalidLength),
+        len => {
+            let mut value = None;
+            for _ in len..GROUP_SYMBOLS {
+                value = group.push(RADIX as usize - 1)?;
+            }
+
+            let bytes = value.unwrap_or_default().to_be_bytes();
+            decoder.push(&bytes[..len - 1])?;
+        }
+    }
+
+    decoder.finish()
+}
+
+/// Accumulates the digits of a group of 5 symbols.
+#[derive(Default)]
+struct Group {
+    value: u64,
+    len: usize,
+}
+
+impl Group {
+    /// Adds a digit, returning the value of the group once it is complete.
+    fn push(&mut self, digit: usize) -> Result<Option<u32>, ParseError> {
+        self.value = self.value * u64::from(RADIX) + digit as u64;
+        self.len += 1;
+
+        if self.len < GROUP_SYMBOLS {
+            return Ok(None);
+        }
+
+        let value = self.value;
+        *self = Group::default();
+        if value > u64::from(u32::MAX) {
+            Err(ParseError::Overflow)
+        } else {
+            Ok(Some(value as u32))
+        }
+    }
+}
+
+/// Accumulates the decoded bytes of a number.
+#[derive(Default)]
+struct Decoder {
+    number: u128,
+    started: bool,
+}
+
+impl Decoder {
+    fn push(&mut self, bytes: &[u8]) -> Result<(), ParseError> {
+        self.started = true;
+        for &byte in bytes {
+            if self.number.leading_zeros() < 8 {
+                return Err(ParseError::Overflow);
+            }
+            self.number = (self.number << 8) | u128::from(byte);
+        }
+        Ok(())
+    }
+
+    fn finish<T: IntoDigits>(self) -> Result<T, ParseError> {
+        if !self.started {
+            return Err(ParseError::Empty);
+        }
+        T::from_u128(self.number).ok_or(ParseError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_z85_reference_vector() {
+        // The 8 byte test vector of the Z85 specification.
+        let number = 0x864f_d26f_b559_f75b_u64;
+        assert_eq!(z85(number).to_string(), "HelloWorld");
+        assert_eq!(decode_z85::<u64>("HelloWorld"), Ok(number));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_u128_session_id() {
+        let id = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210_u128;
+        let encoded = z85(id).to_string();
+        assert_eq!(encoded.len(), 20);
+        assert_eq!(decode_z85::<u128>(&encoded), Ok(id));
+
+        let encoded = ascii85(id).to_string();
+        assert_eq!(encoded.len(), 20);
+        assert_eq!(decode_ascii85::<u128>(&encoded), Ok(id));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_round_trips() {
+        for &n in &[0_u64, 1, 0xff, 0xffff_ffff, 1 << 32, u64::MAX] {
+            assert_eq!(decode_z85::<u64>(&z85(n).to_string()), Ok(n));
+            assert_eq!(decode_ascii85::<u64>(&ascii85(n).to_string()), Ok(n));
+        }
+
+        for &n in &[0_u16, 1, 0xff, 0x100, u16::MAX] {
+            assert_eq!(decode_z85::<u16>(&z85(n).to_string()), Ok(n));
+            assert_eq!(decode_ascii85::<u16>(&ascii85(n).to_string()), Ok(n));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ascii85_zero() {
+        assert_eq!(ascii85(0_u64).to_string(), "zz");
+        assert_eq!(ascii85(0_u8).to_string(), "!!");
+        assert_eq!(decode_ascii85::<u64>("zz"), Ok(0));
+    }
+
+    #[test]
+    fn test_group_overflow() {
+        // "s8W-!" is the largest group, 2^32 - 1.
+        assert_eq!(decode_ascii85::<u32>("s8W-!"), Ok(u32::MAX));
+        assert_eq!(decode_ascii85::<u32>("s8W-\""), Err(ParseError::Overflow));
+    }
+
+    #[test]
+    fn test_type_overflow() {
+        assert_eq!(decode_z85::<u16>("00001"), Ok(1));
+        assert_eq!(decode_z85::<u16>("0001000000"), Err(ParseError::Overflow));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(decode_z85::<u32>(""), Err(ParseError::Empty));
+        assert_eq!(decode_ascii85::<u32>(""), Err(ParseError::Empty));
+    }
+}
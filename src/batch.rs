@@ -0,0 +1,491 @@
+//! Conversions of many numbers at once.
+//!
+//! The digits of all the numbers are written to a single buffer provided by the caller, with the
+//! offsets where the digits of each number start. The radix is checked once, and no iterator is
+//! created per number.
+//!
+//! With the `rayon` feature, the `par_` versions split the numbers in chunks processed in
+//! parallel.
+use crate::digits_iterator::{RadixError, Strategy};
+use crate::reversed_digits::ReversedDigits;
+use crate::{IntoDigits, RadixMath};
+use core::fmt;
+
+/// Errors that can occur when converting a slice of numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchError {
+    /// The radix is 0 or 1.
+    Radix(RadixError),
+    /// The digits buffer cannot hold the digits of all the numbers.
+    DigitsTooShort,
+    /// The offsets buffer cannot hold one more offset than there are numbers.
+    OffsetsTooShort,
+    /// The sums buffer cannot hold a sum for every number.
+    SumsTooShort,
+    /// The counts buffer cannot hold a count for every digit.
+    CountsTooShort,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BatchError::Radix(error) => error.fmt(f),
+            BatchError::DigitsTooShort => write!(f, "digits buffer too short"),
+            BatchError::OffsetsTooShort => write!(f, "offsets buffer too short"),
+            BatchError::SumsTooShort => write!(f, "sums buffer too short"),
+            BatchError::CountsTooShort => write!(f, "counts buffer too short"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+impl From<RadixError> for BatchError {
+    fn from(error: RadixError) -> Self {
+        BatchError::Radix(error)
+    }
+}
+
+fn check_radix<T: IntoDigits>(radix: T) -> Result<(), RadixError> {
+    if radix == T::zero() {
+        Err(RadixError::Radix0)
+    } else if radix == T::one() {
+        Err(RadixError::Radix1)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the number of digits of a number, which is at least 1.
+fn digits_len<T: IntoDigits>(number: T, radix: T) -> usize {
+    number.checked_ilog_radix(radix).unwrap_or_default() + 1
+}
+
+/// Returns the total number of digits of `numbers` under `radix`, which is the size of the
+/// digits buffer needed by [`digits_of_slice`](fn.digits_of_slice.html).
+///
+/// Returns an `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::batch::count_digits_of_slice;
+///
+/// assert_eq!(count_digits_of_slice(&[7_u32, 42, 1000], 10), Ok(7));
+/// assert_eq!(count_digits_of_slice::<u32>(&[], 10), Ok(0));
+/// ```
+pub fn count_digits_of_slice<T: IntoDigits>(numbers: &[T], radix: T) -> Result<usize, RadixError> {
+    check_radix(radix)?;
+    Ok(numbers
+        .iter()
+        .map(|&number| digits_len(number, radix))
+        .sum())
+}
+
+/// Writes the digits of each of `numbers` under `radix`, most significant first, one number
+/// after the other, to `digits`.
+///
+/// The digits of `numbers[i]` are written to `digits[offsets[i]..offsets[i + 1]]`, so `offsets`
+/// must hold one more offset than there are numbers; the extra offsets are left untouched.
+/// Returns the total number of digits written.
+///
+/// Returns an `Err(BatchError)` if the radix is 0 or 1, or if either buffer is too short. In that
+/// case, nothing is written.
+///
+/// # Example
+///
+/// ```
+/// use radixal::batch::digits_of_slice;
+///
+/// let numbers = [7_u32, 42, 1000];
+/// let mut digits = [0; 16];
+/// let mut offsets = [0; 4];
+///
+/// let len = digits_of_slice(&numbers, 10, &mut digits, &mut offsets).unwrap();
+/// assert_eq!(digits[..len], [7, 4, 2, 1, 0, 0, 0]);
+/// assert_eq!(offsets, [0, 1, 3, 7]);
+/// assert_eq!(digits[offsets[1]..offsets[2]], [4, 2]);
+/// ```
+pub fn digits_of_slice<T: IntoDigits>(
+    numbers: &[T],
+    radix: T,
+    digits: &mut [T],
+    offsets: &mut [usize],
+) -> Result<usize, BatchError> {
+    let total = lay_out(numbers, radix, digits, offsets)?;
+    write_digits(numbers, radix, digits, &offsets[..=numbers.len()]);
+    Ok(total)
+}
+
+/// Checks the radix and the buffers, then writes the offsets of the digits of every number.
+///
+/// Returns the total number of digits.
+fn lay_out<T: IntoDigits>(
+    numbers: &[T],
+    radix: T,
+    digits: &[T],
+    offsets: &mut [usize],
+) -> Result<usize, BatchError> {
+    check_radix(radix)?;
+    if offsets.len() <= numbers.len() {
+        return Err(BatchError::OffsetsTooShort);
+    }
+
+    let total = count_digits_of_slice(numbers, radix)?;
+    if total > digits.len() {
+        return Err(BatchError::DigitsTooShort);
+    }
+
+    offsets[0] = 0;
+    let mut end = 0;
+    for (&number, offset) in numbers.iter().zip(&mut offsets[1..]) {
+        end += digits_len(number, radix);
+        *offset = end;
+    }
+
+    Ok(total)
+}
+
+/// Writes the digits of `numbers` between consecutive `offsets`, which are relative to the start
+/// of `digits`.
+fn write_digits<T: IntoDigits>(numbers: &[T], radix: T, digits: &mut [T], offsets: &[usize]) {
+    let strategy = Strategy::new(radix);
+    let start = offsets[0];
+    for (&number, bounds) in numbers.iter().zip(offsets.windows(2)) {
+        let mut number = number;
+        for digit in digits[bounds[0] - start..bounds[1] - start]
+            .iter_mut()
+            .rev()
+        {
+            let quotient = strategy.div(number, radix);
+            *digit = number - quotient * radix;
+            number = quotient;
+        }
+    }
+}
+
+/// Writes the sum of the digits of each of `numbers` under `radix` to `sums`.
+///
+/// The sum of the digits of a number is never larger than the number itself, so it fits in `T`.
+///
+/// Returns an `Err(BatchError)` if the radix is 0 or 1, or if `sums` is shorter than `numbers`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::batch::digit_sums_of_slice;
+///
+/// let mut sums = [0; 3];
+/// digit_sums_of_slice(&[7_u32, 42, 999], 10, &mut sums).unwrap();
+/// assert_eq!(sums, [7, 6, 27]);
+/// ```
+pub fn digit_sums_of_slice<T: IntoDigits>(
+    numbers: &[T],
+    radix: T,
+    sums: &mut [T],
+) -> Result<(), BatchError> {
+    check_radix(radix)?;
+    if sums.len() < numbers.len() {
+        return Err(BatchError::SumsTooShort);
+    }
+
+    write_digit_sums(numbers, radix, sums);
+    Ok(())
+}
+
+fn write_digit_sums<T: IntoDigits>(numbers: &[T], radix: T, sums: &mut [T]) {
+    let digits = ReversedDigits::new(T::zero(), radix).unwrap();
+    for (&number, sum) in numbers.iter().zip(sums) {
+        *sum = digits.with_number(number).digit_sum();
+    }
+}
+
+/// Adds the number of occurrences of each digit under `radix` in `numbers` to `counts`, indexed by
+/// the digit.
+///
+/// Returns an `Err(BatchError)` if the radix is 0 or 1, or if `counts` has fewer than `radix`
+/// counts.
+///
+/// # Example
+///
+/// ```
+/// use radixal::batch::digit_histogram_of_slice;
+///
+/// let mut counts = [0; 10];
+/// digit_histogram_of_slice(&[7_u32, 77, 100], 10, &mut counts).unwrap();
+/// assert_eq!(counts, [2, 1, 0, 0, 0, 0, 0, 3, 0, 0]);
+/// ```
+pub fn digit_histogram_of_slice<T: IntoDigits>(
+    numbers: &[T],
+    radix: T,
+    counts: &mut [usize],
+) -> Result<(), BatchError> {
+    check_histogram(radix, counts)?;
+    add_histogram(numbers, radix, counts);
+    Ok(())
+}
+
+fn check_histogram<T: IntoDigits>(radix: T, counts: &[usize]) -> Result<(), BatchError> {
+    check_radix(radix)?;
+    if radix.to_usize().is_none_or(|radix| counts.len() < radix) {
+        Err(BatchError::CountsTooShort)
+    } else {
+        Ok(())
+    }
+}
+
+fn add_histogram<T: IntoDigits>(numbers: &[T], radix: T, counts: &mut [usize]) {
+    let digits = ReversedDigits::new(T::zero(), radix).unwrap();
+    for &number in numbers {
+        // The increments of the counts are the bottleneck, rather than the divisions: splitting
+        // off pairs of digits does not help here.
+        for digit in digits.with_number(number) {
+            counts[digit.to_usize().unwrap_or_default()] += 1;
+        }
+    }
+}
+
+/// Parallel versions of the batch conversions, which split the numbers in chunks.
+#[cfg(feature = "rayon")]
+mod parallel {
+    use super::*;
+    use rayon::prelude::*;
+
+    /// The number of numbers converted by each task.
+    const CHUNK_LEN: usize = 1024;
+
+    /// Like [`digits_of_slice`](fn.digits_of_slice.html), converting chunks of numbers in
+    /// parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::batch::par_digits_of_slice;
+    ///
+    /// let numbers: Vec<u32> = (0..10_000).collect();
+    /// let mut digits = vec![0; 38_890];
+    /// let mut offsets = vec![0; numbers.len() + 1];
+    ///
+    /// assert_eq!(par_digits_of_slice(&numbers, 10, &mut digits, &mut offsets), Ok(38_890));
+    /// assert_eq!(digits[offsets[1234]..offsets[1235]], [1, 2, 3, 4]);
+    /// ```
+    pub fn par_digits_of_slice<T: IntoDigits + Send + Sync>(
+        numbers: &[T],
+        radix: T,
+        digits: &mut [T],
+        offsets: &mut [usize],
+    ) -> Result<usize, BatchError> {
+        let total = lay_out(numbers, radix, digits, offsets)?;
+
+        // Splits the digits at the offsets where each chunk of numbers starts.
+        let mut chunks = Vec::with_capacity(numbers.len() / CHUNK_LEN + 1);
+        let mut rest = &mut digits[..total];
+        for (idx, numbers) in numbers.chunks(CHUNK_LEN).enumerate() {
+            let start = idx * CHUNK_LEN;
+            let bounds = &offsets[start..=start + numbers.len()];
+            let (chunk, tail) = rest.split_at_mut(bounds[numbers.len()] - bounds[0]);
+            chunks.push((numbers, chunk, bounds));
+            rest = tail;
+        }
+
+        chunks
+            .into_par_iter()
+            .for_each(|(numbers, digits, offsets)| write_digits(numbers, radix, digits, offsets));
+        Ok(total)
+    }
+
+    /// Like [`digit_sums_of_slice`](fn.digit_sums_of_slice.html), summing chunks of numbers in
+    /// parallel.
+    pub fn par_digit_sums_of_slice<T: IntoDigits + Send + Sync>(
+        numbers: &[T],
+        radix: T,
+        sums: &mut [T],
+    ) -> Result<(), BatchError> {
+        check_radix(radix)?;
+        if sums.len() < numbers.len() {
+            return Err(BatchError::SumsTooShort);
+        }
+
+        numbers
+            .par_chunks(CHUNK_LEN)
+            .zip(sums.par_chunks_mut(CHUNK_LEN))
+            .for_each(|(numbers, sums)| write_digit_sums(numbers, radix, sums));
+        Ok(())
+    }
+
+    /// Like [`digit_histogram_of_slice`](fn.digit_histogram_of_slice.html), counting chunks of
+    /// numbers in parallel.
+    pub fn par_digit_histogram_of_slice<T: IntoDigits + Send + Sync>(
+        numbers: &[T],
+        radix: T,
+        counts: &mut [usize],
+    ) -> Result<(), BatchError> {
+        check_histogram(radix, counts)?;
+
+        let len = counts.len();
+        let histogram = numbers
+            .par_chunks(CHUNK_LEN)
+            .fold(
+                || vec![0; len],
+                |mut counts, numbers| {
+                    add_histogram(numbers, radix, &mut counts);
+                    counts
+                },
+            )
+            .reduce(
+                || vec![0; len],
+                |mut counts, other| {
+                    counts
+                        .iter_mut()
+                        .zip(other)
+                        .for_each(|(count, other)| *count += other);
+                    counts
+                },
+            );
+
+        counts
+            .iter_mut()
+            .zip(histogram)
+            .for_each(|(count, added)| *count += added);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub use parallel::{par_digit_histogram_of_slice, par_digit_sums_of_slice, par_digits_of_slice};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_matches_iteration() {
+        let numbers: Vec<u64> = (0..64)
+            .map(|shift| u64::MAX >> shift)
+            .chain(0..1000)
+            .collect();
+
+        for &radix in &[2_u64, 3, 8, 10, 16, 36, u64::MAX] {
+            let total = count_digits_of_slice(&numbers, radix).unwrap();
+            let mut digits = vec![0; total];
+            let mut offsets = vec![0; numbers.len() + 1];
+            assert_eq!(
+                digits_of_slice(&numbers, radix, &mut digits, &mut offsets),
+                Ok(total)
+            );
+
+            for (idx, &number) in numbers.iter().enumerate() {
+                assert!(
+                    number
+                        .into_digits(radix)
+                        .unwrap()
+                        .eq(digits[offsets[idx]..offsets[idx + 1]].iter().copied()),
+                    "{} {}",
+                    number,
+                    radix
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let numbers = [12_u8, 3];
+        let mut digits = [0; 3];
+        let mut offsets = [0; 3];
+
+        assert_eq!(
+            digits_of_slice(&numbers, 1, &mut digits, &mut offsets),
+            Err(BatchError::Radix(RadixError::Radix1))
+        );
+        assert_eq!(
+            digits_of_slice(&numbers, 10, &mut digits, &mut offsets[..2]),
+            Err(BatchError::OffsetsTooShort)
+        );
+        assert_eq!(
+            digits_of_slice(&numbers, 10, &mut digits[..2], &mut offsets),
+            Err(BatchError::DigitsTooShort)
+        );
+        assert_eq!(digits, [0; 3]);
+        assert_eq!(offsets, [0; 3]);
+
+        assert_eq!(
+            digits_of_slice(&numbers, 10, &mut digits, &mut offsets),
+            Ok(3)
+        );
+        assert_eq!(digits, [1, 2, 3]);
+        assert_eq!(offsets, [0, 2, 3]);
+        assert_eq!(count_digits_of_slice(&numbers, 0), Err(RadixError::Radix0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sums_and_histogram() {
+        let numbers: Vec<u32> = (0..5000).chain(u32::MAX - 100..=u32::MAX).collect();
+        for &radix in &[2_u32, 7, 10, 16] {
+            let mut sums = vec![0; numbers.len()];
+            let mut counts = vec![1; radix as usize + 1];
+            digit_sums_of_slice(&numbers, radix, &mut sums).unwrap();
+            digit_histogram_of_slice(&numbers, radix, &mut counts).unwrap();
+
+            let mut expected = vec![1; radix as usize + 1];
+            for (&number, &sum) in numbers.iter().zip(&sums) {
+                let digits = number.into_digits(radix).unwrap();
+                assert_eq!(digits.clone().sum::<u32>(), sum, "{} {}", number, radix);
+                digits.for_each(|digit| expected[digit as usize] += 1);
+            }
+            assert_eq!(counts, expected);
+        }
+
+        assert_eq!(
+            digit_sums_of_slice(&[1_u8, 2], 10, &mut [0]),
+            Err(BatchError::SumsTooShort)
+        );
+        assert_eq!(
+            digit_histogram_of_slice(&[1_u8, 2], 10, &mut [0; 9]),
+            Err(BatchError::CountsTooShort)
+        );
+        assert_eq!(
+            digit_histogram_of_slice(&[1_u64], u64::MAX, &mut [0; 10]),
+            Err(BatchError::CountsTooShort)
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let numbers: Vec<u64> = (0..20_000_u64)
+            .map(|n| n.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> (n % 64))
+            .collect();
+
+        for &radix in &[2_u64, 10, 13] {
+            let total = count_digits_of_slice(&numbers, radix).unwrap();
+            let (mut digits, mut par_digits) = (vec![0; total + 3], vec![0; total + 3]);
+            let (mut offsets, mut par_offsets) =
+                (vec![0; numbers.len() + 1], vec![0; numbers.len() + 1]);
+            assert_eq!(
+                digits_of_slice(&numbers, radix, &mut digits, &mut offsets),
+                par_digits_of_slice(&numbers, radix, &mut par_digits, &mut par_offsets)
+            );
+            assert_eq!(digits, par_digits);
+            assert_eq!(offsets, par_offsets);
+
+            let (mut sums, mut par_sums) = (vec![0; numbers.len()], vec![0; numbers.len()]);
+            digit_sums_of_slice(&numbers, radix, &mut sums).unwrap();
+            par_digit_sums_of_slice(&numbers, radix, &mut par_sums).unwrap();
+            assert_eq!(sums, par_sums);
+
+            let (mut counts, mut par_counts) = (vec![0; 16], vec![0; 16]);
+            digit_histogram_of_slice(&numbers, radix, &mut counts).unwrap();
+            par_digit_histogram_of_slice(&numbers, radix, &mut par_counts).unwrap();
+            assert_eq!(counts, par_counts);
+        }
+
+        assert_eq!(
+            par_digits_of_slice(&numbers, 10, &mut [0; 10], &mut [0; 10]),
+            Err(BatchError::OffsetsTooShort)
+        );
+    }
+}
@@ -0,0 +1,746 @@
+//! Digits iteration and formatting for the arbitrary precision integers of `num-bigint`.
+//!
+//! The digits are not split off a `BigUint` one by one, which would take a division of the whole
+//! number for every digit. Instead, the number is split in chunks of as many digits as fit in a
+//! `u64`, by dividing it in halves by the matching powers of the radix, and the digits of each
+//! chunk are then split off a `u64`.
+//!
+//! # Example
+//!
+//! ```
+//! use num_bigint::BigUint;
+//! use radixal::bigint::{from_digits_be, IntoBigDigits};
+//!
+//! let n = BigUint::from(u128::MAX) * 10_u32 + 7_u32;
+//! let digits: Vec<_> = (&n).into_digits(10).unwrap().collect();
+//! assert_eq!(digits.len(), 40);
+//! assert_eq!(digits[39], 7);
+//!
+//! assert_eq!(from_digits_be(digits, 10), Ok(n));
+//! ```
+//!
+//! With the `rayon` feature, `DigitBlocks` splits a huge number in blocks of digits, in
+//! parallel, by dividing it by powers of the radix whose exponents halve at each step.
+use crate::alphabet::Alphabet;
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::format::{check_radix, write_symbols_io, DigitsDisplay, SignStyle, WriteError};
+use crate::parse::{DigitValue, ParseError};
+use core::convert::TryFrom;
+use core::fmt;
+use core::iter::FusedIterator;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{ToPrimitive, Zero};
+
+/// Returns the largest power of `radix` that fits in a `u64`, and its exponent.
+fn chunk_base(radix: u64) -> (u64, usize) {
+    let (mut base, mut len) = (radix, 1);
+    while radix >= 2 && base <= u64::MAX / radix {
+        base *= radix;
+        len += 1;
+    }
+    (base, len)
+}
+
+/// An iterator over the digits of a `BigUint`, most significant first.
+///
+/// The number is split in chunks when the iterator is created, by dividing it by powers of the
+/// chunk base whose exponents halve at each step, and the digits of each chunk are split off when
+/// they are reached.
+#[derive(Clone, Debug)]
+pub struct BigDigits {
+    /// The chunks whose digits are yet to be split off, most significant last.
+    chunks: Vec<u64>,
+    current: DigitsIterator<u64>,
+    radix: u64,
+    chunk_len: usize,
+}
+
+impl BigDigits {
+    /// Creates a new `BigDigits` for `number` using `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is `0` or `1`.
+    pub fn new(number: BigUint, radix: u64) -> Result<Self, RadixError> {
+        if radix == 0 {
+            return Err(RadixError::Radix0);
+        } else if radix == 1 {
+            return Err(RadixError::Radix1);
+        }
+
+        let (base, chunk_len) = chunk_base(radix);
+
+        // `base^(2^k)`, up to the last one not larger than the number.
+        let mut powers = vec![BigUint::from(base)];
+        while let Some(power) = powers.last().filter(|&power| power <= &number) {
+            powers.push(power * power);
+        }
+        powers.pop();
+
+        let mut chunks = Vec::new();
+        split_chunks(number, &powers, true, &mut chunks);
+        // The most significant chunk is the last one, and there is always one.
+        let number = chunks.pop().unwrap_or(0);
+
+        Ok(BigDigits {
+            chunks,
+            current: DigitsIterator::new(number, radix)?,
+            radix,
+            chunk_len,
+        })
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> u64 {
+        self.radix
+    }
+}
+
+/// Pushes the chunks of `number`, smaller than `base^(2^powers.len())` where `powers` are the
+/// `base^(2^k)`, least significant first, dropping the leading chunks of zeroes if `leading`.
+///
+/// Dividing by the largest power first splits the number in halves of similar sizes, instead of
+/// dividing the whole number once per chunk.
+fn split_chunks(number: BigUint, powers: &[BigUint], leading: bool, chunks: &mut Vec<u64>) {
+    let (power, powers) = match powers.split_last() {
+        Some(split) => split,
+        None => return chunks.push(number.to_u64().unwrap()),
+    };
+
+    let (high, low) = number.div_rem(power);
+    if leading && high.is_zero() {
+        return split_chunks(low, powers, true, chunks);
+    }
+
+    split_chunks(low, powers, false, chunks);
+    split_chunks(high, powers, leading, chunks);
+}
+
+impl Iterator for BigDigits {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(digit) = self.current.next() {
+            return Some(digit);
+        }
+
+        // Every chunk but the most significant one keeps its leading zeroes.
+        let chunk = self.chunks.pop()?;
+        self.current = DigitsIterator::zero_padded(chunk, self.radix, self.chunk_len);
+        self.current.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.current.len() + self.chunks.len() * self.chunk_len;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BigDigits {}
+
+impl FusedIterator for BigDigits {}
+
+/// An extension trait on `BigUint`, mirroring [`IntoDigits`](../trait.IntoDigits.html).
+pub trait IntoBigDigits {
+    /// Creates a `BigDigits` with a given `radix`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn into_digits(self, radix: u64) -> Result<BigDigits, RadixError>;
+
+    /// Counts the number of digits for a given `radix`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn nbr_digits(self, radix: u64) -> Result<usize, RadixError>
+    where
+        Self: Sized,
+    {
+        self.into_digits(radix).map(|digits| digits.len())
+    }
+}
+
+impl IntoBigDigits for BigUint {
+    fn into_digits(self, radix: u64) -> Result<BigDigits, RadixError> {
+        BigDigits::new(self, radix)
+    }
+}
+
+impl IntoBigDigits for &BigUint {
+    fn into_digits(self, radix: u64) -> Result<BigDigits, RadixError> {
+        BigDigits::new(self.clone(), radix)
+    }
+}
+
+/// An extension trait on `BigInt`, splitting it into a sign and the digits of its magnitude, as
+/// [`SignedInteger`](../trait.SignedInteger.html) does for the primitive signed types.
+pub trait IntoSignedBigDigits {
+    /// Returns whether the number is strictly negative, and a `BigDigits` over the digits of its
+    /// magnitude with a given `radix`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    /// use radixal::bigint::IntoSignedBigDigits;
+    ///
+    /// let (negative, digits) = BigInt::from(-255).into_signed_digits(16).unwrap();
+    /// assert!(negative);
+    /// assert!(digits.eq([15, 15]));
+    /// ```
+    fn into_signed_digits(self, radix: u64) -> Result<(bool, BigDigits), RadixError>;
+}
+
+impl IntoSignedBigDigits for BigInt {
+    fn into_signed_digits(self, radix: u64) -> Result<(bool, BigDigits), RadixError> {
+        let negative = self.sign() == Sign::Minus;
+        Ok((negative, self.into_parts().1.into_digits(radix)?))
+    }
+}
+
+impl IntoSignedBigDigits for &BigInt {
+    fn into_signed_digits(self, radix: u64) -> Result<(bool, BigDigits), RadixError> {
+        let negative = self.sign() == Sign::Minus;
+        Ok((negative, self.magnitude().into_digits(radix)?))
+    }
+}
+
+/// Displays a `BigUint` or a `BigInt` as the sequence of its digits, using the symbols of an
+/// `Alphabet`, with the options of a [`DigitsDisplay`](../format/struct.DigitsDisplay.html).
+///
+/// # Example
+///
+/// ```
+/// use num_bigint::{BigInt, BigUint};
+/// use radixal::alphabet::Alphabet;
+/// use radixal::bigint::BigDisplay;
+/// use radixal::format::SignStyle;
+///
+/// let n = BigUint::from(u128::MAX) + 1_u8;
+/// let display = BigDisplay::new(n, Alphabet::LOWER_HEX).with_prefix("0x");
+/// assert_eq!(display.to_string(), "0x100000000000000000000000000000000");
+///
+/// let display = BigDisplay::new_signed(BigInt::from(-1_234_567), Alphabet::DECIMAL);
+/// assert_eq!(display.clone().with_grouping(',', 3).to_string(), "-1,234,567");
+/// assert_eq!(display.with_sign(SignStyle::Parentheses).to_string(), "(1234567)");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigDisplay<'a> {
+    magnitude: BigUint,
+    /// The options of the display, whose own number is not used.
+    options: DigitsDisplay<'a, u64>,
+}
+
+impl<'a> BigDisplay<'a> {
+    /// Creates a new `BigDisplay` for `number` using `alphabet`.
+    pub fn new(number: BigUint, alphabet: Alphabet<'a>) -> Self {
+        BigDisplay {
+            magnitude: number,
+            options: DigitsDisplay::new(0, alphabet),
+        }
+    }
+
+    /// Creates a new `BigDisplay` for the signed `number` using `alphabet`, which displays the
+    /// digits of its magnitude after its sign.
+    pub fn new_signed(number: BigInt, alphabet: Alphabet<'a>) -> Self {
+        let negative = number.sign() == Sign::Minus;
+        BigDisplay {
+            magnitude: number.into_parts().1,
+            options: DigitsDisplay::new(0, alphabet).with_negative(negative),
+        }
+    }
+
+    /// Uses `style` to display the sign, like
+    /// [`DigitsDisplay::with_sign`](../format/struct.DigitsDisplay.html#method.with_sign).
+    pub fn with_sign(self, style: SignStyle) -> Self {
+        BigDisplay {
+            options: self.options.with_sign(style),
+            ..self
+        }
+    }
+
+    /// Writes `prefix` before the digits, like
+    /// [`DigitsDisplay::with_prefix`](../format/struct.DigitsDisplay.html#method.with_prefix).
+    pub fn with_prefix(self, prefix: &'a str) -> Self {
+        BigDisplay {
+            options: self.options.with_prefix(prefix),
+            ..self
+        }
+    }
+
+    /// Separates the digits into groups of `size` digits with `separator`, like
+    /// [`DigitsDisplay::with_grouping`](../format/struct.DigitsDisplay.html#method.with_grouping).
+    pub fn with_grouping(self, separator: char, size: usize) -> Self {
+        BigDisplay {
+            options: self.options.with_grouping(separator, size),
+            ..self
+        }
+    }
+
+    /// Returns the number being displayed, or its magnitude for a signed number.
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+}
+
+impl<'a> fmt::Display for BigDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let radix = self.options.alphabet().radix() as u64;
+        let digits = (&self.magnitude)
+            .into_digits(radix)
+            .map_err(|_| fmt::Error)?;
+        self.options.fmt_digits(f, digits)
+    }
+}
+
+/// Writes the digits of `number` under `radix` to `writer`, using the first `radix` symbols of
+/// `alphabet`, like [`format::write_digits_io`](../format/fn.write_digits_io.html).
+///
+/// Returns an `Err(WriteError::Radix)` if the radix is 0, 1 or larger than the number of
+/// symbols of the alphabet, and an `Err(WriteError::Write)` if the writer fails.
+///
+/// # Example
+///
+/// ```
+/// use num_bigint::BigUint;
+/// use radixal::alphabet::Alphabet;
+/// use radixal::bigint::write_digits_io;
+///
+/// let n = BigUint::from(2_u8).pow(200);
+/// let mut bytes = Vec::new();
+/// write_digits_io(&mut bytes, &n, 16, &Alphabet::LOWER_HEX).unwrap();
+/// assert_eq!(bytes.len(), 51);
+/// assert!(bytes.starts_with(b"100"));
+/// ```
+pub fn write_digits_io<W: std::io::Write>(
+    writer: &mut W,
+    number: &BigUint,
+    radix: u64,
+    alphabet: &Alphabet,
+) -> Result<(), WriteError<std::io::Error>> {
+    check_radix(radix, alphabet).map_err(WriteError::Radix)?;
+    let digits = number.into_digits(radix).map_err(WriteError::Radix)?;
+    write_symbols_io(writer, digits, alphabet)
+}
+
+/// Builds a `BigUint` from its digits under `radix`, most significant first, like
+/// [`parse::from_digits_be`](../parse/fn.from_digits_be.html).
+///
+/// The digits are gathered in chunks of as many digits as fit in a `u64`, so that the number is
+/// multiplied once per chunk.
+///
+/// Returns an `Err(ParseError::Radix)` if the radix is 0 or 1, and an `Err(ParseError)` if there
+/// are no digits, or if a digit is invalid or not smaller than the radix.
+///
+/// # Example
+///
+/// ```
+/// use num_bigint::BigUint;
+/// use radixal::bigint::from_digits_be;
+/// use radixal::digits_iterator::RadixError;
+/// use radixal::parse::ParseError;
+///
+/// let n = from_digits_be(core::iter::repeat_n(1_u8, 100), 2).unwrap();
+/// assert_eq!(n, (BigUint::from(1_u8) << 100) - 1_u8);
+/// assert_eq!(from_digits_be(&[1_u8, 10], 10), Err(ParseError::DigitTooLarge(10)));
+/// assert_eq!(from_digits_be(&[0_u8], 1), Err(ParseError::Radix(RadixError::Radix1)));
+/// ```
+pub fn from_digits_be<I>(digits: I, radix: u64) -> Result<BigUint, ParseError>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    if radix == 0 {
+        return Err(ParseError::Radix(RadixError::Radix0));
+    } else if radix == 1 {
+        return Err(ParseError::Radix(RadixError::Radix1));
+    }
+
+    let (base, chunk_len) = chunk_base(radix);
+    let mut number = None;
+    let (mut chunk, mut len) = (0, 0);
+    for digit in digits {
+        let value = digit.into_value()?;
+        let digit = u64::try_from(value)
+            .ok()
+            .filter(|&digit| digit < radix)
+            .ok_or(ParseError::DigitTooLarge(value))?;
+
+        chunk = chunk * radix + digit;
+        len += 1;
+        if len == chunk_len {
+            let high: BigUint = number.unwrap_or_else(BigUint::zero);
+            number = Some(high * base + chunk);
+            chunk = 0;
+            len = 0;
+        }
+    }
+
+    if len > 0 {
+        let high = number.unwrap_or_else(BigUint::zero);
+        number = Some(high * radix.pow(len as u32) + chunk);
+    }
+    number.ok_or(ParseError::Empty)
+}
+
+/// Parallel splitting of a `BigUint` in blocks of digits.
+#[cfg(feature = "rayon")]
+mod parallel {
+    use super::*;
+    use rayon::prelude::*;
+
+    /// The number of chunks of a `u64` in each block.
+    const BLOCK_CHUNKS: usize = 64;
+
+    /// The digits of a `BigUint`, most significant first, split in blocks of the same number of
+    /// digits but the most significant one, to be consumed in parallel.
+    ///
+    /// Each block is a `BigDigits`, and the blocks but the most significant one keep their
+    /// leading zeroes, so that chaining them gives the digits of the whole number.
+    ///
+    /// The number is divided by `radix^(block_len * 2^k)`, for `k` decreasing down to 0, splitting
+    /// the quotients and the remainders in parallel, instead of dividing the whole number once
+    /// per chunk of digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use radixal::bigint::{DigitBlocks, IntoBigDigits};
+    /// use rayon::prelude::*;
+    ///
+    /// let n = BigUint::from(3_u8).pow(50_000);
+    /// let blocks = DigitBlocks::new(&n, 10).unwrap();
+    /// assert_eq!(blocks.nbr_digits(), 23_857);
+    ///
+    /// let sum: u64 = blocks.into_par_iter().map(|digits| digits.sum::<u64>()).sum();
+    /// assert_eq!(sum, n.into_digits(10).unwrap().sum::<u64>());
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct DigitBlocks {
+        blocks: Vec<BigDigits>,
+        radix: u64,
+        block_len: usize,
+    }
+
+    impl DigitBlocks {
+        /// Splits `number` in blocks of digits using `radix`.
+        ///
+        /// Returns an `Err(RadixError)` if the radix is `0` or `1`.
+        pub fn new(number: &BigUint, radix: u64) -> Result<Self, RadixError> {
+            if radix == 0 {
+                return Err(RadixError::Radix0);
+            } else if radix == 1 {
+                return Err(RadixError::Radix1);
+            }
+
+            let (base, chunk_len) = chunk_base(radix);
+            let block_len = chunk_len * BLOCK_CHUNKS;
+
+            // `radix^(block_len * 2^k)`, up to the first one larger than the number.
+            let mut powers = vec![BigUint::from(base).pow(BLOCK_CHUNKS as u32)];
+            while let Some(power) = powers.last().filter(|&power| power <= number) {
+                powers.push(power * power);
+            }
+            powers.pop();
+
+            let blocks = split(number.clone(), &powers, true)
+                .into_par_iter()
+                .enumerate()
+                .map(|(idx, block)| {
+                    if idx == 0 {
+                        BigDigits::new(block, radix).unwrap()
+                    } else {
+                        zero_padded(block, radix, block_len)
+                    }
+                })
+                .collect();
+
+            Ok(DigitBlocks {
+                blocks,
+                radix,
+                block_len,
+            })
+        }
+
+        /// Returns the radix.
+        pub fn radix(&self) -> u64 {
+            self.radix
+        }
+
+        /// Returns the number of digits of every block but the most significant one, which may
+        /// have fewer.
+        pub fn block_len(&self) -> usize {
+            self.block_len
+        }
+
+        /// Returns the number of blocks, at least 1.
+        pub fn nbr_blocks(&self) -> usize {
+            self.blocks.len()
+        }
+
+        /// Counts the number of digits of the whole number.
+        pub fn nbr_digits(&self) -> usize {
+            self.blocks[0].len() + (self.blocks.len() - 1) * self.block_len
+        }
+
+        /// Returns the blocks, most significant first.
+        pub fn as_slice(&self) -> &[BigDigits] {
+            &self.blocks
+        }
+    }
+
+    impl IntoParallelIterator for DigitBlocks {
+        type Item = BigDigits;
+        type Iter = rayon::vec::IntoIter<BigDigits>;
+
+        fn into_par_iter(self) -> Self::Iter {
+            self.blocks.into_par_iter()
+        }
+    }
+
+    impl<'a> IntoParallelIterator for &'a DigitBlocks {
+        type Item = &'a BigDigits;
+        type Iter = rayon::slice::Iter<'a, BigDigits>;
+
+        fn into_par_iter(self) -> Self::Iter {
+            self.blocks.par_iter()
+        }
+    }
+
+    /// Splits `number`, smaller than `radix^(block_len * 2^powers.len())`, in blocks of
+    /// `block_len` digits, most significant first, dropping the leading blocks of zeroes if
+    /// `leading`.
+    fn split(number: BigUint, powers: &[BigUint], leading: bool) -> Vec<BigUint> {
+        let (power, powers) = match powers.split_last() {
+            Some(split) => split,
+            None => return vec![number],
+        };
+
+        let (high, low) = number.div_rem(power);
+        if leading && high.is_zero() {
+            return split(low, powers, true);
+        }
+
+        let (mut high, low) = rayon::join(
+            || split(high, powers, leading),
+            || split(low, powers, false),
+        );
+        high.extend(low);
+        high
+    }
+
+    /// Creates a `BigDigits` over the `width` digits of `number`, smaller than `radix^width`,
+    /// including the leading zeroes.
+    fn zero_padded(number: BigUint, radix: u64, width: usize) -> BigDigits {
+        let (base, chunk_len) = chunk_base(radix);
+        let nbr_chunks = width.div_ceil(chunk_len);
+
+        let base = BigUint::from(base);
+        let mut chunks = Vec::with_capacity(nbr_chunks - 1);
+        let mut number = number;
+        for _ in 1..nbr_chunks {
+            let (quotient, chunk) = number.div_rem(&base);
+            chunks.push(chunk.to_u64().unwrap());
+            number = quotient;
+        }
+
+        let current_len = width - chunks.len() * chunk_len;
+        BigDigits {
+            chunks,
+            current: DigitsIterator::zero_padded(number.to_u64().unwrap(), radix, current_len),
+            radix,
+            chunk_len,
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub use parallel::DigitBlocks;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers() -> Vec<BigUint> {
+        let large = BigUint::from(u128::MAX) * BigUint::from(u128::MAX);
+        vec![
+            BigUint::zero(),
+            BigUint::from(1_u8),
+            BigUint::from(u64::MAX),
+            BigUint::from(u64::MAX) + 1_u8,
+            BigUint::from(10_u8).pow(40),
+            // The fourth power of the decimal chunk base, exactly.
+            BigUint::from(10_u8).pow(76),
+            large.clone(),
+            large.pow(5) + 12_345_u32,
+        ]
+    }
+
+    #[test]
+    fn test_matches_to_radix() {
+        for number in numbers() {
+            for radix in 2..=36 {
+                let expected: Vec<u64> = number
+                    .to_radix_be(radix)
+                    .into_iter()
+                    .map(u64::from)
+                    .collect();
+                let digits = (&number).into_digits(radix.into()).unwrap();
+                assert_eq!(digits.len(), expected.len());
+                assert_eq!(digits.collect::<Vec<_>>(), expected, "{} {}", number, radix);
+                assert_eq!(from_digits_be(&expected, radix.into()), Ok(number.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_wide_radices() {
+        for number in numbers() {
+            for &radix in &[1000, u32::MAX.into(), 1 << 32, u64::MAX] {
+                let digits: Vec<_> = (&number).into_digits(radix).unwrap().collect();
+                assert_eq!(from_digits_be(&digits, radix), Ok(number.clone()));
+                assert_eq!(number.clone().nbr_digits(radix), Ok(digits.len()));
+            }
+        }
+
+        let digits = BigUint::from(u64::MAX).into_digits(u64::MAX).unwrap();
+        assert!(digits.eq([1, 0]));
+    }
+
+    #[test]
+    fn test_write_digits_io() {
+        for number in numbers() {
+            for &radix in &[2, 10, 36] {
+                let mut bytes = Vec::new();
+                write_digits_io(&mut bytes, &number, radix, &Alphabet::BASE36).unwrap();
+                assert_eq!(
+                    String::from_utf8(bytes).unwrap(),
+                    number.to_str_radix(radix as u32)
+                );
+            }
+        }
+
+        let mut bytes = Vec::new();
+        assert!(matches!(
+            write_digits_io(&mut bytes, &BigUint::zero(), 11, &Alphabet::DECIMAL),
+            Err(WriteError::Radix(RadixError::RadixTooLarge))
+        ));
+        assert!(matches!(
+            write_digits_io(&mut bytes, &BigUint::zero(), 1, &Alphabet::DECIMAL),
+            Err(WriteError::Radix(RadixError::Radix1))
+        ));
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn test_signed() {
+        for number in numbers() {
+            for &negative in &[false, true] {
+                let signed = BigInt::from_biguint(
+                    if negative { Sign::Minus } else { Sign::Plus },
+                    number.clone(),
+                );
+                let (is_negative, digits) = (&signed).into_signed_digits(10).unwrap();
+                assert_eq!(is_negative, negative && !number.is_zero());
+                assert!(digits.eq((&number).into_digits(10).unwrap()));
+
+                let display = BigDisplay::new_signed(signed.clone(), Alphabet::DECIMAL);
+                assert_eq!(display.to_string(), signed.to_string());
+                assert_eq!(
+                    format!("{:+}", display.clone().with_sign(SignStyle::MinusOnly)),
+                    format!("{:+}", signed)
+                );
+                assert_eq!(
+                    BigDisplay::new(number.clone(), Alphabet::LOWER_HEX).to_string(),
+                    number.to_str_radix(16)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_display_matches_primitives() {
+        use crate::SignedInteger;
+
+        for &n in &[0_i128, 1, -1, 99, -1_234_567, i128::MIN, i128::MAX] {
+            let alphabet = Alphabet::BASE36;
+            let expected = alphabet
+                .display_signed(n)
+                .with_grouping('_', 4)
+                .with_sign(SignStyle::Always)
+                .with_prefix("z:");
+            let display = BigDisplay::new_signed(n.into(), alphabet)
+                .with_grouping('_', 4)
+                .with_sign(SignStyle::Always)
+                .with_prefix("z:");
+            assert_eq!(format!("{:>60}", display), format!("{:>60}", expected));
+            assert_eq!(format!("{:060}", display), format!("{:060}", expected));
+            assert_eq!(
+                display.magnitude(),
+                &BigUint::from(SignedInteger::magnitude(n))
+            );
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_digit_blocks_match_digits() {
+        let mut numbers = numbers();
+        let power = BigUint::from(10_u8).pow(19 * 64);
+        numbers.extend(vec![
+            power.clone() - 1_u8,
+            power.clone(),
+            power.pow(4) - 1_u8,
+            power.pow(4),
+            power.pow(5) + 1_u8,
+            BigUint::from(7_u8).pow(20_000),
+        ]);
+
+        for number in numbers {
+            for &radix in &[2, 3, 10, 16, 1000, u64::MAX] {
+                let blocks = DigitBlocks::new(&number, radix).unwrap();
+                let expected: Vec<_> = (&number).into_digits(radix).unwrap().collect();
+                assert_eq!(blocks.nbr_digits(), expected.len());
+                assert_eq!(blocks.radix(), radix);
+
+                let digits: Vec<_> = blocks.as_slice().iter().cloned().flatten().collect();
+                assert_eq!(digits, expected, "{} {}", number, radix);
+                assert!(blocks.as_slice()[1..]
+                    .iter()
+                    .all(|block| block.len() == blocks.block_len()));
+            }
+        }
+
+        assert_eq!(
+            DigitBlocks::new(&BigUint::zero(), 10).unwrap().nbr_blocks(),
+            1
+        );
+        assert_eq!(DigitBlocks::new(&power, 10).unwrap().as_slice()[0].len(), 1);
+        assert_eq!(DigitBlocks::new(&power, 1).unwrap_err(), RadixError::Radix1);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(
+            BigUint::zero().into_digits(0).unwrap_err(),
+            RadixError::Radix0
+        );
+        assert_eq!(
+            BigUint::zero().into_digits(1).unwrap_err(),
+            RadixError::Radix1
+        );
+        assert_eq!(from_digits_be(&[] as &[u8], 10), Err(ParseError::Empty));
+        assert_eq!(
+            from_digits_be([1_u8], 0),
+            Err(ParseError::Radix(RadixError::Radix0))
+        );
+        assert_eq!(
+            from_digits_be([0_u8, 0], 1),
+            Err(ParseError::Radix(RadixError::Radix1))
+        );
+        assert_eq!(
+            from_digits_be(&[] as &[u8], 1),
+            Err(ParseError::Radix(RadixError::Radix1))
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Check digits, computed over the digits of a number.
+//!
+//! The digits are given most significant first, in the order they are read, as any iterator over
+//! integers: a [`DigitsIterator`](../digits_iterator/struct.DigitsIterator.html), a slice, or
+//! the digits of a string. Algorithms which weigh digits from the right keep track of every
+//! possible alignment, so that the digits can be streamed without knowing their number ahead of
+//! time.
+//!
+//! Schemes which weigh each digit and take the remainder of their sum can be described with a
+//! [`WeightedChecksum`](weighted/struct.WeightedChecksum.html), of which Luhn, ISBN-10, EAN-13,
+//! UPC-A, the NHS number and the CPF are presets.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::checksum::weighted::WeightedChecksum;
+//! use radixal::checksum::Checksum;
+//!
+//! let id = WeightedChecksum::LUHN.with_check_digit(7_992_739_871_u64).unwrap();
+//! assert_eq!(id, 79_927_398_713);
+//! assert_eq!(WeightedChecksum::LUHN.strip_check_digit(id), Some(7_992_739_871));
+//! assert_eq!(WeightedChecksum::LUHN.strip_check_digit(id + 1), None);
+//! ```
+use crate::parse::{push_digit, DigitValue};
+use crate::{IntoDigits, RadixMath};
+
+pub mod damm;
+pub mod ean;
+pub mod isbn;
+pub mod luhn;
+pub mod mod11;
+pub mod mod97;
+pub mod verhoeff;
+pub mod weighted;
+
+/// Returns the value of `digit` if it is smaller than `radix`.
+fn digit_value<D: DigitValue>(digit: D, radix: usize) -> Option<usize> {
+    digit.into_value().ok().filter(|&digit| digit < radix)
+}
+
+/// An algorithm computing a single check digit from the digits of a payload.
+pub trait Checksum {
+    /// Returns the radix of the digits.
+    fn radix(&self) -> usize;
+
+    /// Returns the check digit to append to the payload `digits`, most significant first.
+    ///
+    /// Returns `None` if a digit is invalid, or if the payload has no check digit.
+    fn compute<I>(&self, digits: I) -> Option<usize>
+    where
+        I: IntoIterator,
+        I::Item: DigitValue;
+
+    /// Returns `true` if `digits`, most significant first and ending with their check digit, are
+    /// valid.
+    fn validate<I>(&self, digits: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: DigitValue;
+
+    /// Appends the check digit of `number` to it, shifting it by one digit under the radix.
+    ///
+    /// The payload is the digits of the number without leading zeroes, so the payload of 0 is
+    /// empty.
+    ///
+    /// Returns `None` if the radix does not fit in `T`, if the number has no check digit, if the
+    /// check digit is not smaller than the radix (such as the `X` of an ISBN-10), or if the result
+    /// overflows.
+    fn with_check_digit<T: IntoDigits>(&self, number: T) -> Option<T> {
+        let radix = T::from_usize(self.radix())?;
+        let digits = number.into_digits(radix).ok()?;
+        let check_digit = self.compute(digits.skip_while(|digit| digit.is_zero()))?;
+        let check_digit = T::from_usize(check_digit).filter(|&digit| digit < radix)?;
+        push_digit(number, radix, check_digit)
+    }
+
+    /// Removes the check digit at the end of `number`, returning the payload if the check digit
+    /// is valid.
+    ///
+    /// Returns `None` if the radix does not fit in `T`, or if the check digit is invalid.
+    fn strip_check_digit<T: IntoDigits>(&self, number: T) -> Option<T> {
+        let radix = T::from_usize(self.radix())?;
+        if self.validate(number.into_digits(radix).ok()?) {
+            Some(number / radix)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::damm::Damm;
+    use super::verhoeff::Verhoeff;
+    use super::weighted::WeightedChecksum;
+    use super::*;
+
+    #[test]
+    fn test_round_trips() {
+        fn round_trip<C: Checksum>(checksum: C, payload: u64) {
+            let number = checksum.with_check_digit(payload).unwrap();
+            assert!(checksum.validate(number.into_digits(10).unwrap()));
+            assert_eq!(checksum.strip_check_digit(number), Some(payload));
+            let other = number / 10 * 10 + (number + 1) % 10;
+            assert_eq!(checksum.strip_check_digit(other), None);
+        }
+
+        for &payload in &[0, 7, 236, 572, 1_234_567_890] {
+            round_trip(WeightedChecksum::LUHN, payload);
+            round_trip(Verhoeff, payload);
+            round_trip(Damm, payload);
+        }
+        assert_eq!(Verhoeff.with_check_digit(236_u16), Some(2363));
+        assert_eq!(Damm.with_check_digit(572_u16), Some(5724));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(WeightedChecksum::LUHN.with_check_digit(24_u8), Some(240));
+        assert_eq!(WeightedChecksum::LUHN.with_check_digit(25_u8), None);
+        assert_eq!(WeightedChecksum::LUHN.with_check_digit(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_check_digit_outside_radix() {
+        // The check digit of 080442957 is `X`.
+        assert_eq!(
+            WeightedChecksum::ISBN10.with_check_digit(80_442_957_u64),
+            None
+        );
+        assert_eq!(
+            WeightedChecksum::ISBN10.with_check_digit(30_640_615_u64),
+            Some(306_406_152)
+        );
+    }
+}
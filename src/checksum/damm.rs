@@ -0,0 +1,142 @@
+//! The Damm algorithm, which detects all single digit errors and all transpositions of adjacent
+//! digits, like the Verhoeff algorithm, with a single table.
+//!
+//! The digits are combined by a totally anti-symmetric quasigroup of order 10, whose diagonal is
+//! zero: the check digit is the interim digit of the payload itself.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::checksum::damm;
+//! use radixal::IntoDigits;
+//!
+//! assert_eq!(damm::compute_check_digit(572_u32.into_decimal_digits()), Some(4));
+//! assert!(damm::check(5724_u32.into_decimal_digits()));
+//! assert!(!damm::check(7524_u32.into_decimal_digits()));
+//! ```
+use super::{digit_value, Checksum};
+use crate::parse::DigitValue;
+
+const DECIMAL: usize = 10;
+
+/// The quasigroup operation, indexed by the interim digit then by the next digit.
+const QUASIGROUP: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+/// The Damm algorithm, as a [`Checksum`](../trait.Checksum.html).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Damm;
+
+impl Checksum for Damm {
+    fn radix(&self) -> usize {
+        DECIMAL
+    }
+
+    fn compute<I>(&self, digits: I) -> Option<usize>
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        compute_check_digit(digits)
+    }
+
+    fn validate<I>(&self, digits: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        check(digits)
+    }
+}
+
+/// Returns `true` if the decimal `digits`, ending with their check digit, are valid.
+///
+/// Returns `false` if there are no digits, or if a digit is larger than 9.
+pub fn check<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    let mut digits = digits.into_iter().peekable();
+    digits.peek().is_some() && interim(digits) == Some(0)
+}
+
+/// Returns the check digit to append to the decimal `digits`.
+///
+/// Returns `None` if a digit is larger than 9.
+pub fn compute_check_digit<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    interim(digits)
+}
+
+/// Returns the interim digit after all the `digits`.
+fn interim<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    digits.into_iter().try_fold(0, |interim, digit| {
+        let digit = digit_value(digit, DECIMAL)?;
+        Some(usize::from(QUASIGROUP[interim][digit]))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    #[test]
+    fn test_reference_numbers() {
+        for &(payload, check_digit) in &[(572_u64, 4), (0, 0), (1, 3), (123456789, 4)] {
+            assert_eq!(
+                compute_check_digit(payload.into_decimal_digits()),
+                Some(check_digit)
+            );
+            assert!(check(
+                (payload * 10 + check_digit as u64).into_decimal_digits()
+            ));
+        }
+    }
+
+    #[test]
+    fn test_detects_single_errors_and_transpositions() {
+        let digits = [1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 4];
+        assert!(check(digits));
+
+        for idx in 0..digits.len() {
+            for digit in 0..10 {
+                let mut changed = digits;
+                changed[idx] = digit;
+                assert_eq!(check(changed), changed == digits, "{} {}", idx, digit);
+            }
+        }
+
+        for idx in 0..digits.len() - 1 {
+            let mut swapped = digits;
+            swapped.swap(idx, idx + 1);
+            assert!(!check(swapped), "{}", idx);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(!check(core::iter::empty::<u8>()));
+        assert!(!check([1_u8, 10]));
+        assert_eq!(compute_check_digit([10_u8]), None);
+        assert_eq!(compute_check_digit(core::iter::empty::<u8>()), Some(0));
+    }
+}
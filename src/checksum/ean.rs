@@ -0,0 +1,121 @@
+//! The check digits of UPC-A and EAN-13 barcodes.
+//!
+//! From the rightmost digit, which is the check digit, the digits are weighted alternately by 1
+//! and 3, and their sum must be a multiple of 10. A UPC-A is an EAN-13 starting with 0, so both
+//! give the same check digit, and the digits of a number, without its leading zeroes, can be used
+//! for either.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::checksum::ean;
+//! use radixal::IntoDigits;
+//!
+//! assert!(ean::check_upca(36_000_291_452_u64.into_decimal_digits()));
+//! assert_eq!(ean::compute_ean13_check_digit([4_u8, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3]), Some(1));
+//! ```
+use super::weighted::WeightedChecksum;
+use crate::parse::DigitValue;
+
+/// Returns `true` if the decimal `digits`, ending with their check digit, are a valid UPC-A.
+///
+/// Returns `false` if there are no digits or more than 12, or if a digit is larger than 9.
+pub fn check_upca<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::UPCA.validate(digits)
+}
+
+/// Returns the check digit of a UPC-A to append to the decimal `digits`.
+///
+/// Returns `None` if there are more than 11 digits or if a digit is larger than 9.
+pub fn compute_upca_check_digit<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::UPCA.compute(digits)
+}
+
+/// Returns `true` if the decimal `digits`, ending with their check digit, are a valid EAN-13.
+///
+/// Returns `false` if there are no digits or more than 13, or if a digit is larger than 9.
+///
+/// # Example
+///
+/// ```
+/// use radixal::checksum::ean;
+///
+/// assert!(ean::check_ean13([4_u8, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3, 1]));
+/// assert!(!ean::check_ean13([4_u8, 0, 0, 6, 3, 8, 1, 3, 3, 3, 3, 9, 1]));
+/// ```
+pub fn check_ean13<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::EAN13.validate(digits)
+}
+
+/// Returns the check digit of an EAN-13 to append to the decimal `digits`.
+///
+/// Returns `None` if there are more than 12 digits or if a digit is larger than 9.
+pub fn compute_ean13_check_digit<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::EAN13.compute(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    #[test]
+    fn test_reference_numbers() {
+        for &upca in &[36_000_291_452_u64, 42_100_005_264, 12_345_678_905] {
+            assert!(check_upca(upca.into_decimal_digits()), "{}", upca);
+            assert!(check_ean13(upca.into_decimal_digits()), "{}", upca);
+            assert_eq!(
+                compute_upca_check_digit((upca / 10).into_decimal_digits()),
+                Some((upca % 10) as usize)
+            );
+        }
+
+        for &ean13 in &[4_006_381_333_931_u64, 5_901_234_123_457, 9_780_201_379_624] {
+            assert!(check_ean13(ean13.into_decimal_digits()), "{}", ean13);
+            assert!(!check_upca(ean13.into_decimal_digits()), "{}", ean13);
+            assert_eq!(
+                compute_ean13_check_digit((ean13 / 10).into_decimal_digits()),
+                Some((ean13 % 10) as usize)
+            );
+        }
+    }
+
+    #[test]
+    fn test_detects_single_errors() {
+        let digits = [5_u8, 9, 0, 1, 2, 3, 4, 1, 2, 3, 4, 5, 7];
+        for idx in 0..digits.len() {
+            for digit in 0..10 {
+                let mut changed = digits;
+                changed[idx] = digit;
+                assert_eq!(check_ean13(changed), changed == digits, "{} {}", idx, digit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lengths() {
+        assert!(!check_upca(core::iter::empty::<u8>()));
+        assert!(check_upca([0_u8; 12]));
+        assert!(!check_upca([0_u8; 13]));
+        assert_eq!(compute_upca_check_digit([0_u8; 12]), None);
+        assert!(check_ean13([0_u8; 13]));
+        assert_eq!(compute_ean13_check_digit([0_u8; 13]), None);
+        assert_eq!(compute_ean13_check_digit([1_u8, 10]), None);
+    }
+}
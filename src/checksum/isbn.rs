@@ -0,0 +1,139 @@
+//! The check digits of ISBN-10 and ISBN-13.
+//!
+//! Both weigh the digits by their position from the right, so the digits of a number, without its
+//! leading zeroes, give the same check digit as the full ISBN.
+//!
+//! An ISBN-10 is checked modulo 11: its check digit can be 10, written `X`, which the
+//! [`ISBN10`](constant.ISBN10.html) alphabet reads. An ISBN-13 is an
+//! [EAN-13](../ean/index.html); its prefix (978 or 979) is not checked.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::checksum::isbn;
+//! use radixal::parse::CharDigits;
+//! use radixal::IntoDigits;
+//!
+//! assert_eq!(isbn::compute_isbn10_check_digit(30_640_615_u32.into_decimal_digits()), Some(2));
+//! assert!(isbn::check_isbn10(CharDigits::new("080442957X", isbn::ISBN10)));
+//! assert!(isbn::check_isbn13(9_780_306_406_157_u64.into_decimal_digits()));
+//! ```
+use super::ean;
+use super::weighted::WeightedChecksum;
+use crate::alphabet::Alphabet;
+use crate::parse::DigitValue;
+
+const ISBN10_SYMBOLS: [char; 11] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'X'];
+
+/// The decimal digits, and `X` for the check digit 10 of an ISBN-10.
+pub const ISBN10: Alphabet<'static> = Alphabet::from_symbols(&ISBN10_SYMBOLS);
+
+/// Returns `true` if the decimal `digits`, ending with their check digit, are a valid ISBN-10.
+///
+/// The check digit can be 10. Returns `false` if there are no digits or more than 10, or if a digit
+/// other than the check digit is larger than 9.
+///
+/// # Example
+///
+/// ```
+/// use radixal::checksum::isbn;
+///
+/// assert!(isbn::check_isbn10([0_u8, 3, 0, 6, 4, 0, 6, 1, 5, 2]));
+/// assert!(isbn::check_isbn10([0_u8, 8, 0, 4, 4, 2, 9, 5, 7, 10]));
+/// assert!(!isbn::check_isbn10([0_u8, 3, 0, 6, 4, 0, 6, 1, 2, 5]));
+/// ```
+pub fn check_isbn10<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::ISBN10.validate(digits)
+}
+
+/// Returns the check digit of an ISBN-10 to append to the decimal `digits`, from 0 to 10.
+///
+/// Returns `None` if there are more than 9 digits or if a digit is larger than 9.
+pub fn compute_isbn10_check_digit<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::ISBN10.compute(digits)
+}
+
+/// Returns `true` if the decimal `digits`, ending with their check digit, are a valid ISBN-13.
+///
+/// Returns `false` if there are no digits or more than 13, or if a digit is larger than 9.
+pub fn check_isbn13<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    ean::check_ean13(digits)
+}
+
+/// Returns the check digit of an ISBN-13 to append to the decimal `digits`.
+///
+/// Returns `None` if there are more than 12 digits or if a digit is larger than 9.
+pub fn compute_isbn13_check_digit<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    ean::compute_ean13_check_digit(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::CharDigits;
+    use crate::IntoDigits;
+
+    #[test]
+    fn test_isbn10() {
+        for &isbn in &[
+            "0306406152",
+            "0471958697",
+            "0198526636",
+            "080442957X",
+            "9999999999",
+        ] {
+            assert!(check_isbn10(CharDigits::new(isbn, ISBN10)), "{}", isbn);
+
+            let (payload, check_symbol) = isbn.split_at(9);
+            let check_digit = compute_isbn10_check_digit(CharDigits::new(payload, ISBN10));
+            assert_eq!(
+                check_digit.and_then(|digit| ISBN10.symbol(digit)),
+                check_symbol.chars().next()
+            );
+        }
+
+        assert!(!check_isbn10(CharDigits::new("0306406125", ISBN10)));
+        assert!(!check_isbn10(CharDigits::new("03064061X2", ISBN10)));
+    }
+
+    #[test]
+    fn test_isbn13() {
+        for &isbn in &[9_780_306_406_157_u64, 9_781_861_972_712, 9_790_260_000_438] {
+            assert!(check_isbn13(isbn.into_decimal_digits()), "{}", isbn);
+            assert!(!check_isbn13((isbn + 10).into_decimal_digits()), "{}", isbn);
+            assert_eq!(
+                compute_isbn13_check_digit((isbn / 10).into_decimal_digits()),
+                Some((isbn % 10) as usize)
+            );
+        }
+    }
+
+    #[test]
+    fn test_lengths() {
+        assert!(!check_isbn10(core::iter::empty::<u8>()));
+        assert!(!check_isbn10([0_u8; 11]));
+        assert_eq!(compute_isbn10_check_digit([0_u8; 10]), None);
+        assert!(check_isbn10([0_u8; 10]));
+
+        assert!(!check_isbn13(core::iter::empty::<u8>()));
+        assert!(!check_isbn13([0_u8; 14]));
+        assert_eq!(compute_isbn13_check_digit([0_u8; 13]), None);
+        assert!(check_isbn13([0_u8; 13]));
+    }
+}
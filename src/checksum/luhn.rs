@@ -0,0 +1,145 @@
+//! The Luhn algorithm, used by credit card numbers and IMEIs, and its generalization to other
+//! radices (Luhn mod N).
+//!
+//! From the rightmost digit, every second digit is doubled, and replaced by the sum of its
+//! digits. A number is valid if the sum of all the digits is a multiple of the radix.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::checksum::luhn;
+//! use radixal::IntoDigits;
+//!
+//! assert!(luhn::check(4539_1488_0343_6467_u64.into_decimal_digits()));
+//! assert_eq!(luhn::compute_check_digit([7_u8, 9, 9, 2, 7, 3, 9, 8, 7, 1]), Some(3));
+//! ```
+use super::weighted::WeightedChecksum;
+use crate::parse::DigitValue;
+
+/// Returns `true` if the decimal `digits`, ending with their check digit, are valid.
+///
+/// Returns `false` if there are no digits, or if a digit is larger than 9.
+///
+/// # Example
+///
+/// ```
+/// use radixal::checksum::luhn;
+///
+/// // An IMEI.
+/// assert!(luhn::check([4_u8, 9, 0, 1, 5, 4, 2, 0, 3, 2, 3, 7, 5, 1, 8]));
+/// assert!(!luhn::check([4_u8, 9, 0, 1, 5, 4, 2, 0, 3, 2, 3, 7, 5, 8, 1]));
+/// ```
+pub fn check<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::LUHN.validate(digits)
+}
+
+/// Returns the check digit to append to the decimal `digits`.
+///
+/// Returns `None` if a digit is larger than 9.
+pub fn compute_check_digit<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::LUHN.compute(digits)
+}
+
+/// Returns `true` if `digits` under `radix`, ending with their check digit, are valid.
+///
+/// Returns `false` if there are no digits, if the radix is smaller than 2 or if a digit is not
+/// smaller than the radix.
+///
+/// # Example
+///
+/// ```
+/// use radixal::checksum::luhn;
+/// use radixal::IntoDigits;
+///
+/// let payload = 0xc0ffee_u32;
+/// let check = luhn::compute_check_digit_radix(payload.into_digits(16).unwrap(), 16).unwrap();
+/// assert_eq!(check, 0xb);
+/// assert!(luhn::check_radix((payload << 4 | check as u32).into_digits(16).unwrap(), 16));
+/// ```
+pub fn check_radix<I>(digits: I, radix: usize) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    luhn(radix).is_some_and(|checksum| checksum.validate(digits))
+}
+
+/// Returns the check digit to append to `digits` under `radix`.
+///
+/// Returns `None` if the radix is smaller than 2 or if a digit is not smaller than the radix.
+pub fn compute_check_digit_radix<I>(digits: I, radix: usize) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    luhn(radix)?.compute(digits)
+}
+
+/// Returns the Luhn mod N checksum, where N is the radix.
+fn luhn(radix: usize) -> Option<WeightedChecksum<'static>> {
+    let checksum = WeightedChecksum::new(WeightedChecksum::LUHN.weights(), radix)
+        .and_then(|checksum| checksum.with_radix(radix))
+        .ok()?;
+    Some(checksum.with_digit_sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    #[test]
+    fn test_reference_numbers() {
+        // Valid test card numbers, and the IMEI example of the GSMA.
+        for &number in &[
+            79_927_398_713_u64,
+            4_111_111_111_111_111,
+            5_555_555_555_554_444,
+            378_282_246_310_005,
+            490_154_203_237_518,
+        ] {
+            assert!(check(number.into_decimal_digits()), "{}", number);
+            assert!(!check((number + 1).into_decimal_digits()), "{}", number);
+
+            let payload = (number / 10).into_decimal_digits();
+            assert_eq!(compute_check_digit(payload), Some((number % 10) as usize));
+        }
+    }
+
+    #[test]
+    fn test_leading_zeroes_do_not_matter() {
+        assert!(check([0_u8, 0, 1, 8]));
+        assert!(check([1_u8, 8]));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(!check(core::iter::empty::<u8>()));
+        assert!(!check([1_u8, 10]));
+        assert_eq!(compute_check_digit([10_u8]), None);
+        assert_eq!(compute_check_digit_radix([0_u8], 1), None);
+    }
+
+    #[test]
+    fn test_other_radices() {
+        for radix in 2..=36_u64 {
+            for &payload in &[0, 1, 7, 1234, u32::MAX as u64] {
+                let digits = payload.into_digits(radix).unwrap();
+                let check_digit = compute_check_digit_radix(digits, radix as usize).unwrap();
+                let number = payload * radix + check_digit as u64;
+                assert!(check_radix(
+                    number.into_digits(radix).unwrap(),
+                    radix as usize
+                ));
+            }
+        }
+    }
+}
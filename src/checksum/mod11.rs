@@ -0,0 +1,189 @@
+//! Check digits of national identifiers weighted modulo 11.
+//!
+//! The NHS number of England and Wales has a single check digit, given by the
+//! [`NHS`](../weighted/struct.WeightedChecksum.html#associatedconstant.NHS) preset. The Brazilian
+//! CPF has two: the second one is computed over the number followed by the first one, with the
+//! same [`CPF`](../weighted/struct.WeightedChecksum.html#associatedconstant.CPF) preset.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::checksum::mod11;
+//! use radixal::IntoDigits;
+//!
+//! assert!(mod11::check_nhs(9_434_765_919_u64.into_decimal_digits()));
+//! assert_eq!(
+//!     mod11::compute_cpf_check_digits(111_444_777_u32.into_decimal_digits()),
+//!     Some([3, 5])
+//! );
+//! assert!(mod11::check_cpf(11_144_477_735_u64.into_decimal_digits()));
+//! ```
+use super::digit_value;
+use super::weighted::{State, WeightedChecksum};
+use crate::parse::DigitValue;
+
+const DECIMAL: usize = 10;
+const CPF_LEN: usize = 9;
+
+/// Returns `true` if the decimal `digits`, ending with their check digit, are a valid NHS number.
+///
+/// Returns `false` if there are no digits or more than 10, or if a digit is larger than 9.
+pub fn check_nhs<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::NHS.validate(digits)
+}
+
+/// Returns the check digit of an NHS number to append to the decimal `digits`.
+///
+/// Returns `None` if there are more than 9 digits, if a digit is larger than 9, or if no NHS
+/// number starts with these digits.
+///
+/// # Example
+///
+/// ```
+/// use radixal::checksum::mod11;
+///
+/// assert_eq!(mod11::compute_nhs_check_digit([4_u8, 0, 1, 0, 2, 3, 2, 1, 3]), Some(7));
+/// // The check digit would be 10.
+/// assert_eq!(mod11::compute_nhs_check_digit([1_u8, 2, 3, 4, 5, 6, 7, 8, 9]), None);
+/// ```
+pub fn compute_nhs_check_digit<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    WeightedChecksum::NHS.compute(digits)
+}
+
+/// Returns `true` if the decimal `digits`, ending with their two check digits, are a valid CPF.
+///
+/// Returns `false` if there are fewer than 2 digits or more than 11, or if a digit is larger than
+/// 9.
+pub fn check_cpf<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    let mut state = State::default();
+    let mut len = 0;
+    // The last two digits, once read.
+    let mut last = [None; 2];
+    for digit in digits {
+        let digit = match digit_value(digit, DECIMAL) {
+            Some(digit) => digit,
+            None => return false,
+        };
+        if let Some(previous) = last[0] {
+            WeightedChecksum::CPF.push(&mut state, previous);
+            len += 1;
+        }
+        last = [last[1], Some(digit)];
+    }
+
+    match last {
+        [Some(first), Some(second)] if len <= CPF_LEN => {
+            let mut with_first = state;
+            WeightedChecksum::CPF.push(&mut with_first, first);
+            WeightedChecksum::CPF.check_digit(&state) == Some(first)
+                && WeightedChecksum::CPF.check_digit(&with_first) == Some(second)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the two check digits of a CPF to append to the decimal `digits`.
+///
+/// Returns `None` if there are more than 9 digits or if a digit is larger than 9.
+pub fn compute_cpf_check_digits<I>(digits: I) -> Option<[usize; 2]>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    let mut state = State::default();
+    let mut len = 0;
+    for digit in digits {
+        WeightedChecksum::CPF.push(&mut state, digit_value(digit, DECIMAL)?);
+        len += 1;
+    }
+    if len > CPF_LEN {
+        return None;
+    }
+
+    let first = WeightedChecksum::CPF.check_digit(&state)?;
+    WeightedChecksum::CPF.push(&mut state, first);
+    let second = WeightedChecksum::CPF.check_digit(&state)?;
+    Some([first, second])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::CharDigits;
+    use crate::IntoDigits;
+
+    #[test]
+    fn test_nhs() {
+        for &number in &[
+            9_434_765_919_u64,
+            4_010_232_137,
+            1_234_567_881,
+            4_505_577_104,
+        ] {
+            assert!(check_nhs(number.into_decimal_digits()), "{}", number);
+            assert!(!check_nhs((number ^ 1).into_decimal_digits()), "{}", number);
+            assert_eq!(
+                compute_nhs_check_digit((number / 10).into_decimal_digits()),
+                Some((number % 10) as usize)
+            );
+        }
+
+        assert!(!check_nhs([1_u8, 2, 3, 4, 5, 6, 7, 8, 9, 10]));
+        assert!(!check_nhs([0_u8; 11]));
+        assert_eq!(compute_nhs_check_digit([0_u8; 10]), None);
+    }
+
+    #[test]
+    fn test_cpf() {
+        for &(cpf, check_digits) in &[
+            ("11144477735", [3, 5]),
+            ("52998224725", [2, 5]),
+            ("12345678909", [0, 9]),
+            ("01234567890", [9, 0]),
+            ("00000000000", [0, 0]),
+        ] {
+            let digits = || CharDigits::with_radix(cpf, 10).unwrap();
+            assert!(check_cpf(digits()), "{}", cpf);
+            assert_eq!(
+                compute_cpf_check_digits(digits().take(CPF_LEN)),
+                Some(check_digits)
+            );
+        }
+
+        for &cpf in &[
+            "11144477753",
+            "11144477745",
+            "1114447773",
+            "111444777350",
+            "5",
+        ] {
+            assert!(
+                !check_cpf(CharDigits::with_radix(cpf, 10).unwrap()),
+                "{}",
+                cpf
+            );
+        }
+        assert_eq!(compute_cpf_check_digits([0_u8; 10]), None);
+    }
+
+    #[test]
+    fn test_cpf_without_leading_zeroes() {
+        assert!(check_cpf(1_234_567_890_u64.into_decimal_digits()));
+        assert_eq!(
+            compute_cpf_check_digits(12_345_678_u32.into_decimal_digits()),
+            Some([9, 0])
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! The ISO 7064 MOD 97-10 check digits, used by IBANs.
+//!
+//! A number is valid if it is congruent to 1 modulo 97. The remainder is reduced after every
+//! digit, so numbers of any length can be checked without overflowing.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::checksum::mod97;
+//!
+//! assert!(mod97::check_iban("GB82 WEST 1234 5698 7654 32"));
+//! assert!(!mod97::check_iban("GB82 WEST 1234 5698 7654 23"));
+//! assert_eq!(mod97::compute_iban_check_digits("GB", "WEST12345698765432"), Some(82));
+//! ```
+use super::digit_value;
+use crate::parse::{DigitValue, ParseError};
+
+const DECIMAL: usize = 10;
+const MODULUS: u32 = 97;
+/// The remainder of a valid number.
+const VALID: u32 = 1;
+
+const IBAN_MAX_LEN: usize = 34;
+const IBAN_COUNTRY_LEN: usize = 2;
+const IBAN_HEADER_LEN: usize = IBAN_COUNTRY_LEN + 2;
+const IBAN_SEPARATOR: char = ' ';
+
+/// The remainder modulo 97 of a decimal number, read one digit at a time, most significant first.
+///
+/// # Example
+///
+/// ```
+/// use radixal::checksum::mod97::Mod97;
+/// use radixal::IntoDigits;
+///
+/// let mut reduction = Mod97::new();
+/// for digit in u128::MAX.into_decimal_digits().chain(u128::MAX.into_decimal_digits()) {
+///     reduction.push(digit).unwrap();
+/// }
+/// assert_eq!(reduction.remainder(), 33);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Mod97 {
+    remainder: u32,
+}
+
+impl Mod97 {
+    /// Creates a new `Mod97`, as if no digit had been read.
+    pub fn new() -> Self {
+        Mod97::default()
+    }
+
+    /// Reads the next decimal digit.
+    ///
+    /// Returns an `Err(ParseError)` if the digit is invalid or larger than 9, leaving the remainder
+    /// unchanged.
+    pub fn push<D: DigitValue>(&mut self, digit: D) -> Result<(), ParseError> {
+        let value = digit.into_value()?;
+        let digit = digit_value(value, DECIMAL).ok_or(ParseError::DigitTooLarge(value))?;
+        self.push_value(digit as u32);
+        Ok(())
+    }
+
+    /// Returns the remainder of the digits read so far.
+    pub fn remainder(&self) -> u32 {
+        self.remainder
+    }
+
+    /// Returns `true` if the digits read so far, ending with their two check digits, are valid.
+    pub fn is_valid(&self) -> bool {
+        self.remainder == VALID
+    }
+
+    /// Returns the two check digits to append to the digits read so far, from 2 to 98.
+    pub fn check_digits(&self) -> u8 {
+        (MODULUS + VALID - self.remainder * 100 % MODULUS) as u8
+    }
+
+    /// Reads a value smaller than 100, as one or two decimal digits.
+    fn push_value(&mut self, value: u32) {
+        let shift = if value < 10 { 10 } else { 100 };
+        self.remainder = (self.remainder * shift + value) % MODULUS;
+    }
+
+    /// Reads the digits and uppercase letters of an IBAN, each letter standing for its two digits
+    /// value from 10 (`A`) to 35 (`Z`).
+    ///
+    /// Returns `false` on any other symbol.
+    fn push_symbols<I: Iterator<Item = char>>(&mut self, symbols: I) -> bool {
+        for symbol in symbols {
+            match symbol.to_digit(36) {
+                Some(value) if !symbol.is_ascii_lowercase() => self.push_value(value),
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+/// Returns `true` if the decimal `digits`, ending with their two check digits, are valid.
+///
+/// Returns `false` if there are no digits, or if a digit is larger than 9.
+///
+/// # Example
+///
+/// ```
+/// use radixal::checksum::mod97;
+/// use radixal::IntoDigits;
+///
+/// assert!(mod97::check(777_u32.into_decimal_digits()));
+/// assert!(!mod97::check(771_u32.into_decimal_digits()));
+/// ```
+pub fn check<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    let mut digits = digits.into_iter().peekable();
+    digits.peek().is_some() && reduce(digits).is_some_and(|reduction| reduction.is_valid())
+}
+
+/// Returns the two check digits to append to the decimal `digits`, from 2 to 98.
+///
+/// Returns `None` if a digit is larger than 9.
+pub fn compute_check_digits<I>(digits: I) -> Option<u8>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    reduce(digits).map(|reduction| reduction.check_digits())
+}
+
+fn reduce<I>(digits: I) -> Option<Mod97>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    let mut reduction = Mod97::new();
+    for digit in digits {
+        reduction.push(digit).ok()?;
+    }
+    Some(reduction)
+}
+
+/// Returns `true` if `iban` is a valid IBAN, ignoring spaces.
+///
+/// The IBAN must be written in uppercase, with a country code of two letters followed by two
+/// check digits, and at most 34 symbols. The country code and the length of the account number
+/// for each country are not checked.
+pub fn check_iban(iban: &str) -> bool {
+    let symbols = || iban.chars().filter(|&symbol| symbol != IBAN_SEPARATOR);
+
+    let len = symbols().count();
+    let mut header = symbols();
+    let valid_header = header
+        .by_ref()
+        .take(IBAN_COUNTRY_LEN)
+        .all(|c| c.is_ascii_uppercase())
+        && header
+            .take(IBAN_HEADER_LEN - IBAN_COUNTRY_LEN)
+            .all(|c| c.is_ascii_digit());
+    if len <= IBAN_HEADER_LEN || len > IBAN_MAX_LEN || !valid_header {
+        return false;
+    }
+
+    // The header is moved after the account number.
+    let mut reduction = Mod97::new();
+    reduction.push_symbols(
+        symbols()
+            .skip(IBAN_HEADER_LEN)
+            .chain(symbols().take(IBAN_HEADER_LEN)),
+    ) && reduction.is_valid()
+}
+
+/// Returns the two check digits of the IBAN with the `country` code and the account number
+/// `bban`, from 2 to 98.
+///
+/// Returns `None` if the country code is not two uppercase letters, if the account number is empty
+/// or too long, or if it holds a symbol other than a digit or an uppercase letter.
+pub fn compute_iban_check_digits(country: &str, bban: &str) -> Option<u8> {
+    if country.len() != IBAN_COUNTRY_LEN
+        || !country.chars().all(|c| c.is_ascii_uppercase())
+        || bban.is_empty()
+        || bban.len() > IBAN_MAX_LEN - IBAN_HEADER_LEN
+    {
+        return None;
+    }
+
+    let mut reduction = Mod97::new();
+    if reduction.push_symbols(bban.chars().chain(country.chars())) {
+        Some(reduction.check_digits())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ibans() {
+        for &iban in &[
+            "GB82WEST12345698765432",
+            "DE89 3704 0044 0532 0130 00",
+            "FR14 2004 1010 0505 0001 3M02 606",
+            "NO9386011117947",
+            "MT84 MALT 0110 0001 2345 MTLC AST0 01S",
+            "BE71 0961 2345 6769",
+        ] {
+            assert!(check_iban(iban), "{}", iban);
+
+            let compact: String = iban.chars().filter(|&c| c != ' ').collect();
+            let check_digits = compact[2..4].parse::<u8>().unwrap();
+            assert_eq!(
+                compute_iban_check_digits(&compact[..2], &compact[4..]),
+                Some(check_digits),
+                "{}",
+                iban
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_ibans() {
+        for &iban in &[
+            "",
+            "GB82",
+            "GB28WEST12345698765432",
+            "gb82west12345698765432",
+            "GB82-WEST-1234-5698-7654-32",
+            "1282WEST12345698765432",
+            "GBX2WEST12345698765432",
+            "MT84MALT011000012345MTLCAST001S0000",
+        ] {
+            assert!(!check_iban(iban), "{}", iban);
+        }
+
+        assert_eq!(compute_iban_check_digits("G", "WEST12345698765432"), None);
+        assert_eq!(compute_iban_check_digits("GB", ""), None);
+        assert_eq!(compute_iban_check_digits("GB", "west12345698765432"), None);
+    }
+
+    #[test]
+    fn test_long_numbers() {
+        let digits = || u128::MAX.into_decimal_digits();
+        let mut expected = 0;
+        for digit in digits().chain(digits()).chain(digits()) {
+            expected = (expected * 10 + digit) % 97;
+        }
+
+        let reduction = reduce(digits().chain(digits()).chain(digits())).unwrap();
+        assert_eq!(u128::from(reduction.remainder()), expected);
+
+        let check_digits = reduction.check_digits();
+        let check_digits = [check_digits / 10, check_digits % 10];
+        assert!(check(
+            digits()
+                .chain(digits())
+                .chain(digits())
+                .chain(check_digits.iter().map(|&digit| u128::from(digit)))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_digits() {
+        let mut reduction = Mod97::new();
+        assert_eq!(reduction.push(10_u8), Err(ParseError::DigitTooLarge(10)));
+        assert_eq!(
+            reduction.push(Err(ParseError::InvalidDigit('x'))),
+            Err(ParseError::InvalidDigit('x'))
+        );
+        assert_eq!(reduction, Mod97::new());
+
+        assert!(!check(core::iter::empty::<u8>()));
+        assert_eq!(compute_check_digits([1_u8, 10]), None);
+    }
+}
@@ -0,0 +1,216 @@
+//! The Verhoeff algorithm, which detects all single digit errors and all transpositions of
+//! adjacent digits, unlike the Luhn algorithm (which misses `09` and `90`).
+//!
+//! Each digit is permuted according to its position from the right, and the digits are combined
+//! with the multiplication of the dihedral group D5, which is not commutative.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::checksum::verhoeff;
+//! use radixal::IntoDigits;
+//!
+//! assert_eq!(verhoeff::compute_check_digit(236_u32.into_decimal_digits()), Some(3));
+//! assert!(verhoeff::check(2363_u32.into_decimal_digits()));
+//! assert!(!verhoeff::check(3263_u32.into_decimal_digits()));
+//! ```
+use super::{digit_value, Checksum};
+use crate::parse::DigitValue;
+
+const DECIMAL: usize = 10;
+
+/// The multiplication table of D5.
+const MULTIPLICATION: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// The permutation applied to a digit, by position from the right modulo 8.
+const PERMUTATION: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+/// The inverse of each element of D5.
+const INVERSE: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+const CYCLE: usize = PERMUTATION.len();
+
+/// The Verhoeff algorithm, as a [`Checksum`](../trait.Checksum.html).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Verhoeff;
+
+impl Checksum for Verhoeff {
+    fn radix(&self) -> usize {
+        DECIMAL
+    }
+
+    fn compute<I>(&self, digits: I) -> Option<usize>
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        compute_check_digit(digits)
+    }
+
+    fn validate<I>(&self, digits: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        check(digits)
+    }
+}
+
+/// Returns `true` if the decimal `digits`, ending with their check digit, are valid.
+///
+/// Returns `false` if there are no digits, or if a digit is larger than 9.
+pub fn check<I>(digits: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    match State::new(digits) {
+        Some(state) if state.len > 0 => state.with_check_digit() == 0,
+        _ => false,
+    }
+}
+
+/// Returns the check digit to append to the decimal `digits`.
+///
+/// Returns `None` if a digit is larger than 9.
+pub fn compute_check_digit<I>(digits: I) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    let state = State::new(digits)?;
+    Some(usize::from(INVERSE[state.without_check_digit()]))
+}
+
+/// The product of the permuted digits, for each possible number of digits modulo 8.
+///
+/// The digits are read from the left, so each new digit multiplies the product on the left.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct State {
+    products: [u8; CYCLE],
+    len: usize,
+}
+
+impl State {
+    fn new<I>(digits: I) -> Option<Self>
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        let mut state = State::default();
+        for digit in digits {
+            state.push(digit_value(digit, DECIMAL)?);
+        }
+        Some(state)
+    }
+
+    /// Adds the next digit, which must be smaller than 10.
+    pub(crate) fn push(&mut self, digit: usize) {
+        for (alignment, product) in self.products.iter_mut().enumerate() {
+            // The position from the right, if the final number of digits is `alignment` modulo
+            // 8.
+            let position = (alignment + CYCLE - 1 - self.len % CYCLE) % CYCLE;
+            let permuted = PERMUTATION[position][digit];
+            *product = MULTIPLICATION[usize::from(permuted)][usize::from(*product)];
+        }
+        self.len += 1;
+    }
+
+    /// Returns the product when the last digit is the check digit.
+    pub(crate) fn with_check_digit(&self) -> usize {
+        usize::from(self.products[self.len % CYCLE])
+    }
+
+    /// Returns the product when a check digit is still to be appended.
+    pub(crate) fn without_check_digit(&self) -> usize {
+        usize::from(self.products[(self.len + 1) % CYCLE])
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            products: [0; CYCLE],
+            len: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    #[test]
+    fn test_reference_numbers() {
+        for &(payload, check_digit) in &[
+            (236_u64, 3),
+            (12345, 1),
+            (142857, 0),
+            (123456789012, 0),
+            (8473643095483728456, 7),
+        ] {
+            assert_eq!(
+                compute_check_digit(payload.into_decimal_digits()),
+                Some(check_digit)
+            );
+
+            let digits = payload
+                .into_decimal_digits()
+                .chain(Some(check_digit as u64));
+            assert!(check(digits), "{}", payload);
+        }
+    }
+
+    #[test]
+    fn test_detects_adjacent_transpositions() {
+        let digits = [
+            8_u8, 4, 7, 3, 6, 4, 3, 0, 9, 5, 4, 8, 3, 7, 2, 8, 4, 5, 6, 7,
+        ];
+        assert!(check(digits));
+
+        for idx in 0..digits.len() - 1 {
+            let mut swapped = digits;
+            swapped.swap(idx, idx + 1);
+            if swapped != digits {
+                assert!(!check(swapped), "{}", idx);
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_length_modulo_8() {
+        for len in 1..=17 {
+            let payload = core::iter::repeat_n(7_u8, len);
+            let check_digit = compute_check_digit(payload.clone()).unwrap() as u8;
+            assert!(check(payload.chain(Some(check_digit))), "{}", len);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(!check(core::iter::empty::<u8>()));
+        assert!(!check([1_u8, 10]));
+        assert_eq!(compute_check_digit([10_u8]), None);
+    }
+}
@@ -0,0 +1,680 @@
+//! Checksums which weigh each digit by its position, and map the remainder of the weighted sum to
+//! a check digit.
+//!
+//! The weights are given from the right: the first weight applies to the last digit of the
+//! payload, next to the check digit. They are either repeated, as for Luhn or EAN-13, or given for
+//! each position, as for ISBN-10.
+//!
+//! # Example
+//!
+//! A checksum modulo 7, weighing the digits by 1, 2 and 4 from the right:
+//!
+//! ```
+//! use radixal::checksum::weighted::{WeightedChecksum, Weights};
+//!
+//! let checksum = WeightedChecksum::new(Weights::Cyclic(&[1, 2, 4]), 7).unwrap();
+//! // (3 * 1 + 2 * 2 + 1 * 4) % 7 = 4, and (7 - 4) % 7 = 3.
+//! assert_eq!(checksum.compute([1_u8, 2, 3]), Some(3));
+//! assert!(checksum.validate([1_u8, 2, 3, 3]));
+//! ```
+use super::{digit_value, Checksum};
+use crate::digits_iterator::RadixError;
+use crate::parse::{DigitValue, ParseError};
+use core::fmt;
+
+/// The maximum number of weights of a `WeightedChecksum`.
+pub const MAX_WEIGHTS: usize = 16;
+
+/// Maps the remainder of the weighted sum of the payload, and the modulus, to the check digit,
+/// or to `None` if the payload has no valid check digit.
+pub type CheckMapping = fn(remainder: usize, modulus: usize) -> Option<usize>;
+
+/// The check digit which makes the sum of the payload and the check digit a multiple of the
+/// modulus, i.e. `(modulus - remainder) % modulus`.
+///
+/// This is the default mapping of a `WeightedChecksum`.
+pub fn complement(remainder: usize, modulus: usize) -> Option<usize> {
+    Some((modulus - remainder) % modulus)
+}
+
+/// The complement of the remainder, if it is a decimal digit.
+fn decimal_complement(remainder: usize, modulus: usize) -> Option<usize> {
+    complement(remainder, modulus).filter(|&digit| digit < 10)
+}
+
+/// Errors that can occur when building a `WeightedChecksum`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumError {
+    /// No weights were given.
+    NoWeights,
+    /// More than `MAX_WEIGHTS` weights were given.
+    TooManyWeights,
+    /// The modulus is 0 or 1.
+    ModulusTooSmall,
+    /// The radix of the digits is invalid.
+    Radix(RadixError),
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChecksumError::NoWeights => write!(f, "a checksum needs at least one weight"),
+            ChecksumError::TooManyWeights => {
+                write!(
+                    f,
+                    "a checksum cannot have more than {} weights",
+                    MAX_WEIGHTS
+                )
+            }
+            ChecksumError::ModulusTooSmall => write!(f, "modulus must be at least 2"),
+            ChecksumError::Radix(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChecksumError {}
+
+/// The weights of the digits of the payload, from the right.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weights<'a> {
+    /// The weights are repeated for as many digits as there are.
+    Cyclic(&'a [usize]),
+    /// One weight for each digit: the payload cannot have more digits than there are weights.
+    Fixed(&'a [usize]),
+}
+
+impl<'a> Weights<'a> {
+    fn as_slice(&self) -> &'a [usize] {
+        match *self {
+            Weights::Cyclic(weights) | Weights::Fixed(weights) => weights,
+        }
+    }
+}
+
+/// A checksum computing a single check digit from the weighted sum of the payload digits.
+///
+/// By default, the digits are decimal, the check digit is the [`complement`](fn.complement.html)
+/// of the remainder, and the payload can have any number of digits allowed by the weights.
+///
+/// The check digit is not limited by the radix: the check digit of an ISBN-10 can be 10.
+#[derive(Clone, Copy, Debug)]
+pub struct WeightedChecksum<'a> {
+    weights: Weights<'a>,
+    modulus: usize,
+    radix: usize,
+    digit_sum: bool,
+    mapping: CheckMapping,
+    max_len: Option<usize>,
+}
+
+impl<'a> WeightedChecksum<'a> {
+    /// The Luhn algorithm, over decimal digits.
+    pub const LUHN: WeightedChecksum<'static> = WeightedChecksum {
+        weights: Weights::Cyclic(&[2, 1]),
+        modulus: 10,
+        radix: 10,
+        digit_sum: true,
+        mapping: complement,
+        max_len: None,
+    };
+
+    /// The check digit of an ISBN-10, from 0 to 10, after at most 9 digits.
+    pub const ISBN10: WeightedChecksum<'static> = WeightedChecksum {
+        weights: Weights::Fixed(&[2, 3, 4, 5, 6, 7, 8, 9, 10]),
+        modulus: 11,
+        radix: 10,
+        digit_sum: false,
+        mapping: complement,
+        max_len: None,
+    };
+
+    /// The check digit of an EAN-13, after at most 12 digits.
+    pub const EAN13: WeightedChecksum<'static> = WeightedChecksum {
+        weights: Weights::Cyclic(&[3, 1]),
+        modulus: 10,
+        radix: 10,
+        digit_sum: false,
+        mapping: complement,
+        max_len: Some(12),
+    };
+
+    /// The check digit of a UPC-A, after at most 11 digits.
+    pub const UPCA: WeightedChecksum<'static> = WeightedChecksum {
+        max_len: Some(11),
+        ..WeightedChecksum::EAN13
+    };
+
+    /// The check digit of an NHS number, after at most 9 digits.
+    ///
+    /// A payload whose check digit would be 10 is not a valid NHS number.
+    pub const NHS: WeightedChecksum<'static> = WeightedChecksum {
+        weights: Weights::Fixed(&[2, 3, 4, 5, 6, 7, 8, 9, 10]),
+        modulus: 11,
+        radix: 10,
+        digit_sum: false,
+        mapping: decimal_complement,
+        max_len: None,
+    };
+
+    /// Either check digit of a Brazilian CPF, after at most 10 digits: the first one follows the 9
+    /// digits of the number, and the second one follows the first one.
+    ///
+    /// The check digit is `11 - remainder`, or 0 when it would be 10 or 11.
+    pub const CPF: WeightedChecksum<'static> = WeightedChecksum {
+        weights: Weights::Fixed(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+        modulus: 11,
+        radix: 10,
+        digit_sum: false,
+        mapping: |remainder, modulus| {
+            Some(if remainder < 2 {
+                0
+            } else {
+                modulus - remainder
+            })
+        },
+        max_len: None,
+    };
+
+    /// Creates a new `WeightedChecksum` over decimal digits, with the given `weights` and
+    /// `modulus`.
+    ///
+    /// Returns an `Err(ChecksumError)` if there are no weights or more than `MAX_WEIGHTS`, or if
+    /// the modulus is smaller than 2.
+    pub fn new(weights: Weights<'a>, modulus: usize) -> Result<Self, ChecksumError> {
+        match weights.as_slice().len() {
+            0 => return Err(ChecksumError::NoWeights),
+            len if len > MAX_WEIGHTS => return Err(ChecksumError::TooManyWeights),
+            _ => {}
+        }
+        if modulus < 2 {
+            return Err(ChecksumError::ModulusTooSmall);
+        }
+
+        Ok(WeightedChecksum {
+            weights,
+            modulus,
+            radix: 10,
+            digit_sum: false,
+            mapping: complement,
+            max_len: None,
+        })
+    }
+
+    /// Reads the digits under `radix` instead of 10.
+    ///
+    /// Returns an `Err(ChecksumError::Radix)` if the radix is 0 or 1.
+    pub fn with_radix(self, radix: usize) -> Result<Self, ChecksumError> {
+        match radix {
+            0 => Err(ChecksumError::Radix(RadixError::Radix0)),
+            1 => Err(ChecksumError::Radix(RadixError::Radix1)),
+            radix => Ok(WeightedChecksum { radix, ..self }),
+        }
+    }
+
+    /// Replaces each weighted digit by the sum of its digits under the radix, as the Luhn
+    /// algorithm does.
+    pub fn with_digit_sum(self) -> Self {
+        WeightedChecksum {
+            digit_sum: true,
+            ..self
+        }
+    }
+
+    /// Maps the remainder of the weighted sum to the check digit with `mapping`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::checksum::weighted::{WeightedChecksum, Weights};
+    ///
+    /// // The remainder itself is the check digit.
+    /// let checksum = WeightedChecksum::new(Weights::Cyclic(&[1]), 9)
+    ///     .unwrap()
+    ///     .with_mapping(|remainder, _| Some(remainder));
+    /// assert_eq!(checksum.compute([9_u8, 9, 5]), Some(5));
+    /// ```
+    pub fn with_mapping(self, mapping: CheckMapping) -> Self {
+        WeightedChecksum { mapping, ..self }
+    }
+
+    /// Limits the payload to `max_len` digits, if it is fewer than the fixed weights.
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        WeightedChecksum {
+            max_len: Some(max_len),
+            ..self
+        }
+    }
+
+    /// Returns the weights of the digits.
+    pub fn weights(&self) -> Weights<'a> {
+        self.weights
+    }
+
+    /// Returns the modulus of the weighted sum.
+    pub fn modulus(&self) -> usize {
+        self.modulus
+    }
+
+    /// Returns the radix of the digits.
+    pub fn radix(&self) -> usize {
+        self.radix
+    }
+
+    /// Returns the maximum number of digits of the payload, if any.
+    pub fn max_len(&self) -> Option<usize> {
+        let fixed = match self.weights {
+            Weights::Cyclic(_) => None,
+            Weights::Fixed(weights) => Some(weights.len()),
+        };
+
+        match (fixed, self.max_len) {
+            (Some(fixed), Some(max_len)) => Some(fixed.min(max_len)),
+            (fixed, max_len) => fixed.or(max_len),
+        }
+    }
+
+    /// Returns the check digit to append to the payload `digits`.
+    ///
+    /// Returns `None` if a digit is not smaller than the radix, if there are too many digits, or
+    /// if the payload has no valid check digit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::checksum::weighted::WeightedChecksum;
+    /// use radixal::IntoDigits;
+    ///
+    /// let isbn = 30_640_615_u32.into_decimal_digits();
+    /// assert_eq!(WeightedChecksum::ISBN10.compute(isbn), Some(2));
+    /// ```
+    pub fn compute<I>(&self, digits: I) -> Option<usize>
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        let mut state = State::default();
+        for digit in digits {
+            self.push(&mut state, digit_value(digit, self.radix)?);
+        }
+
+        self.check_digit(&state)
+    }
+
+    /// Returns `true` if `digits`, ending with their check digit, are valid.
+    ///
+    /// Returns `false` if there are no digits, if a digit of the payload is not smaller than the
+    /// radix, or if there are too many digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::checksum::weighted::WeightedChecksum;
+    /// use radixal::IntoDigits;
+    ///
+    /// assert!(WeightedChecksum::LUHN.validate(79_927_398_713_u64.into_decimal_digits()));
+    /// assert!(WeightedChecksum::UPCA.validate(36_000_291_452_u64.into_decimal_digits()));
+    /// assert!(!WeightedChecksum::UPCA.validate(4_006_381_333_931_u64.into_decimal_digits()));
+    /// ```
+    pub fn validate<I>(&self, digits: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        let mut state = State::default();
+        let mut last = None;
+        for digit in digits {
+            if let Some(previous) = last {
+                match digit_value(previous, self.radix) {
+                    Some(previous) => self.push(&mut state, previous),
+                    None => return false,
+                }
+            }
+            match digit.into_value() {
+                Ok(digit) => last = Some(digit),
+                Err(_) => return false,
+            }
+        }
+
+        last.is_some() && self.check_digit(&state) == last
+    }
+
+    /// Adds the next digit of the payload, which must be smaller than the radix, to the sums of
+    /// every alignment of the weights.
+    ///
+    /// The alignment `a` holds the sum if the payload ends up with a number of digits congruent to
+    /// `a` modulo the number of cyclic weights, or with `a + 1` digits for fixed weights.
+    pub(crate) fn push(&self, state: &mut State, digit: usize) {
+        let weights = self.weights.as_slice();
+        let index = state.len;
+        let alignments = match self.weights {
+            Weights::Cyclic(_) => 0..weights.len(),
+            // The payloads shorter than this digit are no longer possible.
+            Weights::Fixed(_) => index.min(weights.len())..weights.len(),
+        };
+
+        for alignment in alignments {
+            let position = match self.weights {
+                Weights::Cyclic(_) => {
+                    (alignment + weights.len() - 1 - index % weights.len()) % weights.len()
+                }
+                Weights::Fixed(_) => alignment - index,
+            };
+            let weighted = self.weigh(weights[position], digit);
+            state.sums[alignment] = (state.sums[alignment] + weighted) % self.modulus;
+        }
+
+        state.len += 1;
+    }
+
+    /// Returns the check digit of the payload read into `state`.
+    pub(crate) fn check_digit(&self, state: &State) -> Option<usize> {
+        if self.max_len().is_some_and(|max_len| state.len > max_len) {
+            return None;
+        }
+
+        let remainder = match (self.weights, state.len) {
+            (_, 0) => 0,
+            (Weights::Cyclic(weights), len) => state.sums[len % weights.len()],
+            (Weights::Fixed(_), len) => state.sums[len - 1],
+        };
+        (self.mapping)(remainder, self.modulus)
+    }
+
+    fn weigh(&self, weight: usize, digit: usize) -> usize {
+        if self.digit_sum {
+            let mut product = weight * digit;
+            let mut sum = 0;
+            while product > 0 {
+                sum += product % self.radix;
+                product /= self.radix;
+            }
+            sum % self.modulus
+        } else {
+            (weight % self.modulus) * (digit % self.modulus) % self.modulus
+        }
+    }
+}
+
+impl<'a> Checksum for WeightedChecksum<'a> {
+    fn radix(&self) -> usize {
+        self.radix
+    }
+
+    fn compute<I>(&self, digits: I) -> Option<usize>
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        WeightedChecksum::compute(self, digits)
+    }
+
+    fn validate<I>(&self, digits: I) -> bool
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        WeightedChecksum::validate(self, digits)
+    }
+}
+
+/// The weighted sums of a payload, for each alignment of the weights.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct State {
+    sums: [usize; MAX_WEIGHTS],
+    len: usize,
+}
+
+/// A `WeightedChecksum` over digits given one at a time, such as while they are typed.
+///
+/// After each digit, the stream tells whether the digits so far end with a valid check digit, and
+/// which check digit would complete them.
+///
+/// # Example
+///
+/// ```
+/// use radixal::checksum::weighted::{ChecksumStream, WeightedChecksum};
+///
+/// let mut stream = ChecksumStream::new(WeightedChecksum::LUHN);
+/// for &digit in &[7_u8, 9, 9, 2, 7, 3, 9, 8, 7, 1] {
+///     stream.push(digit).unwrap();
+/// }
+/// assert!(!stream.is_valid());
+/// assert_eq!(stream.check_digit(), Some(3));
+///
+/// stream.push(3_u8).unwrap();
+/// assert!(stream.is_valid());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ChecksumStream<'a> {
+    checksum: WeightedChecksum<'a>,
+    /// The sums of every digit but the last one.
+    payload: State,
+    last: Option<usize>,
+}
+
+impl<'a> ChecksumStream<'a> {
+    /// Creates a new `ChecksumStream`, without any digit.
+    pub fn new(checksum: WeightedChecksum<'a>) -> Self {
+        ChecksumStream {
+            checksum,
+            payload: State::default(),
+            last: None,
+        }
+    }
+
+    /// Returns the checksum of the stream.
+    pub fn checksum(&self) -> WeightedChecksum<'a> {
+        self.checksum
+    }
+
+    /// Adds the next digit.
+    ///
+    /// The last digit can be a check digit which is not smaller than the radix, such as the `X`
+    /// of an ISBN-10, but only until another digit is added.
+    ///
+    /// Returns an `Err(ParseError)` if the digit is invalid, or if the previous digit is not
+    /// smaller than the radix, leaving the stream unchanged.
+    pub fn push<D: DigitValue>(&mut self, digit: D) -> Result<(), ParseError> {
+        let digit = digit.into_value()?;
+        if let Some(last) = self.last {
+            if last >= self.checksum.radix {
+                return Err(ParseError::DigitTooLarge(last));
+            }
+            self.checksum.push(&mut self.payload, last);
+        }
+
+        self.last = Some(digit);
+        Ok(())
+    }
+
+    /// Removes all the digits.
+    pub fn clear(&mut self) {
+        self.payload = State::default();
+        self.last = None;
+    }
+
+    /// Returns the number of digits added.
+    pub fn len(&self) -> usize {
+        self.payload.len + self.last.map_or(0, |_| 1)
+    }
+
+    /// Returns `true` if no digit was added.
+    pub fn is_empty(&self) -> bool {
+        self.last.is_none()
+    }
+
+    /// Returns `true` if the digits so far end with their valid check digit.
+    pub fn is_valid(&self) -> bool {
+        self.last.is_some() && self.checksum.check_digit(&self.payload) == self.last
+    }
+
+    /// Returns the check digit which would complete the digits so far.
+    ///
+    /// Returns `None` if the last digit is not smaller than the radix, if there are already too
+    /// many digits, or if the digits have no valid check digit.
+    pub fn check_digit(&self) -> Option<usize> {
+        match self.last {
+            Some(last) if last >= self.checksum.radix => None,
+            Some(last) => {
+                let mut state = self.payload;
+                self.checksum.push(&mut state, last);
+                self.checksum.check_digit(&state)
+            }
+            None => self.checksum.check_digit(&self.payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    #[test]
+    fn test_presets() {
+        let luhn = 79_927_398_713_u64;
+        assert!(WeightedChecksum::LUHN.validate(luhn.into_decimal_digits()));
+        assert_eq!(
+            WeightedChecksum::LUHN.compute((luhn / 10).into_decimal_digits()),
+            Some(3)
+        );
+
+        assert!(WeightedChecksum::ISBN10.validate([0_u8, 8, 0, 4, 4, 2, 9, 5, 7, 10]));
+        assert!(!WeightedChecksum::ISBN10.validate([0_u8, 0, 8, 0, 4, 4, 2, 9, 5, 7, 10]));
+        assert!(!WeightedChecksum::ISBN10.validate([0_u8, 8, 0, 4, 4, 2, 9, 5, 10, 7]));
+
+        assert!(WeightedChecksum::EAN13.validate(4_006_381_333_931_u64.into_decimal_digits()));
+        assert_eq!(WeightedChecksum::EAN13.compute([0_u8; 13]), None);
+    }
+
+    #[test]
+    fn test_cyclic_alignments() {
+        let checksum = WeightedChecksum::new(Weights::Cyclic(&[1, 2, 4]), 7).unwrap();
+        for len in 0..10 {
+            let digits = || (0..len).map(|idx| (idx * 7 + 3) % 10);
+
+            let mut expected = 0;
+            for (position, digit) in digits().rev().enumerate() {
+                expected += [1, 2, 4][position % 3] * digit;
+            }
+            let check_digit = (7 - expected % 7) % 7;
+
+            assert_eq!(checksum.compute(digits()), Some(check_digit), "{}", len);
+            assert!(
+                checksum.validate(digits().chain(Some(check_digit))),
+                "{}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn test_fixed_weights() {
+        let checksum = WeightedChecksum::new(Weights::Fixed(&[3, 5, 7]), 11).unwrap();
+        assert_eq!(checksum.compute(core::iter::empty::<u8>()), Some(0));
+        assert_eq!(checksum.compute([1_u8]), Some(8));
+        assert_eq!(checksum.compute([1_u8, 1]), Some(3));
+        assert_eq!(checksum.compute([1_u8, 1, 1]), Some(7));
+        assert_eq!(checksum.compute([1_u8, 1, 1, 1]), None);
+        assert_eq!(checksum.with_max_len(2).compute([1_u8, 1, 1]), None);
+    }
+
+    #[test]
+    fn test_radix_and_digit_sum() {
+        let checksum = WeightedChecksum::new(Weights::Cyclic(&[2, 1]), 16)
+            .unwrap()
+            .with_radix(16)
+            .unwrap()
+            .with_digit_sum();
+        let payload = 0xc0ffee_u32.into_digits(16).unwrap();
+        assert_eq!(checksum.compute(payload), Some(0xb));
+        assert!(!checksum.validate([0x10_u8, 0]));
+
+        assert_eq!(
+            WeightedChecksum::LUHN.with_radix(1).unwrap_err(),
+            ChecksumError::Radix(RadixError::Radix1)
+        );
+    }
+
+    #[test]
+    fn test_mapping() {
+        let checksum = WeightedChecksum::new(Weights::Cyclic(&[1]), 11)
+            .unwrap()
+            .with_mapping(|remainder, _| {
+                if remainder == 1 {
+                    None
+                } else {
+                    Some(remainder)
+                }
+            });
+        assert_eq!(checksum.compute([5_u8, 7]), None);
+        assert_eq!(checksum.compute([5_u8, 8]), Some(2));
+        assert!(checksum.validate([5_u8, 8, 2]));
+        assert!(!checksum.validate([5_u8, 7, 1]));
+    }
+
+    #[test]
+    fn test_invalid_checksums() {
+        assert_eq!(
+            WeightedChecksum::new(Weights::Fixed(&[]), 10).unwrap_err(),
+            ChecksumError::NoWeights
+        );
+        assert_eq!(
+            WeightedChecksum::new(Weights::Cyclic(&[1; MAX_WEIGHTS + 1]), 10).unwrap_err(),
+            ChecksumError::TooManyWeights
+        );
+        assert_eq!(
+            WeightedChecksum::new(Weights::Cyclic(&[1]), 1).unwrap_err(),
+            ChecksumError::ModulusTooSmall
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        let digits = [4_u8, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3, 1];
+        let mut stream = ChecksumStream::new(WeightedChecksum::EAN13);
+        assert!(stream.is_empty());
+        assert_eq!(stream.check_digit(), Some(0));
+
+        for (idx, &digit) in digits.iter().enumerate() {
+            stream.push(digit).unwrap();
+            assert_eq!(stream.len(), idx + 1);
+            assert_eq!(
+                stream.is_valid(),
+                WeightedChecksum::EAN13.validate(&digits[..=idx])
+            );
+            assert_eq!(
+                stream.check_digit(),
+                WeightedChecksum::EAN13.compute(&digits[..=idx])
+            );
+        }
+        assert!(stream.is_valid());
+        assert_eq!(stream.check_digit(), None);
+
+        stream.clear();
+        assert!(stream.is_empty());
+        assert!(!stream.is_valid());
+    }
+
+    #[test]
+    fn test_stream_check_digit_outside_radix() {
+        let mut stream = ChecksumStream::new(WeightedChecksum::ISBN10);
+        for &digit in &[0_u8, 8, 0, 4, 4, 2, 9, 5, 7] {
+            stream.push(digit).unwrap();
+        }
+        assert_eq!(stream.check_digit(), Some(10));
+
+        stream.push(10_u8).unwrap();
+        assert!(stream.is_valid());
+        assert_eq!(stream.check_digit(), None);
+        assert_eq!(stream.push(0_u8), Err(ParseError::DigitTooLarge(10)));
+        assert!(stream.is_valid());
+
+        assert_eq!(
+            stream.push(Err(ParseError::InvalidDigit('-'))),
+            Err(ParseError::InvalidDigit('-'))
+        );
+        assert_eq!(stream.len(), 10);
+    }
+}
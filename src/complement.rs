@@ -0,0 +1,535 @@
+//! Digits of the raw patterns of signed integers, as opposed to their sign and magnitude.
+//!
+//! The two's complement pattern of a signed integer is its bits read as the unsigned integer of
+//! the same width. Under a power of two radix, each digit covers the same bits whatever the sign,
+//! which is the view of debuggers and binary formats: `-1_i32` is `ffffffff` in hexadecimal.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::complement;
+//!
+//! let digits = complement::twos_complement_digits(-1_i32, 16).unwrap();
+//! assert!(digits.eq([15; 8]));
+//!
+//! let digits = complement::twos_complement_digits(1_i8, 2).unwrap();
+//! assert!(digits.eq([0, 0, 0, 0, 0, 0, 0, 1]));
+//! ```
+//!
+//! The same idea carries to any radix over a fixed number of digits: in ten's complement over 3
+//! digits, `-1` is `999` and `-500` is `500`, and adding the patterns modulo 1000 adds the signed
+//! numbers, as `RadixComplement` does.
+use crate::alphabet::Alphabet;
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::fixed_width::{FixedWidthDigits, FixedWidthError};
+use crate::{IntoDigits, RadixMath, SignedInteger};
+use core::convert::TryFrom;
+use core::fmt;
+use num_traits::{Bounded, One, Zero};
+
+/// Errors that can occur when iterating over the digits of a complement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComplementError {
+    /// The radix is 0 or 1.
+    Radix(RadixError),
+    /// The radix is not a power of two, so that its digits do not split the bits.
+    NotPowerOfTwo,
+}
+
+impl fmt::Display for ComplementError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ComplementError::Radix(err) => err.fmt(f),
+            ComplementError::NotPowerOfTwo => write!(f, "radix not a power of two"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ComplementError {}
+
+impl From<RadixError> for ComplementError {
+    fn from(err: RadixError) -> Self {
+        ComplementError::Radix(err)
+    }
+}
+
+/// Returns the two's complement pattern of `number`, as the unsigned integer of the same width.
+///
+/// # Example
+///
+/// ```
+/// use radixal::complement;
+///
+/// assert_eq!(complement::twos_complement(-2_i8), 0xfe);
+/// assert_eq!(complement::twos_complement(i16::MIN), 0x8000);
+/// assert_eq!(complement::twos_complement(42_i64), 42);
+/// ```
+pub fn twos_complement<S: SignedInteger>(number: S) -> S::Magnitude {
+    let magnitude = number.magnitude();
+    if number.is_negative() {
+        // The magnitude is at least 1, so that this does not overflow.
+        S::Magnitude::max_value() - magnitude + S::Magnitude::one()
+    } else {
+        magnitude
+    }
+}
+
+/// Creates a `DigitsIterator` over the digits of the two's complement pattern of `number` under
+/// `radix`, a power of two, most significant first.
+///
+/// Every digit of the width is included, leading zeroes as well: when the radix does not split
+/// the width evenly, such as 8 for 32 bits, the most significant digit covers the remaining bits.
+///
+/// Returns an `Err(ComplementError)` if the radix is 0, 1 or not a power of two.
+///
+/// # Example
+///
+/// ```
+/// use radixal::complement;
+///
+/// // 32 bits make 11 octal digits, the first one of 2 bits.
+/// let digits = complement::twos_complement_digits(-1_i32, 8).unwrap();
+/// assert!(digits.eq([3, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7]));
+/// ```
+pub fn twos_complement_digits<S: SignedInteger>(
+    number: S,
+    radix: S::Magnitude,
+) -> Result<DigitsIterator<S::Magnitude>, ComplementError> {
+    let one = S::Magnitude::one();
+    if radix.is_zero() {
+        return Err(RadixError::Radix0.into());
+    } else if radix == one {
+        return Err(RadixError::Radix1.into());
+    }
+
+    let shift = radix.nbr_binary_digits() - 1;
+    if num_traits::pow(S::Magnitude::binary_radix(), shift) != radix {
+        return Err(ComplementError::NotPowerOfTwo);
+    }
+
+    let bits = S::Magnitude::max_value().nbr_binary_digits();
+    let width = bits.div_ceil(shift);
+    Ok(DigitsIterator::with_splitter(
+        twos_complement(number),
+        radix,
+        num_traits::pow(radix, width - 1),
+        width,
+    ))
+}
+
+/// A signed number in radix complement over a fixed number of digits, such as ten's complement.
+///
+/// The patterns of `width` digits under the radix of the alphabet are split in two halves: the
+/// lower one holds the non-negative numbers, the upper one the negative numbers, `-m` being
+/// stored as `radix^width - m`. Under ten's complement over 3 digits, the numbers thus range from
+/// `-500` to `499`; under an odd radix, the range is symmetric.
+///
+/// Arithmetic wraps around at `radix^width`, as in the fixed-width registers of decimal hardware.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::Alphabet;
+/// use radixal::complement::RadixComplement;
+///
+/// let a = RadixComplement::<u32>::from_signed(123, 3, Alphabet::DECIMAL).unwrap();
+/// let b = RadixComplement::from_signed(-23, 3, Alphabet::DECIMAL).unwrap();
+/// assert_eq!(b.to_string(), "977");
+///
+/// let sum = a.wrapping_add(b);
+/// assert_eq!(sum.to_string(), "100");
+/// assert_eq!(sum.to_signed(), Some(100_i32));
+/// assert_eq!(b.wrapping_neg().to_signed(), Some(23_i32));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RadixComplement<'a, T: IntoDigits> {
+    digits: FixedWidthDigits<'a, T>,
+}
+
+impl<'a, T: IntoDigits> RadixComplement<'a, T> {
+    /// Creates a new `RadixComplement` holding `number` over `width` digits under the radix of
+    /// `alphabet`.
+    ///
+    /// Returns an `Err(FixedWidthError)` if the width is 0, if some patterns of that width do not
+    /// fit in `T`, or if `number` is out of the range of the width.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::complement::RadixComplement;
+    /// use radixal::fixed_width::FixedWidthError;
+    ///
+    /// let n = RadixComplement::<u16>::from_signed(-500, 3, Alphabet::DECIMAL).unwrap();
+    /// assert_eq!(n.to_string(), "500");
+    ///
+    /// assert_eq!(
+    ///     RadixComplement::<u16>::from_signed(500, 3, Alphabet::DECIMAL),
+    ///     Err(FixedWidthError::ValueTooLarge)
+    /// );
+    /// ```
+    pub fn from_signed<S: SignedInteger>(
+        number: S,
+        width: usize,
+        alphabet: Alphabet<'a>,
+    ) -> Result<Self, FixedWidthError> {
+        let zero = FixedWidthDigits::new(T::zero(), width, alphabet)?;
+        let max = zero.wrapping_sub(T::one()).value();
+        let half = max / (T::one() + T::one());
+
+        let magnitude = number
+            .magnitude()
+            .to_u128()
+            .and_then(T::from_u128)
+            .ok_or(FixedWidthError::ValueTooLarge)?;
+        let digits = if number.is_negative() {
+            if magnitude > max - half {
+                return Err(FixedWidthError::ValueTooLarge);
+            }
+            zero.wrapping_sub(magnitude)
+        } else {
+            if magnitude > half {
+                return Err(FixedWidthError::ValueTooLarge);
+            }
+            zero.wrapping_add(magnitude)
+        };
+
+        Ok(RadixComplement { digits })
+    }
+
+    /// Returns the signed number, or `None` if it does not fit in `S`.
+    pub fn to_signed<S: SignedInteger>(&self) -> Option<S> {
+        let value = self.digits.value();
+        if self.is_negative() {
+            // `max - value` is the magnitude minus 1, below `radix^width / 2`, which fits in
+            // `i128` when `T` has at most 128 bits.
+            let magnitude = i128::try_from((self.max() - value).to_u128()?).ok()?;
+            S::from(-magnitude - 1)
+        } else {
+            S::from(value.to_u128()?)
+        }
+    }
+
+    /// Returns the pattern, as the unsigned integer of its digits.
+    pub fn value(&self) -> T {
+        self.digits.value()
+    }
+
+    /// Returns the number of digits.
+    pub fn width(&self) -> usize {
+        self.digits.width()
+    }
+
+    /// Returns the alphabet, whose number of symbols is the radix.
+    pub fn alphabet(&self) -> Alphabet<'a> {
+        self.digits.alphabet()
+    }
+
+    /// Returns an iterator over all the digits of the pattern, including the leading zeroes.
+    pub fn digits(&self) -> DigitsIterator<T> {
+        self.digits.digits()
+    }
+
+    /// Returns `true` if the number is negative, that is if the pattern is in the upper half.
+    pub fn is_negative(&self) -> bool {
+        self.digits.value() > self.max() / (T::one() + T::one())
+    }
+
+    /// Returns the magnitude of the number.
+    pub fn magnitude(&self) -> T {
+        let value = self.digits.value();
+        if self.is_negative() {
+            // The pattern is in the upper half, so that this does not overflow.
+            self.max() - value + T::one()
+        } else {
+            value
+        }
+    }
+
+    /// Negates the number, wrapping around at `radix^width`.
+    ///
+    /// Under an even radix, the most negative number is its own negation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::complement::RadixComplement;
+    ///
+    /// let n = RadixComplement::<u16>::from_signed(-500, 3, Alphabet::DECIMAL).unwrap();
+    /// assert_eq!(n.wrapping_neg(), n);
+    /// assert_eq!(n.checked_neg(), None);
+    /// ```
+    pub fn wrapping_neg(self) -> Self {
+        let zero = FixedWidthDigits::new(T::zero(), self.width(), self.alphabet()).unwrap();
+        RadixComplement {
+            digits: zero.wrapping_sub(self.digits.value()),
+        }
+    }
+
+    /// Negates the number, or returns `None` if its negation is out of the range of the width.
+    pub fn checked_neg(self) -> Option<Self> {
+        let negation = self.wrapping_neg();
+        if negation == self && !self.digits.value().is_zero() {
+            None
+        } else {
+            Some(negation)
+        }
+    }
+
+    /// Adds `rhs`, wrapping around at `radix^width`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numbers do not have the same width and alphabet.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.check_same_form(&rhs);
+        RadixComplement {
+            digits: self.digits.wrapping_add(rhs.digits.value()),
+        }
+    }
+
+    /// Subtracts `rhs`, wrapping around at `radix^width`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numbers do not have the same width and alphabet.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.check_same_form(&rhs);
+        RadixComplement {
+            digits: self.digits.wrapping_sub(rhs.digits.value()),
+        }
+    }
+
+    /// Adds `rhs`, or returns `None` if the sum is out of the range of the width.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numbers do not have the same width and alphabet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::complement::RadixComplement;
+    ///
+    /// let n = RadixComplement::<u16>::from_signed(300, 3, Alphabet::DECIMAL).unwrap();
+    /// assert_eq!(n.checked_add(n), None);
+    /// assert_eq!(n.wrapping_add(n).to_signed(), Some(-400_i16));
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let sum = self.wrapping_add(rhs);
+        // The sum overflows exactly when both numbers have the same sign, but not the sum.
+        if self.is_negative() == rhs.is_negative() && sum.is_negative() != self.is_negative() {
+            None
+        } else {
+            Some(sum)
+        }
+    }
+
+    /// Subtracts `rhs`, or returns `None` if the difference is out of the range of the width.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the numbers do not have the same width and alphabet.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let difference = self.wrapping_sub(rhs);
+        if self.is_negative() != rhs.is_negative() && difference.is_negative() != self.is_negative()
+        {
+            None
+        } else {
+            Some(difference)
+        }
+    }
+
+    fn max(&self) -> T {
+        let zero = FixedWidthDigits::new(T::zero(), self.width(), self.alphabet()).unwrap();
+        zero.wrapping_sub(T::one()).value()
+    }
+
+    fn check_same_form(&self, rhs: &Self) {
+        assert!(
+            self.width() == rhs.width() && self.alphabet() == rhs.alphabet(),
+            "radix complements of different widths or alphabets"
+        );
+    }
+}
+
+/// Takes `digits` as a pattern in radix complement.
+impl<'a, T: IntoDigits> From<FixedWidthDigits<'a, T>> for RadixComplement<'a, T> {
+    fn from(digits: FixedWidthDigits<'a, T>) -> Self {
+        RadixComplement { digits }
+    }
+}
+
+impl<'a, T: IntoDigits> From<RadixComplement<'a, T>> for FixedWidthDigits<'a, T> {
+    fn from(complement: RadixComplement<'a, T>) -> Self {
+        complement.digits
+    }
+}
+
+/// Writes the pattern, with its leading zeroes.
+impl<'a, T: IntoDigits> fmt::Display for RadixComplement<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.digits.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::num::Wrapping;
+
+    #[cfg(feature = "std")]
+    /// Checks the digits against the formatting of the standard library, which writes the two's
+    /// complement pattern, without the leading zeroes.
+    fn check(number: i32) {
+        let patterns = [
+            (2, format!("{:032b}", number)),
+            (8, format!("{:011o}", number)),
+            (16, format!("{:08x}", number)),
+        ];
+        for (radix, pattern) in &patterns {
+            let digits = twos_complement_digits(number, *radix).unwrap();
+            let expected = pattern.chars().map(|c| c.to_digit(16).unwrap());
+            assert!(digits.eq(expected), "{} in radix {}", number, radix);
+        }
+
+        let pattern = number as u32;
+        for shift in 1..=31 {
+            let digits = twos_complement_digits(number, 1 << shift).unwrap();
+            assert_eq!(digits.len(), 32_usize.div_ceil(shift));
+            assert_eq!(digits.to_number(), pattern);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_matches_std_formatting() {
+        for &number in &[0, 1, -1, 42, -42, i32::MAX, i32::MIN, i32::MIN + 1] {
+            check(number);
+        }
+        for number in (i32::MIN..=i32::MAX).step_by(99_991) {
+            check(number);
+        }
+    }
+
+    #[test]
+    fn test_other_widths() {
+        assert_eq!(twos_complement(i8::MIN), 0x80);
+        assert_eq!(twos_complement(-1_i128), u128::MAX);
+        assert_eq!(
+            twos_complement(Wrapping(-3_isize)),
+            Wrapping(usize::MAX - 2)
+        );
+
+        let digits = twos_complement_digits(-2_i128, 1 << 64).unwrap();
+        assert!(digits.eq([u64::MAX as u128, u64::MAX as u128 - 1]));
+        let digits = twos_complement_digits(i16::MIN, 128).unwrap();
+        assert!(digits.eq([2, 0, 0]));
+    }
+
+    #[test]
+    fn test_invalid_radix() {
+        assert_eq!(
+            twos_complement_digits(-1_i8, 0),
+            Err(ComplementError::Radix(RadixError::Radix0))
+        );
+        assert_eq!(
+            twos_complement_digits(-1_i8, 1),
+            Err(ComplementError::Radix(RadixError::Radix1))
+        );
+        assert_eq!(
+            twos_complement_digits(-1_i8, 10),
+            Err(ComplementError::NotPowerOfTwo)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_radix_complement_round_trip() {
+        for &(radix, width) in &[(10_u8, 3), (10, 1), (3, 4), (7, 2), (16, 2), (2, 8)] {
+            let symbols: Vec<char> = "0123456789abcdef".chars().take(radix.into()).collect();
+            let alphabet = Alphabet::new(&symbols).unwrap();
+            let count = i32::from(radix).pow(width as u32);
+            let mut seen = 0;
+            for number in -count..count {
+                match RadixComplement::<u16>::from_signed(number as i16, width, alphabet) {
+                    Ok(n) => {
+                        seen += 1;
+                        assert_eq!(n.to_signed(), Some(number));
+                        assert_eq!(n.is_negative(), number < 0);
+                        assert_eq!(u32::from(n.magnitude()), number.unsigned_abs());
+                        assert_eq!(i32::from(n.value()), number.rem_euclid(count));
+                    }
+                    Err(err) => assert_eq!(err, FixedWidthError::ValueTooLarge),
+                }
+            }
+            assert_eq!(seen, count, "radix {} width {}", radix, width);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_radix_complement_arithmetic() {
+        let alphabet = Alphabet::DECIMAL;
+        let range = -500..500_i32;
+        let from = |n: i32| RadixComplement::<u32>::from_signed(n, 3, alphabet).unwrap();
+        for a in range.clone().step_by(37) {
+            for b in range.clone().step_by(41) {
+                let (x, y) = (from(a), from(b));
+                let wrap = |n: i32| (n + 500).rem_euclid(1000) - 500;
+                assert_eq!(x.wrapping_add(y).to_signed(), Some(wrap(a + b)));
+                assert_eq!(x.wrapping_sub(y).to_signed(), Some(wrap(a - b)));
+                assert_eq!(
+                    x.checked_add(y).and_then(|n| n.to_signed()),
+                    Some(a + b).filter(|n| range.contains(n))
+                );
+                assert_eq!(
+                    x.checked_sub(y).and_then(|n| n.to_signed()),
+                    Some(a - b).filter(|n| range.contains(n))
+                );
+            }
+            assert_eq!(
+                from(a).checked_neg().and_then(|n| n.to_signed()),
+                Some(-a).filter(|n| range.contains(n))
+            );
+        }
+
+        assert_eq!(from(0).wrapping_neg(), from(0));
+        assert_eq!(from(-1).to_string(), "999");
+        assert!(from(-1).digits().eq([9, 9, 9]));
+        assert_eq!(from(-200).to_signed::<i8>(), None);
+    }
+
+    #[test]
+    fn test_radix_complement_limits() {
+        let n = RadixComplement::<u128>::from_signed(i128::MIN, 128, Alphabet::BINARY).unwrap();
+        assert_eq!(n.value(), 1 << 127);
+        assert_eq!(n.to_signed(), Some(i128::MIN));
+        assert_eq!(n.magnitude(), 1 << 127);
+
+        assert_eq!(
+            RadixComplement::<u8>::from_signed(0_i8, 0, Alphabet::DECIMAL),
+            Err(FixedWidthError::ZeroWidth)
+        );
+        assert_eq!(
+            RadixComplement::<u8>::from_signed(0_i8, 3, Alphabet::DECIMAL),
+            Err(FixedWidthError::WidthTooLarge)
+        );
+
+        let digits = FixedWidthDigits::new(9_u8, 1, Alphabet::DECIMAL).unwrap();
+        let n = RadixComplement::from(digits);
+        assert_eq!(n.to_signed(), Some(-1_i8));
+        assert_eq!(FixedWidthDigits::from(n), digits);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_radix_complement_different_widths() {
+        let a = RadixComplement::<u32>::from_signed(1, 2, Alphabet::DECIMAL).unwrap();
+        let b = RadixComplement::<u32>::from_signed(1, 3, Alphabet::DECIMAL).unwrap();
+        let _ = a.wrapping_add(b);
+    }
+}
@@ -0,0 +1,199 @@
+//! Crockford's base32 encoding of numbers, with its optional check symbol.
+//!
+//! Numbers are written with `Alphabet::CROCKFORD`. When decoding, hyphens are ignored so that
+//! long encodings can be split into readable groups, the case is ignored, `I` and `L` are read
+//! as `1` and `O` as `0`.
+//!
+//! The check symbol encodes the number modulo 37, using the 32 symbols of the alphabet followed
+//! by `*`, `~`, `$`, `=` and `U`.
+use crate::alphabet::Alphabet;
+use crate::parse::{self, ParseError};
+use crate::{IntoDigits, RadixMath};
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+const CHECK_MODULUS: usize = 37;
+const EXTRA_CHECK_SYMBOLS: [char; 5] = ['*', '~', '$', '=', 'U'];
+const HYPHEN: char = '-';
+
+/// Errors that can occur when decoding a number followed by a check symbol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckError {
+    /// The number itself is not valid.
+    Parse(ParseError),
+    /// The check symbol does not match the number.
+    InvalidChecksum,
+}
+
+impl core::fmt::Display for CheckError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            CheckError::Parse(err) => err.fmt(f),
+            CheckError::InvalidChecksum => write!(f, "invalid check symbol"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CheckError {}
+
+impl From<ParseError> for CheckError {
+    fn from(err: ParseError) -> Self {
+        CheckError::Parse(err)
+    }
+}
+
+/// Encodes `number` in Crockford's base32.
+///
+/// # Example
+///
+/// ```
+/// use radixal::crockford;
+///
+/// assert_eq!(crockford::encode(1234_u32), "16J");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode<T: IntoDigits>(number: T) -> String {
+    Alphabet::CROCKFORD.display(number).to_string()
+}
+
+/// Encodes `number` in Crockford's base32, followed by its check symbol.
+///
+/// # Example
+///
+/// ```
+/// use radixal::crockford;
+///
+/// assert_eq!(crockford::encode_with_check(1234_u32), "16JD");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_with_check<T: IntoDigits>(number: T) -> String {
+    let mut encoded = encode(number);
+    encoded.push(check_symbol(number));
+    encoded
+}
+
+/// Returns the check symbol of `number`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::crockford;
+///
+/// assert_eq!(crockford::check_symbol(1234_u32), 'D');
+/// assert_eq!(crockford::check_symbol(36_u32), 'U');
+/// ```
+pub fn check_symbol<T: IntoDigits>(number: T) -> char {
+    // The modulus fits in any `T`, and the remainder in a `usize`.
+    let modulus = T::from_usize(CHECK_MODULUS).unwrap();
+    let value = (number % modulus).to_usize().unwrap();
+
+    Alphabet::CROCKFORD
+        .symbol(value)
+        .unwrap_or_else(|| EXTRA_CHECK_SYMBOLS[value - Alphabet::CROCKFORD.radix()])
+}
+
+/// Decodes a number written in Crockford's base32, ignoring hyphens.
+///
+/// Returns an `Err(ParseError)` if there are no digits, if a symbol is invalid or if the number
+/// is too large for `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::crockford;
+/// use radixal::parse::ParseError;
+///
+/// assert_eq!(crockford::decode::<u32>("16J"), Ok(1234));
+/// assert_eq!(crockford::decode::<u32>("1-6-j"), Ok(1234));
+/// assert_eq!(crockford::decode::<u32>("Lo"), Ok(32));
+/// assert_eq!(crockford::decode::<u32>("U"), Err(ParseError::InvalidDigit('U')));
+/// assert_eq!(crockford::decode::<u32>("--"), Err(ParseError::Empty));
+/// ```
+pub fn decode<T: IntoDigits>(s: &str) -> Result<T, ParseError> {
+    parse::parse_symbols(s.chars().filter(|&c| c != HYPHEN), &Alphabet::CROCKFORD)
+}
+
+/// Decodes a number written in Crockford's base32 followed by its check symbol, ignoring
+/// hyphens.
+///
+/// Returns an `Err(CheckError)` if the number is invalid or if the check symbol does not match.
+///
+/// # Example
+///
+/// ```
+/// use radixal::crockford::{self, CheckError};
+///
+/// assert_eq!(crockford::decode_with_check::<u32>("16J-D"), Ok(1234));
+/// assert_eq!(
+///     crockford::decode_with_check::<u32>("16J-E"),
+///     Err(CheckError::InvalidChecksum)
+/// );
+/// ```
+pub fn decode_with_check<T: IntoDigits>(s: &str) -> Result<T, CheckError> {
+    let s = s.trim_end_matches(HYPHEN);
+    let check = s.chars().next_back().ok_or(ParseError::Empty)?;
+    let number: T = decode(&s[..s.len() - check.len_utf8()])?;
+
+    let expected = check_value(check).ok_or(ParseError::InvalidDigit(check))?;
+    let modulus = T::from_usize(CHECK_MODULUS).unwrap();
+    if (number % modulus).to_usize() == Some(expected) {
+        Ok(number)
+    } else {
+        Err(CheckError::InvalidChecksum)
+    }
+}
+
+/// Returns the value of a check symbol, accepting the same aliases as the digits.
+fn check_value(symbol: char) -> Option<usize> {
+    Alphabet::CROCKFORD.digit(symbol).or_else(|| {
+        EXTRA_CHECK_SYMBOLS
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(&symbol))
+            .map(|idx| idx + Alphabet::CROCKFORD.radix())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_symbols_cover_modulus() {
+        for value in 0..CHECK_MODULUS {
+            let symbol = check_symbol(value as u32);
+            assert_eq!(check_value(symbol), Some(value));
+            assert_eq!(check_value(symbol.to_ascii_lowercase()), Some(value));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_round_trip_with_check() {
+        for &n in &[0_u64, 1, 31, 32, 36, 37, 1234, u64::MAX] {
+            assert_eq!(decode_with_check::<u64>(&encode_with_check(n)), Ok(n));
+        }
+    }
+
+    #[test]
+    fn test_aliases_in_check_symbol() {
+        // 1 modulo 37 is 1, which can be written `I`, `i`, `L` or `l`.
+        assert_eq!(decode_with_check::<u32>("1i"), Ok(1));
+        assert_eq!(decode_with_check::<u32>("1L"), Ok(1));
+        // 0 modulo 37 is 0, which can be written `O` or `o`.
+        assert_eq!(decode_with_check::<u32>("0o"), Ok(0));
+    }
+
+    #[test]
+    fn test_check_symbol_only() {
+        assert_eq!(
+            decode_with_check::<u32>("D"),
+            Err(CheckError::Parse(ParseError::Empty))
+        );
+    }
+
+    #[test]
+    fn test_trailing_hyphens() {
+        assert_eq!(decode_with_check::<u32>("16J-D--"), Ok(1234));
+    }
+}
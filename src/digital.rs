@@ -0,0 +1,339 @@
+//! Digits of any numeric type providing a division with remainder, through the `Digital` trait.
+//!
+//! `IntoDigits` requires the numerical traits of `num-traits`, and relies on them for its faster
+//! strategies. `Digital` only asks for the operations that digits need, so that checked
+//! wrappers or custom big numbers can be used with every part of the crate once wrapped in a
+//! `DigitalNumber`, which implements `IntoDigits` on top of them and splits the digits with a
+//! plain division per digit. Every `IntoDigits` type is `Digital` as well.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::digital::{Digital, DigitalNumber};
+//! use radixal::IntoDigits;
+//!
+//! /// A decimal counter, whose digits are what matters.
+//! #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+//! struct Counter(u64);
+//!
+//! impl Digital for Counter {
+//!     fn zero() -> Self {
+//!         Counter(0)
+//!     }
+//!
+//!     fn one() -> Self {
+//!         Counter(1)
+//!     }
+//!
+//!     fn div_rem(self, divisor: Self) -> (Self, Self) {
+//!         (Counter(self.0 / divisor.0), Counter(self.0 % divisor.0))
+//!     }
+//!
+//!     fn checked_mul_add(self, factor: Self, addend: Self) -> Option<Self> {
+//!         self.0.checked_mul(factor.0)?.checked_add(addend.0).map(Counter)
+//!     }
+//!
+//!     fn checked_sub(self, other: Self) -> Option<Self> {
+//!         self.0.checked_sub(other.0).map(Counter)
+//!     }
+//! }
+//!
+//! let (number, radix) = (DigitalNumber(Counter(1203)), DigitalNumber(Counter(10)));
+//! let digits: Vec<_> = number.into_digits(radix).unwrap().map(|digit| digit.0).collect();
+//! assert_eq!(digits, [Counter(1), Counter(2), Counter(0), Counter(3)]);
+//! assert_eq!(number.reverse_digits(radix), Ok(DigitalNumber(Counter(3021))));
+//! assert!(DigitalNumber(Counter(12_321)).is_palindrome(radix).unwrap());
+//! ```
+use crate::digits_iterator::RadixError;
+use crate::parse::{self, ParseError};
+use crate::{IntoDigits, RadixMath};
+use core::ops::{Add, Div, Mul, Rem, Sub};
+use num_traits::{Num, One, Unsigned, WrappingAdd, WrappingMul, Zero};
+
+/// The operations on a number needed by its digits, for the numbers wrapped in a
+/// `DigitalNumber`.
+pub trait Digital: Copy + Ord {
+    /// Returns 0.
+    fn zero() -> Self;
+
+    /// Returns 1.
+    fn one() -> Self;
+
+    /// Returns the quotient and the remainder of the division by `divisor`, which is not 0.
+    fn div_rem(self, divisor: Self) -> (Self, Self);
+
+    /// Returns `self * factor + addend`, or `None` if it does not fit.
+    fn checked_mul_add(self, factor: Self, addend: Self) -> Option<Self>;
+
+    /// Returns `self - other`, or `None` if `other` is larger than `self`.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+impl<T: IntoDigits> Digital for T {
+    #[inline]
+    fn zero() -> Self {
+        <T as Zero>::zero()
+    }
+
+    #[inline]
+    fn one() -> Self {
+        <T as One>::one()
+    }
+
+    #[inline]
+    fn div_rem(self, divisor: Self) -> (Self, Self) {
+        (self / divisor, self % divisor)
+    }
+
+    #[inline]
+    fn checked_mul_add(self, factor: Self, addend: Self) -> Option<Self> {
+        RadixMath::checked_mul(self, factor)?.checked_add(addend)
+    }
+
+    #[inline]
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        if other > self {
+            None
+        } else {
+            Some(self - other)
+        }
+    }
+}
+
+/// A `Digital` number, which implements `IntoDigits` and the numerical traits it requires with
+/// the operations of `Digital`.
+///
+/// Like the primitive types in debug builds, the arithmetic operators panic on overflow. So do
+/// the wrapping operations, used where the crate documents wrapping semantics, as `Digital`
+/// knows of no largest number to wrap around.
+///
+/// # Example
+///
+/// ```
+/// use radixal::digital::DigitalNumber;
+/// use radixal::IntoDigits;
+///
+/// let number = DigitalNumber(1203_u32);
+/// assert_eq!(number.nbr_digits(DigitalNumber(10)), Ok(4));
+/// assert_eq!(number + DigitalNumber(1), DigitalNumber(1204));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DigitalNumber<T>(pub T);
+
+impl<T: Digital> Add for DigitalNumber<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        DigitalNumber(
+            self.0
+                .checked_mul_add(T::one(), rhs.0)
+                .expect("attempt to add with overflow"),
+        )
+    }
+}
+
+impl<T: Digital> Sub for DigitalNumber<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        DigitalNumber(
+            self.0
+                .checked_sub(rhs.0)
+                .expect("attempt to subtract with overflow"),
+        )
+    }
+}
+
+impl<T: Digital> Mul for DigitalNumber<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        DigitalNumber(
+            self.0
+                .checked_mul_add(rhs.0, T::zero())
+                .expect("attempt to multiply with overflow"),
+        )
+    }
+}
+
+impl<T: Digital> Div for DigitalNumber<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        assert!(rhs.0 != T::zero(), "attempt to divide by zero");
+        DigitalNumber(self.0.div_rem(rhs.0).0)
+    }
+}
+
+impl<T: Digital> Rem for DigitalNumber<T> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        assert!(
+            rhs.0 != T::zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        DigitalNumber(self.0.div_rem(rhs.0).1)
+    }
+}
+
+impl<T: Digital> Zero for DigitalNumber<T> {
+    #[inline]
+    fn zero() -> Self {
+        DigitalNumber(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == T::zero()
+    }
+}
+
+impl<T: Digital> One for DigitalNumber<T> {
+    #[inline]
+    fn one() -> Self {
+        DigitalNumber(T::one())
+    }
+}
+
+impl<T: Digital> Num for DigitalNumber<T> {
+    type FromStrRadixErr = ParseError;
+
+    /// Parses `s` with the ASCII digits and letters, for a radix of at most 36.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseError> {
+        match radix {
+            0 => Err(ParseError::Radix(RadixError::Radix0)),
+            1 => Err(ParseError::Radix(RadixError::Radix1)),
+            2..=36 => parse::parse_with(s.chars(), radix as usize, |symbol| {
+                symbol.to_digit(radix).map(|digit| digit as usize)
+            }),
+            _ => Err(ParseError::Radix(RadixError::RadixTooLarge)),
+        }
+    }
+}
+
+impl<T: Digital> Unsigned for DigitalNumber<T> {}
+
+impl<T: Digital> WrappingAdd for DigitalNumber<T> {
+    #[inline]
+    fn wrapping_add(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+}
+
+impl<T: Digital> WrappingMul for DigitalNumber<T> {
+    #[inline]
+    fn wrapping_mul(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+}
+
+impl<T: Digital> IntoDigits for DigitalNumber<T> {
+    #[inline]
+    fn try_mul_add(self, factor: Self, addend: Self) -> Option<Self> {
+        self.0
+            .checked_mul_add(factor.0, addend.0)
+            .map(DigitalNumber)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::digits_iterator::DigitsIterator;
+
+    /// A type that is `Digital` without being `IntoDigits`, failing instead of overflowing.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Checked(u16);
+
+    impl Digital for Checked {
+        fn zero() -> Self {
+            Checked(0)
+        }
+
+        fn one() -> Self {
+            Checked(1)
+        }
+
+        fn div_rem(self, divisor: Self) -> (Self, Self) {
+            (Checked(self.0 / divisor.0), Checked(self.0 % divisor.0))
+        }
+
+        fn checked_mul_add(self, factor: Self, addend: Self) -> Option<Self> {
+            let result = self.0.checked_mul(factor.0)?.checked_add(addend.0)?;
+            Some(Checked(result))
+        }
+
+        fn checked_sub(self, other: Self) -> Option<Self> {
+            self.0.checked_sub(other.0).map(Checked)
+        }
+    }
+
+    fn checked(number: u16) -> DigitalNumber<Checked> {
+        DigitalNumber(Checked(number))
+    }
+
+    #[test]
+    fn test_matches_primitive() {
+        for &radix in &[2_u16, 3, 10, 16, 255, u16::MAX] {
+            for number in (0..=u16::MAX).step_by(61).chain([u16::MAX - 1, u16::MAX]) {
+                let expected = number.into_digits(radix).unwrap();
+                let digits = checked(number).into_digits(checked(radix)).unwrap();
+                assert_eq!(digits.len(), expected.len());
+                assert!(digits.clone().map(|digit| digit.0 .0).eq(expected.clone()));
+                assert!(digits
+                    .rev()
+                    .map(|digit| digit.0 .0)
+                    .eq(expected.clone().rev()));
+
+                let (number, radix) = (checked(number), checked(radix));
+                assert_eq!(
+                    number.is_palindrome(radix),
+                    number.0 .0.is_palindrome(radix.0 .0)
+                );
+                assert_eq!(
+                    number.digit_sum(radix).map(|sum| sum.0 .0),
+                    number.0 .0.digit_sum(radix.0 .0)
+                );
+                assert_eq!(
+                    DigitsIterator::new(number, radix)
+                        .unwrap()
+                        .checked_reversed_number()
+                        .map(|reversed| reversed.0 .0),
+                    expected.checked_reversed_number()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(checked(12) + checked(30), checked(42));
+        assert_eq!(checked(42) - checked(30), checked(12));
+        assert_eq!(checked(6) * checked(7), checked(42));
+        assert_eq!(checked(45) / checked(7), checked(6));
+        assert_eq!(checked(45) % checked(7), checked(3));
+        assert_eq!(checked(1).max_above(), checked(u16::MAX));
+        assert_eq!(checked(u16::MAX).checked_add(checked(1)), None);
+        assert_eq!(DigitalNumber::from_str_radix("ff", 16), Ok(checked(255)));
+        assert_eq!(
+            DigitalNumber::<Checked>::from_str_radix("65536", 10),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(
+            DigitalNumber::<Checked>::from_str_radix("1", 37),
+            Err(ParseError::Radix(RadixError::RadixTooLarge))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_overflow() {
+        let _ = checked(u16::MAX) + checked(1);
+    }
+}
@@ -0,0 +1,1331 @@
+//! Owned buffers of digits, which can be edited before being converted back to a number.
+//!
+//! `Digits` grows as needed and requires the `alloc` or the `smallvec` feature, while
+//! `ArrayDigits` has a fixed capacity and never allocates.
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::parse::{push_digit, CharDigits, ParseError};
+use crate::{IntoDigits, RadixMath};
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+/// The number of digits stored inline with the `smallvec` feature.
+#[cfg(feature = "smallvec")]
+const INLINE_LEN: usize = 40;
+
+#[cfg(feature = "smallvec")]
+type Buffer<T> = smallvec::SmallVec<[T; INLINE_LEN]>;
+
+#[cfg(all(feature = "alloc", not(feature = "smallvec")))]
+type Buffer<T> = alloc::vec::Vec<T>;
+
+/// Errors that can occur when editing a `Digits` or converting it to a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DigitsError {
+    /// A digit is not smaller than the radix.
+    DigitTooLarge,
+    /// The number is too large for the integer type.
+    Overflow,
+    /// There is no room left for another digit.
+    CapacityExceeded,
+}
+
+impl fmt::Display for DigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DigitsError::DigitTooLarge => write!(f, "digit not smaller than the radix"),
+            DigitsError::Overflow => write!(f, "number too large for the integer type"),
+            DigitsError::CapacityExceeded => write!(f, "no room left for another digit"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DigitsError {}
+
+/// Converts `digits`, most significant first, to a number.
+fn to_number<T: IntoDigits>(digits: &[T], radix: T) -> Result<T, DigitsError> {
+    digits.iter().try_fold(T::zero(), |number, &digit| {
+        check(digit, radix)?;
+        push_digit(number, radix, digit).ok_or(DigitsError::Overflow)
+    })
+}
+
+fn check<T: IntoDigits>(digit: T, radix: T) -> Result<(), DigitsError> {
+    if digit < radix {
+        Ok(())
+    } else {
+        Err(DigitsError::DigitTooLarge)
+    }
+}
+
+fn check_radix<T: IntoDigits>(radix: T) -> Result<(), RadixError> {
+    if radix == T::zero() {
+        Err(RadixError::Radix0)
+    } else if radix == T::one() {
+        Err(RadixError::Radix1)
+    } else {
+        Ok(())
+    }
+}
+
+/// The largest radix for which the digits are sorted by counting them.
+const MAX_COUNTED_RADIX: usize = 256;
+
+/// Sorts `digits`, counting the occurrences of each digit when the radix is small enough.
+fn sort_digits<T: IntoDigits>(digits: &mut [T], radix: T, descending: bool) {
+    let small = radix
+        .to_usize()
+        .is_some_and(|radix| radix <= MAX_COUNTED_RADIX);
+    if !small || digits.iter().any(|&digit| digit >= radix) {
+        digits.sort_unstable();
+        if descending {
+            digits.reverse();
+        }
+        return;
+    }
+
+    let mut counts = [0; MAX_COUNTED_RADIX];
+    for &digit in digits.iter() {
+        counts[digit.to_usize().unwrap()] += 1;
+    }
+
+    let mut slots = digits.iter_mut();
+    let mut fill = |digit: usize, count: usize| {
+        for slot in slots.by_ref().take(count) {
+            *slot = T::from_usize(digit).unwrap();
+        }
+    };
+    let radix = radix.to_usize().unwrap();
+    if descending {
+        (0..radix)
+            .rev()
+            .for_each(|digit| fill(digit, counts[digit]));
+    } else {
+        (0..radix).for_each(|digit| fill(digit, counts[digit]));
+    }
+}
+
+/// Returns the number made of the digits of `number` sorted, using wrapping semantics if
+/// necessary.
+pub(crate) fn sorted_number<T: IntoDigits>(
+    number: T,
+    radix: T,
+    descending: bool,
+) -> Result<T, RadixError> {
+    // 128 digits are enough for any number, down to radix 2.
+    let mut digits = ArrayDigits::<T, 128>::try_from(number.into_digits(radix)?).unwrap();
+    sort_digits(&mut digits, radix, descending);
+    Ok(digits.iter().fold(T::zero(), |number, &digit| {
+        number.wrapping_mul(&radix).wrapping_add(&digit)
+    }))
+}
+
+/// How `interleave_digits` handles numbers with different numbers of digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Interleave {
+    /// The shorter number is padded with leading zeroes, so that the digits of both numbers
+    /// alternate from the least significant one, as in a Morton code.
+    Pad,
+    /// The digits alternate from the most significant one, and the remaining digits of the longer
+    /// number follow.
+    Append,
+}
+
+/// Returns the number whose digits alternate between those of `a` and `b`, or `None` if it does
+/// not fit in `T`.
+pub(crate) fn interleaved_number<T: IntoDigits>(
+    a: T,
+    b: T,
+    radix: T,
+    mode: Interleave,
+) -> Result<Option<T>, RadixError> {
+    let (mut a, mut b) = (a.into_digits(radix)?, b.into_digits(radix)?);
+    let pair = |number, (high, low)| push_digit(push_digit(number, radix, high)?, radix, low);
+
+    Ok(match mode {
+        Interleave::Pad => {
+            let len = a.len().max(b.len());
+            let pad = |digits: DigitsIterator<T>| {
+                core::iter::repeat_n(T::zero(), len - digits.len()).chain(digits)
+            };
+            pad(a).zip(pad(b)).try_fold(T::zero(), pair)
+        }
+        Interleave::Append => {
+            let len = a.len().min(b.len());
+            a.by_ref()
+                .take(len)
+                .zip(b.by_ref().take(len))
+                .try_fold(T::zero(), pair)
+                .and_then(|number| {
+                    a.chain(b)
+                        .try_fold(number, |number, digit| push_digit(number, radix, digit))
+                })
+        }
+    })
+}
+
+/// Moves the digits kept by `dedup_digits` to the front of `digits`, returning their number.
+fn dedup_digits<T: IntoDigits>(digits: &mut [T]) -> usize {
+    let mut len = 0;
+    for i in 0..digits.len() {
+        if len == 0 || digits[i] != digits[len - 1] {
+            digits[len] = digits[i];
+            len += 1;
+        }
+    }
+    len
+}
+
+/// Moves the digits kept by `unique_digits` to the front of `digits`, returning their number.
+fn unique_digits<T: IntoDigits>(digits: &mut [T]) -> usize {
+    let mut seen = [false; MAX_COUNTED_RADIX];
+    let mut len = 0;
+    for i in 0..digits.len() {
+        let digit = digits[i];
+        let duplicate = match digit.to_usize().filter(|&digit| digit < MAX_COUNTED_RADIX) {
+            Some(digit) => core::mem::replace(&mut seen[digit], true),
+            None => digits[..len].contains(&digit),
+        };
+        if !duplicate {
+            digits[len] = digit;
+            len += 1;
+        }
+    }
+    len
+}
+
+/// Pushes the next term of the look-and-say sequence after `digits`: the length of each run of
+/// equal digits, written in `radix`, followed by the digit.
+fn look_and_say<T, F>(digits: &[T], radix: T, mut push: F) -> Result<(), DigitsError>
+where
+    T: IntoDigits,
+    F: FnMut(T) -> Result<(), DigitsError>,
+{
+    for run in digits.chunk_by(|a, b| a == b) {
+        let digit = run[0];
+        check(digit, radix)?;
+        match radix.to_usize() {
+            Some(radix) => {
+                for count in run.len().into_digits(radix).into_iter().flatten() {
+                    push(T::from_usize(count).ok_or(DigitsError::Overflow)?)?;
+                }
+            }
+            // A radix beyond `usize` is larger than any length.
+            None => push(T::from_usize(run.len()).ok_or(DigitsError::Overflow)?)?,
+        }
+        push(digit)?;
+    }
+    Ok(())
+}
+
+/// Returns the number made of the digits of `number` once edited, or `None` if the edit fails or
+/// if the number does not fit in `T`.
+pub(crate) fn edited_number<T, F>(number: T, radix: T, edit: F) -> Result<Option<T>, RadixError>
+where
+    T: IntoDigits,
+    F: FnOnce(&mut ArrayDigits<T, 128>) -> Result<(), DigitsError>,
+{
+    let mut digits = ArrayDigits::try_from(number.into_digits(radix)?).unwrap();
+    Ok(edit(&mut digits).and_then(|()| digits.to_number()).ok())
+}
+
+/// Rotates `digits` to the left by `k` digits, modulo their number.
+fn rotate_digits_left<T>(digits: &mut [T], k: usize) {
+    if !digits.is_empty() {
+        let len = digits.len();
+        digits.rotate_left(k % len);
+    }
+}
+
+/// Rotates `digits` to the right by `k` digits, modulo their number.
+fn rotate_digits_right<T>(digits: &mut [T], k: usize) {
+    if !digits.is_empty() {
+        let len = digits.len();
+        digits.rotate_right(k % len);
+    }
+}
+
+/// The digits of a number under a radix, most significant first, which can be edited before
+/// being converted back to a number.
+///
+/// It dereferences to a slice of digits, so that they can be sorted, swapped or reversed in
+/// place. Digits that are added are checked against the radix; those written through the slice
+/// are checked when converting back to a number.
+///
+/// # Example
+///
+/// ```
+/// use radixal::digits::Digits;
+/// use radixal::IntoDigits;
+///
+/// let mut digits = Digits::from(3142_u32.into_decimal_digits());
+/// digits.sort_unstable();
+/// assert_eq!(digits.as_slice(), [1, 2, 3, 4]);
+///
+/// digits.insert(0, 9).unwrap();
+/// assert_eq!(digits.to_number(), Ok(91_234));
+/// ```
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Digits<T: IntoDigits> {
+    radix: T,
+    digits: Buffer<T>,
+}
+
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+impl<T: IntoDigits> Digits<T> {
+    /// Creates an empty `Digits` using `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    pub fn new(radix: T) -> Result<Self, RadixError> {
+        check_radix(radix)?;
+        Ok(Digits {
+            radix,
+            digits: Buffer::new(),
+        })
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    /// Returns the digits, most significant first.
+    pub fn as_slice(&self) -> &[T] {
+        &self.digits
+    }
+
+    /// Returns the digits, most significant first, which can be overwritten.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.digits
+    }
+
+    /// Appends a least significant digit.
+    ///
+    /// Returns an `Err(DigitsError)` if the digit is not smaller than the radix.
+    pub fn push(&mut self, digit: T) -> Result<(), DigitsError> {
+        check(digit, self.radix)?;
+        self.digits.push(digit);
+        Ok(())
+    }
+
+    /// Removes the least significant digit, or returns `None` if there are no digits.
+    pub fn pop(&mut self) -> Option<T> {
+        self.digits.pop()
+    }
+
+    /// Replaces the digit at `index`, returning the previous one.
+    ///
+    /// Returns an `Err(DigitsError)` if the digit is not smaller than the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits::{Digits, DigitsError};
+    /// use radixal::IntoDigits;
+    ///
+    /// let mut digits = Digits::from(1234_u16.into_decimal_digits());
+    /// assert_eq!(digits.set_digit(1, 9), Ok(2));
+    /// assert_eq!(digits.to_number(), Ok(1934));
+    /// assert_eq!(digits.set_digit(1, 10), Err(DigitsError::DigitTooLarge));
+    ///
+    /// // Digits can also be read and written by indexing, but are then only checked when
+    /// // converting back to a number.
+    /// digits[0] = 10;
+    /// assert_eq!(digits[..2], [10, 9]);
+    /// assert_eq!(digits.to_number(), Err(DigitsError::DigitTooLarge));
+    /// ```
+    pub fn set_digit(&mut self, index: usize, digit: T) -> Result<T, DigitsError> {
+        check(digit, self.radix)?;
+        Ok(core::mem::replace(&mut self.digits[index], digit))
+    }
+
+    /// Inserts a digit at `index`, shifting the less significant digits.
+    ///
+    /// Returns an `Err(DigitsError)` if the digit is not smaller than the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is larger than the number of digits.
+    pub fn insert(&mut self, index: usize, digit: T) -> Result<(), DigitsError> {
+        check(digit, self.radix)?;
+        self.digits.insert(index, digit);
+        Ok(())
+    }
+
+    /// Removes and returns the digit at `index`, shifting the less significant digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.digits.remove(index)
+    }
+
+    /// Keeps the `len` most significant digits, dropping the others.
+    pub fn truncate(&mut self, len: usize) {
+        self.digits.truncate(len);
+    }
+
+    /// Sorts the digits in ascending order, so that they make the smallest number.
+    ///
+    /// With a radix up to 256, the digits are sorted by counting them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits::Digits;
+    /// use radixal::IntoDigits;
+    ///
+    /// let mut digits = Digits::from(3102_u32.into_decimal_digits());
+    /// digits.sort_digits_ascending();
+    /// assert_eq!(digits.as_slice(), [0, 1, 2, 3]);
+    ///
+    /// digits.sort_digits_descending();
+    /// assert_eq!(digits.to_number(), Ok(3210));
+    /// ```
+    pub fn sort_digits_ascending(&mut self) {
+        sort_digits(&mut self.digits, self.radix, false);
+    }
+
+    /// Sorts the digits in descending order, so that they make the largest number.
+    ///
+    /// With a radix up to 256, the digits are sorted by counting them.
+    pub fn sort_digits_descending(&mut self) {
+        sort_digits(&mut self.digits, self.radix, true);
+    }
+
+    /// Rotates the digits to the left by `k` digits, modulo their number: the `k` most
+    /// significant digits become the least significant ones.
+    ///
+    /// The number of digits is kept, so that leading zeroes stay in the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits::Digits;
+    /// use radixal::IntoDigits;
+    ///
+    /// let mut digits = Digits::from(100_u32.into_decimal_digits());
+    /// digits.rotate_digits_left(1);
+    /// assert_eq!(digits.as_slice(), [0, 0, 1]);
+    ///
+    /// digits.rotate_digits_right(4);
+    /// assert_eq!(digits.to_number(), Ok(100));
+    /// ```
+    pub fn rotate_digits_left(&mut self, k: usize) {
+        rotate_digits_left(&mut self.digits, k);
+    }
+
+    /// Rotates the digits to the right by `k` digits, modulo their number: the `k` least
+    /// significant digits become the most significant ones.
+    ///
+    /// The number of digits is kept, so that leading zeroes stay in the buffer.
+    pub fn rotate_digits_right(&mut self, k: usize) {
+        rotate_digits_right(&mut self.digits, k);
+    }
+
+    /// Collapses the runs of equal digits into a single digit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits::Digits;
+    /// use radixal::IntoDigits;
+    ///
+    /// let mut digits = Digits::from(1_223_111_u32.into_decimal_digits());
+    /// digits.dedup_digits();
+    /// assert_eq!(digits.as_slice(), [1, 2, 3, 1]);
+    ///
+    /// digits.unique_digits();
+    /// assert_eq!(digits.to_number(), Ok(123));
+    /// ```
+    pub fn dedup_digits(&mut self) {
+        let len = dedup_digits(&mut self.digits);
+        self.digits.truncate(len);
+    }
+
+    /// Keeps the first occurrence of each digit, removing the others.
+    ///
+    /// With digits smaller than 256, the digits already seen are looked up in a table.
+    pub fn unique_digits(&mut self) {
+        let len = unique_digits(&mut self.digits);
+        self.digits.truncate(len);
+    }
+
+    /// Returns the next term of the look-and-say sequence: the length of each run of equal
+    /// digits, written in the radix, followed by the digit.
+    ///
+    /// Returns an `Err(DigitsError)` if a digit is not smaller than the radix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits::Digits;
+    /// use radixal::IntoDigits;
+    ///
+    /// let digits = Digits::from(1211_u32.into_decimal_digits());
+    /// assert_eq!(digits.look_and_say().unwrap().as_slice(), [1, 1, 1, 2, 2, 1]);
+    ///
+    /// // Twelve zeroes.
+    /// let digits = Digits::from(0_u32.into_decimal_digits()).look_and_say().unwrap();
+    /// let mut zeroes = Digits::new(16_u32).unwrap();
+    /// zeroes.extend(core::iter::repeat(0).take(12));
+    /// assert_eq!(digits.as_slice(), [1, 0]);
+    /// assert_eq!(zeroes.look_and_say().unwrap().as_slice(), [12, 0]);
+    /// ```
+    pub fn look_and_say(&self) -> Result<Self, DigitsError> {
+        let mut digits = Buffer::new();
+        look_and_say(&self.digits, self.radix, |digit| {
+            digits.push(digit);
+            Ok(())
+        })?;
+        Ok(Digits {
+            radix: self.radix,
+            digits,
+        })
+    }
+
+    /// Converts the digits back to a number; without digits, the number is 0.
+    ///
+    /// Returns an `Err(DigitsError)` if a digit is not smaller than the radix or if the number
+    /// does not fit in `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits::{Digits, DigitsError};
+    /// use radixal::IntoDigits;
+    ///
+    /// let mut digits = Digits::from(255_u8.into_decimal_digits());
+    /// digits.swap(0, 2);
+    /// assert_eq!(digits.to_number(), Err(DigitsError::Overflow));
+    ///
+    /// digits[0] = 10;
+    /// assert_eq!(digits.to_number(), Err(DigitsError::DigitTooLarge));
+    /// ```
+    pub fn to_number(&self) -> Result<T, DigitsError> {
+        to_number(&self.digits, self.radix)
+    }
+
+    /// Appends the digits of an iterator, as least significant digits.
+    ///
+    /// Returns an `Err(DigitsError)` at the first digit that is not smaller than the radix,
+    /// keeping the digits before it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits::{Digits, DigitsError};
+    ///
+    /// let mut digits = Digits::new(10_u32).unwrap();
+    /// digits.try_extend(vec![4, 2]).unwrap();
+    /// assert_eq!(digits.try_extend(vec![1, 10]), Err(DigitsError::DigitTooLarge));
+    /// assert_eq!(digits.to_number(), Ok(421));
+    /// ```
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, digits: I) -> Result<(), DigitsError> {
+        digits.into_iter().try_for_each(|digit| self.push(digit))
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+impl<T: IntoDigits> From<DigitsIterator<T>> for Digits<T> {
+    fn from(digits: DigitsIterator<T>) -> Self {
+        Digits {
+            radix: digits.radix(),
+            digits: digits.collect(),
+        }
+    }
+}
+
+/// Appends the digits of an iterator, as least significant digits.
+///
+/// # Panics
+///
+/// Panics if a digit is not smaller than the radix; see `try_extend` for a fallible version.
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+impl<T: IntoDigits> Extend<T> for Digits<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, digits: I) {
+        if let Err(error) = self.try_extend(digits) {
+            panic!("{}", error);
+        }
+    }
+}
+
+/// Collects the digits of a string, with the radix of the `CharDigits`.
+///
+/// Returns an `Err(ParseError)` if a symbol is not a digit or if the radix does not fit in `T`.
+///
+/// # Example
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use radixal::digits::Digits;
+/// use radixal::parse::CharDigits;
+///
+/// let digits = Digits::<u8>::try_from(CharDigits::with_radix("ff", 16).unwrap()).unwrap();
+/// assert_eq!((digits.radix(), digits.as_slice()), (16, [15, 15].as_ref()));
+/// ```
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+impl<'a, 'b, T: IntoDigits> TryFrom<CharDigits<'a, 'b>> for Digits<T> {
+    type Error = ParseError;
+
+    fn try_from(symbols: CharDigits<'a, 'b>) -> Result<Self, Self::Error> {
+        let radix = T::from_usize(symbols.radix()).ok_or(ParseError::Overflow)?;
+        let digits = symbols
+            .map(|digit| digit.and_then(|digit| T::from_usize(digit).ok_or(ParseError::Overflow)))
+            .collect::<Result<_, _>>()?;
+        Ok(Digits { radix, digits })
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+impl<T: IntoDigits> Deref for Digits<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+impl<T: IntoDigits> DerefMut for Digits<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+/// Serializes the radix and the digits, including the leading zeroes.
+#[cfg(all(feature = "defmt", any(feature = "alloc", feature = "smallvec")))]
+impl<T: IntoDigits + defmt::Format> defmt::Format for Digits<T> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Digits {{ radix: {}, digits: {} }}",
+            self.radix,
+            self.as_slice()
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: IntoDigits + serde::Serialize> serde::Serialize for Digits<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Digits", 2)?;
+        state.serialize_field("radix", &self.radix)?;
+        state.serialize_field("digits", self.as_slice())?;
+        state.end()
+    }
+}
+
+/// Deserializes the radix and the digits, checking them as `new` and `push` do.
+#[cfg(feature = "serde")]
+impl<'de, T: IntoDigits + serde::Deserialize<'de>> serde::Deserialize<'de> for Digits<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Digits")]
+        struct Repr<T> {
+            radix: T,
+            digits: Vec<T>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        let mut digits = Digits::new(repr.radix).map_err(D::Error::custom)?;
+        digits.try_extend(repr.digits).map_err(D::Error::custom)?;
+        Ok(digits)
+    }
+}
+
+/// The digits of a number under a radix, most significant first, stored in place with room for
+/// `N` digits.
+///
+/// Like `Digits`, it dereferences to a slice of digits, but adding a digit beyond the capacity
+/// fails instead of allocating.
+///
+/// # Example
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use radixal::digits::{ArrayDigits, DigitsError};
+/// use radixal::IntoDigits;
+///
+/// let mut digits = ArrayDigits::<u32, 4>::try_from(3142_u32.into_decimal_digits()).unwrap();
+/// digits.sort_unstable();
+/// assert_eq!(digits.to_number(), Ok(1234));
+/// assert_eq!(digits.push(5), Err(DigitsError::CapacityExceeded));
+///
+/// assert_eq!(
+///     ArrayDigits::<u32, 4>::try_from(12_345_u32.into_decimal_digits()),
+///     Err(DigitsError::CapacityExceeded)
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayDigits<T: IntoDigits, const N: usize> {
+    radix: T,
+    digits: [T; N],
+    len: usize,
+}
+
+impl<T: IntoDigits, const N: usize> ArrayDigits<T, N> {
+    /// Creates an empty `ArrayDigits` using `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    pub fn new(radix: T) -> Result<Self, RadixError> {
+        check_radix(radix)?;
+        Ok(ArrayDigits {
+            radix,
+            digits: [T::zero(); N],
+            len: 0,
+        })
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    /// Returns the number of digits that can be stored.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the digits, most significant first.
+    pub fn as_slice(&self) -> &[T] {
+        &self.digits[..self.len]
+    }
+
+    /// Returns the digits, most significant first, which can be overwritten.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.digits[..self.len]
+    }
+
+    /// Appends a least significant digit.
+    ///
+    /// Returns an `Err(DigitsError)` if the digit is not smaller than the radix or if there is no
+    /// room left.
+    pub fn push(&mut self, digit: T) -> Result<(), DigitsError> {
+        self.insert(self.len, digit)
+    }
+
+    /// Removes the least significant digit, or returns `None` if there are no digits.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            Some(self.digits[self.len])
+        }
+    }
+
+    /// Replaces the digit at `index`, returning the previous one.
+    ///
+    /// Returns an `Err(DigitsError)` if the digit is not smaller than the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set_digit(&mut self, index: usize, digit: T) -> Result<T, DigitsError> {
+        check(digit, self.radix)?;
+        Ok(core::mem::replace(&mut self.as_mut_slice()[index], digit))
+    }
+
+    /// Inserts a digit at `index`, shifting the less significant digits.
+    ///
+    /// Returns an `Err(DigitsError)` if the digit is not smaller than the radix or if there is no
+    /// room left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is larger than the number of digits.
+    pub fn insert(&mut self, index: usize, digit: T) -> Result<(), DigitsError> {
+        assert!(index <= self.len, "index out of bounds");
+        check(digit, self.radix)?;
+        if self.len == N {
+            return Err(DigitsError::CapacityExceeded);
+        }
+
+        self.digits.copy_within(index..self.len, index + 1);
+        self.digits[index] = digit;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the digit at `index`, shifting the less significant digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let digit = self.as_slice()[index];
+        self.digits.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+        digit
+    }
+
+    /// Keeps the `len` most significant digits, dropping the others.
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    /// Sorts the digits in ascending order, so that they make the smallest number.
+    ///
+    /// With a radix up to 256, the digits are sorted by counting them.
+    pub fn sort_digits_ascending(&mut self) {
+        let radix = self.radix;
+        sort_digits(self.as_mut_slice(), radix, false);
+    }
+
+    /// Sorts the digits in descending order, so that they make the largest number.
+    ///
+    /// With a radix up to 256, the digits are sorted by counting them.
+    pub fn sort_digits_descending(&mut self) {
+        let radix = self.radix;
+        sort_digits(self.as_mut_slice(), radix, true);
+    }
+
+    /// Rotates the digits to the left by `k` digits, modulo their number: the `k` most
+    /// significant digits become the least significant ones.
+    ///
+    /// The number of digits is kept, so that leading zeroes stay in the buffer.
+    pub fn rotate_digits_left(&mut self, k: usize) {
+        rotate_digits_left(self.as_mut_slice(), k);
+    }
+
+    /// Rotates the digits to the right by `k` digits, modulo their number: the `k` least
+    /// significant digits become the most significant ones.
+    ///
+    /// The number of digits is kept, so that leading zeroes stay in the buffer.
+    pub fn rotate_digits_right(&mut self, k: usize) {
+        rotate_digits_right(self.as_mut_slice(), k);
+    }
+
+    /// Collapses the runs of equal digits into a single digit.
+    pub fn dedup_digits(&mut self) {
+        self.len = dedup_digits(self.as_mut_slice());
+    }
+
+    /// Keeps the first occurrence of each digit, removing the others.
+    ///
+    /// With digits smaller than 256, the digits already seen are looked up in a table.
+    pub fn unique_digits(&mut self) {
+        self.len = unique_digits(self.as_mut_slice());
+    }
+
+    /// Returns the next term of the look-and-say sequence: the length of each run of equal
+    /// digits, written in the radix, followed by the digit.
+    ///
+    /// Returns an `Err(DigitsError)` if a digit is not smaller than the radix or if the term has
+    /// more than `N` digits.
+    pub fn look_and_say(&self) -> Result<Self, DigitsError> {
+        let mut digits = ArrayDigits {
+            radix: self.radix,
+            digits: [T::zero(); N],
+            len: 0,
+        };
+        look_and_say(self.as_slice(), self.radix, |digit| digits.push(digit))?;
+        Ok(digits)
+    }
+
+    /// Converts the digits back to a number; without digits, the number is 0.
+    ///
+    /// Returns an `Err(DigitsError)` if a digit is not smaller than the radix or if the number
+    /// does not fit in `T`.
+    pub fn to_number(&self) -> Result<T, DigitsError> {
+        to_number(self.as_slice(), self.radix)
+    }
+
+    /// Appends the digits of an iterator, as least significant digits.
+    ///
+    /// Returns an `Err(DigitsError)` at the first digit that is not smaller than the radix or
+    /// that does not fit, keeping the digits before it.
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, digits: I) -> Result<(), DigitsError> {
+        digits.into_iter().try_for_each(|digit| self.push(digit))
+    }
+}
+
+impl<T: IntoDigits, const N: usize> TryFrom<DigitsIterator<T>> for ArrayDigits<T, N> {
+    type Error = DigitsError;
+
+    /// Returns an `Err(DigitsError)` if there are more than `N` digits.
+    fn try_from(digits: DigitsIterator<T>) -> Result<Self, Self::Error> {
+        if digits.len() > N {
+            return Err(DigitsError::CapacityExceeded);
+        }
+
+        let mut array = ArrayDigits {
+            radix: digits.radix(),
+            digits: [T::zero(); N],
+            len: digits.len(),
+        };
+        for (slot, digit) in array.digits.iter_mut().zip(digits) {
+            *slot = digit;
+        }
+        Ok(array)
+    }
+}
+
+/// Appends the digits of an iterator, as least significant digits.
+///
+/// # Panics
+///
+/// Panics if a digit is not smaller than the radix or if there is no room left; see
+/// `try_extend` for a fallible version.
+impl<T: IntoDigits, const N: usize> Extend<T> for ArrayDigits<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, digits: I) {
+        if let Err(error) = self.try_extend(digits) {
+            panic!("{}", error);
+        }
+    }
+}
+
+/// Collects the digits of a string, with the radix of the `CharDigits`.
+///
+/// Returns an `Err(ParseError)` if a symbol is not a digit, if the radix does not fit in `T`, or
+/// if there are more than `N` symbols.
+impl<'a, 'b, T: IntoDigits, const N: usize> TryFrom<CharDigits<'a, 'b>> for ArrayDigits<T, N> {
+    type Error = ParseError;
+
+    fn try_from(symbols: CharDigits<'a, 'b>) -> Result<Self, Self::Error> {
+        let radix = T::from_usize(symbols.radix()).ok_or(ParseError::Overflow)?;
+        let mut array = ArrayDigits {
+            radix,
+            digits: [T::zero(); N],
+            len: 0,
+        };
+        for digit in symbols {
+            let digit = T::from_usize(digit?).ok_or(ParseError::Overflow)?;
+            array.push(digit).map_err(|_| ParseError::InvalidLength)?;
+        }
+        Ok(array)
+    }
+}
+
+impl<T: IntoDigits, const N: usize> Deref for ArrayDigits<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: IntoDigits, const N: usize> DerefMut for ArrayDigits<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: IntoDigits + fmt::Debug, const N: usize> fmt::Debug for ArrayDigits<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArrayDigits")
+            .field("radix", &self.radix)
+            .field("digits", &self.as_slice())
+            .finish()
+    }
+}
+
+impl<T: IntoDigits, const N: usize> PartialEq for ArrayDigits<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.radix == other.radix && self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: IntoDigits, const N: usize> Eq for ArrayDigits<T, N> {}
+
+impl<T: IntoDigits + Hash, const N: usize> Hash for ArrayDigits<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.radix.hash(state);
+        self.as_slice().hash(state);
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: IntoDigits + defmt::Format, const N: usize> defmt::Format for ArrayDigits<T, N> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ArrayDigits {{ radix: {}, digits: {} }}",
+            self.radix,
+            self.as_slice()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(any(feature = "alloc", feature = "smallvec"))]
+    use crate::alphabet::Alphabet;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut digits = Digits::from(1234_u32.into_decimal_digits());
+        digits.insert(0, 0).unwrap();
+        let json = serde_json::to_string(&digits).unwrap();
+        assert_eq!(json, r#"{"radix":10,"digits":[0,1,2,3,4]}"#);
+        assert_eq!(serde_json::from_str::<Digits<u32>>(&json).unwrap(), digits);
+
+        let invalid = serde_json::from_str::<Digits<u32>>(r#"{"radix":10,"digits":[10]}"#);
+        assert!(invalid.is_err());
+        let invalid = serde_json::from_str::<Digits<u32>>(r#"{"radix":1,"digits":[]}"#);
+        assert!(invalid.is_err());
+
+        let json = serde_json::to_string(&RadixError::Radix0).unwrap();
+        assert_eq!(
+            serde_json::from_str::<RadixError>(&json).unwrap(),
+            RadixError::Radix0
+        );
+    }
+
+    #[cfg(any(feature = "alloc", feature = "smallvec"))]
+    #[test]
+    fn test_round_trip() {
+        for &radix in &[2_u64, 3, 10, 16, u64::MAX] {
+            for &number in &[0, 1, 9, 10, 1 << 40, u64::MAX] {
+                let digits = Digits::from(number.into_digits(radix).unwrap());
+                assert_eq!(digits.radix(), radix);
+                assert_eq!(digits.len(), number.nbr_digits(radix).unwrap());
+                assert_eq!(digits.to_number(), Ok(number));
+            }
+        }
+    }
+
+    #[cfg(any(feature = "alloc", feature = "smallvec"))]
+    #[test]
+    fn test_editing() {
+        let mut digits = Digits::new(10_u16).unwrap();
+        assert_eq!(digits.to_number(), Ok(0));
+        assert_eq!(digits.push(10), Err(DigitsError::DigitTooLarge));
+        assert_eq!(digits.insert(0, 11), Err(DigitsError::DigitTooLarge));
+        assert!(digits.is_empty());
+
+        for digit in [6, 5, 5, 3, 5] {
+            digits.push(digit).unwrap();
+        }
+        assert_eq!(digits.to_number(), Ok(u16::MAX));
+
+        digits.insert(1, 0).unwrap();
+        assert_eq!(digits.to_number(), Err(DigitsError::Overflow));
+        assert_eq!(digits.remove(0), 6);
+        assert_eq!(digits.pop(), Some(5));
+        digits.reverse();
+        assert_eq!(digits.to_number(), Ok(3550));
+        digits.truncate(1);
+        assert_eq!(digits.as_slice(), [3]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "smallvec"))]
+    #[test]
+    fn test_invalid_radix() {
+        assert_eq!(Digits::new(0_u8), Err(RadixError::Radix0));
+        assert_eq!(Digits::new(1_u8), Err(RadixError::Radix1));
+    }
+
+    #[cfg(any(feature = "alloc", feature = "smallvec"))]
+    #[test]
+    fn test_extend() {
+        let mut digits = Digits::new(16_u32).unwrap();
+        digits.extend(0xbeef_u32.into_digits(16).unwrap());
+        digits.extend(Some(0));
+        assert_eq!(digits.to_number(), Ok(0xbeef0));
+        assert_eq!(
+            digits.try_extend([15, 16, 1]),
+            Err(DigitsError::DigitTooLarge)
+        );
+        assert_eq!(digits.to_number(), Ok(0xbeef0f));
+    }
+
+    #[cfg(any(feature = "alloc", feature = "smallvec"))]
+    #[test]
+    #[should_panic(expected = "digit not smaller than the radix")]
+    fn test_extend_with_invalid_digit() {
+        Digits::new(2_u8).unwrap().extend([1, 2]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "smallvec"))]
+    #[test]
+    fn test_from_char_digits() {
+        let symbols = CharDigits::new("zz", Alphabet::BASE36);
+        let digits = Digits::<u16>::try_from(symbols).unwrap();
+        assert_eq!(digits.to_number(), Ok(1295));
+
+        let symbols = CharDigits::with_radix("1z", 10).unwrap();
+        assert_eq!(
+            Digits::<u16>::try_from(symbols),
+            Err(ParseError::InvalidDigit('z'))
+        );
+
+        #[cfg(feature = "alphabets")]
+        {
+            let symbols = CharDigits::new("1", Alphabet::BASE62);
+            assert_eq!(Digits::<u8>::try_from(symbols).map(|d| d.radix()), Ok(62));
+        }
+    }
+
+    #[test]
+    fn test_array_extend_and_from_char_digits() {
+        let mut digits = ArrayDigits::<u8, 3>::new(10).unwrap();
+        digits.extend([2, 5]);
+        assert_eq!(
+            digits.try_extend([5, 0]),
+            Err(DigitsError::CapacityExceeded)
+        );
+        assert_eq!(digits.to_number(), Ok(255));
+
+        let symbols = CharDigits::with_radix("777", 8).unwrap();
+        let digits = ArrayDigits::<u16, 3>::try_from(symbols).unwrap();
+        assert_eq!(digits.to_number(), Ok(0o777));
+
+        let symbols = CharDigits::with_radix("7777", 8).unwrap();
+        assert_eq!(
+            ArrayDigits::<u16, 3>::try_from(symbols),
+            Err(ParseError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_set_digit() {
+        let mut digits = ArrayDigits::<u8, 4>::try_from(199_u8.into_decimal_digits()).unwrap();
+        assert_eq!(digits.set_digit(0, 2), Ok(1));
+        assert_eq!(digits.to_number(), Err(DigitsError::Overflow));
+        assert_eq!(digits.set_digit(1, 5), Ok(9));
+        assert_eq!(digits.set_digit(2, 5), Ok(9));
+        assert_eq!(digits.to_number(), Ok(255));
+        assert_eq!(digits.set_digit(2, 10), Err(DigitsError::DigitTooLarge));
+        assert_eq!(digits[2], 5);
+
+        digits[2] = 10;
+        assert_eq!(digits.to_number(), Err(DigitsError::DigitTooLarge));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_digit_past_the_end() {
+        let mut digits = ArrayDigits::<u8, 4>::try_from(199_u8.into_decimal_digits()).unwrap();
+        let _ = digits.set_digit(3, 0);
+    }
+
+    #[test]
+    fn test_sort_digits() {
+        for &radix in &[2_u64, 3, 10, 256, 257, 1 << 32] {
+            for &number in &[0, 1, 10, 3102, 1 << 40, u64::MAX - 12_345, u64::MAX] {
+                let mut expected: ArrayDigits<u64, 64> =
+                    ArrayDigits::try_from(number.into_digits(radix).unwrap()).unwrap();
+                expected.as_mut_slice().sort_unstable();
+
+                let mut digits = expected;
+                digits.as_mut_slice().reverse();
+                digits.sort_digits_ascending();
+                assert_eq!(digits, expected);
+
+                expected.as_mut_slice().reverse();
+                digits.sort_digits_descending();
+                assert_eq!(digits, expected);
+            }
+        }
+
+        // Out of range digits do not disturb the counting.
+        let mut digits = ArrayDigits::<u8, 3>::new(10).unwrap();
+        digits.extend([3, 1, 2]);
+        digits[1] = 200;
+        digits.sort_digits_ascending();
+        assert_eq!(digits.as_slice(), [2, 3, 200]);
+    }
+
+    #[test]
+    fn test_rotate_digits() {
+        let mut digits =
+            ArrayDigits::<u32, 10>::try_from(12_340_u32.into_decimal_digits()).unwrap();
+        digits.rotate_digits_left(1);
+        assert_eq!(digits.as_slice(), [2, 3, 4, 0, 1]);
+        digits.rotate_digits_left(7);
+        assert_eq!(digits.as_slice(), [4, 0, 1, 2, 3]);
+        digits.rotate_digits_right(3);
+        assert_eq!(digits.to_number(), Ok(12_340));
+        digits.rotate_digits_right(5);
+        assert_eq!(digits.to_number(), Ok(12_340));
+
+        for number in 0..=u8::MAX {
+            for &radix in &[2, 3, 10, 255] {
+                let len = number.nbr_digits(radix).unwrap();
+                for k in 0..=len {
+                    let left = number.rotate_digits_left(k, radix).unwrap();
+                    let right = number.rotate_digits_right(len - k, radix).unwrap();
+                    assert_eq!(left, right, "{} {} {}", number, radix, k);
+
+                    // Rotating in wider numbers never overflows.
+                    let wide = u32::from(number).rotate_digits_left(k, radix.into());
+                    assert_eq!(left, wide.unwrap().and_then(|n| u8::try_from(n).ok()));
+                }
+            }
+        }
+        assert_eq!(u128::MAX.rotate_digits_left(1, 2), Ok(Some(u128::MAX)));
+
+        let mut empty = ArrayDigits::<u8, 0>::new(10).unwrap();
+        empty.rotate_digits_left(1);
+        empty.rotate_digits_right(1);
+        assert!(empty.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_dedup_and_unique_digits() {
+        for &radix in &[2_u64, 3, 10, 255, 256, 257, 1 << 32] {
+            for &number in &[0, 1, 10, 1_223_111, 1 << 40, u64::MAX - 12_345, u64::MAX] {
+                let expected: Vec<_> = number.into_digits(radix).unwrap().collect();
+                let mut digits: ArrayDigits<u64, 64> =
+                    ArrayDigits::try_from(number.into_digits(radix).unwrap()).unwrap();
+
+                let mut deduped = expected.clone();
+                deduped.dedup();
+                digits.dedup_digits();
+                assert_eq!(digits.as_slice(), deduped);
+
+                let mut unique = Vec::new();
+                for &digit in &expected {
+                    if !unique.contains(&digit) {
+                        unique.push(digit);
+                    }
+                }
+                digits.unique_digits();
+                assert_eq!(digits.as_slice(), unique);
+            }
+        }
+
+        assert_eq!(1_223_111_u32.dedup_digits(10), Ok(1231));
+        assert_eq!(1_223_111_u32.unique_digits(10), Ok(123));
+        assert_eq!(1001_u32.unique_digits(10), Ok(10));
+        assert_eq!(u128::MAX.dedup_digits(2), Ok(1));
+        assert_eq!(1_u8.unique_digits(0), Err(RadixError::Radix0));
+    }
+
+    #[test]
+    fn test_look_and_say() {
+        let mut term = ArrayDigits::<u8, 20>::try_from(1_u8.into_binary_digits()).unwrap();
+        for expected in [
+            &[1, 1][..],
+            &[1, 0, 1],
+            &[1, 1, 1, 0, 1, 1],
+            &[1, 1, 1, 1, 0, 1, 0, 1],
+            &[1, 0, 0, 1, 1, 0, 1, 1, 1, 0, 1, 1],
+        ] {
+            term = term.look_and_say().unwrap();
+            assert_eq!(term.as_slice(), expected);
+        }
+        let mut small = ArrayDigits::<u8, 12>::new(2).unwrap();
+        small.try_extend(term.iter().copied()).unwrap();
+        assert_eq!(small.look_and_say(), Err(DigitsError::CapacityExceeded));
+        assert_eq!(term.look_and_say().map(|term| term.len()), Ok(18));
+
+        let mut digits = ArrayDigits::<u16, 301>::new(256).unwrap();
+        digits.extend(core::iter::repeat_n(3, 300));
+        digits.push(255).unwrap();
+        assert_eq!(
+            digits.look_and_say().unwrap().as_slice(),
+            [1, 44, 3, 1, 255]
+        );
+
+        digits.as_mut_slice()[0] = 256;
+        assert_eq!(digits.look_and_say(), Err(DigitsError::DigitTooLarge));
+        let empty = ArrayDigits::<u8, 4>::new(10).unwrap();
+        assert_eq!(empty.look_and_say(), Ok(empty));
+    }
+
+    #[test]
+    fn test_digit_surgery() {
+        assert_eq!(1234_u16.insert_digit(4, 5, 10), Ok(Some(12_345)));
+        assert_eq!(1234_u16.insert_digit(0, 0, 10), Ok(Some(1234)));
+        assert_eq!(1234_u16.insert_digit(0, 7, 10), Ok(None));
+        assert_eq!(1234_u16.insert_digit(1, 10, 10), Ok(None));
+        assert_eq!(u128::MAX.insert_digit(0, 1, 2), Ok(None));
+        assert_eq!(1234_u16.insert_digit(0, 1, 1), Err(RadixError::Radix1));
+
+        assert_eq!(1234_u16.remove_digit(3, 10), Ok(123));
+        assert_eq!(1034_u16.remove_digit(0, 10), Ok(34));
+        assert_eq!(7_u16.remove_digit(0, 10), Ok(0));
+        assert_eq!(u128::MAX.remove_digit(0, 2), Ok(u128::MAX >> 1));
+
+        assert_eq!(1234_u16.replace_digit(0, 0, 10), Ok(Some(234)));
+        assert_eq!(1234_u16.replace_digit(2, 9, 10), Ok(Some(1294)));
+        assert_eq!(1234_u16.replace_digit(2, 10, 10), Ok(None));
+        assert_eq!(255_u8.replace_digit(1, 6, 10), Ok(None));
+
+        for number in 0..=u8::MAX {
+            let len = number.nbr_decimal_digits();
+            for pos in 0..len {
+                let digit = number.into_decimal_digits().nth(pos).unwrap();
+                // Only the most significant digit can uncover leading zeroes.
+                if pos > 0 {
+                    let removed = number.remove_digit(pos, 10).unwrap();
+                    assert_eq!(removed.insert_digit(pos, digit, 10), Ok(Some(number)));
+                }
+                assert_eq!(number.replace_digit(pos, digit, 10), Ok(Some(number)));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remove_digit_out_of_bounds() {
+        let _ = 1234_u16.remove_digit(4, 10);
+    }
+
+    #[test]
+    fn test_array_round_trip() {
+        for &radix in &[2_u128, 3, 10, 16, u128::MAX] {
+            for &number in &[0, 1, 9, 10, 1 << 100, u128::MAX] {
+                let digits =
+                    ArrayDigits::<u128, 128>::try_from(number.into_digits(radix).unwrap()).unwrap();
+                assert_eq!(digits.radix(), radix);
+                assert!(digits
+                    .iter()
+                    .copied()
+                    .eq(number.into_digits(radix).unwrap()));
+                assert_eq!(digits.to_number(), Ok(number));
+            }
+        }
+
+        let digits = ArrayDigits::<u128, 39>::try_from(u128::MAX.into_decimal_digits()).unwrap();
+        assert_eq!(digits.len(), digits.capacity());
+    }
+
+    #[test]
+    fn test_array_editing() {
+        let mut digits = ArrayDigits::<u16, 5>::new(10).unwrap();
+        assert_eq!(digits.to_number(), Ok(0));
+        assert_eq!(digits.pop(), None);
+        assert_eq!(digits.push(10), Err(DigitsError::DigitTooLarge));
+
+        for &digit in &[6, 5, 3, 5] {
+            digits.push(digit).unwrap();
+        }
+        digits.insert(2, 5).unwrap();
+        assert_eq!(digits.to_number(), Ok(u16::MAX));
+        assert_eq!(digits.push(0), Err(DigitsError::CapacityExceeded));
+        assert_eq!(digits.insert(0, 1), Err(DigitsError::CapacityExceeded));
+
+        assert_eq!(digits.remove(1), 5);
+        assert_eq!(digits.as_slice(), [6, 5, 3, 5]);
+        assert_eq!(digits.pop(), Some(5));
+        digits.truncate(2);
+        assert_eq!(digits.to_number(), Ok(65));
+
+        // Digits beyond the length do not matter.
+        let mut other = ArrayDigits::<u16, 5>::new(10).unwrap();
+        other.push(6).unwrap();
+        other.push(5).unwrap();
+        assert_eq!(digits, other);
+
+        assert_eq!(ArrayDigits::<u8, 3>::new(1), Err(RadixError::Radix1));
+    }
+}
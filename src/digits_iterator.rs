@@ -1,21 +1,137 @@
-use crate::IntoDigits;
+use crate::{IntoDigits, RadixMath};
 
-#[derive(Debug, PartialEq, Eq)]
+/// An invalid radix for an operation.
+///
+/// More variants may be added for the radices that new operations do not support.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum RadixError {
     Radix0,
     Radix1,
+    /// The radix is larger than what the operation supports, e.g. the number of symbols of an
+    /// alphabet.
+    RadixTooLarge,
+    /// The radix is smaller than what the operation supports, e.g. for a table of its powers in
+    /// a type wider than 128 bits.
+    RadixTooSmall,
 }
 
+impl core::fmt::Display for RadixError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            RadixError::Radix0 => write!(f, "radix cannot be 0"),
+            RadixError::Radix1 => write!(f, "radix cannot be 1"),
+            RadixError::RadixTooLarge => write!(f, "radix too large"),
+            RadixError::RadixTooSmall => write!(f, "radix too small"),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for RadixError {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            RadixError::Radix0 => defmt::write!(f, "radix cannot be 0"),
+            RadixError::Radix1 => defmt::write!(f, "radix cannot be 1"),
+            RadixError::RadixTooLarge => defmt::write!(f, "radix too large"),
+            RadixError::RadixTooSmall => defmt::write!(f, "radix too small"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RadixError {}
+
 /// An iterator over the digits of a number.
 ///
 /// For a given radix, iterates over the digits in big endian order, i.e. from most significant
 /// to least significant.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub struct DigitsIterator<T: IntoDigits> {
     current: T,
     radix: T,
+    /// `radix^(splitter_len - 1)`, which splits off the first digit when `splitter_len` is `len`.
+    ///
+    /// Iterating from the back leaves it behind: it only catches up when the front is needed.
     splitter: T,
+    splitter_len: usize,
     len: usize,
+    strategy: Strategy<T>,
+}
+
+impl<T: IntoDigits> PartialEq for DigitsIterator<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // The splitter and the strategy only depend on the radix and the length.
+        self.current == other.current && self.radix == other.radix && self.len == other.len
+    }
+}
+
+impl<T: IntoDigits> Eq for DigitsIterator<T> {}
+
+/// The tens and units of every number below 100.
+pub(crate) const DECIMAL_PAIRS: [[u8; 2]; 100] = decimal_pairs();
+
+const fn decimal_pairs() -> [[u8; 2]; 100] {
+    let mut pairs = [[0; 2]; 100];
+    let mut n = 0;
+    while n < 100 {
+        pairs[n] = [(n / 10) as u8, (n % 10) as u8];
+        n += 1;
+    }
+    pairs
+}
+
+/// How the digits are split off the number, depending on the radix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Strategy<T> {
+    /// The radix is a power of two, with this base 2 logarithm: digits are extracted with shifts
+    /// and masks.
+    Shift(usize),
+    /// The radix is 10: dividing by a constant lets the compiler multiply by its reciprocal
+    /// instead.
+    Decimal,
+    /// Any other radix, multiplying by its reciprocal, with this multiplier and shift.
+    Reciprocal { multiplier: T, shift: usize },
+    /// Any other radix, in a type too wide for `Reciprocal` or that is not a primitive type.
+    Divide,
+}
+
+impl<T: IntoDigits> Strategy<T> {
+    ///
+    /// The types other than the primitive ones always divide, except by 10.
+    #[inline]
+    pub(crate) fn new(radix: T) -> Self {
+        if radix == T::decimal_radix() {
+            Strategy::Decimal
+        } else if let Some(shift) = radix.power_of_two_log() {
+            Strategy::Shift(shift)
+        } else if let Some((multiplier, shift)) = radix.reciprocal() {
+            Strategy::Reciprocal { multiplier, shift }
+        } else {
+            Strategy::Divide
+        }
+    }
+
+    /// Divides `n` by `squared`, the square of `radix`.
+    pub(crate) fn div_squared(self, n: T, squared: T) -> T {
+        match self {
+            Strategy::Shift(shift) => n.shift_right(2 * shift),
+            Strategy::Decimal => n / (T::decimal_radix() * T::decimal_radix()),
+            Strategy::Reciprocal { .. } | Strategy::Divide => n / squared,
+        }
+    }
+
+    /// Divides `n` by `radix`.
+    #[inline]
+    pub(crate) fn div(self, n: T, radix: T) -> T {
+        match self {
+            Strategy::Shift(shift) => n.shift_right(shift),
+            Strategy::Decimal => n / T::decimal_radix(),
+            Strategy::Reciprocal { multiplier, shift } => n.div_reciprocal(multiplier, shift),
+            Strategy::Divide => n / radix,
+        }
+    }
 }
 
 impl<T: IntoDigits> DigitsIterator<T> {
@@ -41,6 +157,7 @@ impl<T: IntoDigits> DigitsIterator<T> {
     /// let mut digits = DigitsIterator::new(123_u32, 1);
     /// assert_eq!(digits.unwrap_err(), RadixError::Radix1);
     /// ```
+    #[inline]
     pub fn new(number: T, radix: T) -> Result<DigitsIterator<T>, RadixError> {
         // Handle errors (radix too small).
         if radix == T::zero() {
@@ -55,37 +172,75 @@ impl<T: IntoDigits> DigitsIterator<T> {
                 current: number,
                 radix,
                 splitter: T::one(),
+                splitter_len: 1,
                 len: 1,
+                strategy: Strategy::new(radix),
             });
         }
 
-        let mut len = 0;
-        let mut splitter = T::one();
-        let mut n = number;
+        let len = number.checked_ilog_radix(radix).unwrap_or_default() + 1;
+        Ok(DigitsIterator::with_len(number, radix, len))
+    }
 
-        // Iterate until we reach the middle.
-        while n >= splitter {
-            len += 1;
-            splitter = splitter * radix;
-            n = n / radix;
+    /// Creates a `DigitsIterator` over the `len` digits of `number`.
+    ///
+    /// The caller must ensure that the radix is valid and that `number` has exactly `len` digits,
+    /// at least 1.
+    pub(crate) fn with_len(number: T, radix: T, len: usize) -> DigitsIterator<T> {
+        let strategy = Strategy::new(radix);
+        let splitter = match strategy {
+            Strategy::Shift(shift) => T::one().shift_left((len - 1) * shift),
+            _ => num_traits::pow(radix, len - 1),
+        };
+
+        DigitsIterator {
+            current: number,
+            radix,
+            splitter,
+            splitter_len: len,
+            len,
+            strategy,
         }
+    }
 
-        // Then adjust for the remainder.
-        let adjustment = splitter / radix;
-        if n >= adjustment {
-            splitter = splitter * adjustment;
-            len += len;
-        } else {
-            splitter = adjustment * adjustment;
-            len += len - 1;
+    /// Creates a `DigitsIterator` over exactly `width` digits, starting with leading zeroes.
+    ///
+    /// The caller must ensure that the radix is valid, that `width` is at least 1 and that
+    /// `radix^width` fits in `T` and is larger than `number`.
+    pub(crate) fn zero_padded(number: T, radix: T, width: usize) -> DigitsIterator<T> {
+        let mut splitter = T::one();
+        for _ in 1..width {
+            splitter = splitter * radix;
         }
 
-        Ok(DigitsIterator {
+        DigitsIterator::with_splitter(number, radix, splitter, width)
+    }
+
+    /// Creates a `DigitsIterator` over `len` digits, where `splitter` is `radix^(len - 1)`.
+    ///
+    /// The caller must ensure that the radix is valid and that `number` is below `radix^len`.
+    pub(crate) fn with_splitter(number: T, radix: T, splitter: T, len: usize) -> DigitsIterator<T> {
+        DigitsIterator {
             current: number,
             radix,
             splitter,
+            splitter_len: len,
             len,
-        })
+            strategy: Strategy::new(radix),
+        }
+    }
+
+    /// Returns the radix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(123_u32.into_digits(7).unwrap().radix(), 7);
+    /// ```
+    pub fn radix(&self) -> T {
+        self.radix
     }
 
     /// Converts the `DigitsIterator` into a number.
@@ -193,6 +348,132 @@ impl<T: IntoDigits> DigitsIterator<T> {
         })
     }
 
+    /// Returns the current number being iterated over with the digits reversed, or `None` if it
+    /// overflows, leaving the iterator unchanged.
+    ///
+    /// Overflowing needs as many digits as `T::MAX` and a last digit at least as large as its
+    /// leading digit, so that other numbers are reversed without checking every step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits_iterator::DigitsIterator;
+    ///
+    /// let digits = DigitsIterator::new(152_u8, 10).unwrap();
+    /// assert_eq!(digits.checked_reversed_number(), Some(251));
+    ///
+    /// let digits = DigitsIterator::new(255_u8, 10).unwrap();
+    /// assert_eq!(digits.checked_reversed_number(), None);
+    /// ```
+    pub fn checked_reversed_number(&self) -> Option<T> {
+        if let Some(max) = T::max_number() {
+            let max_len = max.ilog_radix(self.radix) + 1;
+            if self.len == max_len {
+                // Nothing has been taken off yet, so that the splitter is up to date.
+                let leading = max / self.splitter;
+                let last = self.low_digit();
+                if last < leading {
+                    return Some(self.to_reversed_number());
+                } else if last > leading {
+                    return None;
+                }
+            } else if self.len < max_len {
+                return Some(self.to_reversed_number());
+            }
+        }
+
+        let radix = self.radix;
+        self.clone().rfold(Some(T::zero()), |acc, digit| {
+            acc.and_then(|acc| acc.checked_mul(radix)?.checked_add(digit))
+        })
+    }
+
+    /// Groups the remaining digits in runs of equal digits.
+    pub fn runs(self) -> DigitRuns<T> {
+        DigitRuns {
+            digits: self,
+            next: None,
+        }
+    }
+
+    /// Returns the overlapping windows of `K` consecutive remaining digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is 0.
+    pub fn windows<const K: usize>(self) -> DigitWindows<T, K> {
+        assert!(K > 0, "window size is 0");
+        DigitWindows {
+            digits: self,
+            window: None,
+        }
+    }
+
+    /// Returns the remaining digits in groups of `K` digits, aligned on the least significant
+    /// digit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is 0.
+    pub fn chunks<const K: usize>(self) -> DigitChunks<T, K> {
+        assert!(K > 0, "chunk size is 0");
+        DigitChunks { digits: self }
+    }
+
+    /// Returns the pairs of adjacent remaining digits, the overlapping windows of 2 digits.
+    pub fn pairs(self) -> DigitPairs<T> {
+        DigitPairs {
+            windows: self.windows(),
+        }
+    }
+
+    /// Returns whether each remaining digit is at least the previous one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert!(1_123_u32.into_decimal_digits().is_digits_sorted_ascending());
+    /// assert!(!1_132_u32.into_decimal_digits().is_digits_sorted_ascending());
+    /// ```
+    pub fn is_digits_sorted_ascending(self) -> bool {
+        self.pairs().all(|(a, b)| a <= b)
+    }
+
+    /// Returns whether two adjacent remaining digits are equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// // The six-digit codes with sorted digits and a double.
+    /// let codes = (100_000..=999_999_u32)
+    ///     .map(IntoDigits::into_decimal_digits)
+    ///     .filter(|digits| digits.clone().is_digits_sorted_ascending())
+    ///     .filter(|digits| digits.clone().has_adjacent_equal_digits());
+    /// assert_eq!(codes.count(), 2_919);
+    /// ```
+    pub fn has_adjacent_equal_digits(self) -> bool {
+        self.pairs().any(|(a, b)| a == b)
+    }
+
+    /// Returns whether two adjacent remaining digits add up to `sum`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert!(1_734_u32.into_decimal_digits().has_adjacent_pair_summing_to(10));
+    /// assert!(!1_234_u32.into_decimal_digits().has_adjacent_pair_summing_to(10));
+    /// ```
+    pub fn has_adjacent_pair_summing_to(self, sum: T) -> bool {
+        // Adjacent digits `a` and `b` add up to at most `a * radix + b`, which fits in `T`.
+        self.pairs().any(|(a, b)| a + b == sum)
+    }
+
     /// Rotate the digits such that the first digit (most significant) becomes the last digit
     /// (least significant). This operation preserves the number of digits; in other words, the
     /// first digit may now be `0`.
@@ -215,6 +496,7 @@ impl<T: IntoDigits> DigitsIterator<T> {
             return self;
         }
 
+        self.catch_up_splitter();
         let mut n = n % self.len;
 
         while n > 0 {
@@ -250,6 +532,7 @@ impl<T: IntoDigits> DigitsIterator<T> {
             return self;
         }
 
+        self.catch_up_splitter();
         let mut n = n % self.len;
 
         while n > 0 {
@@ -263,19 +546,186 @@ impl<T: IntoDigits> DigitsIterator<T> {
     }
 }
 
+impl<T: IntoDigits> DigitsIterator<T> {
+    /// Returns the digits of a number below 100.
+    fn decimal_pair(pair: T) -> [T; 2] {
+        let [tens, units] = DECIMAL_PAIRS[pair.to_usize().unwrap_or_default()];
+        [
+            T::from_u128(tens.into()).unwrap_or_else(T::zero),
+            T::from_u128(units.into()).unwrap_or_else(T::zero),
+        ]
+    }
+
+    /// Brings the splitter up to date after iterating from the back.
+    #[inline]
+    fn catch_up_splitter(&mut self) {
+        if self.splitter_len <= self.len {
+            return;
+        }
+
+        match self.strategy {
+            Strategy::Shift(shift) if self.len > 0 => {
+                self.splitter = T::one().shift_left((self.len - 1) * shift);
+            }
+            strategy => {
+                while self.splitter_len > self.len {
+                    self.splitter = strategy.div(self.splitter, self.radix);
+                    self.splitter_len -= 1;
+                }
+            }
+        }
+        self.splitter_len = self.len;
+    }
+
+    /// Returns the least significant digit.
+    fn low_digit(&self) -> T {
+        match self.strategy {
+            Strategy::Shift(shift) => self.current.low_bits(shift),
+            strategy => self.current - strategy.div(self.current, self.radix) * self.radix,
+        }
+    }
+}
+
+/// Constant versions of the constructor and of the digit count, for each primitive type, since
+/// the operations of `IntoDigits` cannot be used in constant expressions.
+macro_rules! impl_const_digits {
+    ( $($t:ty)* ) => {
+        $(
+            impl DigitsIterator<$t> {
+                /// Like [`new`](#method.new), in constant expressions.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// use radixal::digits_iterator::DigitsIterator;
+                ///
+                #[doc = concat!("const DIGITS: DigitsIterator<", stringify!($t), "> =")]
+                #[doc = concat!(
+                    "    match DigitsIterator::<",
+                    stringify!($t),
+                    ">::new_const(123, 10) {"
+                )]
+                ///         Ok(digits) => digits,
+                ///         Err(_) => panic!("invalid radix"),
+                ///     };
+                ///
+                /// assert!(DIGITS.eq([1, 2, 3]));
+                /// ```
+                pub const fn new_const(number: $t, radix: $t) -> Result<Self, RadixError> {
+                    let len = match Self::digit_count(number, radix) {
+                        Ok(len) => len,
+                        Err(error) => return Err(error),
+                    };
+
+                    let strategy = if radix == 10 {
+                        Strategy::Decimal
+                    } else if radix.is_power_of_two() {
+                        Strategy::Shift(radix.trailing_zeros() as usize)
+                    } else if <$t>::BITS <= u64::BITS {
+                        let (multiplier, shift) =
+                            crate::reciprocal::reciprocal(radix as u64, <$t>::BITS);
+                        Strategy::Reciprocal {
+                            multiplier: multiplier as $t,
+                            shift,
+                        }
+                    } else {
+                        Strategy::Divide
+                    };
+
+                    Ok(DigitsIterator {
+                        current: number,
+                        radix,
+                        splitter: radix.pow((len - 1) as u32),
+                        splitter_len: len,
+                        len,
+                        strategy,
+                    })
+                }
+
+                /// Returns the number of digits of `number` under `radix`, in constant
+                /// expressions.
+                ///
+                /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// use radixal::digits_iterator::DigitsIterator;
+                ///
+                /// // The number of decimal digits of each power of two.
+                #[doc = concat!("const LENGTHS: [usize; ", stringify!($t), "::BITS as usize] = {")]
+                #[doc = concat!("    let mut lengths = [0; ", stringify!($t), "::BITS as usize];")]
+                ///     let mut shift = 0;
+                ///     while shift < lengths.len() {
+                #[doc = concat!(
+                    "        let count = DigitsIterator::<",
+                    stringify!($t),
+                    ">::digit_count(1 << shift, 10);"
+                )]
+                ///         lengths[shift] = match count {
+                ///             Ok(len) => len,
+                ///             Err(_) => 0,
+                ///         };
+                ///         shift += 1;
+                ///     }
+                ///     lengths
+                /// };
+                ///
+                /// assert_eq!(LENGTHS[..8], [1, 1, 1, 1, 2, 2, 2, 3]);
+                /// ```
+                pub const fn digit_count(number: $t, radix: $t) -> Result<usize, RadixError> {
+                    if radix == 0 {
+                        return Err(RadixError::Radix0);
+                    } else if radix == 1 {
+                        return Err(RadixError::Radix1);
+                    }
+
+                    let log = if radix.is_power_of_two() {
+                        match number.checked_ilog2() {
+                            Some(log) => log / radix.trailing_zeros(),
+                            None => 0,
+                        }
+                    } else if radix == 10 {
+                        match number.checked_ilog10() {
+                            Some(log) => log,
+                            None => 0,
+                        }
+                    } else {
+                        match number.checked_ilog(radix) {
+                            Some(log) => log,
+                            None => 0,
+                        }
+                    };
+                    Ok(log as usize + 1)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_digits!(u8 u16 u32 u64 u128 usize);
+
 impl<T: IntoDigits> Iterator for DigitsIterator<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.len == 0 {
-            None
+            return None;
+        }
+
+        let digit = if let Strategy::Shift(shift) = self.strategy {
+            let low_bits = (self.len - 1) * shift;
+            let digit = self.current.shift_right(low_bits);
+            self.current = self.current.low_bits(low_bits);
+            digit
         } else {
+            self.catch_up_splitter();
             let digit = self.current / self.splitter;
-            self.current = self.current % self.splitter;
-            self.splitter = self.splitter / self.radix;
-            self.len -= 1;
-            Some(digit)
-        }
+            self.current = self.current - digit * self.splitter;
+            digit
+        };
+        self.len -= 1;
+        Some(digit)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -286,9 +736,39 @@ impl<T: IntoDigits> Iterator for DigitsIterator<T> {
         self.len
     }
 
+    /// Extracts decimal digits two at a time, halving the number of divisions by a variable
+    /// splitter.
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        if self.strategy == Strategy::Decimal {
+            self.catch_up_splitter();
+            while self.len >= 2 {
+                let pair_splitter = self.splitter / T::decimal_radix();
+                let pair = self.current / pair_splitter;
+                self.current = self.current - pair * pair_splitter;
+                self.splitter = pair_splitter / T::decimal_radix();
+                self.len -= 2;
+                self.splitter_len = self.len;
+
+                let [tens, units] = Self::decimal_pair(pair);
+                acc = f(acc, tens);
+                acc = f(acc, units);
+            }
+        }
+
+        // A `for` loop calls `next`, and not `fold`.
+        for digit in self {
+            acc = f(acc, digit);
+        }
+        acc
+    }
+
     fn last(self) -> Option<Self::Item> {
         if self.len > 0 {
-            Some(self.current % self.radix)
+            Some(self.low_digit())
         } else {
             None
         }
@@ -300,22 +780,241 @@ impl<T: IntoDigits> DoubleEndedIterator for DigitsIterator<T> {
         if self.len == 0 {
             None
         } else {
-            let digit = self.current % self.radix;
-            self.current = self.current / self.radix;
-            self.splitter = self.splitter / self.radix;
+            let digit = self.low_digit();
+            self.current = self.strategy.div(self.current, self.radix);
             self.len -= 1;
             Some(digit)
         }
     }
+
+    /// Extracts decimal digits two at a time, dividing by 100 instead of 10.
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        if self.strategy == Strategy::Decimal {
+            let hundred = T::decimal_radix() * T::decimal_radix();
+            while self.len >= 2 {
+                let rest = self.current / hundred;
+                let pair = self.current - rest * hundred;
+                self.current = rest;
+                self.len -= 2;
+
+                let [tens, units] = Self::decimal_pair(pair);
+                acc = f(acc, units);
+                acc = f(acc, tens);
+            }
+        }
+
+        while let Some(digit) = self.next_back() {
+            acc = f(acc, digit);
+        }
+        acc
+    }
 }
 
 impl<T: IntoDigits> core::iter::FusedIterator for DigitsIterator<T> {}
 
 impl<T: IntoDigits> ExactSizeIterator for DigitsIterator<T> {}
 
+/// An iterator over the runs of equal digits of a number, most significant first, as pairs of
+/// the digit and of the number of times it is repeated.
+///
+/// [`from_digit_runs`](../parse/fn.from_digit_runs.html) rebuilds the number from its runs.
+///
+/// # Example
+///
+/// ```
+/// use radixal::IntoDigits;
+///
+/// let runs = 1_112_000_u32.digit_runs(10).unwrap();
+/// assert!(runs.eq([(1, 3), (2, 1), (0, 3)]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigitRuns<T: IntoDigits> {
+    digits: DigitsIterator<T>,
+    /// The first digit of the next run, already taken from `digits`.
+    next: Option<T>,
+}
+
+impl<T: IntoDigits> Iterator for DigitRuns<T> {
+    type Item = (T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let digit = self.next.take().or_else(|| self.digits.next())?;
+        let mut len = 1;
+        for next in self.digits.by_ref() {
+            if next != digit {
+                self.next = Some(next);
+                break;
+            }
+            len += 1;
+        }
+        Some((digit, len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.digits.len() + usize::from(self.next.is_some());
+        (len.min(1), Some(len))
+    }
+}
+
+impl<T: IntoDigits> core::iter::FusedIterator for DigitRuns<T> {}
+
+/// An iterator over the overlapping windows of `K` consecutive digits of a number, most
+/// significant first, like `slice::windows`.
+///
+/// A number with fewer than `K` digits has no windows.
+///
+/// # Example
+///
+/// ```
+/// use radixal::IntoDigits;
+///
+/// let windows = 12_345_u32.into_decimal_digits().windows::<3>();
+/// assert!(windows.eq([[1, 2, 3], [2, 3, 4], [3, 4, 5]]));
+///
+/// // The largest product of two adjacent digits.
+/// let product = 917_263_u32.into_decimal_digits().windows().map(|[a, b]| a * b).max();
+/// assert_eq!(product, Some(18));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigitWindows<T: IntoDigits, const K: usize> {
+    digits: DigitsIterator<T>,
+    /// The last window, or `None` before the first one.
+    window: Option<[T; K]>,
+}
+
+impl<T: IntoDigits, const K: usize> Iterator for DigitWindows<T, K> {
+    type Item = [T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = match &mut self.window {
+            Some(window) => {
+                let digit = self.digits.next()?;
+                window.rotate_left(1);
+                window[K - 1] = digit;
+                window
+            }
+            None => {
+                if self.digits.len() < K {
+                    self.digits.nth(K);
+                    return None;
+                }
+
+                let mut window = [T::zero(); K];
+                window
+                    .iter_mut()
+                    .zip(self.digits.by_ref())
+                    .for_each(|(slot, digit)| *slot = digit);
+                self.window.insert(window)
+            }
+        };
+        Some(*window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self.window {
+            Some(_) => self.digits.len(),
+            None => (self.digits.len() + 1).saturating_sub(K),
+        };
+        (len, Some(len))
+    }
+}
+
+impl<T: IntoDigits, const K: usize> ExactSizeIterator for DigitWindows<T, K> {}
+
+impl<T: IntoDigits, const K: usize> core::iter::FusedIterator for DigitWindows<T, K> {}
+
+/// An iterator over the pairs of adjacent digits of a number, most significant first.
+///
+/// This struct is created by the [`pairs`](struct.DigitsIterator.html#method.pairs) method.
+///
+/// # Example
+///
+/// ```
+/// use radixal::IntoDigits;
+///
+/// let pairs = 1_234_u32.into_decimal_digits().pairs();
+/// assert!(pairs.eq([(1, 2), (2, 3), (3, 4)]));
+///
+/// // The number of descents.
+/// let descents = 0x3a94_u32.into_digits(16).unwrap().pairs().filter(|(a, b)| a > b).count();
+/// assert_eq!(descents, 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigitPairs<T: IntoDigits> {
+    windows: DigitWindows<T, 2>,
+}
+
+impl<T: IntoDigits> Iterator for DigitPairs<T> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.windows.next().map(|[a, b]| (a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+impl<T: IntoDigits> ExactSizeIterator for DigitPairs<T> {}
+
+impl<T: IntoDigits> core::iter::FusedIterator for DigitPairs<T> {}
+
+/// An iterator over the digits of a number in groups of `K` digits, most significant first.
+///
+/// The groups are aligned on the least significant digit, as when grouping the digits by
+/// thousands, so that the first group is padded with leading zeroes when the number of digits is
+/// not a multiple of `K`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::IntoDigits;
+///
+/// let chunks = 1_234_567_u32.into_decimal_digits().chunks::<3>();
+/// assert!(chunks.eq([[0, 0, 1], [2, 3, 4], [5, 6, 7]]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigitChunks<T: IntoDigits, const K: usize> {
+    digits: DigitsIterator<T>,
+}
+
+impl<T: IntoDigits, const K: usize> Iterator for DigitChunks<T, K> {
+    type Item = [T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = match self.digits.len() % K {
+            0 if self.digits.len() == 0 => return None,
+            0 => K,
+            len => len,
+        };
+
+        let mut chunk = [T::zero(); K];
+        chunk[K - len..]
+            .iter_mut()
+            .zip(self.digits.by_ref())
+            .for_each(|(slot, digit)| *slot = digit);
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.digits.len().div_ceil(K);
+        (len, Some(len))
+    }
+}
+
+impl<T: IntoDigits, const K: usize> ExactSizeIterator for DigitChunks<T, K> {}
+
+impl<T: IntoDigits, const K: usize> core::iter::FusedIterator for DigitChunks<T, K> {}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
+    use crate::digital::{Digital, DigitalNumber};
 
     #[test]
     fn test_types() {
@@ -334,6 +1033,191 @@ mod tests {
         assert!(DigitsIterator::new(Wrapping(1_usize), Wrapping(10)).is_ok());
     }
 
+    /// A number implementing `IntoDigits` outside of the primitive types, whose digits are split
+    /// with plain divisions.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Wide(u128);
+
+    macro_rules! impl_wide_op {
+        ( $($trait:ident $method:ident)* ) => {$(
+            impl core::ops::$trait for Wide {
+                type Output = Wide;
+
+                fn $method(self, rhs: Wide) -> Wide {
+                    Wide(core::ops::$trait::$method(self.0, rhs.0))
+                }
+            }
+        )*};
+    }
+
+    impl_wide_op!(Add add Sub sub Mul mul Div div Rem rem);
+
+    impl num_traits::Zero for Wide {
+        fn zero() -> Self {
+            Wide(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl num_traits::One for Wide {
+        fn one() -> Self {
+            Wide(1)
+        }
+    }
+
+    impl num_traits::Num for Wide {
+        type FromStrRadixErr = core::num::ParseIntError;
+
+        fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            u128::from_str_radix(s, radix).map(Wide)
+        }
+    }
+
+    impl num_traits::Unsigned for Wide {}
+
+    impl num_traits::WrappingAdd for Wide {
+        fn wrapping_add(&self, rhs: &Self) -> Self {
+            Wide(self.0.wrapping_add(rhs.0))
+        }
+    }
+
+    impl num_traits::WrappingMul for Wide {
+        fn wrapping_mul(&self, rhs: &Self) -> Self {
+            Wide(self.0.wrapping_mul(rhs.0))
+        }
+    }
+
+    impl IntoDigits for Wide {}
+
+    #[test]
+    fn test_user_defined_type() {
+        for &number in &[0, 7, 1 << 70, u128::MAX / 3, u128::MAX] {
+            for &radix in &[2, 7, 10, 16, 1 << 65] {
+                let expected = DigitsIterator::new(number, radix).unwrap();
+                let digits = DigitsIterator::new(Wide(number), Wide(radix)).unwrap();
+                assert_eq!(digits.len(), expected.len());
+                assert!(digits.clone().map(|digit| digit.0).eq(expected.clone()));
+                assert!(digits.rev().map(|digit| digit.0).eq(expected.rev()));
+            }
+        }
+        assert_eq!(Wide(1203).nbr_digits(Wide(10)), Ok(4));
+        assert_eq!(Wide(1203).reverse_digits(Wide(10)), Ok(Wide(3021)));
+        assert_eq!(Strategy::new(Wide(16)), Strategy::Divide);
+
+        let digits = DigitsIterator::new(Wide(u128::MAX), Wide(10)).unwrap();
+        assert_eq!(digits.checked_reversed_number(), None);
+        assert_eq!(Wide(u128::MAX / 2).to_u128(), Some(u128::MAX / 2));
+        assert_eq!(Wide::from_u128(12_345), Some(Wide(12_345)));
+        assert_eq!(Wide(1).max_above(), Wide(u128::MAX));
+    }
+
+    /// A 256-bit number, made of its high and low halves, with more digits than the tables sized
+    /// for a `u128`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub(crate) struct Wide256(pub(crate) u128, pub(crate) u128);
+
+    impl Wide256 {
+        pub(crate) const MAX: DigitalNumber<Wide256> = DigitalNumber(Wide256(u128::MAX, u128::MAX));
+
+        fn bit(self, bit: u32) -> bool {
+            if bit < u128::BITS {
+                self.1 >> bit & 1 == 1
+            } else {
+                self.0 >> (bit - u128::BITS) & 1 == 1
+            }
+        }
+
+        /// Shifts to the left by one bit, returning the bit shifted out.
+        fn double(self) -> (Self, bool) {
+            (
+                Wide256(self.0 << 1 | self.1 >> 127, self.1 << 1),
+                self.0 >> 127 == 1,
+            )
+        }
+
+        fn wrapping_sub(self, other: Self) -> Self {
+            let (low, borrow) = self.1.overflowing_sub(other.1);
+            Wide256(
+                self.0.wrapping_sub(other.0).wrapping_sub(borrow as u128),
+                low,
+            )
+        }
+    }
+
+    impl Digital for Wide256 {
+        fn zero() -> Self {
+            Wide256(0, 0)
+        }
+
+        fn one() -> Self {
+            Wide256(0, 1)
+        }
+
+        fn div_rem(self, divisor: Self) -> (Self, Self) {
+            let (mut quotient, mut remainder) = (Wide256(0, 0), Wide256(0, 0));
+            for bit in (0..256).rev() {
+                let (doubled, carry) = remainder.double();
+                remainder = Wide256(doubled.0, doubled.1 | self.bit(bit) as u128);
+                quotient = quotient.double().0;
+                if carry || remainder >= divisor {
+                    remainder = remainder.wrapping_sub(divisor);
+                    quotient.1 |= 1;
+                }
+            }
+            (quotient, remainder)
+        }
+
+        fn checked_mul_add(self, factor: Self, addend: Self) -> Option<Self> {
+            let add = |a: Wide256, b: Wide256| {
+                let (low, carry) = a.1.overflowing_add(b.1);
+                Some(Wide256(
+                    a.0.checked_add(b.0)?.checked_add(carry as u128)?,
+                    low,
+                ))
+            };
+
+            let mut product = Wide256(0, 0);
+            for bit in (0..256).rev() {
+                product = match product.double() {
+                    (_, true) => return None,
+                    (doubled, false) => doubled,
+                };
+                if factor.bit(bit) {
+                    product = add(product, self)?;
+                }
+            }
+            add(product, addend)
+        }
+
+        fn checked_sub(self, other: Self) -> Option<Self> {
+            if other > self {
+                None
+            } else {
+                Some(self.wrapping_sub(other))
+            }
+        }
+    }
+
+    #[test]
+    fn test_wider_than_u128() {
+        let max = Wide256::MAX;
+        let digits = max.into_binary_digits();
+        assert_eq!(digits.len(), 256);
+        assert!(digits.clone().all(|digit| digit == DigitalNumber::one()));
+        assert_eq!(digits.to_number(), max);
+
+        let number = DigitalNumber(Wide256(0, u128::MAX));
+        let expected = u128::MAX.into_decimal_digits();
+        let digits = number.into_decimal_digits();
+        assert!(digits.map(|digit| digit.0 .1).eq(expected));
+        assert_eq!(max.nbr_decimal_digits(), 78);
+        assert_eq!(max.max_above(), max);
+        assert_eq!(max.to_u128(), None);
+    }
+
     #[test]
     fn very_small() {
         let mut digits = DigitsIterator::new(8_u32, 10_u32).unwrap();
@@ -434,6 +1318,31 @@ mod tests {
         assert_eq!(reversed, 40);
     }
 
+    #[test]
+    fn test_checked_reversed_number() {
+        for &radix in &[2_u16, 3, 7, 10, 16, 255, 256, u16::MAX] {
+            for number in 0..=u16::MAX {
+                let digits = DigitsIterator::new(number, radix).unwrap();
+                let expected = digits.clone().rev().try_fold(0_u16, |acc, digit| {
+                    acc.checked_mul(radix)?.checked_add(digit)
+                });
+                assert_eq!(digits.checked_reversed_number(), expected);
+            }
+        }
+
+        let mut digits = DigitsIterator::new(u64::MAX - 6, 10).unwrap();
+        assert_eq!(digits.checked_reversed_number(), None);
+        digits.next_back();
+        assert_eq!(
+            digits.checked_reversed_number(),
+            Some(615_590_737_044_764_481)
+        );
+        assert_eq!(
+            DigitsIterator::zero_padded(12_u16, 10, 3).checked_reversed_number(),
+            Some(210)
+        );
+    }
+
     #[test]
     fn test_last() {
         let number = 123_456_u32;
@@ -487,4 +1396,463 @@ mod tests {
         digits.next();
         digits.rotate_left(1);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_power_of_two_radices() {
+        for &radix in &[2_u64, 4, 8, 16, 32, 64, 128, 1 << 32, 1 << 63] {
+            for &number in &[0, 1, radix - 1, radix, 0xdead_beef, u64::MAX] {
+                let digits = DigitsIterator::new(number, radix).unwrap();
+                let mut expected = Vec::new();
+                let mut n = number;
+                loop {
+                    expected.push(n % radix);
+                    n /= radix;
+                    if n == 0 {
+                        break;
+                    }
+                }
+                expected.reverse();
+
+                assert_eq!(digits.len(), expected.len(), "{} {}", number, radix);
+                assert_eq!(digits.clone().collect::<Vec<_>>(), expected);
+                assert!(digits.clone().rev().eq(expected.iter().rev().copied()));
+                assert_eq!(digits.last(), expected.last().copied());
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_power_of_two_mixed_directions() {
+        let mut digits = DigitsIterator::new(0x1234_5678_u32, 16).unwrap();
+        assert_eq!(digits.next(), Some(1));
+        assert_eq!(digits.next_back(), Some(8));
+        assert_eq!(digits.next(), Some(2));
+        assert_eq!(digits.len(), 5);
+        assert_eq!(digits.clone().into_number(), 0x34567);
+        assert_eq!(digits.rotate_left(1).to_number(), 0x45673);
+        assert_eq!(digits.collect::<Vec<_>>(), vec![4, 5, 6, 7, 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decimal_matches_formatting() {
+        let mut number = 1_u128;
+        while number < u128::MAX / 3 {
+            for &n in &[number - 1, number, number + 1] {
+                let expected: Vec<_> = n
+                    .to_string()
+                    .bytes()
+                    .map(|digit| u128::from(digit - b'0'))
+                    .collect();
+                let digits = DigitsIterator::new(n, 10).unwrap();
+                assert_eq!(digits.clone().collect::<Vec<_>>(), expected);
+                assert!(digits.rev().eq(expected.into_iter().rev()));
+            }
+            number *= 3;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_folds_match_iteration() {
+        for &number in &[0_u128, 7, 10, 99, 100, 12_345, u64::MAX as u128, u128::MAX] {
+            for &radix in &[10, 7, 16] {
+                for &(front, back) in &[(0, 0), (1, 0), (0, 1), (2, 3)] {
+                    let mut digits = DigitsIterator::new(number, radix).unwrap();
+                    for _ in 0..front {
+                        digits.next();
+                    }
+                    for _ in 0..back {
+                        digits.next_back();
+                    }
+
+                    let mut expected = Vec::new();
+                    for digit in digits.clone() {
+                        expected.push(digit);
+                    }
+
+                    let folded = digits.clone().fold(Vec::new(), |mut acc, digit| {
+                        acc.push(digit);
+                        acc
+                    });
+                    assert_eq!(folded, expected, "{} {}", number, radix);
+
+                    let mut rfolded = digits.rfold(Vec::new(), |mut acc, digit| {
+                        acc.push(digit);
+                        acc
+                    });
+                    rfolded.reverse();
+                    assert_eq!(rfolded, expected, "{} {}", number, radix);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_mixed_directions_after_many_back_steps() {
+        for &radix in &[10_u64, 16, 7] {
+            // Small enough for the rotations not to wrap.
+            let number = u64::MAX / 1000;
+            let expected: Vec<_> = DigitsIterator::new(number, radix).unwrap().collect();
+
+            for back in 0..expected.len() {
+                let mut digits = DigitsIterator::new(number, radix).unwrap();
+                for _ in 0..back {
+                    digits.next_back();
+                }
+                assert_eq!(
+                    digits.clone().collect::<Vec<_>>(),
+                    expected[..expected.len() - back]
+                );
+
+                let mut rotated = digits.clone();
+                rotated.rotate_left(1);
+                let mut rotated_expected = expected[..expected.len() - back].to_vec();
+                let shift = 1.min(rotated_expected.len());
+                rotated_expected.rotate_left(shift);
+                assert_eq!(rotated.collect::<Vec<_>>(), rotated_expected, "{}", back);
+            }
+        }
+    }
+
+    #[test]
+    fn test_equality_ignores_pending_splitter() {
+        let mut back = DigitsIterator::new(1234_u32, 10).unwrap();
+        back.next_back();
+        let front = DigitsIterator::new(123_u32, 10).unwrap();
+        assert_eq!(back, front);
+    }
+
+    #[test]
+    fn test_len_around_powers() {
+        for radix in 2..=40_u64 {
+            let mut power = radix;
+            let mut len = 1;
+            loop {
+                for &(number, expected) in &[(power - 1, len), (power, len + 1)] {
+                    let digits = DigitsIterator::new(number, radix).unwrap();
+                    assert_eq!(digits.len(), expected, "{} {}", number, radix);
+                    assert_eq!(digits.clone().count(), expected);
+                    assert_eq!(digits.rev().count(), expected);
+                }
+
+                match power.checked_mul(radix) {
+                    Some(next) => power = next,
+                    None => break,
+                }
+                len += 1;
+            }
+
+            assert_eq!(
+                DigitsIterator::new(u64::MAX, radix).unwrap().count(),
+                len + 1
+            );
+
+            let radix = u128::from(radix);
+            let (mut number, mut len) = (u128::MAX, 0);
+            while number > 0 {
+                number /= radix;
+                len += 1;
+            }
+            assert_eq!(DigitsIterator::new(u128::MAX, radix).unwrap().len(), len);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_every_u8_in_every_radix() {
+        for radix in 2..=u8::MAX {
+            for number in 0..=u8::MAX {
+                let mut expected = Vec::new();
+                let mut n = number;
+                loop {
+                    expected.push(n % radix);
+                    n /= radix;
+                    if n == 0 {
+                        break;
+                    }
+                }
+
+                let digits = DigitsIterator::new(number, radix).unwrap();
+                assert!(digits.clone().rev().eq(expected.iter().copied()));
+                assert!(digits.eq(expected.iter().rev().copied()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_palindromes_match_iteration() {
+        fn by_iteration(number: u16, radix: u16) -> bool {
+            let mut digits = DigitsIterator::new(number, radix).unwrap();
+            while digits.len() > 1 {
+                if digits.next() != digits.next_back() {
+                    return false;
+                }
+            }
+            true
+        }
+
+        for &radix in &[2_u16, 3, 7, 10, 16, 255, 256, u16::MAX] {
+            for number in 0..=u16::MAX {
+                assert_eq!(
+                    number.is_palindrome(radix),
+                    Ok(by_iteration(number, radix)),
+                    "{} {}",
+                    number,
+                    radix
+                );
+            }
+        }
+
+        assert!(u64::MAX.is_binary_palindrome());
+        assert!(18_446_744_066_044_764_481_u64.is_decimal_palindrome());
+        assert!(!u64::MAX.is_decimal_palindrome());
+        assert_eq!(1_u8.is_palindrome(0), Err(RadixError::Radix0));
+    }
+
+    #[test]
+    fn test_ends_with_reverse_of() {
+        assert!(u64::MAX.ends_with_reverse_of(516, 10).unwrap());
+        assert!(u64::MAX.ends_with_reverse_of(u64::MAX, 2).unwrap());
+        assert!(0_u8.ends_with_reverse_of(0, 10).unwrap());
+        assert!(10_u8.ends_with_reverse_of(0, 10).unwrap());
+        assert!(!10_u8.ends_with_reverse_of(10, 10).unwrap());
+        assert!(!9_u8.ends_with_reverse_of(19, 10).unwrap());
+        assert_eq!(1_u8.ends_with_reverse_of(1, 1), Err(RadixError::Radix1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sub_number() {
+        for &number in &[0_u64, 7, 10, 12_345, 100_200, u64::MAX] {
+            let digits = number.to_string();
+            for start in 0..=digits.len() {
+                for end in start..=digits.len() {
+                    let expected = digits[start..end].parse().unwrap_or(0);
+                    assert_eq!(number.sub_number(start..end, 10), Ok(Some(expected)));
+                }
+                assert_eq!(number.sub_number(start..=digits.len(), 10), Ok(None));
+            }
+        }
+
+        assert_eq!(u128::MAX.sub_number(.., 2), Ok(Some(u128::MAX)));
+        assert_eq!(u128::MAX.sub_number(1.., 2), Ok(Some(u128::MAX >> 1)));
+        assert_eq!(u8::MAX.sub_number(..=0, 16), Ok(Some(15)));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 123_u8.sub_number(2..1, 10);
+        assert_eq!(reversed, Ok(None));
+        assert_eq!(1_u8.sub_number(.., 1), Err(RadixError::Radix1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_concat_digits() {
+        for a in 0..=u8::MAX {
+            for &b in &[0, 1, 9, 10, 99, 100, 255] {
+                let expected = format!("{}{}", a, b).parse::<u8>().ok();
+                assert_eq!(a.concat_digits(b, 10), Ok(expected), "{} {}", a, b);
+            }
+        }
+
+        assert_eq!(u64::MAX.concat_digits(0, 2), Ok(None));
+        assert_eq!(0_u64.concat_digits(u64::MAX, 2), Ok(Some(u64::MAX)));
+        assert_eq!((u64::MAX >> 1).concat_digits(1, 2), Ok(Some(u64::MAX)));
+        assert_eq!(1_u8.concat_digits(1, 0), Err(RadixError::Radix0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_interleave_digits() {
+        use crate::digits::Interleave;
+
+        for a in 0..=u16::from(u8::MAX) {
+            for &b in &[0, 1, 9, 10, 99, 100, 255] {
+                let (a_digits, b_digits) = (a.to_string(), b.to_string());
+                let len = a_digits.len().max(b_digits.len());
+                let padded: String = format!("{:0>1$}", a_digits, len)
+                    .chars()
+                    .zip(format!("{:0>1$}", b_digits, len).chars())
+                    .flat_map(|(a, b)| [a, b])
+                    .collect();
+                let expected = padded.parse::<u16>().ok();
+                assert_eq!(a.interleave_digits(b, Interleave::Pad, 10), Ok(expected));
+
+                let common = a_digits.len().min(b_digits.len());
+                let mut appended: String = a_digits
+                    .chars()
+                    .zip(b_digits.chars())
+                    .flat_map(|(a, b)| [a, b])
+                    .collect();
+                appended.push_str(&a_digits[common..]);
+                appended.push_str(&b_digits[common..]);
+                let expected = appended.parse::<u16>().ok();
+                assert_eq!(a.interleave_digits(b, Interleave::Append, 10), Ok(expected));
+            }
+        }
+
+        let morton = u64::from(u32::MAX).interleave_digits(0, Interleave::Pad, 2);
+        assert_eq!(morton, Ok(Some(0xAAAA_AAAA_AAAA_AAAA)));
+        assert_eq!(
+            1_u8.interleave_digits(1, Interleave::Pad, 1),
+            Err(RadixError::Radix1)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_digit_runs() {
+        use crate::parse::from_digit_runs;
+
+        for &radix in &[2_u64, 3, 10, 16, u64::MAX] {
+            for &number in &[0, 1, 9, 10, 255, 1 << 40, 1_112_000, u64::MAX - 1, u64::MAX] {
+                let runs: Vec<_> = number.digit_runs(radix).unwrap().collect();
+                assert!(runs.windows(2).all(|pair| pair[0].0 != pair[1].0));
+                assert!(runs
+                    .iter()
+                    .flat_map(|&(digit, len)| core::iter::repeat_n(digit, len))
+                    .eq(number.into_digits(radix).unwrap()));
+                assert_eq!(from_digit_runs(runs, radix), Ok(number));
+            }
+        }
+
+        let mut runs = 1211_u32.digit_runs(10).unwrap();
+        assert_eq!(runs.size_hint(), (1, Some(4)));
+        assert_eq!(runs.next(), Some((1, 1)));
+        assert_eq!(runs.size_hint(), (1, Some(3)));
+        assert!(runs.eq([(2, 1), (1, 2)]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_pairs_and_predicates() {
+        for &radix in &[2_u64, 3, 10, 16, 1 << 32, u64::MAX] {
+            for &number in &[
+                0,
+                1,
+                9,
+                10,
+                11,
+                255,
+                1 << 40,
+                1_234_567,
+                7_654_321,
+                u64::MAX,
+            ] {
+                let expected: Vec<_> = number.into_digits(radix).unwrap().collect();
+                let digits = number.into_digits(radix).unwrap();
+                assert_eq!(digits.clone().pairs().len(), expected.len() - 1);
+                assert!(digits
+                    .clone()
+                    .pairs()
+                    .eq(expected.windows(2).map(|pair| (pair[0], pair[1]))));
+
+                assert_eq!(
+                    digits.clone().is_digits_sorted_ascending(),
+                    expected.windows(2).all(|pair| pair[0] <= pair[1])
+                );
+                assert_eq!(
+                    digits.clone().has_adjacent_equal_digits(),
+                    expected.windows(2).any(|pair| pair[0] == pair[1])
+                );
+                for &sum in &[0, 1, 9, 10, 15, u64::MAX - 1, u64::MAX] {
+                    assert_eq!(
+                        digits.clone().has_adjacent_pair_summing_to(sum),
+                        expected
+                            .windows(2)
+                            .any(|pair| pair[0].checked_add(pair[1]) == Some(sum))
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_windows_and_chunks_match_slices() {
+        fn check<const K: usize>(number: u64, radix: u64) {
+            use core::convert::TryFrom;
+
+            let expected: Vec<_> = number.into_digits(radix).unwrap().collect();
+
+            let windows = number.into_digits(radix).unwrap().windows::<K>();
+            assert_eq!(windows.len(), expected.windows(K).len());
+            assert!(windows.eq(expected
+                .windows(K)
+                .map(|window| <[u64; K]>::try_from(window).unwrap())));
+
+            let mut padded = vec![0; (K - expected.len() % K) % K];
+            padded.extend(&expected);
+            let chunks = number.into_digits(radix).unwrap().chunks::<K>();
+            assert_eq!(chunks.len(), padded.chunks(K).len());
+            assert!(chunks.eq(padded
+                .chunks(K)
+                .map(|chunk| <[u64; K]>::try_from(chunk).unwrap())));
+        }
+
+        for &radix in &[2_u64, 3, 10, 16, u64::MAX] {
+            for &number in &[0, 1, 9, 10, 255, 1 << 40, 1_234_567, u64::MAX] {
+                check::<1>(number, radix);
+                check::<2>(number, radix);
+                check::<3>(number, radix);
+                check::<4>(number, radix);
+                check::<20>(number, radix);
+            }
+        }
+
+        let mut windows = 123_u8.into_decimal_digits().windows::<2>();
+        assert_eq!(windows.next(), Some([1, 2]));
+        assert_eq!(windows.len(), 1);
+        let mut windows = 12_u8.into_decimal_digits().windows::<3>();
+        assert_eq!(windows.next(), None);
+        assert_eq!(windows.len(), 0);
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_windows() {
+        let _ = 123_u8.into_decimal_digits().windows::<0>();
+    }
+
+    #[test]
+    fn test_const_matches_generic() {
+        for radix in 0..=40_u32 {
+            for &number in &[
+                0,
+                1,
+                9,
+                10,
+                99,
+                100,
+                255,
+                256,
+                1 << 20,
+                u32::MAX - 1,
+                u32::MAX,
+            ] {
+                let digits = DigitsIterator::new(number, radix);
+                assert_eq!(DigitsIterator::<u32>::new_const(number, radix), digits);
+                assert_eq!(
+                    DigitsIterator::<u32>::digit_count(number, radix),
+                    digits.map(|digits| digits.len())
+                );
+
+                if let Ok(digits) = DigitsIterator::<u32>::new_const(number, radix) {
+                    assert!(digits
+                        .clone()
+                        .eq(DigitsIterator::new(number, radix).unwrap()));
+                    assert!(digits
+                        .rev()
+                        .eq(DigitsIterator::new(number, radix).unwrap().rev()));
+                }
+            }
+        }
+
+        const MAX: Result<DigitsIterator<u128>, RadixError> =
+            DigitsIterator::<u128>::new_const(u128::MAX, 10);
+        assert_eq!(MAX.map(|digits| digits.len()), Ok(39));
+    }
 }
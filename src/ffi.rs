@@ -0,0 +1,540 @@
+//! C bindings of the digits, the check digits and the formatting and parsing of `u64`, suitable
+//! for generating a header with `cbindgen`.
+//!
+//! Every function returns a `RadixalStatus` and writes its results through pointers provided by
+//! the caller, so that nothing is allocated on either side. When a buffer is too small, the
+//! length it needs is still written, so that a first call with a capacity of 0 (and a null
+//! buffer) queries the size to allocate.
+//!
+//! # Example
+//!
+//! ```
+//! use core::ffi::CStr;
+//! use radixal::ffi::{radixal_format, RadixalStatus};
+//!
+//! let mut buffer = [0; 8];
+//! let mut len = 0;
+//! let status = unsafe {
+//!     radixal_format(255, 16, false, buffer.as_mut_ptr(), buffer.len(), &mut len)
+//! };
+//!
+//! assert_eq!((status, len), (RadixalStatus::Ok, 2));
+//! assert_eq!(unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_str(), Ok("ff"));
+//! ```
+use crate::alphabet::Alphabet;
+use crate::checksum::{damm, ean, isbn, luhn, mod11, verhoeff};
+use crate::digits_iterator::RadixError;
+use crate::parse::{self, ParseError};
+use crate::IntoDigits;
+use core::convert::TryFrom;
+use core::ffi::{c_char, CStr};
+use core::slice;
+
+/// The outcome of a call, `Ok` on success.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RadixalStatus {
+    Ok = 0,
+    /// A required pointer is null.
+    NullPointer,
+    Radix0,
+    Radix1,
+    /// The radix is larger than what the function supports.
+    RadixTooLarge,
+    /// The output buffer is too small, its needed length has been written.
+    BufferTooSmall,
+    /// A digit or a symbol is not valid under the radix.
+    InvalidDigit,
+    /// The number is too large for a `u64`.
+    Overflow,
+    /// The string holds no digits.
+    Empty,
+    /// The digits do not have a check digit under the algorithm, such as a payload with too many
+    /// digits.
+    InvalidPayload,
+    /// The value is not one of the `RadixalChecksum` algorithms.
+    InvalidAlgorithm,
+    /// The radix is smaller than what the function supports.
+    RadixTooSmall,
+}
+
+impl From<RadixError> for RadixalStatus {
+    fn from(err: RadixError) -> Self {
+        match err {
+            RadixError::Radix0 => RadixalStatus::Radix0,
+            RadixError::Radix1 => RadixalStatus::Radix1,
+            RadixError::RadixTooLarge => RadixalStatus::RadixTooLarge,
+            RadixError::RadixTooSmall => RadixalStatus::RadixTooSmall,
+        }
+    }
+}
+
+impl From<ParseError> for RadixalStatus {
+    fn from(err: ParseError) -> Self {
+        match err {
+            ParseError::Empty => RadixalStatus::Empty,
+            ParseError::Overflow => RadixalStatus::Overflow,
+            ParseError::InvalidDigit(_)
+            | ParseError::InvalidLength
+            | ParseError::Malformed
+            | ParseError::DigitTooLarge(_) => RadixalStatus::InvalidDigit,
+            ParseError::Radix(err) => err.into(),
+        }
+    }
+}
+
+/// The check digit algorithms over decimal digits.
+///
+/// The functions take the algorithm as a `u32`, since any other value than these would be
+/// undefined behavior for a Rust enum, and return `InvalidAlgorithm` for it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RadixalChecksum {
+    Luhn = 0,
+    Verhoeff = 1,
+    Damm = 2,
+    /// ISBN-10, whose check digit 10 is written `X`.
+    Isbn10 = 3,
+    Isbn13 = 4,
+    Ean13 = 5,
+    Upca = 6,
+    /// The NHS number, which has no check digit when it would be 10.
+    Nhs = 7,
+}
+
+impl TryFrom<u32> for RadixalChecksum {
+    type Error = RadixalStatus;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(RadixalChecksum::Luhn),
+            1 => Ok(RadixalChecksum::Verhoeff),
+            2 => Ok(RadixalChecksum::Damm),
+            3 => Ok(RadixalChecksum::Isbn10),
+            4 => Ok(RadixalChecksum::Isbn13),
+            5 => Ok(RadixalChecksum::Ean13),
+            6 => Ok(RadixalChecksum::Upca),
+            7 => Ok(RadixalChecksum::Nhs),
+            _ => Err(RadixalStatus::InvalidAlgorithm),
+        }
+    }
+}
+
+impl RadixalChecksum {
+    fn compute(self, digits: &[u8]) -> Option<usize> {
+        match self {
+            RadixalChecksum::Luhn => luhn::compute_check_digit(digits),
+            RadixalChecksum::Verhoeff => verhoeff::compute_check_digit(digits),
+            RadixalChecksum::Damm => damm::compute_check_digit(digits),
+            RadixalChecksum::Isbn10 => isbn::compute_isbn10_check_digit(digits),
+            RadixalChecksum::Isbn13 => isbn::compute_isbn13_check_digit(digits),
+            RadixalChecksum::Ean13 => ean::compute_ean13_check_digit(digits),
+            RadixalChecksum::Upca => ean::compute_upca_check_digit(digits),
+            RadixalChecksum::Nhs => mod11::compute_nhs_check_digit(digits),
+        }
+    }
+
+    fn check(self, digits: &[u8]) -> bool {
+        match self {
+            RadixalChecksum::Luhn => luhn::check(digits),
+            RadixalChecksum::Verhoeff => verhoeff::check(digits),
+            RadixalChecksum::Damm => damm::check(digits),
+            RadixalChecksum::Isbn10 => isbn::check_isbn10(digits),
+            RadixalChecksum::Isbn13 => isbn::check_isbn13(digits),
+            RadixalChecksum::Ean13 => ean::check_ean13(digits),
+            RadixalChecksum::Upca => ean::check_upca(digits),
+            RadixalChecksum::Nhs => mod11::check_nhs(digits),
+        }
+    }
+}
+
+/// Returns the first `radix` symbols of `Alphabet::BASE36`, case insensitive.
+fn base36(radix: u64) -> Result<Alphabet<'static>, RadixalStatus> {
+    let symbols = Alphabet::BASE36.symbols();
+    match radix {
+        0 => Err(RadixalStatus::Radix0),
+        1 => Err(RadixalStatus::Radix1),
+        radix if radix > symbols.len() as u64 => Err(RadixalStatus::RadixTooLarge),
+        radix => Alphabet::from_symbols(&symbols[..radix as usize])
+            .with_ignore_case()
+            .map_err(|_| RadixalStatus::RadixTooLarge),
+    }
+}
+
+/// Returns the caller buffer `ptr` of `capacity` elements, or an empty one if `capacity` is 0.
+///
+/// # Safety
+///
+/// If `capacity` is not 0, `ptr` must be null or valid for writing `capacity` elements.
+unsafe fn buffer<'a, T>(ptr: *mut T, capacity: usize) -> Result<&'a mut [T], RadixalStatus> {
+    if capacity == 0 {
+        Ok(&mut [])
+    } else if ptr.is_null() {
+        Err(RadixalStatus::NullPointer)
+    } else {
+        Ok(slice::from_raw_parts_mut(ptr, capacity))
+    }
+}
+
+/// Writes the number of digits of `number` under `radix` to `len`.
+///
+/// # Safety
+///
+/// `len` must be null or valid for writing.
+#[no_mangle]
+pub unsafe extern "C" fn radixal_nbr_digits(
+    number: u64,
+    radix: u64,
+    len: *mut usize,
+) -> RadixalStatus {
+    if len.is_null() {
+        return RadixalStatus::NullPointer;
+    }
+
+    match number.nbr_digits(radix) {
+        Ok(nbr_digits) => {
+            *len = nbr_digits;
+            RadixalStatus::Ok
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// Writes the digits of `number` under `radix`, at most 256, most significant first, to the
+/// `capacity` bytes of `out`, and their number to `len`.
+///
+/// # Safety
+///
+/// `len` must be null or valid for writing, and `out` must be null or valid for writing
+/// `capacity` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn radixal_digits(
+    number: u64,
+    radix: u64,
+    out: *mut u8,
+    capacity: usize,
+    len: *mut usize,
+) -> RadixalStatus {
+    if len.is_null() {
+        return RadixalStatus::NullPointer;
+    } else if radix > 256 {
+        return RadixalStatus::RadixTooLarge;
+    }
+
+    let digits = match number.into_digits(radix) {
+        Ok(digits) => digits,
+        Err(err) => return err.into(),
+    };
+    *len = digits.len();
+
+    let out = match buffer(out, capacity) {
+        Ok(out) if out.len() >= digits.len() => out,
+        Ok(_) => return RadixalStatus::BufferTooSmall,
+        Err(status) => return status,
+    };
+    for (byte, digit) in out.iter_mut().zip(digits) {
+        // Smaller than the radix, so that it fits.
+        *byte = digit as u8;
+    }
+    RadixalStatus::Ok
+}
+
+/// Writes `number` under `radix`, at most 36, with the digits `0-9` and the letters `a-z` (or
+/// `A-Z` if `uppercase`), followed by a NUL, to the `capacity` chars of `out`, and the length of
+/// the string without the NUL to `len`.
+///
+/// # Safety
+///
+/// `len` must be null or valid for writing, and `out` must be null or valid for writing
+/// `capacity` chars.
+#[no_mangle]
+pub unsafe extern "C" fn radixal_format(
+    number: u64,
+    radix: u64,
+    uppercase: bool,
+    out: *mut c_char,
+    capacity: usize,
+    len: *mut usize,
+) -> RadixalStatus {
+    if len.is_null() {
+        return RadixalStatus::NullPointer;
+    }
+
+    let alphabet = match base36(radix) {
+        Ok(alphabet) => alphabet,
+        Err(status) => return status,
+    };
+    let digits = match number.into_digits(radix) {
+        Ok(digits) => digits,
+        Err(err) => return err.into(),
+    };
+    *len = digits.len();
+
+    let out = match buffer(out, capacity) {
+        Ok(out) if out.len() > digits.len() => out,
+        Ok(_) => return RadixalStatus::BufferTooSmall,
+        Err(status) => return status,
+    };
+    let symbols = digits.map(|digit| {
+        // The symbols of `BASE36` are ASCII.
+        let symbol = alphabet.symbols()[digit as usize] as u8;
+        if uppercase {
+            symbol.to_ascii_uppercase()
+        } else {
+            symbol
+        }
+    });
+    for (c, symbol) in out.iter_mut().zip(symbols.chain(Some(0))) {
+        *c = symbol as c_char;
+    }
+    RadixalStatus::Ok
+}
+
+/// Parses the NUL-terminated string `s` under `radix`, at most 36, ignoring the case of the
+/// letters, and writes the result to `number`.
+///
+/// A leading `+` is accepted, as by the parsers of this crate.
+///
+/// # Safety
+///
+/// `s` must be null or a valid NUL-terminated string, and `number` must be null or valid for
+/// writing.
+#[no_mangle]
+pub unsafe extern "C" fn radixal_parse(
+    s: *const c_char,
+    radix: u64,
+    number: *mut u64,
+) -> RadixalStatus {
+    if s.is_null() || number.is_null() {
+        return RadixalStatus::NullPointer;
+    }
+
+    let alphabet = match base36(radix) {
+        Ok(alphabet) => alphabet,
+        Err(status) => return status,
+    };
+    let s = match CStr::from_ptr(s).to_str() {
+        Ok(s) => s,
+        Err(_) => return RadixalStatus::InvalidDigit,
+    };
+    match parse::parse(s, &alphabet) {
+        Ok(parsed) => {
+            *number = parsed;
+            RadixalStatus::Ok
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// Writes the check digit of the `len` decimal digits of `digits`, most significant first, under
+/// `algorithm`, one of the `RadixalChecksum` values, to `check_digit`.
+///
+/// # Safety
+///
+/// `check_digit` must be null or valid for writing, and `digits` must be null or valid for
+/// reading `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn radixal_checksum_compute(
+    algorithm: u32,
+    digits: *const u8,
+    len: usize,
+    check_digit: *mut u8,
+) -> RadixalStatus {
+    if check_digit.is_null() || (digits.is_null() && len > 0) {
+        return RadixalStatus::NullPointer;
+    }
+
+    let algorithm = match RadixalChecksum::try_from(algorithm) {
+        Ok(algorithm) => algorithm,
+        Err(status) => return status,
+    };
+
+    let digits = if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(digits, len)
+    };
+    if digits.iter().any(|&digit| digit > 9) {
+        return RadixalStatus::InvalidDigit;
+    }
+    match algorithm.compute(digits) {
+        Some(digit) => {
+            // Check digits are at most 10.
+            *check_digit = digit as u8;
+            RadixalStatus::Ok
+        }
+        None => RadixalStatus::InvalidPayload,
+    }
+}
+
+/// Writes whether the `len` decimal digits of `digits`, most significant first and ending with
+/// their check digit, are valid under `algorithm`, one of the `RadixalChecksum` values, to
+/// `valid`.
+///
+/// # Safety
+///
+/// `valid` must be null or valid for writing, and `digits` must be null or valid for reading
+/// `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn radixal_checksum_validate(
+    algorithm: u32,
+    digits: *const u8,
+    len: usize,
+    valid: *mut bool,
+) -> RadixalStatus {
+    if valid.is_null() || (digits.is_null() && len > 0) {
+        return RadixalStatus::NullPointer;
+    }
+
+    let algorithm = match RadixalChecksum::try_from(algorithm) {
+        Ok(algorithm) => algorithm,
+        Err(status) => return status,
+    };
+
+    let digits = if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(digits, len)
+    };
+    *valid = algorithm.check(digits);
+    RadixalStatus::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    #[test]
+    fn test_digits() {
+        let mut len = 0;
+        assert_eq!(
+            unsafe { radixal_nbr_digits(u64::MAX, 2, &mut len) },
+            RadixalStatus::Ok
+        );
+        assert_eq!(len, 64);
+
+        let mut out = [0; 4];
+        let status = unsafe { radixal_digits(1203, 10, out.as_mut_ptr(), out.len(), &mut len) };
+        assert_eq!((status, len, out), (RadixalStatus::Ok, 4, [1, 2, 0, 3]));
+
+        let status = unsafe { radixal_digits(u64::MAX, 256, out.as_mut_ptr(), 4, &mut len) };
+        assert_eq!((status, len), (RadixalStatus::BufferTooSmall, 8));
+        let status = unsafe { radixal_digits(12, 257, out.as_mut_ptr(), 4, &mut len) };
+        assert_eq!(status, RadixalStatus::RadixTooLarge);
+        let status = unsafe { radixal_nbr_digits(12, 1, &mut len) };
+        assert_eq!(status, RadixalStatus::Radix1);
+        let status = unsafe { radixal_digits(12, 10, ptr::null_mut(), 4, &mut len) };
+        assert_eq!(status, RadixalStatus::NullPointer);
+        let status = unsafe { radixal_nbr_digits(12, 10, ptr::null_mut()) };
+        assert_eq!(status, RadixalStatus::NullPointer);
+    }
+
+    #[test]
+    fn test_format_and_parse() {
+        let mut len = 0;
+        let status = unsafe { radixal_format(u64::MAX, 36, true, ptr::null_mut(), 0, &mut len) };
+        assert_eq!((status, len), (RadixalStatus::BufferTooSmall, 13));
+
+        let mut out = [1 as c_char; 14];
+        let status = unsafe { radixal_format(u64::MAX, 36, true, out.as_mut_ptr(), 14, &mut len) };
+        assert_eq!(status, RadixalStatus::Ok);
+        let s = unsafe { CStr::from_ptr(out.as_ptr()) };
+        assert_eq!(s.to_str(), Ok("3W5E11264SGSF"));
+
+        let status = unsafe { radixal_format(0, 37, false, out.as_mut_ptr(), 14, &mut len) };
+        assert_eq!(status, RadixalStatus::RadixTooLarge);
+
+        let mut number = 0;
+        let status = unsafe { radixal_parse(s.as_ptr(), 36, &mut number) };
+        assert_eq!((status, number), (RadixalStatus::Ok, u64::MAX));
+        let status = unsafe { radixal_parse(b"3w5e11264sgsf\0".as_ptr().cast(), 36, &mut number) };
+        assert_eq!((status, number), (RadixalStatus::Ok, u64::MAX));
+        let status = unsafe { radixal_parse(b"3w5e11264sgsg\0".as_ptr().cast(), 36, &mut number) };
+        assert_eq!(status, RadixalStatus::Overflow);
+        let status = unsafe { radixal_parse(b"19a\0".as_ptr().cast(), 10, &mut number) };
+        assert_eq!(status, RadixalStatus::InvalidDigit);
+        let status = unsafe { radixal_parse(b"\0".as_ptr().cast(), 10, &mut number) };
+        assert_eq!(status, RadixalStatus::Empty);
+    }
+
+    #[test]
+    fn test_checksums() {
+        let imei = [4, 9, 0, 1, 5, 4, 2, 0, 3, 2, 3, 7, 5, 1, 8];
+        let (mut check_digit, mut valid) = (0, false);
+
+        let status = unsafe {
+            radixal_checksum_compute(
+                RadixalChecksum::Luhn as u32,
+                imei.as_ptr(),
+                14,
+                &mut check_digit,
+            )
+        };
+        assert_eq!((status, check_digit), (RadixalStatus::Ok, 8));
+        let status = unsafe {
+            radixal_checksum_validate(RadixalChecksum::Luhn as u32, imei.as_ptr(), 15, &mut valid)
+        };
+        assert_eq!((status, valid), (RadixalStatus::Ok, true));
+
+        let isbn = [0, 8, 0, 4, 4, 2, 9, 5, 7];
+        let status = unsafe {
+            radixal_checksum_compute(
+                RadixalChecksum::Isbn10 as u32,
+                isbn.as_ptr(),
+                9,
+                &mut check_digit,
+            )
+        };
+        assert_eq!((status, check_digit), (RadixalStatus::Ok, 10));
+        let status = unsafe {
+            radixal_checksum_compute(
+                RadixalChecksum::Isbn10 as u32,
+                imei.as_ptr(),
+                10,
+                &mut check_digit,
+            )
+        };
+        assert_eq!(status, RadixalStatus::InvalidPayload);
+
+        let nhs = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let status = unsafe {
+            radixal_checksum_compute(
+                RadixalChecksum::Nhs as u32,
+                nhs.as_ptr(),
+                9,
+                &mut check_digit,
+            )
+        };
+        assert_eq!(status, RadixalStatus::InvalidPayload);
+
+        let status = unsafe {
+            radixal_checksum_compute(
+                RadixalChecksum::Damm as u32,
+                [5, 7, 2].as_ptr(),
+                3,
+                &mut check_digit,
+            )
+        };
+        assert_eq!((status, check_digit), (RadixalStatus::Ok, 4));
+        let status = unsafe {
+            radixal_checksum_compute(
+                RadixalChecksum::Luhn as u32,
+                [1, 10].as_ptr(),
+                2,
+                &mut check_digit,
+            )
+        };
+        assert_eq!(status, RadixalStatus::InvalidDigit);
+    }
+
+    #[test]
+    fn test_checksum_invalid_algorithm() {
+        let (mut check_digit, mut valid) = (0, false);
+
+        let status = unsafe { radixal_checksum_compute(8, [1, 2].as_ptr(), 2, &mut check_digit) };
+        assert_eq!(status, RadixalStatus::InvalidAlgorithm);
+        let status = unsafe { radixal_checksum_validate(u32::MAX, [1, 2].as_ptr(), 2, &mut valid) };
+        assert_eq!((status, valid), (RadixalStatus::InvalidAlgorithm, false));
+    }
+}
@@ -0,0 +1,402 @@
+//! Digits of the fixed-point numbers of `fixed`, split in their integer and fractional parts.
+//!
+//! The fractional part of a number with `F` fractional bits is a fraction over `2^F`, whose
+//! expansion under a radix ends if and only if the radix is even, or the fraction is 0: each
+//! digit multiplies the fraction by the radix, taking off as many factors of 2 from its
+//! denominator as the radix has. Under an odd radix, the expansion is periodic and never ends.
+//!
+//! Signed numbers are split in their sign and the digits of their `unsigned_abs`.
+//!
+//! # Example
+//!
+//! ```
+//! use fixed::types::{I16F16, U8F8};
+//! use radixal::fixed_point::{FixedDisplay, IntoFixedDigits};
+//! use radixal::alphabet::Alphabet;
+//!
+//! let n = U8F8::from_num(12.375);
+//! assert!(n.into_int_digits(10).unwrap().eq([1, 2]));
+//! assert!(n.into_frac_digits(10).unwrap().eq([3, 7, 5]));
+//! assert!(n.into_frac_digits(2).unwrap().eq([0, 1, 1]));
+//!
+//! let n = I16F16::from_num(-2.5);
+//! let magnitude = FixedDisplay::new(n.unsigned_abs(), Alphabet::DECIMAL);
+//! assert_eq!(format!("{}{}", if n.is_negative() { "-" } else { "" }, magnitude), "-2.5");
+//! ```
+use crate::alphabet::Alphabet;
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::fraction::next_digit;
+use crate::{IntoDigits, RadixMath};
+use core::fmt::{self, Write};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use fixed::traits::{Fixed, FixedUnsigned};
+use num_traits::Zero;
+
+/// Returns the fractional bits of `bits`, the bits of a number with `frac_bits` fractional bits,
+/// or `None` if they do not fit in a `u128`.
+fn fraction<T: IntoDigits>(bits: T, frac_bits: u32) -> Option<u128> {
+    if let Some(bits) = bits.to_u128() {
+        return match 1_u128.checked_shl(frac_bits) {
+            Some(one) => Some(bits & (one - 1)),
+            None => Some(bits),
+        };
+    } else if frac_bits == 0 {
+        return Some(0);
+    }
+
+    // The bits of a wider type are reduced in it, under `2^frac_bits`.
+    let half = 1_u128.checked_shl(frac_bits - 1)?;
+    let one = T::from_u128(half)?.checked_mul(T::binary_radix())?;
+    (bits % one).to_u128()
+}
+
+/// An iterator over the digits of the fractional part of a fixed-point number, most significant
+/// first.
+///
+/// The iterator ends when the remaining fraction is 0, which never happens under an odd radix,
+/// unless the fraction is 0 to begin with.
+///
+/// # Example
+///
+/// ```
+/// use fixed::types::U8F8;
+/// use radixal::fixed_point::IntoFixedDigits;
+///
+/// let n = U8F8::from_num(0.75);
+/// let digits = n.into_frac_digits(3).unwrap();
+/// assert_eq!(digits.exact_len(), None);
+/// assert!(digits.take(4).eq([2, 0, 2, 0]));
+///
+/// let digits = n.into_frac_digits(6).unwrap();
+/// assert_eq!(digits.exact_len(), Some(2));
+/// assert!(digits.eq([4, 3]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FracDigits<T: IntoDigits> {
+    /// The remaining fraction, over `2^frac_bits`.
+    frac: u128,
+    frac_bits: u32,
+    radix: u128,
+    digit: PhantomData<T>,
+}
+
+impl<T: IntoDigits> FracDigits<T> {
+    /// Creates a new `FracDigits` for `frac`, over `2^frac_bits`, using `radix`.
+    fn new(frac: u128, frac_bits: u32, radix: T) -> Result<Self, RadixError> {
+        if radix == T::zero() {
+            return Err(RadixError::Radix0);
+        } else if radix == T::one() {
+            return Err(RadixError::Radix1);
+        }
+
+        Ok(FracDigits {
+            frac,
+            frac_bits,
+            radix: radix.to_u128().ok_or(RadixError::RadixTooLarge)?,
+            digit: PhantomData,
+        })
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        T::from_u128(self.radix).unwrap()
+    }
+
+    /// Returns the number of remaining digits, or `None` if they never end.
+    ///
+    /// A fraction `m / 2^k`, with `m` odd, needs `ceil(k / a)` digits under a radix `2^a * q`,
+    /// with `q` odd.
+    pub fn exact_len(&self) -> Option<usize> {
+        if self.frac == 0 {
+            return Some(0);
+        }
+
+        let factors = self.radix.trailing_zeros();
+        if factors == 0 {
+            return None;
+        }
+
+        let denominator = self.frac_bits - self.frac.trailing_zeros();
+        Some(denominator.div_ceil(factors) as usize)
+    }
+}
+
+impl<T: IntoDigits> Iterator for FracDigits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frac == 0 {
+            return None;
+        }
+
+        let (digit, frac) = next_digit(self.frac, self.frac_bits, self.radix);
+        self.frac = frac;
+        Some(T::from_u128(digit).unwrap())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.exact_len() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for FracDigits<T> {}
+
+/// An extension trait on the unsigned fixed-point numbers of `fixed`, mirroring
+/// [`IntoDigits`](../trait.IntoDigits.html) for their integer and fractional parts.
+///
+/// The radix has the type of the bits of the number, `u32` for a `FixedU32`.
+pub trait IntoFixedDigits: Sized {
+    /// The type of the bits of the number, and of its digits.
+    type Bits: IntoDigits;
+
+    /// Creates a `DigitsIterator` over the digits of the integer part, with a given `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    fn into_int_digits(self, radix: Self::Bits) -> Result<DigitsIterator<Self::Bits>, RadixError>;
+
+    /// Creates a `FracDigits` over the digits of the fractional part, with a given `radix`,
+    /// without the trailing zeroes.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1, or if the radix or the fractional
+    /// part does not fit in a `u128`.
+    fn into_frac_digits(self, radix: Self::Bits) -> Result<FracDigits<Self::Bits>, RadixError>;
+}
+
+impl<F> IntoFixedDigits for F
+where
+    F: FixedUnsigned,
+    <F as Fixed>::Bits: IntoDigits,
+{
+    type Bits = <F as Fixed>::Bits;
+
+    fn into_int_digits(self, radix: Self::Bits) -> Result<DigitsIterator<Self::Bits>, RadixError> {
+        let int = if F::INT_NBITS == 0 {
+            Self::Bits::zero()
+        } else {
+            self.to_bits() >> F::FRAC_NBITS
+        };
+        DigitsIterator::new(int, radix)
+    }
+
+    fn into_frac_digits(self, radix: Self::Bits) -> Result<FracDigits<Self::Bits>, RadixError> {
+        let frac = fraction(self.to_bits(), F::FRAC_NBITS).ok_or(RadixError::RadixTooLarge)?;
+        FracDigits::new(frac, F::FRAC_NBITS, radix)
+    }
+}
+
+/// Displays an unsigned fixed-point number as the digits of its integer part, then the digits
+/// of its fractional part after a `.`, using the symbols of an `Alphabet`.
+///
+/// The precision of the formatter, if any, is the number of fractional digits, truncated or
+/// padded with zeroes. Otherwise, all the fractional digits are written if they end, or as many
+/// as `radix^n >= 2^F` for `F` fractional bits if they do not, which is enough to tell apart
+/// every fractional part. The digits are truncated, not rounded.
+///
+/// # Example
+///
+/// ```
+/// use fixed::types::U16F16;
+/// use radixal::alphabet::Alphabet;
+/// use radixal::fixed_point::FixedDisplay;
+///
+/// let n = U16F16::from_num(3.25);
+/// assert_eq!(FixedDisplay::new(n, Alphabet::DECIMAL).to_string(), "3.25");
+/// assert_eq!(FixedDisplay::new(n, Alphabet::BINARY).to_string(), "11.01");
+/// assert_eq!(format!("{:.4}", FixedDisplay::new(n, Alphabet::DECIMAL)), "3.2500");
+/// assert_eq!(format!("{:.1}", FixedDisplay::new(n, Alphabet::DECIMAL)), "3.2");
+///
+/// // `3^11 >= 2^16`, so that 11 digits of the periodic expansion are written.
+/// let ternary = Alphabet::new(&['0', '1', '2']).unwrap();
+/// assert_eq!(FixedDisplay::new(n, ternary).to_string(), "10.02020202020");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedDisplay<'a, F> {
+    value: F,
+    alphabet: Alphabet<'a>,
+}
+
+impl<'a, F> FixedDisplay<'a, F>
+where
+    F: FixedUnsigned,
+    <F as Fixed>::Bits: IntoDigits,
+{
+    /// Creates a new `FixedDisplay` for `value` using `alphabet`.
+    pub fn new(value: F, alphabet: Alphabet<'a>) -> Self {
+        FixedDisplay { value, alphabet }
+    }
+
+    /// Returns the number being displayed.
+    pub fn value(&self) -> F {
+        self.value
+    }
+
+    /// Writes the symbol of `digit`.
+    fn write_digit<T: IntoDigits>(&self, f: &mut fmt::Formatter, digit: T) -> fmt::Result {
+        let symbol = digit
+            .to_usize()
+            .and_then(|digit| self.alphabet.symbol(digit))
+            .ok_or(fmt::Error)?;
+        f.write_char(symbol)
+    }
+}
+
+/// Returns the smallest `n` such that `radix^n >= 2^frac_bits`.
+fn frac_len(radix: u128, frac_bits: u32) -> usize {
+    let target = 1_u128.checked_shl(frac_bits);
+    let mut power = 1_u128;
+    let mut len = 0;
+    while target.is_some_and(|target| power < target) || (target.is_none() && power != 0) {
+        // Overflowing `u128` means reaching `2^128`.
+        power = power.checked_mul(radix).unwrap_or(0);
+        len += 1;
+    }
+    len
+}
+
+impl<'a, F> fmt::Display for FixedDisplay<'a, F>
+where
+    F: FixedUnsigned,
+    <F as Fixed>::Bits: IntoDigits,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Alphabets hold at most `u8::MAX` symbols, so the radix fits in any `Bits`.
+        let radix: <F as Fixed>::Bits =
+            RadixMath::from_usize(self.alphabet.radix()).ok_or(fmt::Error)?;
+        for digit in self.value.into_int_digits(radix).map_err(|_| fmt::Error)? {
+            self.write_digit(f, digit)?;
+        }
+
+        let digits = self.value.into_frac_digits(radix).map_err(|_| fmt::Error)?;
+        let len = match (f.precision(), digits.exact_len()) {
+            (Some(precision), _) => precision,
+            (None, Some(len)) => len,
+            (None, None) => frac_len(digits.radix, F::FRAC_NBITS),
+        };
+        if len > 0 {
+            f.write_char('.')?;
+        }
+
+        let zeroes = core::iter::repeat(<F as Fixed>::Bits::zero());
+        for digit in digits.chain(zeroes).take(len) {
+            self.write_digit(f, digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fixed::types::extra::{U0, U128, U4};
+    #[cfg(feature = "std")]
+    use fixed::{types::extra::U8, FixedU16};
+    use fixed::{FixedU128, FixedU8};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_every_u16() {
+        for bits in 0..=u16::MAX {
+            let n = FixedU16::<U8>::from_bits(bits);
+            assert!(n
+                .into_int_digits(10)
+                .unwrap()
+                .eq((bits >> 8).into_decimal_digits()));
+
+            // The decimal digits `d` of the fraction `m / 256` are `m * 10^len / 256`.
+            let digits = n.into_frac_digits(10).unwrap();
+            let len = digits.exact_len().unwrap();
+            assert_eq!(digits.size_hint(), (len, Some(len)));
+            let scaled = digits.fold(0_u64, |acc, digit| acc * 10 + u64::from(digit));
+            assert_eq!(
+                scaled,
+                u64::from(bits & 0xFF) * 10_u64.pow(len as u32) / 256
+            );
+            assert_eq!(
+                scaled * 256,
+                u64::from(bits & 0xFF) * 10_u64.pow(len as u32)
+            );
+
+            let digits: Vec<_> = n.into_frac_digits(16).unwrap().collect();
+            let expected: Vec<u16> = format!("{:02x}", bits & 0xFF)
+                .trim_end_matches('0')
+                .chars()
+                .map(|c| c.to_digit(16).unwrap() as u16)
+                .collect();
+            assert_eq!(digits, expected);
+        }
+    }
+
+    #[test]
+    fn test_odd_radices() {
+        let half = FixedU8::<U4>::from_num(0.5);
+        let digits = half.into_frac_digits(3).unwrap();
+        assert_eq!(digits.exact_len(), None);
+        assert_eq!(digits.size_hint(), (usize::MAX, None));
+        assert!(digits.take(6).eq([1; 6]));
+
+        let zero = FixedU8::<U4>::from_num(7);
+        assert_eq!(zero.into_frac_digits(3).unwrap().exact_len(), Some(0));
+        assert_eq!(half.into_frac_digits(1), Err(RadixError::Radix1));
+        assert_eq!(half.into_int_digits(0), Err(RadixError::Radix0));
+    }
+
+    #[test]
+    fn test_extreme_frac_bits() {
+        let n = FixedU128::<U128>::from_bits(1 << 127);
+        assert!(n.into_int_digits(10).unwrap().eq([0]));
+        assert!(n.into_frac_digits(10).unwrap().eq([5]));
+        let tiny = FixedU128::<U128>::from_bits(1);
+        assert_eq!(tiny.into_frac_digits(10).unwrap().count(), 128);
+        assert_eq!(
+            tiny.into_frac_digits(u128::MAX - 1).unwrap().exact_len(),
+            Some(128)
+        );
+        assert!(tiny.into_frac_digits(1 << 64).unwrap().eq([0, 1]));
+
+        let int = FixedU8::<U0>::from_num(255);
+        assert!(int.into_int_digits(16).unwrap().eq([15, 15]));
+        assert_eq!(int.into_frac_digits(10).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_wider_than_u128() {
+        use crate::digital::DigitalNumber;
+        use crate::digits_iterator::tests::Wide256;
+
+        let wide = |high, low| DigitalNumber(Wide256(high, low));
+        assert_eq!(fraction(wide(1, 5), 0), Some(0));
+        assert_eq!(fraction(wide(1, 5), 2), Some(1));
+        assert_eq!(fraction(wide(1, u128::MAX), 128), Some(u128::MAX));
+        assert_eq!(fraction(wide(1, 0), 129), None);
+        assert_eq!(
+            FracDigits::new(1, 4, wide(1, 0)),
+            Err(RadixError::RadixTooLarge)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display() {
+        let n = FixedU16::<U8>::from_num(10.5);
+        assert_eq!(FixedDisplay::new(n, Alphabet::DECIMAL).to_string(), "10.5");
+        assert_eq!(
+            format!("{:.0}", FixedDisplay::new(n, Alphabet::DECIMAL)),
+            "10"
+        );
+        assert_eq!(FixedDisplay::new(n, Alphabet::UPPER_HEX).to_string(), "A.8");
+        let int = FixedU8::<U0>::from_num(255);
+        assert_eq!(FixedDisplay::new(int, Alphabet::DECIMAL).to_string(), "255");
+
+        // `7^3 >= 256`
+        assert_eq!(frac_len(7, 8), 3);
+        let digits = Alphabet::new(&['0', '1', '2', '3', '4', '5', '6']).unwrap();
+        assert_eq!(FixedDisplay::new(n, digits).to_string(), "13.333");
+        assert_eq!(frac_len(3, 128), 81);
+        assert_eq!(frac_len(2, 128), 128);
+        assert_eq!(frac_len(u128::MAX, 128), 2);
+    }
+}
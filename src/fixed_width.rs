@@ -0,0 +1,412 @@
+//! Numbers with a fixed number of digits, such as `0042`.
+use crate::alphabet::Alphabet;
+use crate::digits_iterator::DigitsIterator;
+use crate::parse::ParseError;
+use crate::{IntoDigits, RadixMath};
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+
+/// Errors that can occur when creating a `FixedWidthDigits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixedWidthError {
+    /// The width is 0.
+    ZeroWidth,
+    /// Some numbers of this width do not fit in the integer type.
+    WidthTooLarge,
+    /// The value has more digits than the width.
+    ValueTooLarge,
+}
+
+impl fmt::Display for FixedWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FixedWidthError::ZeroWidth => write!(f, "width cannot be 0"),
+            FixedWidthError::WidthTooLarge => write!(f, "width too large for the integer type"),
+            FixedWidthError::ValueTooLarge => write!(f, "value too large for the width"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FixedWidthError {}
+
+/// A number with a fixed number of digits under the radix of an `Alphabet`, which keeps its
+/// leading zeroes.
+///
+/// The width is limited so that every number of that width fits in `T`; arithmetic then wraps
+/// around at `radix^width`, like an odometer.
+///
+/// Comparisons follow the order of the digits, as for strings: `042` is smaller than `05`, but
+/// larger than `0042`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::Alphabet;
+/// use radixal::fixed_width::FixedWidthDigits;
+///
+/// let mut n = FixedWidthDigits::<u32>::parse("0099", Alphabet::DECIMAL).unwrap();
+/// assert_eq!(n.value(), 99);
+///
+/// n.increment();
+/// assert_eq!(n.to_string(), "0100");
+/// assert_eq!(n.digits().collect::<Vec<_>>(), vec![0, 1, 0, 0]);
+///
+/// assert_eq!(n.wrapping_sub(101).to_string(), "9999");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedWidthDigits<'a, T: IntoDigits> {
+    value: T,
+    width: usize,
+    alphabet: Alphabet<'a>,
+}
+
+impl<'a, T: IntoDigits> FixedWidthDigits<'a, T> {
+    /// Creates a new `FixedWidthDigits` holding `value` over `width` digits under the radix of
+    /// `alphabet`.
+    ///
+    /// Returns an `Err(FixedWidthError)` if the width is 0, if some numbers of that width do not
+    /// fit in `T`, or if `value` has more digits than the width.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::fixed_width::{FixedWidthDigits, FixedWidthError};
+    ///
+    /// let n = FixedWidthDigits::new(42_u8, 2, Alphabet::DECIMAL).unwrap();
+    /// assert_eq!(n.to_string(), "42");
+    ///
+    /// // 999 does not fit in a `u8`.
+    /// assert_eq!(
+    ///     FixedWidthDigits::new(42_u8, 3, Alphabet::DECIMAL),
+    ///     Err(FixedWidthError::WidthTooLarge)
+    /// );
+    /// assert_eq!(
+    ///     FixedWidthDigits::new(42_u8, 1, Alphabet::DECIMAL),
+    ///     Err(FixedWidthError::ValueTooLarge)
+    /// );
+    /// ```
+    pub fn new(value: T, width: usize, alphabet: Alphabet<'a>) -> Result<Self, FixedWidthError> {
+        if width == 0 {
+            return Err(FixedWidthError::ZeroWidth);
+        }
+
+        let max = max(width, &alphabet).ok_or(FixedWidthError::WidthTooLarge)?;
+        if value > max {
+            return Err(FixedWidthError::ValueTooLarge);
+        }
+
+        Ok(FixedWidthDigits {
+            value,
+            width,
+            alphabet,
+        })
+    }
+
+    /// Parses `s` with `alphabet`, keeping its number of symbols as the width.
+    ///
+    /// Returns an `Err(ParseError)` if the string is empty, contains a symbol outside of the
+    /// alphabet or if some numbers of that width do not fit in `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::fixed_width::FixedWidthDigits;
+    /// use radixal::parse::ParseError;
+    ///
+    /// let n = FixedWidthDigits::<u16>::parse("0ff", Alphabet::LOWER_HEX).unwrap();
+    /// assert_eq!((n.value(), n.width()), (255, 3));
+    ///
+    /// assert_eq!(
+    ///     FixedWidthDigits::<u16>::parse("000ff", Alphabet::LOWER_HEX),
+    ///     Err(ParseError::Overflow)
+    /// );
+    /// ```
+    pub fn parse(s: &str, alphabet: Alphabet<'a>) -> Result<Self, ParseError> {
+        let width = s.chars().count();
+        let value = alphabet.parse(s)?;
+        FixedWidthDigits::new(value, width, alphabet).map_err(|err| match err {
+            FixedWidthError::ZeroWidth => ParseError::Empty,
+            FixedWidthError::WidthTooLarge | FixedWidthError::ValueTooLarge => ParseError::Overflow,
+        })
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the number of digits.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the alphabet, whose number of symbols is the radix.
+    pub fn alphabet(&self) -> Alphabet<'a> {
+        self.alphabet
+    }
+
+    /// Returns an iterator over all the digits, including the leading zeroes.
+    pub fn digits(&self) -> DigitsIterator<T> {
+        DigitsIterator::zero_padded(self.value, self.radix(), self.width)
+    }
+
+    /// Adds `rhs`, wrapping around at `radix^width`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::fixed_width::FixedWidthDigits;
+    ///
+    /// let n = FixedWidthDigits::new(0b110_u8, 3, Alphabet::BINARY).unwrap();
+    /// assert_eq!(n.wrapping_add(3).to_string(), "001");
+    /// assert_eq!(n.wrapping_add(255).to_string(), "101");
+    /// ```
+    pub fn wrapping_add(self, rhs: T) -> Self {
+        let max = self.max();
+        let rhs = self.reduce(rhs);
+        let value = if self.value > max - rhs {
+            self.value - (max - rhs) - T::one()
+        } else {
+            self.value + rhs
+        };
+
+        FixedWidthDigits { value, ..self }
+    }
+
+    /// Subtracts `rhs`, wrapping around at `radix^width`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::fixed_width::FixedWidthDigits;
+    ///
+    /// let n = FixedWidthDigits::new(1_u32, 3, Alphabet::DECIMAL).unwrap();
+    /// assert_eq!(n.wrapping_sub(2).to_string(), "999");
+    /// ```
+    pub fn wrapping_sub(self, rhs: T) -> Self {
+        let max = self.max();
+        let rhs = self.reduce(rhs);
+        let value = if self.value >= rhs {
+            self.value - rhs
+        } else {
+            self.value + (max - rhs) + T::one()
+        };
+
+        FixedWidthDigits { value, ..self }
+    }
+
+    /// Adds one, wrapping around to all zeroes.
+    pub fn increment(&mut self) {
+        *self = self.wrapping_add(T::one());
+    }
+
+    /// Subtracts one, wrapping around to the largest digit everywhere.
+    pub fn decrement(&mut self) {
+        *self = self.wrapping_sub(T::one());
+    }
+
+    fn radix(&self) -> T {
+        // Alphabets hold at most `u8::MAX` symbols, so the radix fits in any `T`.
+        T::from_usize(self.alphabet.radix()).unwrap()
+    }
+
+    fn max(&self) -> T {
+        // The width was checked on creation.
+        max(self.width, &self.alphabet).unwrap()
+    }
+
+    /// Reduces `rhs` modulo `radix^width`, which may not fit in `T`.
+    fn reduce(&self, rhs: T) -> T {
+        match self.max().checked_add(T::one()) {
+            Some(modulus) => rhs % modulus,
+            None => rhs,
+        }
+    }
+}
+
+/// Returns `radix^width - 1`, or `None` if it does not fit in `T`.
+fn max<T: IntoDigits>(width: usize, alphabet: &Alphabet) -> Option<T> {
+    let radix = T::from_usize(alphabet.radix())?;
+    let mut power = T::one();
+    for _ in 1..width {
+        power = power.checked_mul(radix)?;
+    }
+
+    // `radix^width - 1 = radix^(width - 1) * (radix - 1) + radix^(width - 1) - 1`
+    power
+        .checked_mul(radix - T::one())?
+        .checked_add(power - T::one())
+}
+
+impl<'a, T: IntoDigits> PartialOrd for FixedWidthDigits<'a, T> {
+    /// Compares the digits in order, as for strings.
+    ///
+    /// Numbers with different alphabets cannot be compared.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.alphabet != other.alphabet {
+            return None;
+        }
+
+        Some(self.digits().cmp(other.digits()))
+    }
+}
+
+impl<'a, T: IntoDigits> fmt::Display for FixedWidthDigits<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for digit in self.digits() {
+            let symbol = digit
+                .to_usize()
+                .and_then(|digit| self.alphabet.symbol(digit))
+                .ok_or(fmt::Error)?;
+            f.write_char(symbol)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'a, T: IntoDigits> defmt::Format for FixedWidthDigits<'a, T> {
+    fn format(&self, f: defmt::Formatter) {
+        for digit in self.digits() {
+            let symbol = digit
+                .to_usize()
+                .and_then(|digit| self.alphabet.symbol(digit))
+                .unwrap_or(char::REPLACEMENT_CHARACTER);
+            defmt::write!(f, "{=char}", symbol);
+        }
+    }
+}
+
+/// Serializes the value, the width and the alphabet, so that the leading zeroes are kept.
+#[cfg(feature = "serde")]
+impl<'a, T: IntoDigits + serde::Serialize> serde::Serialize for FixedWidthDigits<'a, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("FixedWidthDigits", 3)?;
+        state.serialize_field("value", &self.value)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("alphabet", &self.alphabet)?;
+        state.end()
+    }
+}
+
+/// Deserializes the value, the width and the alphabet, checking them as `new` does.
+///
+/// The alphabet is made of the symbols and the aliases of built-in alphabets, as for the
+/// deserialization of an `Alphabet`.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for FixedWidthDigits<'static, T>
+where
+    T: IntoDigits + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "FixedWidthDigits")]
+        struct Repr<T> {
+            value: T,
+            width: usize,
+            alphabet: Alphabet<'static>,
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+        FixedWidthDigits::new(repr.value, repr.width, repr.alphabet).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(s: &str) -> FixedWidthDigits<'static, u32> {
+        FixedWidthDigits::parse(s, Alphabet::DECIMAL).unwrap()
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_keeps_leading_zeroes() {
+        let n = FixedWidthDigits::<u16>::parse("00ff", Alphabet::LOWER_HEX).unwrap();
+        let json = serde_json::to_string(&n).unwrap();
+        let deserialized: FixedWidthDigits<u16> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, n);
+        assert_eq!(deserialized.to_string(), "00ff");
+
+        let json = json.replace("\"width\":4", "\"width\":1");
+        assert!(serde_json::from_str::<FixedWidthDigits<u16>>(&json).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_round_trip_keeps_zeroes() {
+        for &s in &["0", "0000", "0042", "4200", "999999999"] {
+            assert_eq!(decimal(s).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_maximum_width() {
+        assert!(FixedWidthDigits::new(0_u32, 9, Alphabet::DECIMAL).is_ok());
+        assert_eq!(
+            FixedWidthDigits::new(0_u32, 10, Alphabet::DECIMAL),
+            Err(FixedWidthError::WidthTooLarge)
+        );
+        assert!(FixedWidthDigits::new(u8::MAX, 8, Alphabet::BINARY).is_ok());
+        assert_eq!(
+            FixedWidthDigits::new(0_u8, 9, Alphabet::BINARY),
+            Err(FixedWidthError::WidthTooLarge)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_full_width_wraparound() {
+        let mut n = FixedWidthDigits::new(u8::MAX, 8, Alphabet::BINARY).unwrap();
+        n.increment();
+        assert_eq!(n.to_string(), "00000000");
+        assert_eq!(n.wrapping_sub(2).value(), 254);
+        assert_eq!(n.wrapping_add(u8::MAX).wrapping_add(3).value(), 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_wraparound() {
+        let mut n = decimal("999");
+        n.increment();
+        assert_eq!(n.to_string(), "000");
+        n.decrement();
+        assert_eq!(n.to_string(), "999");
+
+        assert_eq!(decimal("500").wrapping_add(u32::MAX).to_string(), "795");
+        assert_eq!(decimal("500").wrapping_sub(u32::MAX).to_string(), "205");
+    }
+
+    #[test]
+    fn test_comparisons_follow_digits() {
+        assert!(decimal("042") < decimal("05"));
+        assert!(decimal("042") > decimal("0042"));
+        assert!(decimal("042") < decimal("043"));
+        assert_ne!(decimal("042"), decimal("0042"));
+
+        let hex = FixedWidthDigits::parse("042", Alphabet::LOWER_HEX).unwrap();
+        assert_eq!(decimal("042").partial_cmp(&hex), None);
+    }
+
+    #[test]
+    fn test_digits_from_both_ends() {
+        let mut digits = decimal("0042").digits();
+        assert_eq!(digits.len(), 4);
+        assert_eq!(digits.next_back(), Some(2));
+        assert_eq!(digits.next(), Some(0));
+        assert_eq!(digits.next_back(), Some(4));
+        assert_eq!(digits.next(), Some(0));
+        assert_eq!(digits.next(), None);
+    }
+}
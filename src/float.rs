@@ -0,0 +1,599 @@
+//! Exact digits of the finite floating-point numbers `f32` and `f64`, split in their sign, integer
+//! part and fractional part.
+//!
+//! A finite float is `m * 2^e` for integers `m` and `e`, so that its fractional part is a
+//! fraction over a power of 2, whose expansion under a radix ends if and only if the radix is
+//! even, or the fraction is 0. The expansion is then exact, but long: the smallest positive `f64`
+//! has 1074 decimal digits after the point. Under an odd radix, the expansion never ends.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::float::IntoFloatDigits;
+//!
+//! let digits = 0.1_f64.into_float_digits(10).unwrap();
+//! assert!(!digits.is_negative());
+//! assert!(digits.int_digits().eq([0]));
+//!
+//! let frac: String = digits
+//!     .frac_digits()
+//!     .map(|digit| char::from_digit(digit, 10).unwrap())
+//!     .collect();
+//! assert_eq!(frac, "1000000000000000055511151231257827021181583404541015625");
+//! ```
+use crate::digits_iterator::RadixError;
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// The number of 32 bits limbs of a `Natural`, enough for a fraction over `2^1074`, the
+/// denominator of the smallest `f64`, times a `u32` radix.
+const LIMBS: usize = 36;
+
+/// The largest number of chunks of the integer part, below `2^1024`: a chunk holds a power of
+/// the radix above `2^16`.
+const CHUNKS: usize = 66;
+
+/// Errors that can occur when splitting a float in its digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatError {
+    /// The radix is 0 or 1.
+    Radix(RadixError),
+    /// The number is infinite or NaN.
+    NotFinite,
+}
+
+impl fmt::Display for FloatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FloatError::Radix(err) => err.fmt(f),
+            FloatError::NotFinite => write!(f, "number is not finite"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FloatError {}
+
+impl From<RadixError> for FloatError {
+    fn from(err: RadixError) -> Self {
+        FloatError::Radix(err)
+    }
+}
+
+/// A fixed-size natural number, as little-endian 32 bits limbs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Natural {
+    limbs: [u32; LIMBS],
+}
+
+impl Natural {
+    /// Returns `mantissa * 2^shift`, which must fit.
+    fn from_shifted(mantissa: u64, shift: u32) -> Self {
+        let mut limbs = [0; LIMBS];
+        let (index, offset) = ((shift / 32) as usize, shift % 32);
+        let wide = u128::from(mantissa) << offset;
+        for (i, limb) in limbs.iter_mut().skip(index).take(3).enumerate() {
+            *limb = (wide >> (32 * i)) as u32;
+        }
+
+        Natural { limbs }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&limb| limb == 0)
+    }
+
+    /// Returns the number of trailing zeroes, which is only meaningful if not 0.
+    fn trailing_zeros(&self) -> u32 {
+        self.limbs
+            .iter()
+            .position(|&limb| limb != 0)
+            .map_or(0, |index| {
+                index as u32 * 32 + self.limbs[index].trailing_zeros()
+            })
+    }
+
+    /// Divides by `divisor` in place, returning the remainder.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let divisor = u64::from(divisor);
+        let mut rem = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let acc = (rem << 32) | u64::from(*limb);
+            *limb = (acc / divisor) as u32;
+            rem = acc % divisor;
+        }
+
+        rem as u32
+    }
+
+    /// Multiplies by `factor` in place, the product having to fit.
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0;
+        for limb in self.limbs.iter_mut() {
+            let acc = u64::from(*limb) * u64::from(factor) + carry;
+            *limb = acc as u32;
+            carry = acc >> 32;
+        }
+        debug_assert_eq!(carry, 0);
+    }
+
+    /// Takes off the bits from `bit` up, below `2^(bit + 32)`, and returns them.
+    fn split_off(&mut self, bit: u32) -> u32 {
+        let (index, offset) = ((bit / 32) as usize, bit % 32);
+        let mut high = u64::from(self.limbs[index]) >> offset;
+        if let Some(&next) = self.limbs.get(index + 1) {
+            high |= u64::from(next) << (32 - offset);
+        }
+
+        self.limbs[index] &= (1_u32 << offset).wrapping_sub(1);
+        for limb in self.limbs.iter_mut().skip(index + 1) {
+            *limb = 0;
+        }
+        high as u32
+    }
+}
+
+/// An iterator over the digits of the integer part of a float, most significant first.
+///
+/// The integer part is converted once in chunks of digits, each one fitting in a `u32`, so that
+/// the digits come from both ends at no further cost.
+///
+/// # Example
+///
+/// ```
+/// use radixal::float::IntoFloatDigits;
+///
+/// let digits = 1e20_f64.into_float_digits(10).unwrap().int_digits();
+/// assert_eq!(digits.len(), 21);
+/// assert!(digits.rev().take(3).eq([0, 0, 0]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntDigits {
+    /// The chunks, least significant first, each one worth `radix^chunk_len`.
+    chunks: [u32; CHUNKS],
+    nbr_chunks: usize,
+    chunk_len: usize,
+    /// The number of digits of the most significant chunk, at least 1.
+    top_len: usize,
+    radix: u32,
+    front: usize,
+    back: usize,
+}
+
+impl IntDigits {
+    /// Creates a new `IntDigits` for `int`, using a valid `radix`.
+    fn new(mut int: Natural, radix: u32) -> Self {
+        let mut chunk_radix = radix;
+        let mut chunk_len = 1;
+        while let Some(next) = chunk_radix.checked_mul(radix) {
+            chunk_radix = next;
+            chunk_len += 1;
+        }
+
+        let mut chunks = [0; CHUNKS];
+        let mut nbr_chunks = 0;
+        loop {
+            chunks[nbr_chunks] = int.div_rem_small(chunk_radix);
+            nbr_chunks += 1;
+            if int.is_zero() {
+                break;
+            }
+        }
+
+        let mut top = chunks[nbr_chunks - 1] / radix;
+        let mut top_len = 1;
+        while top != 0 {
+            top /= radix;
+            top_len += 1;
+        }
+
+        IntDigits {
+            chunks,
+            nbr_chunks,
+            chunk_len,
+            top_len,
+            radix,
+            front: 0,
+            back: top_len + (nbr_chunks - 1) * chunk_len,
+        }
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Returns the digit at `position`, from the most significant one.
+    fn digit(&self, position: usize) -> u32 {
+        let (chunk, exponent) = if position < self.top_len {
+            (self.nbr_chunks - 1, self.top_len - 1 - position)
+        } else {
+            let position = position - self.top_len;
+            (
+                self.nbr_chunks - 2 - position / self.chunk_len,
+                self.chunk_len - 1 - position % self.chunk_len,
+            )
+        };
+
+        // The exponent is below the length of a chunk, so that the power fits.
+        self.chunks[chunk] / self.radix.pow(exponent as u32) % self.radix
+    }
+}
+
+impl Iterator for IntDigits {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let digit = self.digit(self.front);
+        self.front += 1;
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for IntDigits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.digit(self.back))
+    }
+}
+
+impl ExactSizeIterator for IntDigits {}
+
+impl FusedIterator for IntDigits {}
+
+/// An iterator over the digits of the fractional part of a float, most significant first.
+///
+/// The iterator ends when the remaining fraction is 0, which never happens under an odd radix,
+/// unless the fraction is 0 to begin with.
+///
+/// # Example
+///
+/// ```
+/// use radixal::float::IntoFloatDigits;
+///
+/// let digits = 0.75_f32.into_float_digits(3).unwrap().frac_digits();
+/// assert_eq!(digits.exact_len(), None);
+/// assert!(digits.take(4).eq([2, 0, 2, 0]));
+///
+/// let digits = 0.75_f32.into_float_digits(6).unwrap().frac_digits();
+/// assert_eq!(digits.exact_len(), Some(2));
+/// assert!(digits.eq([4, 3]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FracDigits {
+    /// The remaining fraction, over `2^frac_bits`.
+    frac: Natural,
+    frac_bits: u32,
+    radix: u32,
+}
+
+impl FracDigits {
+    /// Returns the radix.
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Returns the number of remaining digits, or `None` if they never end.
+    ///
+    /// A fraction `m / 2^k`, with `m` odd, needs `ceil(k / a)` digits under a radix `2^a * q`,
+    /// with `q` odd.
+    pub fn exact_len(&self) -> Option<usize> {
+        if self.frac.is_zero() {
+            return Some(0);
+        }
+
+        let factors = self.radix.trailing_zeros();
+        if factors == 0 {
+            return None;
+        }
+
+        let denominator = self.frac_bits - self.frac.trailing_zeros();
+        Some(denominator.div_ceil(factors) as usize)
+    }
+}
+
+impl Iterator for FracDigits {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frac.is_zero() {
+            return None;
+        }
+
+        // The fraction is below 1, so that the digit is below the radix.
+        self.frac.mul_small(self.radix);
+        Some(self.frac.split_off(self.frac_bits))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.exact_len() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for FracDigits {}
+
+/// The exact digits of a finite float under a radix: its sign, the digits of its integer part
+/// and the digits of its fractional part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FloatDigits {
+    negative: bool,
+    int: IntDigits,
+    frac: FracDigits,
+}
+
+impl FloatDigits {
+    /// Splits `mantissa * 2^exponent` in its digits under `radix`.
+    fn new(negative: bool, mantissa: u64, exponent: i32, radix: u32) -> Result<Self, RadixError> {
+        if radix == 0 {
+            return Err(RadixError::Radix0);
+        } else if radix == 1 {
+            return Err(RadixError::Radix1);
+        }
+
+        let (int, frac, frac_bits) = if exponent >= 0 {
+            (Natural::from_shifted(mantissa, exponent as u32), 0, 0)
+        } else {
+            let shift = exponent.unsigned_abs();
+            let (int, frac) = match 1_u64.checked_shl(shift) {
+                Some(one) => (mantissa >> shift, mantissa & (one - 1)),
+                None => (0, mantissa),
+            };
+            // Keeps the fraction in lowest terms, so that no digit is left to compute once it
+            // reaches 0.
+            let zeroes = if frac == 0 { 0 } else { frac.trailing_zeros() };
+            (
+                Natural::from_shifted(int, 0),
+                frac >> zeroes,
+                shift - zeroes,
+            )
+        };
+
+        Ok(FloatDigits {
+            negative,
+            int: IntDigits::new(int, radix),
+            frac: FracDigits {
+                frac: Natural::from_shifted(frac, 0),
+                frac_bits,
+                radix,
+            },
+        })
+    }
+
+    /// Splits the finite float of `bits`, made of a sign bit, `exponent_bits` biased exponent
+    /// bits and `mantissa_bits` explicit mantissa bits, in its digits under `radix`.
+    fn from_bits(
+        bits: u64,
+        exponent_bits: u32,
+        mantissa_bits: u32,
+        radix: u32,
+    ) -> Result<Self, FloatError> {
+        let negative = bits >> (exponent_bits + mantissa_bits) & 1 == 1;
+        let biased = (bits >> mantissa_bits) & ((1 << exponent_bits) - 1);
+        let mantissa = bits & ((1 << mantissa_bits) - 1);
+        if biased == (1 << exponent_bits) - 1 {
+            return Err(FloatError::NotFinite);
+        }
+
+        // Subnormal numbers have the exponent of the smallest normal numbers, without the
+        // implicit leading bit.
+        let bias = (1 << (exponent_bits - 1)) - 1;
+        let (mantissa, exponent) = if biased == 0 {
+            (mantissa, 1 - bias - mantissa_bits as i32)
+        } else {
+            (
+                mantissa | (1 << mantissa_bits),
+                biased as i32 - bias - mantissa_bits as i32,
+            )
+        };
+
+        Ok(FloatDigits::new(negative, mantissa, exponent, radix)?)
+    }
+
+    /// Returns `true` if the sign bit is set, including for `-0.0`.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns an iterator over the digits of the integer part, which is `[0]` for 0.
+    pub fn int_digits(&self) -> IntDigits {
+        self.int.clone()
+    }
+
+    /// Returns an iterator over the digits of the fractional part, without the trailing zeroes.
+    pub fn frac_digits(&self) -> FracDigits {
+        self.frac.clone()
+    }
+
+    /// Returns the sign, the digits of the integer part and the digits of the fractional part.
+    pub fn into_parts(self) -> (bool, IntDigits, FracDigits) {
+        (self.negative, self.int, self.frac)
+    }
+}
+
+/// An extension trait on `f32` and `f64`, splitting them in their exact digits.
+pub trait IntoFloatDigits: Copy {
+    /// Splits the number in its sign and the exact digits of its integer and fractional parts,
+    /// with a given `radix`.
+    ///
+    /// Returns an `Err(FloatError)` if the radix is 0 or 1, or if the number is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::float::{FloatError, IntoFloatDigits};
+    ///
+    /// let digits = (-2.5_f32).into_float_digits(2).unwrap();
+    /// assert!(digits.is_negative());
+    /// assert!(digits.int_digits().eq([1, 0]));
+    /// assert!(digits.frac_digits().eq([1]));
+    ///
+    /// assert_eq!(f64::NAN.into_float_digits(10), Err(FloatError::NotFinite));
+    /// ```
+    fn into_float_digits(self, radix: u32) -> Result<FloatDigits, FloatError>;
+}
+
+impl IntoFloatDigits for f32 {
+    fn into_float_digits(self, radix: u32) -> Result<FloatDigits, FloatError> {
+        FloatDigits::from_bits(u64::from(self.to_bits()), 8, 23, radix)
+    }
+}
+
+impl IntoFloatDigits for f64 {
+    fn into_float_digits(self, radix: u32) -> Result<FloatDigits, FloatError> {
+        FloatDigits::from_bits(self.to_bits(), 11, 52, radix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    #[cfg(feature = "std")]
+    /// Writes the digits under radix 10 as the standard library does with enough precision,
+    /// without the trailing zeroes.
+    fn to_decimal<F: IntoFloatDigits>(number: F) -> String {
+        let digits = number.into_float_digits(10).unwrap();
+        let mut s = String::new();
+        if digits.is_negative() {
+            s.push('-');
+        }
+        s.extend(
+            digits
+                .int_digits()
+                .map(|d| char::from_digit(d, 10).unwrap()),
+        );
+        if digits.frac_digits().exact_len() != Some(0) {
+            s.push('.');
+            s.extend(
+                digits
+                    .frac_digits()
+                    .map(|d| char::from_digit(d, 10).unwrap()),
+            );
+        }
+        s
+    }
+
+    #[cfg(feature = "std")]
+    fn std_decimal(s: String) -> String {
+        if s.contains('.') {
+            s.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            s
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_matches_std_formatting() {
+        for bits in (0..0x7f80_0000_u32).step_by(99_991) {
+            for &number in &[f32::from_bits(bits), -f32::from_bits(bits)] {
+                let expected = std_decimal(format!("{:.149}", number));
+                assert_eq!(to_decimal(number), expected, "{:e}", number);
+            }
+        }
+        for bits in (0..0x7ff0_0000_0000_0000_u64).step_by(0x0003_0000_0000_0001) {
+            let number = f64::from_bits(bits);
+            let expected = std_decimal(format!("{:.1074}", number));
+            assert_eq!(to_decimal(number), expected, "{:e}", number);
+        }
+
+        assert_eq!(to_decimal(0.1_f32), "0.100000001490116119384765625");
+        assert_eq!(to_decimal(-0.0_f64), "-0");
+        assert_eq!(to_decimal(f64::MAX).len(), 309);
+    }
+
+    #[test]
+    fn test_matches_into_digits() {
+        for &radix in &[2, 3, 7, 10, 16, 255, 1 << 16, u32::MAX] {
+            for number in (0..1_u64 << 53).step_by(0x0000_7654_3210_0001) {
+                let digits = (number as f64).into_float_digits(radix).unwrap();
+                let expected = number
+                    .into_digits(u64::from(radix))
+                    .unwrap()
+                    .map(|digit| digit as u32);
+                assert!(digits.int_digits().eq(expected.clone()));
+                assert!(digits.int_digits().rev().eq(expected.rev()));
+                assert_eq!(digits.frac_digits().count(), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        let max = f64::MAX.into_float_digits(2).unwrap();
+        assert_eq!(max.int_digits().len(), 1024);
+        assert_eq!(max.int_digits().filter(|&d| d == 1).count(), 53);
+        assert!(max.int_digits().take(53).all(|d| d == 1));
+
+        let tiny = f64::from_bits(1).into_float_digits(2).unwrap();
+        assert!(tiny.int_digits().eq([0]));
+        let frac = tiny.frac_digits();
+        assert_eq!(frac.exact_len(), Some(1074));
+        assert!(frac.clone().take(1073).all(|d| d == 0));
+        assert_eq!(frac.last(), Some(1));
+
+        let frac = tiny.frac_digits();
+        let tiny = f64::from_bits(1).into_float_digits(10).unwrap();
+        assert_eq!(tiny.frac_digits().count(), 1074);
+        assert_eq!(frac.radix(), 2);
+
+        let (negative, int, frac) = (-0.5_f64).into_float_digits(1 << 31).unwrap().into_parts();
+        assert!(negative);
+        assert!(int.eq([0]));
+        assert!(frac.eq([1 << 30]));
+        let digits = (2.0_f64).powi(62).into_float_digits(1 << 31).unwrap();
+        assert!(digits.int_digits().eq([1, 0, 0]));
+    }
+
+    #[test]
+    fn test_odd_radices() {
+        let digits = 0.5_f64.into_float_digits(3).unwrap();
+        let frac = digits.frac_digits();
+        assert_eq!(frac.exact_len(), None);
+        assert_eq!(frac.size_hint(), (usize::MAX, None));
+        assert!(frac.take(8).eq([1; 8]));
+        assert_eq!(digits.int_digits().radix(), 3);
+
+        let digits = 7.0_f32.into_float_digits(3).unwrap();
+        assert_eq!(digits.frac_digits().exact_len(), Some(0));
+        assert!(digits.int_digits().eq([2, 1]));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            1.0_f32.into_float_digits(0),
+            Err(FloatError::Radix(RadixError::Radix0))
+        );
+        assert_eq!(
+            1.0_f64.into_float_digits(1),
+            Err(FloatError::Radix(RadixError::Radix1))
+        );
+        assert_eq!(
+            f32::INFINITY.into_float_digits(10),
+            Err(FloatError::NotFinite)
+        );
+        assert_eq!(
+            f64::NEG_INFINITY.into_float_digits(10),
+            Err(FloatError::NotFinite)
+        );
+        assert_eq!(f32::NAN.into_float_digits(10), Err(FloatError::NotFinite));
+    }
+}
@@ -0,0 +1,1458 @@
+//! Formatting of numbers under the radix of an `Alphabet`.
+use crate::alphabet::{Alphabet, SUPERSCRIPT_DIGITS};
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::powers::RadixPowers;
+use crate::{IntoDigits, RadixMath, SignedInteger};
+use core::fmt::{self, Write};
+
+/// Displays a number as the sequence of its digits, using the symbols of an `Alphabet`.
+///
+/// The radix is the number of symbols in the alphabet.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::Alphabet;
+/// use radixal::format::DigitsDisplay;
+///
+/// let display = DigitsDisplay::new(1295_u32, Alphabet::BASE36);
+/// assert_eq!(format!("id-{}", display), "id-zz");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DigitsDisplay<'a, T: IntoDigits> {
+    number: T,
+    alphabet: Alphabet<'a>,
+    grouping: Option<Grouping>,
+    prefix: &'a str,
+    sign: Sign,
+}
+
+/// How the sign of a number is displayed.
+///
+/// The signs themselves are those of the alphabet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SignStyle {
+    /// Only negative numbers have a sign. This is the default, and the `+` flag of the formatter
+    /// turns it into `Always`.
+    #[default]
+    MinusOnly,
+    /// Every number has a sign, zero being positive.
+    Always,
+    /// Negative numbers are enclosed in parentheses, as in accounting.
+    Parentheses,
+}
+
+/// The sign of the displayed number, and how to write it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Sign {
+    negative: bool,
+    style: SignStyle,
+}
+
+impl Sign {
+    /// Returns the symbols to write before and after the number.
+    fn affixes(&self, sign_plus: bool, alphabet: &Alphabet) -> (Option<char>, Option<char>) {
+        match (self.style, self.negative) {
+            (SignStyle::Parentheses, true) => (Some('('), Some(')')),
+            (_, true) => (Some(alphabet.minus_sign()), None),
+            (SignStyle::Always, false) => (Some(alphabet.plus_sign()), None),
+            (SignStyle::MinusOnly, false) if sign_plus => (Some(alphabet.plus_sign()), None),
+            (_, false) => (None, None),
+        }
+    }
+}
+
+/// Separates the digits into groups of `size`, counted from the least significant digit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Grouping {
+    separator: char,
+    size: usize,
+}
+
+impl<'a, T: IntoDigits> DigitsDisplay<'a, T> {
+    /// Creates a new `DigitsDisplay` for `number` using `alphabet`.
+    pub fn new(number: T, alphabet: Alphabet<'a>) -> Self {
+        DigitsDisplay {
+            number,
+            alphabet,
+            grouping: None,
+            prefix: "",
+            sign: Sign::default(),
+        }
+    }
+
+    /// Creates a new `DigitsDisplay` for the signed `number` using `alphabet`, which displays
+    /// the digits of its magnitude after its sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::format::DigitsDisplay;
+    ///
+    /// let display = DigitsDisplay::new_signed(-255_i32, Alphabet::LOWER_HEX).with_prefix("0x");
+    /// assert_eq!(display.to_string(), "-0xff");
+    /// assert_eq!(format!("{:07}", display), "-0x00ff");
+    /// assert_eq!(display.number(), 255_u32);
+    /// ```
+    pub fn new_signed<S>(number: S, alphabet: Alphabet<'a>) -> Self
+    where
+        S: SignedInteger<Magnitude = T>,
+    {
+        DigitsDisplay {
+            sign: Sign {
+                negative: number.is_negative(),
+                style: SignStyle::MinusOnly,
+            },
+            ..DigitsDisplay::new(number.magnitude(), alphabet)
+        }
+    }
+
+    /// Uses `style` to display the sign.
+    ///
+    /// The width of the formatter counts the sign. When padding with zeroes (the `0` flag), the
+    /// padding digits are written after the sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::format::SignStyle;
+    ///
+    /// let display = Alphabet::DECIMAL.display_signed(-42_i32);
+    /// assert_eq!(display.with_sign(SignStyle::Parentheses).to_string(), "(42)");
+    /// assert_eq!(format!("{:>6}", display.with_sign(SignStyle::Parentheses)), "  (42)");
+    ///
+    /// let display = Alphabet::DECIMAL.display_signed(42_i32);
+    /// assert_eq!(display.with_sign(SignStyle::Always).to_string(), "+42");
+    /// assert_eq!(format!("{:+}", display), "+42");
+    /// assert_eq!(display.with_sign(SignStyle::Parentheses).to_string(), "42");
+    /// ```
+    pub fn with_sign(self, style: SignStyle) -> Self {
+        DigitsDisplay {
+            sign: Sign { style, ..self.sign },
+            ..self
+        }
+    }
+
+    /// Writes `prefix` before the digits.
+    ///
+    /// The width of the formatter counts the prefix. When padding with zeroes (the `0` flag), the
+    /// padding digits are written after the prefix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// let display = Alphabet::LOWER_HEX.display(255_u8).with_prefix("0x");
+    /// assert_eq!(display.to_string(), "0xff");
+    /// assert_eq!(format!("{:6}", display), "  0xff");
+    /// assert_eq!(format!("{:06}", display), "0x00ff");
+    /// ```
+    pub fn with_prefix(self, prefix: &'a str) -> Self {
+        DigitsDisplay { prefix, ..self }
+    }
+
+    /// Separates the digits into groups of `size` digits with `separator`, starting from the
+    /// least significant digit. A `size` of 0 disables grouping.
+    ///
+    /// The width of the formatter counts separators. When padding with zeroes (the `0` flag),
+    /// the padding digits are grouped as well, and the padding is never wider than the width: a
+    /// padding digit would not fit with its separator, so that the fill character pads the rest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::format::DigitsDisplay;
+    ///
+    /// let display = DigitsDisplay::new(1234567_u32, Alphabet::BASE36).with_grouping(',', 3);
+    /// assert_eq!(display.to_string(), "q,glj");
+    ///
+    /// let display = DigitsDisplay::new(1234567_u32, Alphabet::DECIMAL).with_grouping(',', 3);
+    /// assert_eq!(display.to_string(), "1,234,567");
+    /// assert_eq!(format!("{:>12}", display), "   1,234,567");
+    /// assert_eq!(format!("{:013}", display), "0,001,234,567");
+    /// assert_eq!(format!("{:012}", display), " 001,234,567");
+    /// ```
+    pub fn with_grouping(self, separator: char, size: usize) -> Self {
+        let grouping = if size == 0 {
+            None
+        } else {
+            Some(Grouping { separator, size })
+        };
+
+        DigitsDisplay { grouping, ..self }
+    }
+
+    /// Returns the number being displayed, or its magnitude for a signed number.
+    pub fn number(&self) -> T {
+        self.number
+    }
+
+    /// Returns the alphabet used for display.
+    pub fn alphabet(&self) -> Alphabet<'a> {
+        self.alphabet
+    }
+
+    /// Displays the number in scientific notation instead, with `significant` digits (at least
+    /// one) rounded to nearest, ties to even.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// let display = Alphabet::BASE36.display(1_000_000_u32);
+    /// assert_eq!(display.to_string(), "lfls");
+    /// assert_eq!(display.scientific(3).to_string(), "l.fme3");
+    /// assert_eq!(display.scientific(3).power_notation().to_string(), "l.fm×36^3");
+    /// ```
+    pub fn scientific(self, significant: usize) -> ScientificDisplay<'a, T> {
+        ScientificDisplay {
+            number: self.number,
+            alphabet: self.alphabet,
+            significant: significant.max(1),
+            engineering: false,
+            power_notation: false,
+            superscript: false,
+            rounding: RoundingMode::HalfEven,
+            sign: self.sign,
+        }
+    }
+
+    /// Returns the number of characters used to display `nbr_digits` digits, and `affixes`
+    /// signs.
+    fn rendered_len(&self, nbr_digits: usize, affixes: usize) -> usize {
+        let separators = match self.grouping {
+            Some(grouping) => (nbr_digits.max(1) - 1) / grouping.size,
+            None => 0,
+        };
+
+        affixes + self.prefix.chars().count() + nbr_digits + separators
+    }
+}
+
+impl<'a, T: IntoDigits> DigitsDisplay<'a, T> {
+    /// Marks the displayed number as negative or not, for signed numbers that are not primitives.
+    #[cfg(feature = "bigint")]
+    pub(crate) fn with_negative(self, negative: bool) -> Self {
+        DigitsDisplay {
+            sign: Sign {
+                negative,
+                ..self.sign
+            },
+            ..self
+        }
+    }
+
+    /// Writes `digits`, most significant first, with the options of this display instead of the
+    /// digits of its number.
+    pub(crate) fn fmt_digits<I>(&self, f: &mut fmt::Formatter, digits: I) -> fmt::Result
+    where
+        I: ExactSizeIterator<Item = T>,
+    {
+        let width = f.width().unwrap_or(0);
+        let (before_sign, after_sign) = self.sign.affixes(f.sign_plus(), &self.alphabet);
+        let affixes = before_sign.iter().chain(&after_sign).count();
+
+        // Zero padding adds leading digits, which are then grouped like the others, as long as
+        // they fit with their separators.
+        let mut nbr_digits = digits.len();
+        if f.sign_aware_zero_pad() {
+            while self.rendered_len(nbr_digits + 1, affixes) <= width {
+                nbr_digits += 1;
+            }
+        }
+
+        let padding = width.saturating_sub(self.rendered_len(nbr_digits, affixes));
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+
+        if let Some(sign) = before_sign {
+            f.write_char(sign)?;
+        }
+        f.write_str(self.prefix)?;
+
+        self.write_symbols(digits, nbr_digits, |symbol| {
+            f.write_char(symbol.ok_or(fmt::Error)?)
+        })?;
+
+        if let Some(sign) = after_sign {
+            f.write_char(sign)?;
+        }
+
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the symbols of `digits`, most significant first, after the leading zeroes making up
+    /// `nbr_digits` digits and with the separators of the groups, or `None` for a digit without
+    /// a symbol.
+    fn write_symbols<I, E, W>(&self, digits: I, nbr_digits: usize, mut write: W) -> Result<(), E>
+    where
+        I: ExactSizeIterator<Item = T>,
+        W: FnMut(Option<char>) -> Result<(), E>,
+    {
+        let zeroes = core::iter::repeat_n(T::zero(), nbr_digits - digits.len());
+        for (idx, digit) in zeroes.chain(digits).enumerate() {
+            if let Some(grouping) = self.grouping {
+                if idx > 0 && (nbr_digits - idx).is_multiple_of(grouping.size) {
+                    write(Some(grouping.separator))?;
+                }
+            }
+
+            write(symbol(&self.alphabet, digit))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T: IntoDigits> fmt::Display for DigitsDisplay<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Alphabets hold at most `u8::MAX` symbols, so the radix fits in any `T`.
+        let radix = T::from_usize(self.alphabet.radix()).ok_or(fmt::Error)?;
+        let digits = self.number.into_digits(radix).map_err(|_| fmt::Error)?;
+        self.fmt_digits(f, digits)
+    }
+}
+
+/// Writes the sign, the prefix and the digits with their groups; the width and the fill of
+/// `core::fmt` have no equivalent.
+#[cfg(feature = "defmt")]
+impl<'a, T: IntoDigits> defmt::Format for DigitsDisplay<'a, T> {
+    fn format(&self, f: defmt::Formatter) {
+        // Alphabets hold at least 2 and at most `u8::MAX` symbols, so the radix is valid for any
+        // `T`.
+        let radix = T::from_usize(self.alphabet.radix()).unwrap();
+        let digits = self.number.into_digits(radix).unwrap();
+        let (before_sign, after_sign) = self.sign.affixes(false, &self.alphabet);
+
+        if let Some(sign) = before_sign {
+            defmt::write!(f, "{=char}", sign);
+        }
+        defmt::write!(f, "{=str}", self.prefix);
+
+        let nbr_digits = digits.len();
+        let _ = self.write_symbols(digits, nbr_digits, |symbol| {
+            defmt::write!(f, "{=char}", symbol.unwrap_or(char::REPLACEMENT_CHARACTER));
+            Ok::<(), core::convert::Infallible>(())
+        });
+
+        if let Some(sign) = after_sign {
+            defmt::write!(f, "{=char}", sign);
+        }
+    }
+}
+
+/// Displays a number in scientific (or engineering) notation, using the symbols of an
+/// `Alphabet` for the mantissa.
+///
+/// The exponent, and the radix in power notation, are written in decimal.
+///
+/// This struct is created by the [`scientific`](struct.DigitsDisplay.html#method.scientific)
+/// method on `DigitsDisplay`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScientificDisplay<'a, T: IntoDigits> {
+    number: T,
+    alphabet: Alphabet<'a>,
+    significant: usize,
+    engineering: bool,
+    power_notation: bool,
+    superscript: bool,
+    rounding: RoundingMode,
+    sign: Sign,
+}
+
+impl<'a, T: IntoDigits> ScientificDisplay<'a, T> {
+    /// Uses engineering notation, where the exponent is a multiple of 3.
+    ///
+    /// If there are fewer significant digits than digits before the radix point, the mantissa is
+    /// padded with zeroes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// let display = Alphabet::BASE36.display(36_u32.pow(4) * 35);
+    /// assert_eq!(display.to_string(), "z0000");
+    /// assert_eq!(display.scientific(3).engineering().to_string(), "z0.0e3");
+    /// assert_eq!(display.scientific(1).engineering().to_string(), "z0e3");
+    /// ```
+    pub fn engineering(self) -> Self {
+        ScientificDisplay {
+            engineering: true,
+            ..self
+        }
+    }
+
+    /// Writes the exponent as `×r^k` instead of `ek`.
+    pub fn power_notation(self) -> Self {
+        ScientificDisplay {
+            power_notation: true,
+            ..self
+        }
+    }
+
+    /// Writes the exponent as `×r` followed by the exponent in superscript digits, as in `×10⁵`,
+    /// instead of `ek`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    ///
+    /// let display = Alphabet::DECIMAL.display(123_456_u32).scientific(3);
+    /// assert_eq!(display.superscript().to_string(), "1.23×10⁵");
+    /// assert_eq!(display.engineering().superscript().to_string(), "123×10³");
+    /// ```
+    pub fn superscript(self) -> Self {
+        ScientificDisplay {
+            power_notation: true,
+            superscript: true,
+            ..self
+        }
+    }
+
+    /// Uses `mode` to round the significant digits, instead of rounding to nearest, ties to
+    /// even.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::format::RoundingMode;
+    ///
+    /// let display = Alphabet::DECIMAL.display(125_u32).scientific(2);
+    /// assert_eq!(display.to_string(), "1.2e2");
+    /// assert_eq!(display.with_rounding(RoundingMode::HalfUp).to_string(), "1.3e2");
+    /// ```
+    pub fn with_rounding(self, mode: RoundingMode) -> Self {
+        ScientificDisplay {
+            rounding: mode,
+            ..self
+        }
+    }
+}
+
+impl<'a, T: IntoDigits> fmt::Display for ScientificDisplay<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let radix = T::from_usize(self.alphabet.radix()).ok_or(fmt::Error)?;
+        let rounded = round_significant(self.number, radix, self.significant, self.rounding)
+            .map_err(|_| fmt::Error)?;
+        let (mantissa, exponent) = (rounded.mantissa, rounded.exponent);
+        let (exponent, integer_digits) = if self.engineering {
+            (exponent - exponent % 3, exponent % 3 + 1)
+        } else {
+            (exponent, 1)
+        };
+
+        let (before_sign, after_sign) = self.sign.affixes(f.sign_plus(), &self.alphabet);
+        if let Some(sign) = before_sign {
+            f.write_char(sign)?;
+        }
+
+        let digits = mantissa.into_digits(radix).map_err(|_| fmt::Error)?;
+        let padding = self.significant.max(integer_digits) - digits.len();
+        let zeroes = core::iter::repeat_n(T::zero(), padding);
+        for (idx, digit) in digits.chain(zeroes).enumerate() {
+            if idx == integer_digits {
+                f.write_char('.')?;
+            }
+
+            f.write_char(symbol(&self.alphabet, digit).ok_or(fmt::Error)?)?;
+        }
+
+        if self.superscript {
+            let exponent = SUPERSCRIPT_DIGITS.display(exponent);
+            write!(f, "×{}{}", self.alphabet.radix(), exponent)?;
+        } else if self.power_notation {
+            write!(f, "×{}^{}", self.alphabet.radix(), exponent)?;
+        } else {
+            write!(f, "e{}", exponent)?;
+        }
+
+        match after_sign {
+            Some(sign) => f.write_char(sign),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'a, T: IntoDigits> defmt::Format for ScientificDisplay<'a, T> {
+    fn format(&self, f: defmt::Formatter) {
+        let radix = T::from_usize(self.alphabet.radix()).unwrap();
+        let rounded = match round_significant(self.number, radix, self.significant, self.rounding) {
+            Ok(rounded) => rounded,
+            Err(error) => return defmt::write!(f, "{}", error),
+        };
+        let (mantissa, exponent) = (rounded.mantissa, rounded.exponent);
+        let (exponent, integer_digits) = if self.engineering {
+            (exponent - exponent % 3, exponent % 3 + 1)
+        } else {
+            (exponent, 1)
+        };
+
+        let (before_sign, after_sign) = self.sign.affixes(false, &self.alphabet);
+        if let Some(sign) = before_sign {
+            defmt::write!(f, "{=char}", sign);
+        }
+
+        let digits = mantissa.into_digits(radix).unwrap();
+        let padding = self.significant.max(integer_digits) - digits.len();
+        let zeroes = core::iter::repeat_n(T::zero(), padding);
+        for (idx, digit) in digits.chain(zeroes).enumerate() {
+            if idx == integer_digits {
+                defmt::write!(f, ".");
+            }
+
+            let symbol = symbol(&self.alphabet, digit);
+            defmt::write!(f, "{=char}", symbol.unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+
+        if self.superscript {
+            let exponent = SUPERSCRIPT_DIGITS.display(exponent);
+            defmt::write!(f, "×{=usize}{}", self.alphabet.radix(), exponent);
+        } else if self.power_notation {
+            defmt::write!(f, "×{=usize}^{=usize}", self.alphabet.radix(), exponent);
+        } else {
+            defmt::write!(f, "e{=usize}", exponent);
+        }
+
+        if let Some(sign) = after_sign {
+            defmt::write!(f, "{=char}", sign);
+        }
+    }
+}
+
+/// How to round a number to fewer digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to nearest, ties away from zero.
+    HalfUp,
+    /// Rounds to nearest, ties to an even last digit.
+    HalfEven,
+    /// Truncates the extra digits, which is the floor of the unsigned numbers.
+    TowardZero,
+    /// Rounds up, away from zero, unless the extra digits are all zeroes.
+    Ceil,
+}
+
+/// A number rounded to a number of significant digits.
+///
+/// This struct is created by the [`round_significant`](fn.round_significant.html) function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rounded<T: IntoDigits> {
+    mantissa: T,
+    radix: T,
+    exponent: usize,
+}
+
+impl<T: IntoDigits> Rounded<T> {
+    /// Returns the significant digits, as a number.
+    ///
+    /// Trailing zeroes are kept, so that it has as many digits as requested, unless the number
+    /// itself had fewer digits.
+    pub fn mantissa(&self) -> T {
+        self.mantissa
+    }
+
+    /// Returns the exponent of the most significant digit, as in scientific notation.
+    pub fn exponent(&self) -> usize {
+        self.exponent
+    }
+
+    /// Returns an iterator over the significant digits.
+    pub fn digits(&self) -> DigitsIterator<T> {
+        // The radix was checked on creation.
+        self.mantissa.into_digits(self.radix).unwrap()
+    }
+
+    /// Returns the rounded number, the mantissa followed by zero digits up to the exponent, or
+    /// `None` if it does not fit in `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::format::{round_significant, RoundingMode};
+    ///
+    /// let rounded = round_significant(12_345_u32, 10, 2, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(rounded.value(), Some(12_000));
+    ///
+    /// let rounded = round_significant(251_u8, 10, 2, RoundingMode::Ceil).unwrap();
+    /// assert_eq!((rounded.mantissa(), rounded.exponent()), (26, 2));
+    /// assert_eq!(rounded.value(), None);
+    /// ```
+    pub fn value(&self) -> Option<T> {
+        // The mantissa was checked to have at most `exponent + 1` digits.
+        let zeroes = self.exponent + 1 - self.mantissa.nbr_digits(self.radix).unwrap();
+        (0..zeroes).try_fold(self.mantissa, |value, _| {
+            crate::parse::push_digit(value, self.radix, T::zero())
+        })
+    }
+}
+
+/// Rounds `number` to `significant` digits (at least one) under `radix`, following `mode`.
+///
+/// The rounded number is the mantissa followed by `exponent + 1 - nbr_digits(mantissa)` zero
+/// digits. Rounding up may add a digit to the number, e.g. 999 rounded to 2 digits is 1.0e3.
+///
+/// Returns an `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::format::{round_significant, RoundingMode};
+/// use radixal::IntoDigits;
+///
+/// // 0x2c0 is 0b10_1100_0000, halfway between 0b10_1000_0000 and 0b11_0000_0000.
+/// let rounded = round_significant(0x2c0_u32, 2, 3, RoundingMode::HalfUp).unwrap();
+/// assert_eq!((rounded.mantissa(), rounded.exponent()), (0b110, 9));
+///
+/// let rounded = round_significant(0x2c0_u32, 2, 3, RoundingMode::TowardZero).unwrap();
+/// assert_eq!(rounded.digits().collect::<Vec<_>>(), vec![1, 0, 1]);
+///
+/// let rounded = round_significant(999_u32, 10, 2, RoundingMode::HalfEven).unwrap();
+/// assert_eq!((rounded.mantissa(), rounded.exponent()), (10, 3));
+/// ```
+pub fn round_significant<T: IntoDigits>(
+    number: T,
+    radix: T,
+    significant: usize,
+    mode: RoundingMode,
+) -> Result<Rounded<T>, RadixError> {
+    let significant = significant.max(1);
+    let powers = RadixPowers::new(radix)?;
+    let len = powers.nbr_digits(number);
+    if len <= significant {
+        return Ok(Rounded {
+            mantissa: number,
+            radix,
+            exponent: len - 1,
+        });
+    }
+
+    // `radix^(len - significant)` is at most `number`, so it is in the table.
+    let power = powers.as_slice()[len - significant];
+
+    let mut mantissa = number / power;
+    let remainder = number % power;
+    let complement = power - remainder;
+    let round_up = match mode {
+        RoundingMode::HalfUp => remainder >= complement,
+        RoundingMode::HalfEven => {
+            let odd = mantissa % (T::one() + T::one()) == T::one();
+            remainder > complement || (remainder == complement && odd)
+        }
+        RoundingMode::TowardZero => false,
+        RoundingMode::Ceil => !remainder.is_zero(),
+    };
+    if round_up {
+        mantissa = mantissa + T::one();
+    }
+
+    // Rounding up may carry into a new digit (e.g. 999 to 1000).
+    let (mantissa, exponent) = if mantissa.nbr_digits(radix)? > significant {
+        (mantissa / radix, len)
+    } else {
+        (mantissa, len - 1)
+    };
+
+    Ok(Rounded {
+        mantissa,
+        radix,
+        exponent,
+    })
+}
+
+/// Formats a number under a given radix, so that it can be used with the traits and flags of
+/// `core::fmt`.
+///
+/// * `Display` writes the digits under the radix, with the lowercase letters `a-z` as the digits
+///   from 10 to 35.
+/// * `Binary`, `Octal`, `LowerHex` and `UpperHex` write the digits under their own radix,
+///   regardless of the one given, and the alternate flag (`#`) adds the `0b`, `0o` or `0x`
+///   prefix.
+///
+/// All of them honor the width, fill, alignment and zero padding flags.
+///
+/// # Example
+///
+/// ```
+/// use radixal::format::WithRadix;
+///
+/// let n = WithRadix::new(1295_u32, 36).unwrap();
+///
+/// assert_eq!(format!("{}", n), "zz");
+/// assert_eq!(format!("{:>4}|{:<4}|{:^6}", n, n, n), "  zz|zz  |  zz  ");
+/// assert_eq!(format!("{:#06x}", n), "0x050f");
+/// assert_eq!(format!("{:#b}", n), "0b10100001111");
+/// assert_eq!(format!("{:o} {:X}", n, n), "2417 50F");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WithRadix<T: IntoDigits> {
+    number: T,
+    radix: T,
+}
+
+impl<T: IntoDigits> WithRadix<T> {
+    /// Creates a new `WithRadix` for `number` using `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0, 1 or larger than 36.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits_iterator::RadixError;
+    /// use radixal::format::WithRadix;
+    ///
+    /// assert_eq!(WithRadix::new(1_u32, 1), Err(RadixError::Radix1));
+    /// assert_eq!(WithRadix::new(1_u32, 37), Err(RadixError::RadixTooLarge));
+    /// ```
+    pub fn new(number: T, radix: T) -> Result<Self, RadixError> {
+        if radix == T::zero() {
+            return Err(RadixError::Radix0);
+        } else if radix == T::one() {
+            return Err(RadixError::Radix1);
+        } else if T::from_usize(Alphabet::BASE36.radix()).is_some_and(|max| radix > max) {
+            return Err(RadixError::RadixTooLarge);
+        }
+
+        Ok(WithRadix { number, radix })
+    }
+
+    /// Returns the number being formatted.
+    pub fn number(&self) -> T {
+        self.number
+    }
+
+    /// Returns the radix used by `Display`.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    fn fmt_with(&self, f: &mut fmt::Formatter, alphabet: Alphabet, prefix: &str) -> fmt::Result {
+        let prefix = if f.alternate() { prefix } else { "" };
+        fmt::Display::fmt(
+            &DigitsDisplay::new(self.number, alphabet).with_prefix(prefix),
+            f,
+        )
+    }
+}
+
+impl<T: IntoDigits> fmt::Display for WithRadix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The radix was checked to be at most 36 on creation.
+        let radix = self.radix.to_usize().ok_or(fmt::Error)?;
+        let symbols = Alphabet::BASE36.symbols();
+        self.fmt_with(f, Alphabet::from_symbols(&symbols[..radix]), "")
+    }
+}
+
+impl<T: IntoDigits> fmt::Binary for WithRadix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with(f, Alphabet::BINARY, "0b")
+    }
+}
+
+impl<T: IntoDigits> fmt::Octal for WithRadix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with(f, Alphabet::OCTAL, "0o")
+    }
+}
+
+impl<T: IntoDigits> fmt::LowerHex for WithRadix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with(f, Alphabet::LOWER_HEX, "0x")
+    }
+}
+
+impl<T: IntoDigits> fmt::UpperHex for WithRadix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with(f, Alphabet::UPPER_HEX, "0x")
+    }
+}
+
+/// Writes the number under its radix, without the prefixes of the alternate forms.
+#[cfg(feature = "defmt")]
+impl<T: IntoDigits> defmt::Format for WithRadix<T> {
+    fn format(&self, f: defmt::Formatter) {
+        // The radix was checked to be at most 36 on creation.
+        let radix = self.radix.to_usize().unwrap();
+        let symbols = Alphabet::BASE36.symbols();
+        DigitsDisplay::new(self.number, Alphabet::from_symbols(&symbols[..radix])).format(f)
+    }
+}
+
+/// Returns the symbol representing `digit` in `alphabet`.
+fn symbol<T: IntoDigits>(alphabet: &Alphabet, digit: T) -> Option<char> {
+    digit.to_usize().and_then(|digit| alphabet.symbol(digit))
+}
+
+/// Errors that can occur when writing digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WriteError<E> {
+    /// The radix is invalid, or larger than the number of symbols of the alphabet.
+    Radix(RadixError),
+    /// The underlying writer failed.
+    Write(E),
+}
+
+impl<E: fmt::Display> fmt::Display for WriteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WriteError::Radix(err) => err.fmt(f),
+            WriteError::Write(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error> std::error::Error for WriteError<E> {}
+
+/// Checks that `radix` is valid and that `alphabet` has a symbol for each of its digits.
+pub(crate) fn check_radix<T: IntoDigits>(radix: T, alphabet: &Alphabet) -> Result<(), RadixError> {
+    if radix == T::zero() {
+        Err(RadixError::Radix0)
+    } else if radix == T::one() {
+        Err(RadixError::Radix1)
+    } else if radix
+        .to_usize()
+        .is_none_or(|radix| radix > alphabet.radix())
+    {
+        Err(RadixError::RadixTooLarge)
+    } else {
+        Ok(())
+    }
+}
+
+/// Writes the digits of `number` under `radix` to `writer`, using the first `radix` symbols of
+/// `alphabet`.
+///
+/// Symbols are streamed to the writer one at a time, without any allocation.
+///
+/// Returns an `Err(WriteError::Radix)` if the radix is 0, 1 or larger than the number of
+/// symbols of the alphabet, and an `Err(WriteError::Write)` if the writer fails.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::Alphabet;
+/// use radixal::digits_iterator::RadixError;
+/// use radixal::format::{write_digits, WriteError};
+///
+/// let mut s = String::new();
+/// write_digits(&mut s, 255_u8, 16, &Alphabet::BASE36).unwrap();
+/// assert_eq!(s, "ff");
+///
+/// assert_eq!(
+///     write_digits(&mut s, 255_u8, 17, &Alphabet::LOWER_HEX),
+///     Err(WriteError::Radix(RadixError::RadixTooLarge))
+/// );
+/// ```
+pub fn write_digits<W, T>(
+    writer: &mut W,
+    number: T,
+    radix: T,
+    alphabet: &Alphabet,
+) -> Result<(), WriteError<fmt::Error>>
+where
+    W: fmt::Write,
+    T: IntoDigits,
+{
+    check_radix(radix, alphabet).map_err(WriteError::Radix)?;
+    let digits = number.into_digits(radix).map_err(WriteError::Radix)?;
+
+    for digit in digits {
+        let symbol = symbol(alphabet, digit).ok_or(WriteError::Write(fmt::Error))?;
+        writer.write_char(symbol).map_err(WriteError::Write)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the digits of `number` under `radix` to `writer`, using the first `radix` symbols of
+/// `alphabet`.
+///
+/// Symbols are encoded into a small buffer on the stack, which is written to the writer each
+/// time it fills up, so that the digits never go through a `String`. With the `bigint` feature,
+/// [`bigint::write_digits_io`](../bigint/fn.write_digits_io.html) does the same for a `BigUint`.
+///
+/// Returns an `Err(WriteError::Radix)` if the radix is 0, 1 or larger than the number of
+/// symbols of the alphabet, and an `Err(WriteError::Write)` if the writer fails.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::Alphabet;
+/// use radixal::format::write_digits_io;
+///
+/// let mut bytes = Vec::new();
+/// write_digits_io(&mut bytes, 2024_u32, 10, &Alphabet::EASTERN_ARABIC).unwrap();
+/// assert_eq!(String::from_utf8(bytes).unwrap(), "٢٠٢٤");
+/// ```
+#[cfg(feature = "std")]
+pub fn write_digits_io<W, T>(
+    writer: &mut W,
+    number: T,
+    radix: T,
+    alphabet: &Alphabet,
+) -> Result<(), WriteError<std::io::Error>>
+where
+    W: std::io::Write,
+    T: IntoDigits,
+{
+    check_radix(radix, alphabet).map_err(WriteError::Radix)?;
+    let digits = number.into_digits(radix).map_err(WriteError::Radix)?;
+    write_symbols_io(writer, digits, alphabet)
+}
+
+/// Writes the symbols of `digits` to `writer` through a buffer on the stack.
+///
+/// The caller must ensure that the radix of the digits is valid for `alphabet`.
+#[cfg(feature = "std")]
+pub(crate) fn write_symbols_io<W, T, I>(
+    writer: &mut W,
+    digits: I,
+    alphabet: &Alphabet,
+) -> Result<(), WriteError<std::io::Error>>
+where
+    W: std::io::Write,
+    T: IntoDigits,
+    I: Iterator<Item = T>,
+{
+    const BUFFER_LEN: usize = 256;
+
+    let mut buffer = [0_u8; BUFFER_LEN];
+    let mut len = 0;
+    for digit in digits {
+        let symbol = symbol(alphabet, digit).ok_or_else(|| {
+            WriteError::Write(std::io::Error::from(std::io::ErrorKind::InvalidData))
+        })?;
+
+        if len + symbol.len_utf8() > BUFFER_LEN {
+            writer
+                .write_all(&buffer[..len])
+                .map_err(WriteError::Write)?;
+            len = 0;
+        }
+        len += symbol.encode_utf8(&mut buffer[len..]).len();
+    }
+
+    writer.write_all(&buffer[..len]).map_err(WriteError::Write)
+}
+
+/// The largest number of digits of a primitive type, a `u128` under radix 2.
+const BUFFER_CAPACITY: usize = 128;
+
+/// A buffer on the stack to format numbers into, without any allocation.
+///
+/// It is large enough for any primitive type under any radix from 2 to 36, written with the
+/// digits `0-9` followed by the lowercase letters `a-z`. The buffer can be reused, each call to
+/// [`format`](#method.format) overwriting the previous number.
+///
+/// # Example
+///
+/// ```
+/// use radixal::format::Buffer;
+///
+/// let mut buffer = Buffer::new();
+/// assert_eq!(buffer.format(255_u8, 16), Ok("ff"));
+/// assert_eq!(buffer.format(u128::MAX, 2).map(str::len), Ok(128));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Buffer {
+    bytes: [u8; BUFFER_CAPACITY],
+}
+
+impl Buffer {
+    /// Creates a new `Buffer`.
+    pub fn new() -> Self {
+        Buffer {
+            bytes: [0; BUFFER_CAPACITY],
+        }
+    }
+
+    /// Writes the digits of `number` under `radix` into the buffer, and returns them.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0, 1 or larger than 36, or if it is too small
+    /// for the digits to fit in the buffer, which only happens for types wider than 128 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits_iterator::RadixError;
+    /// use radixal::format::Buffer;
+    ///
+    /// let mut buffer = Buffer::new();
+    /// assert_eq!(buffer.format(1295_u32, 36), Ok("zz"));
+    /// assert_eq!(buffer.format(0_u32, 10), Ok("0"));
+    /// assert_eq!(buffer.format(1295_u32, 37), Err(RadixError::RadixTooLarge));
+    /// ```
+    pub fn format<T: IntoDigits>(&mut self, number: T, radix: T) -> Result<&str, RadixError> {
+        check_radix(radix, &Alphabet::BASE36)?;
+
+        // Least significant digits first, from the end of the buffer.
+        let digits = number.into_digits(radix)?;
+        if digits.len() > BUFFER_CAPACITY {
+            return Err(RadixError::RadixTooSmall);
+        }
+
+        let bytes = &mut self.bytes;
+        let start = digits.rfold(BUFFER_CAPACITY, |start, digit| {
+            bytes[start - 1] = BASE36_DIGITS[digit.to_usize().unwrap_or_default()];
+            start - 1
+        });
+
+        // The digits are ASCII.
+        Ok(core::str::from_utf8(&self.bytes[start..]).unwrap_or_default())
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Buffer::new()
+    }
+}
+
+const BASE36_DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::digital::DigitalNumber;
+    use crate::digits_iterator::tests::Wide256;
+    use core::num::Wrapping;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display_zero() {
+        assert_eq!(Alphabet::BASE36.display(0_u8).to_string(), "0");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display_wrapping() {
+        assert_eq!(Alphabet::BASE36.display(Wrapping(35_u8)).to_string(), "z");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_grouping_hex() {
+        let display = Alphabet::LOWER_HEX
+            .display(0xdead_beef_u32)
+            .with_grouping('_', 4);
+        assert_eq!(display.to_string(), "dead_beef");
+        for width in 0..20 {
+            let padded = format!("{:01$}", display, width);
+            assert_eq!(padded.chars().count(), width.max(9));
+            assert!(!padded.trim_start().starts_with('_'));
+        }
+        assert_eq!(format!("{:014}", display), "0000_dead_beef");
+        // Zero padding never starts with a separator, so that a space pads the width.
+        assert_eq!(format!("{:010}", display), " dead_beef");
+        assert_eq!(format!("{:011}", display), "0_dead_beef");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_grouping_single_group() {
+        let display = Alphabet::BASE36.display(35_u8).with_grouping(',', 3);
+        assert_eq!(display.to_string(), "z");
+        assert_eq!(format!("{:<3}|", display), "z  |");
+        assert_eq!(format!("{:^5}|", display), "  z  |");
+        assert_eq!(format!("{:*>3}", display), "**z");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_signed_extremes() {
+        assert_eq!(
+            Alphabet::DECIMAL.display_signed(i8::MIN).to_string(),
+            "-128"
+        );
+        assert_eq!(
+            Alphabet::LOWER_HEX.display_signed(i128::MIN).to_string(),
+            "-80000000000000000000000000000000"
+        );
+        assert_eq!(
+            Alphabet::DECIMAL
+                .display_signed(Wrapping(-5_i16))
+                .to_string(),
+            "-5"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_zero_is_positive() {
+        let display = Alphabet::DECIMAL.display_signed(0_i32);
+        assert_eq!(display.to_string(), "0");
+        assert_eq!(display.with_sign(SignStyle::Always).to_string(), "+0");
+        assert_eq!(display.with_sign(SignStyle::Parentheses).to_string(), "0");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parentheses_with_padding_and_grouping() {
+        let display = Alphabet::DECIMAL
+            .display_signed(-1234567_i32)
+            .with_grouping(',', 3)
+            .with_sign(SignStyle::Parentheses);
+        assert_eq!(display.to_string(), "(1,234,567)");
+        assert_eq!(format!("{:<13}|", display), "(1,234,567)  |");
+        assert_eq!(format!("{:015}", display), "(0,001,234,567)");
+        assert_eq!(format!("{:014}", display), " (001,234,567)");
+        for width in 0..20 {
+            let padded = format!("{:01$}", display, width);
+            assert_eq!(padded.chars().count(), width.max(11));
+        }
+    }
+
+    #[cfg(all(feature = "std", feature = "alphabets"))]
+    #[test]
+    fn test_alphabet_signs() {
+        let display = Alphabet::FULLWIDTH.display_signed(7_i8);
+        assert_eq!(format!("{:+}", display), "＋７");
+        assert_eq!(
+            format!("{:+}", display.with_sign(SignStyle::Parentheses)),
+            "７"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_signed_scientific() {
+        let display = Alphabet::DECIMAL.display_signed(-123_456_i32);
+        assert_eq!(display.scientific(3).to_string(), "-1.23e5");
+        assert_eq!(
+            display
+                .with_sign(SignStyle::Parentheses)
+                .scientific(2)
+                .to_string(),
+            "(1.2e5)"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_buffer_matches_display() {
+        let mut buffer = Buffer::new();
+        for radix in 2..=36_u64 {
+            for &n in &[0, 1, radix - 1, radix, 1234567, u64::MAX] {
+                let expected = WithRadix::new(n, radix).unwrap().to_string();
+                assert_eq!(buffer.format(n, radix), Ok(expected.as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_buffer_widest() {
+        let mut buffer = Buffer::default();
+        assert_eq!(buffer.format(u128::MAX, 2), Ok("1".repeat(128).as_str()));
+        assert_eq!(buffer.format(Wrapping(5_u8), Wrapping(2)), Ok("101"));
+        assert_eq!(buffer.format(5_u8, 1), Err(RadixError::Radix1));
+    }
+
+    #[test]
+    fn test_buffer_wider_than_u128() {
+        let radix = |radix| DigitalNumber(Wide256(0, radix));
+        let mut buffer = Buffer::new();
+        assert_eq!(
+            buffer.format(Wide256::MAX, radix(2)),
+            Err(RadixError::RadixTooSmall)
+        );
+        assert_eq!(buffer.format(Wide256::MAX, radix(4)).map(str::len), Ok(128));
+        assert_eq!(
+            buffer.format(Wide256::MAX, radix(36)),
+            Ok("6dp5qcb22im238nr3wvp0ic7q99w035jmy2iw7i6n43d37jtof")
+        );
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let round = |number: u32, radix, mode| {
+            let rounded = round_significant(number, radix, 2, mode).unwrap();
+            (rounded.mantissa(), rounded.exponent())
+        };
+
+        // Ties.
+        assert_eq!(round(125, 10, RoundingMode::HalfUp), (13, 2));
+        assert_eq!(round(125, 10, RoundingMode::HalfEven), (12, 2));
+        assert_eq!(round(135, 10, RoundingMode::HalfEven), (14, 2));
+        assert_eq!(round(129, 10, RoundingMode::TowardZero), (12, 2));
+        // 0x1f8 is halfway between 0x1f0 and 0x200.
+        assert_eq!(round(0x1f8, 16, RoundingMode::HalfUp), (0x20, 2));
+        assert_eq!(round(0x1f8, 16, RoundingMode::HalfEven), (0x20, 2));
+        assert_eq!(round(0x1e8, 16, RoundingMode::HalfEven), (0x1e, 2));
+        assert_eq!(round(0x1ff, 16, RoundingMode::TowardZero), (0x1f, 2));
+        assert_eq!(round(0x1f1, 16, RoundingMode::Ceil), (0x20, 2));
+        assert_eq!(round(0x1f0, 16, RoundingMode::Ceil), (0x1f, 2));
+        assert_eq!(round(121, 10, RoundingMode::Ceil), (13, 2));
+        assert_eq!(round(199, 10, RoundingMode::Ceil), (20, 2));
+        assert_eq!(round(0b1011_0001, 2, RoundingMode::Ceil), (0b11, 7));
+    }
+
+    #[test]
+    fn test_rounded_values() {
+        let modes = [
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+            RoundingMode::TowardZero,
+            RoundingMode::Ceil,
+        ];
+        for &radix in &[2_u32, 3, 10, 16] {
+            for number in (0..100_000).step_by(7) {
+                for significant in 1..=6 {
+                    for &mode in &modes {
+                        let rounded = round_significant(number, radix, significant, mode).unwrap();
+                        let value = rounded.value().unwrap();
+                        let len = number.nbr_digits(radix).unwrap();
+                        let step = radix.pow(len.saturating_sub(significant) as u32);
+                        assert_eq!(value % step, 0);
+                        assert!(value.abs_diff(number) < step);
+                        match mode {
+                            RoundingMode::TowardZero => assert!(value <= number),
+                            RoundingMode::Ceil => assert!(value >= number),
+                            _ => assert!(2 * value.abs_diff(number) <= step),
+                        }
+                    }
+                }
+            }
+        }
+
+        let rounded = round_significant(u8::MAX, 2, 2, RoundingMode::HalfUp).unwrap();
+        assert_eq!((rounded.mantissa(), rounded.exponent()), (0b10, 8));
+        assert_eq!(rounded.value(), None);
+        assert_eq!(
+            255_u8.round_to_significant(2, RoundingMode::Ceil, 10),
+            Ok(None)
+        );
+        assert_eq!(
+            255_u8.round_to_significant(2, RoundingMode::Ceil, 16),
+            Ok(Some(255))
+        );
+    }
+
+    #[test]
+    fn test_rounding_keeps_short_numbers() {
+        let rounded = round_significant(7_u8, 10, 3, RoundingMode::HalfUp).unwrap();
+        assert_eq!((rounded.mantissa(), rounded.exponent()), (7, 0));
+        assert_eq!(
+            round_significant(u8::MAX, 1, 3, RoundingMode::HalfUp),
+            Err(RadixError::Radix1)
+        );
+        let rounded = round_significant(u128::MAX, 2, 1, RoundingMode::HalfUp).unwrap();
+        assert_eq!((rounded.mantissa(), rounded.exponent()), (1, 128));
+    }
+
+    #[cfg(feature = "std")]
+    fn scientific(number: u32, significant: usize) -> String {
+        Alphabet::DECIMAL
+            .display(number)
+            .scientific(significant)
+            .to_string()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_scientific_rounding() {
+        assert_eq!(scientific(123_456, 3), "1.23e5");
+        assert_eq!(scientific(123_556, 3), "1.24e5");
+        // Ties to even.
+        assert_eq!(scientific(125, 2), "1.2e2");
+        assert_eq!(scientific(135, 2), "1.4e2");
+        assert_eq!(scientific(1251, 2), "1.3e3");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_scientific_carry() {
+        assert_eq!(scientific(999, 2), "1.0e3");
+        assert_eq!(scientific(u32::MAX, 3), "4.29e9");
+        assert_eq!(scientific(u32::MAX, 1), "4e9");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_scientific_padding() {
+        assert_eq!(scientific(0, 3), "0.00e0");
+        assert_eq!(scientific(7, 1), "7e0");
+        assert_eq!(scientific(12, 4), "1.200e1");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_engineering() {
+        let display = |n: u64, significant| {
+            Alphabet::DECIMAL
+                .display(n)
+                .scientific(significant)
+                .engineering()
+                .to_string()
+        };
+
+        assert_eq!(display(123_456, 3), "123e3");
+        assert_eq!(display(123_456, 2), "120e3");
+        assert_eq!(display(12_345, 4), "12.34e3");
+        assert_eq!(display(12_355, 4), "12.36e3");
+        assert_eq!(display(999_999, 2), "1.0e6");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_with_radix_agrees_with_core() {
+        for &n in &[0_u64, 1, 7, 8, 255, 256, 1 << 40, u64::MAX] {
+            let with_radix = WithRadix::new(n, 10).unwrap();
+            assert_eq!(format!("{}", with_radix), format!("{}", n));
+            assert_eq!(format!("{:#b}", with_radix), format!("{:#b}", n));
+            assert_eq!(format!("{:o}", with_radix), format!("{:o}", n));
+            assert_eq!(format!("{:#x}", with_radix), format!("{:#x}", n));
+            assert_eq!(format!("{:X}", with_radix), format!("{:X}", n));
+            assert_eq!(format!("{:*^12}", with_radix), format!("{:*^12}", n));
+            assert_eq!(format!("{:#024x}", with_radix), format!("{:#024x}", n));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_with_radix_wrapping() {
+        let n = WithRadix::new(Wrapping(200_u8), Wrapping(16)).unwrap();
+        assert_eq!(format!("{} {:x}", n, n), "c8 c8");
+        assert_eq!(
+            WithRadix::new(Wrapping(200_u8), Wrapping(255)),
+            Err(RadixError::RadixTooLarge)
+        );
+    }
+
+    /// A writer into a fixed buffer, as could be used in a panic handler.
+    struct FixedWriter {
+        bytes: [u8; 8],
+        len: usize,
+    }
+
+    impl fmt::Write for FixedWriter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            if end > self.bytes.len() {
+                return Err(fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_digits_into_fixed_buffer() {
+        let mut buffer = FixedWriter {
+            bytes: [0; 8],
+            len: 0,
+        };
+        write_digits(&mut buffer, 0xbeef_u16, 16, &Alphabet::UPPER_HEX).unwrap();
+        assert_eq!(&buffer.bytes[..buffer.len], b"BEEF");
+
+        assert_eq!(
+            write_digits(&mut buffer, 0x12345_u32, 16, &Alphabet::UPPER_HEX),
+            Err(WriteError::Write(fmt::Error))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_digits_radix_errors() {
+        let mut s = String::new();
+        assert_eq!(
+            write_digits(&mut s, 1_u8, 0, &Alphabet::DECIMAL),
+            Err(WriteError::Radix(RadixError::Radix0))
+        );
+        assert_eq!(
+            write_digits(&mut s, 1_u8, 1, &Alphabet::DECIMAL),
+            Err(WriteError::Radix(RadixError::Radix1))
+        );
+        assert!(s.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    /// A writer recording the size of each write.
+    struct Recorder {
+        bytes: Vec<u8>,
+        writes: Vec<usize>,
+    }
+
+    #[cfg(feature = "std")]
+    impl std::io::Write for Recorder {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.bytes.extend_from_slice(buf);
+            self.writes.push(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_digits_io_buffers_output() {
+        let mut recorder = Recorder {
+            bytes: Vec::new(),
+            writes: Vec::new(),
+        };
+        write_digits_io(&mut recorder, u128::MAX, 2, &Alphabet::BINARY).unwrap();
+        assert_eq!(recorder.bytes, format!("{:b}", u128::MAX).into_bytes());
+        assert_eq!(recorder.writes, vec![128]);
+    }
+
+    #[cfg(all(feature = "std", feature = "alphabets"))]
+    #[test]
+    fn test_write_digits_io_multibyte_symbols() {
+        // Each fullwidth digit takes 3 bytes, which do not divide the buffer evenly.
+        let mut recorder = Recorder {
+            bytes: Vec::new(),
+            writes: Vec::new(),
+        };
+        write_digits_io(&mut recorder, u128::MAX, 2, &Alphabet::FULLWIDTH).unwrap();
+        assert_eq!(recorder.writes, vec![255, 129]);
+
+        let s = String::from_utf8(recorder.bytes).unwrap();
+        assert_eq!(s.chars().count(), 128);
+        assert!(s.chars().all(|c| c == '１'));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_write_digits_io_radix_error() {
+        let mut bytes = Vec::new();
+        assert!(matches!(
+            write_digits_io(&mut bytes, 1_u8, 11, &Alphabet::DECIMAL),
+            Err(WriteError::Radix(RadixError::RadixTooLarge))
+        ));
+        assert!(bytes.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display_u128_max() {
+        assert_eq!(
+            Alphabet::BASE36.display(u128::MAX).to_string(),
+            "f5lxx1zz5pnorynqglhzmsp33"
+        );
+    }
+}
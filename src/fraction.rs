@@ -0,0 +1,457 @@
+//! Digits after the radix point of fractions over a power of 2, such as the fractional parts of
+//! fixed-point numbers.
+//!
+//! A fraction `m / 2^k` in `[0, 1)` has an expansion that ends under an even radix, after at
+//! most `k` digits, and repeats forever under an odd radix. `FractionDigits` yields the digits
+//! one at a time, and can stop after a maximum number of digits, rounding the last one.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::format::RoundingMode;
+//! use radixal::fraction::FractionDigits;
+//!
+//! // 0.8125 = 13/16
+//! let digits = FractionDigits::new(13_u8, 4, 10).unwrap();
+//! assert!(digits.eq([8, 1, 2, 5]));
+//! assert!(digits.with_max_len(2, RoundingMode::HalfUp).eq([8, 1]));
+//! assert!(digits.with_max_len(3, RoundingMode::HalfEven).eq([8, 1, 2]));
+//! assert!(digits.with_max_len(3, RoundingMode::HalfUp).eq([8, 1, 3]));
+//! ```
+use crate::digits_iterator::RadixError;
+use crate::format::RoundingMode;
+use crate::{IntoDigits, RadixMath};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Errors that can occur when creating a `FractionDigits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FractionError {
+    /// The radix is 0 or 1.
+    Radix(RadixError),
+    /// There are more than 128 fractional bits.
+    FracBitsTooLarge,
+    /// The numerator is not below `2^frac_bits`, so that the fraction is not below 1.
+    NotAFraction,
+}
+
+impl fmt::Display for FractionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FractionError::Radix(err) => err.fmt(f),
+            FractionError::FracBitsTooLarge => write!(f, "more than 128 fractional bits"),
+            FractionError::NotAFraction => write!(f, "fraction not below 1"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FractionError {}
+
+impl From<RadixError> for FractionError {
+    fn from(err: RadixError) -> Self {
+        FractionError::Radix(err)
+    }
+}
+
+/// Returns the 256 bits product of `a` and `b`, as its high and low halves.
+pub(crate) fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a_high, a_low) = (a >> 64, a & u128::from(u64::MAX));
+    let (b_high, b_low) = (b >> 64, b & u128::from(u64::MAX));
+
+    // The middle products are worth `2^64`, and their sum may carry a 129th bit.
+    let (middle, carry) = (a_high * b_low).overflowing_add(a_low * b_high);
+    let (low, carry_low) = (a_low * b_low).overflowing_add(middle << 64);
+    let high = a_high * b_high + (middle >> 64) + (u128::from(carry) << 64) + u128::from(carry_low);
+    (high, low)
+}
+
+/// Returns the next digit and the remaining fraction of `frac`, a non-zero fraction over
+/// `2^frac_bits`, under `radix`.
+pub(crate) fn next_digit(frac: u128, frac_bits: u32, radix: u128) -> (u128, u128) {
+    // The fraction is not 0, so that `0 < frac_bits <= 128`.
+    let (high, low) = widening_mul(frac, radix);
+    if frac_bits == u128::BITS {
+        (high, low)
+    } else {
+        (
+            (high << (u128::BITS - frac_bits)) | (low >> frac_bits),
+            low & ((1 << frac_bits) - 1),
+        )
+    }
+}
+
+/// An iterator over the digits of a fraction over a power of 2, most significant first.
+///
+/// The iterator ends when the remaining fraction is 0, which never happens under an odd radix,
+/// unless the fraction is 0 to begin with, or after the maximum number of digits set by
+/// `with_max_len`. Trailing zeroes are left out, including those left by rounding up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FractionDigits<T: IntoDigits> {
+    /// The remaining fraction, over `2^frac_bits`.
+    frac: u128,
+    frac_bits: u32,
+    radix: u128,
+    /// The number of digits yielded so far.
+    index: usize,
+    /// The index the digits stop at, if any.
+    end: Option<usize>,
+    /// The index of the digit incremented by rounding up, if any.
+    round_up_at: Option<usize>,
+    carry: bool,
+    digit: PhantomData<T>,
+}
+
+impl<T: IntoDigits> FractionDigits<T> {
+    /// Creates a new `FractionDigits` for `numerator / 2^frac_bits`, with a given `radix`.
+    ///
+    /// Returns an `Err(FractionError)` if the radix is 0, 1 or does not fit in a `u128`, if there
+    /// are more than 128 fractional bits, or if the numerator is not below `2^frac_bits`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::fraction::{FractionDigits, FractionError};
+    ///
+    /// let digits = FractionDigits::new(1_u32, 1, 3).unwrap();
+    /// assert_eq!(digits.exact_len(), None);
+    /// assert!(digits.take(4).eq([1, 1, 1, 1]));
+    ///
+    /// assert_eq!(FractionDigits::new(4_u32, 2, 10), Err(FractionError::NotAFraction));
+    /// ```
+    pub fn new(numerator: T, frac_bits: u32, radix: T) -> Result<Self, FractionError> {
+        if radix == T::zero() {
+            return Err(RadixError::Radix0.into());
+        } else if radix == T::one() {
+            return Err(RadixError::Radix1.into());
+        } else if frac_bits > u128::BITS {
+            return Err(FractionError::FracBitsTooLarge);
+        }
+
+        // A numerator which does not fit in a `u128` is not below `2^128`.
+        let frac = numerator
+            .to_u128()
+            .filter(|frac| frac.checked_shr(frac_bits).unwrap_or(0) == 0)
+            .ok_or(FractionError::NotAFraction)?;
+        let radix = radix.to_u128().ok_or(RadixError::RadixTooLarge)?;
+
+        Ok(FractionDigits {
+            frac,
+            frac_bits,
+            radix,
+            index: 0,
+            end: None,
+            round_up_at: None,
+            carry: false,
+            digit: PhantomData,
+        })
+    }
+
+    /// Creates a new `FractionDigits` for the fractional part of the fixed-point number
+    /// `value`, with a given `radix`.
+    ///
+    /// Returns an `Err(FractionError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixed::types::U8F8;
+    /// use radixal::fraction::FractionDigits;
+    ///
+    /// let digits = FractionDigits::from_fixed(U8F8::from_num(12.375), 10).unwrap();
+    /// assert!(digits.eq([3, 7, 5]));
+    /// ```
+    #[cfg(feature = "fixed")]
+    pub fn from_fixed<F>(value: F, radix: T) -> Result<Self, FractionError>
+    where
+        F: fixed::traits::FixedUnsigned<Bits = T>,
+    {
+        FractionDigits::new(value.frac().to_bits(), F::FRAC_NBITS, radix)
+    }
+
+    /// Stops after `len` more digits at most, rounding the last one following `mode`.
+    ///
+    /// Rounding up increments the last digit, carrying over the largest digits before it, which
+    /// then become trailing zeroes and are left out. If every digit kept is the largest one, or
+    /// if none is kept, the fraction rounds up to 1: no digit is left, and `carry` returns
+    /// `true`. Ties are rounded down under `HalfEven` when no digit is kept, as the last digit
+    /// is then the one of the integer part.
+    ///
+    /// Finding the rounding takes as many steps as the digits kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::format::RoundingMode;
+    /// use radixal::fraction::FractionDigits;
+    ///
+    /// // 0.99609375 = 255/256
+    /// let digits = FractionDigits::new(255_u8, 8, 10).unwrap();
+    /// let rounded = digits.with_max_len(4, RoundingMode::HalfUp);
+    /// assert!(rounded.eq([9, 9, 6, 1]));
+    ///
+    /// let rounded = digits.with_max_len(2, RoundingMode::HalfUp);
+    /// assert!(rounded.carry());
+    /// assert_eq!(rounded.count(), 0);
+    /// ```
+    pub fn with_max_len(self, len: usize, mode: RoundingMode) -> Self {
+        let largest = self.radix - 1;
+        let (mut frac, mut count) = (self.frac, 0);
+        let (mut last, mut last_not_zero, mut last_not_largest) = (0, 0, None);
+        while count < len && frac != 0 {
+            let (digit, next) = next_digit(frac, self.frac_bits, self.radix);
+            if digit != 0 {
+                last_not_zero = count + 1;
+            }
+            if digit != largest {
+                last_not_largest = Some(count);
+            }
+            last = digit;
+            frac = next;
+            count += 1;
+        }
+
+        // A remaining fraction is not 0, so that there is at least one fractional bit.
+        let round_up = frac != 0 && {
+            let half = 1 << (self.frac_bits - 1);
+            match mode {
+                RoundingMode::HalfUp => frac >= half,
+                RoundingMode::HalfEven => frac > half || (frac == half && last & 1 == 1),
+                RoundingMode::TowardZero => false,
+                RoundingMode::Ceil => true,
+            }
+        };
+
+        let (end, round_up_at, carry) = match (round_up, last_not_largest) {
+            (false, _) => (self.index + last_not_zero, None, false),
+            (true, Some(at)) => (self.index + at + 1, Some(self.index + at), false),
+            (true, None) => (self.index, None, true),
+        };
+        FractionDigits {
+            end: Some(end),
+            round_up_at,
+            carry,
+            ..self
+        }
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        T::from_u128(self.radix).unwrap()
+    }
+
+    /// Returns `true` if the fraction rounded up to 1 with `with_max_len`, so that the integer
+    /// part has to be incremented.
+    pub fn carry(&self) -> bool {
+        self.carry
+    }
+
+    /// Returns the number of remaining digits, or `None` if they never end.
+    ///
+    /// Without a maximum number of digits, a fraction `m / 2^k`, with `m` odd, needs
+    /// `ceil(k / a)` digits under a radix `2^a * q`, with `q` odd.
+    pub fn exact_len(&self) -> Option<usize> {
+        if let Some(end) = self.end {
+            return Some(if self.frac == 0 { 0 } else { end - self.index });
+        } else if self.frac == 0 {
+            return Some(0);
+        }
+
+        let factors = self.radix.trailing_zeros();
+        if factors == 0 {
+            return None;
+        }
+
+        let denominator = self.frac_bits - self.frac.trailing_zeros();
+        Some(denominator.div_ceil(factors) as usize)
+    }
+}
+
+impl<T: IntoDigits> Iterator for FractionDigits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frac == 0 || self.end == Some(self.index) {
+            return None;
+        }
+
+        let (digit, frac) = next_digit(self.frac, self.frac_bits, self.radix);
+        let digit = if self.round_up_at == Some(self.index) {
+            digit + 1
+        } else {
+            digit
+        };
+        self.frac = frac;
+        self.index += 1;
+        Some(T::from_u128(digit).unwrap())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.exact_len() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for FractionDigits<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    /// Expands `numerator / 2^16` over `len` decimal digits with integer arithmetic.
+    fn expand(numerator: u128, len: u32, mode: RoundingMode) -> (Vec<u128>, bool) {
+        let power = 10_u128.pow(len);
+        let (quotient, remainder) = ((numerator * power) >> 16, (numerator * power) & 0xffff);
+        let round_up = match mode {
+            RoundingMode::HalfUp => remainder >= 0x8000,
+            RoundingMode::HalfEven => {
+                remainder > 0x8000 || (remainder == 0x8000 && quotient % 2 == 1)
+            }
+            RoundingMode::TowardZero => false,
+            RoundingMode::Ceil => remainder != 0,
+        };
+        let rounded = quotient + u128::from(round_up);
+        if rounded == power {
+            return (Vec::new(), true);
+        }
+
+        let mut digits: Vec<_> = (0..len)
+            .rev()
+            .map(|exponent| rounded / 10_u128.pow(exponent) % 10)
+            .collect();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        (digits, false)
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_matches_integer_rounding() {
+        let modes = [
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+            RoundingMode::TowardZero,
+            RoundingMode::Ceil,
+        ];
+        for numerator in (0..=u16::MAX).step_by(7).chain([0x8000, 0xffff]) {
+            let digits = FractionDigits::new(numerator, 16, 10).unwrap();
+            let (exact, _) = expand(u128::from(numerator), 16, RoundingMode::TowardZero);
+            assert!(digits.eq(exact.iter().map(|&d| d as u16)));
+            assert_eq!(digits.exact_len(), Some(exact.len()));
+
+            for len in 0..=6 {
+                for &mode in &modes {
+                    let rounded = digits.with_max_len(len, mode);
+                    let (expected, carry) = expand(u128::from(numerator), len as u32, mode);
+                    assert_eq!(rounded.carry(), carry, "{} {} {:?}", numerator, len, mode);
+                    assert_eq!(rounded.exact_len(), Some(expected.len()));
+                    assert!(
+                        rounded.eq(expected.iter().map(|&d| d as u16)),
+                        "{} {} {:?}",
+                        numerator,
+                        len,
+                        mode
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_odd_radices() {
+        let half = FractionDigits::new(1_u8, 1, 3).unwrap();
+        assert_eq!(half.size_hint(), (usize::MAX, None));
+        assert!(half
+            .with_max_len(4, RoundingMode::TowardZero)
+            .eq([1, 1, 1, 1]));
+        // 0.5 is a tie after every digit, which is odd.
+        assert!(half
+            .with_max_len(4, RoundingMode::HalfEven)
+            .eq([1, 1, 1, 2]));
+        assert!(half.with_max_len(4, RoundingMode::HalfUp).eq([1, 1, 1, 2]));
+        assert!(!half.with_max_len(0, RoundingMode::HalfEven).carry());
+        assert!(half.with_max_len(0, RoundingMode::HalfUp).carry());
+
+        // 0.75 = 0.2020… in radix 3, rounded from the middle of the digits.
+        let mut digits = FractionDigits::new(3_u8, 2, 3).unwrap();
+        assert_eq!(digits.next(), Some(2));
+        let rounded = digits.with_max_len(3, RoundingMode::HalfUp);
+        assert_eq!(rounded.exact_len(), Some(3));
+        assert!(rounded.eq([0, 2, 1]));
+    }
+
+    #[test]
+    fn test_extreme_frac_bits() {
+        let digits = FractionDigits::new(u128::MAX, 128, 2).unwrap();
+        assert_eq!(digits.exact_len(), Some(128));
+        assert!(digits.eq([1; 128]));
+        let rounded = digits.with_max_len(4, RoundingMode::HalfUp);
+        assert!(rounded.carry());
+        assert_eq!(rounded.count(), 0);
+
+        let digits = FractionDigits::new(1_u128, 128, 1 << 64).unwrap();
+        assert!(digits.eq([0, 1]));
+        assert_eq!(digits.radix(), 1 << 64);
+        let digits = FractionDigits::new(0_u8, 0, 10).unwrap();
+        assert_eq!(digits.exact_len(), Some(0));
+        assert_eq!(digits.count(), 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            FractionDigits::new(1_u8, 4, 0),
+            Err(FractionError::Radix(RadixError::Radix0))
+        );
+        assert_eq!(
+            FractionDigits::new(1_u8, 4, 1),
+            Err(FractionError::Radix(RadixError::Radix1))
+        );
+        assert_eq!(
+            FractionDigits::new(1_u8, 129, 10),
+            Err(FractionError::FracBitsTooLarge)
+        );
+        assert_eq!(
+            FractionDigits::new(16_u8, 4, 10),
+            Err(FractionError::NotAFraction)
+        );
+        assert_eq!(
+            FractionDigits::new(1_u8, 0, 10),
+            Err(FractionError::NotAFraction)
+        );
+    }
+
+    #[test]
+    fn test_wider_than_u128() {
+        use crate::digital::DigitalNumber;
+        use crate::digits_iterator::tests::Wide256;
+
+        let wide = |high, low| DigitalNumber(Wide256(high, low));
+        assert_eq!(
+            FractionDigits::new(wide(1, 0), 128, wide(0, 10)),
+            Err(FractionError::NotAFraction)
+        );
+        assert_eq!(
+            FractionDigits::new(wide(0, 1), 4, wide(1, 0)),
+            Err(FractionError::Radix(RadixError::RadixTooLarge))
+        );
+        let digits = FractionDigits::new(wide(0, 1), 2, wide(0, 10)).unwrap();
+        assert!(digits.map(|digit| digit.0 .1).eq([2, 5]));
+    }
+
+    #[test]
+    fn test_widening_mul() {
+        assert_eq!(widening_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(widening_mul(1 << 127, 2), (1, 0));
+        assert_eq!(widening_mul(u128::MAX, 10), (9, u128::MAX - 9));
+
+        let (a, b) = (0x1234_5678_9ABC_DEF0_u128, 0xFEDC_BA98_7654_3210_u128);
+        assert_eq!(widening_mul(a << 64, b), ((a * b) >> 64, (a * b) << 64));
+        assert_eq!(widening_mul(a << 64, b << 64), (a * b, 0));
+    }
+}
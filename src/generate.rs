@@ -0,0 +1,1744 @@
+//! Generators of numbers with a given shape of digits, built from the digits rather than by
+//! filtering every integer, and of sequences of digits.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::generate::n_digit_numbers;
+//!
+//! // The 3-digit numbers in octal.
+//! let numbers = n_digit_numbers(8_u16, 3).unwrap();
+//! assert_eq!(numbers.len(), 448);
+//! assert_eq!(numbers.clone().next(), Some(0o100));
+//! assert_eq!(numbers.last(), Some(0o777));
+//! ```
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+use crate::digits::Digits;
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::parse::push_digit;
+use crate::powers::RadixPowers;
+use crate::{IntoDigits, RadixMath};
+use core::iter::FusedIterator;
+
+/// Returns an iterator over the numbers with exactly `n` digits in `radix`, i.e. from
+/// `radix^(n - 1)` to `radix^n - 1`, in increasing order.
+///
+/// The 1-digit numbers include 0. The iterator stops at `T::max_value()` if `radix^n` does not
+/// fit in `T`, and is empty if `radix^(n - 1)` does not fit either, or if `n` is 0.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::n_digit_numbers;
+///
+/// assert!(n_digit_numbers(10_u8, 1).unwrap().eq(0..10));
+/// assert!(n_digit_numbers(10_u8, 3).unwrap().eq(100..=255));
+/// assert!(n_digit_numbers(2_u8, 3).unwrap().rev().eq([7, 6, 5, 4]));
+/// assert_eq!(n_digit_numbers(10_u8, 4).unwrap().len(), 0);
+/// ```
+pub fn n_digit_numbers<T: IntoDigits>(radix: T, n: usize) -> Result<NDigitNumbers<T>, RadixError> {
+    T::zero().nbr_digits(radix)?;
+
+    let empty = NDigitNumbers {
+        front: T::one(),
+        back: T::zero(),
+        done: true,
+    };
+    if n == 0 {
+        return Ok(empty);
+    }
+
+    let mut low = T::one();
+    for _ in 1..n {
+        low = match low.checked_mul(radix) {
+            Some(low) => low,
+            None => return Ok(empty),
+        };
+    }
+    let high = match low.checked_mul(radix) {
+        Some(high) => high - T::one(),
+        None => low.max_above(),
+    };
+
+    Ok(NDigitNumbers {
+        front: if n == 1 { T::zero() } else { low },
+        back: high,
+        done: false,
+    })
+}
+
+/// An iterator over the numbers with a given number of digits, in increasing order.
+///
+/// There are fewer than `2^BITS` numbers with a given number of digits in a `BITS`-bit integer
+/// type, so that it is an `ExactSizeIterator` for the unsigned types not wider than `usize`.
+/// Otherwise the length may not fit in a `usize`, and `size_hint` returns `(usize::MAX, None)`.
+///
+/// This struct is created by the [`n_digit_numbers`](fn.n_digit_numbers.html) function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NDigitNumbers<T: IntoDigits> {
+    // The bounds are inclusive, so that the range can end at the largest number.
+    front: T,
+    back: T,
+    done: bool,
+}
+
+impl<T: IntoDigits> Iterator for NDigitNumbers<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let number = self.front;
+        if number == self.back {
+            self.done = true;
+        } else {
+            self.front = number + T::one();
+        }
+        Some(number)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        match (self.back - self.front)
+            .to_usize()
+            .and_then(|len| len.checked_add(1))
+        {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match T::from_usize(n) {
+            Some(n) if !self.done && n <= self.back - self.front => {
+                self.front = self.front + n;
+                self.next()
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<T: IntoDigits> DoubleEndedIterator for NDigitNumbers<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let number = self.back;
+        if number == self.front {
+            self.done = true;
+        } else {
+            self.back = number - T::one();
+        }
+        Some(number)
+    }
+}
+
+macro_rules! impl_exact_size {
+    ( $($t:ty)* ) => {
+        $(
+            impl ExactSizeIterator for NDigitNumbers<$t> {}
+        )*
+    };
+}
+
+impl_exact_size!(u8 u16 usize);
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_exact_size!(u32);
+
+#[cfg(target_pointer_width = "64")]
+impl_exact_size!(u64);
+
+impl<T: IntoDigits> FusedIterator for NDigitNumbers<T> {}
+
+/// Returns an iterator over the repunits in `radix`, `1`, `11`, `111` and so on, until they do
+/// not fit in `T`.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::repunits;
+///
+/// assert!(repunits(10_u16).unwrap().eq([1, 11, 111, 1111, 11_111]));
+/// assert!(repunits(2_u8).unwrap().eq([1, 3, 7, 15, 31, 63, 127, 255]));
+///
+/// // The repunits divisible by 7.
+/// let mut divisible = repunits(10_u64).unwrap().filter(|repunit| repunit % 7 == 0);
+/// assert_eq!(divisible.next(), Some(111_111));
+/// ```
+pub fn repunits<T: IntoDigits>(radix: T) -> Result<Repunits<T>, RadixError> {
+    T::zero().nbr_digits(radix)?;
+
+    Ok(Repunits {
+        radix,
+        next: Some(T::one()),
+    })
+}
+
+/// An iterator over the repunits in a radix, in increasing order.
+///
+/// This struct is created by the [`repunits`](fn.repunits.html) function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Repunits<T: IntoDigits> {
+    radix: T,
+    next: Option<T>,
+}
+
+impl<T: IntoDigits> Repunits<T> {
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+}
+
+impl<T: IntoDigits> Iterator for Repunits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let repunit = self.next?;
+        let radix = self.radix;
+        self.next = repunit
+            .checked_mul(radix)
+            .and_then(|next| next.checked_add(T::one()));
+        Some(repunit)
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for Repunits<T> {}
+
+/// Returns an iterator over the repdigits in `radix`, the numbers with a single repeated nonzero
+/// digit, in increasing order, until they do not fit in `T`.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::repdigits;
+///
+/// assert!(repdigits(10_u8)
+///     .unwrap()
+///     .skip(7)
+///     .eq([8, 9, 11, 22, 33, 44, 55, 66, 77, 88, 99, 111, 222]));
+/// assert!(repdigits(3_u8).unwrap().eq([1, 2, 4, 8, 13, 26, 40, 80, 121, 242]));
+/// ```
+pub fn repdigits<T: IntoDigits>(radix: T) -> Result<Repdigits<T>, RadixError> {
+    let mut repunits = repunits(radix)?;
+    let repunit = repunits.next();
+
+    Ok(Repdigits {
+        repunits,
+        repunit,
+        digit: T::one(),
+    })
+}
+
+/// An iterator over the repdigits in a radix, in increasing order.
+///
+/// This struct is created by the [`repdigits`](fn.repdigits.html) function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Repdigits<T: IntoDigits> {
+    repunits: Repunits<T>,
+    // The repdigits of a length are the multiples of the repunit by each digit.
+    repunit: Option<T>,
+    digit: T,
+}
+
+impl<T: IntoDigits> Repdigits<T> {
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.repunits.radix
+    }
+}
+
+impl<T: IntoDigits> Iterator for Repdigits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let repunit = self.repunit?;
+        let repdigit = match self.digit.checked_mul(repunit) {
+            Some(repdigit) => repdigit,
+            None => {
+                // The next repdigits are only larger.
+                self.repunit = None;
+                return None;
+            }
+        };
+
+        self.digit = self.digit + T::one();
+        if self.digit == self.repunits.radix {
+            self.digit = T::one();
+            self.repunit = self.repunits.next();
+        }
+        Some(repdigit)
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for Repdigits<T> {}
+
+/// The largest number of digits of a `u128`, under the smallest radix, 2.
+const MAX_DIGITS: usize = 128;
+
+/// Returns an iterator over the numbers whose digits in `radix` are strictly increasing, in
+/// increasing order, until they do not fit in `T`.
+///
+/// The numbers are built from the combinations of the nonzero digits, so that there are at most
+/// `2^(radix - 1)` of them, and 0 comes first.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::increasing_digits;
+///
+/// let numbers = increasing_digits(10_u32).unwrap();
+/// assert!(numbers.clone().skip(8).take(6).eq([8, 9, 12, 13, 14, 15]));
+/// assert_eq!(numbers.clone().count(), 512);
+/// assert_eq!(numbers.last(), Some(123_456_789));
+/// ```
+pub fn increasing_digits<T: IntoDigits>(radix: T) -> Result<MonotonicDigits<T>, RadixError> {
+    MonotonicDigits::new(radix, true)
+}
+
+/// Returns an iterator over the numbers whose digits in `radix` are non-decreasing, in
+/// increasing order, until they do not fit in `T`.
+///
+/// The numbers are built from the combinations with repetitions of the nonzero digits, and 0
+/// comes first.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::non_decreasing_digits;
+///
+/// let numbers = non_decreasing_digits(10_u32).unwrap();
+/// assert!(numbers.clone().skip(8).take(6).eq([8, 9, 11, 12, 13, 14]));
+/// assert!(numbers.skip_while(|&n| n < 199).take(3).eq([199, 222, 223]));
+///
+/// // The non-decreasing numbers below 10^6, each a multiset of at most 6 nonzero digits.
+/// let increasing = non_decreasing_digits(10_u32).unwrap().take_while(|&n| n < 1_000_000);
+/// assert_eq!(increasing.count(), 5005);
+/// ```
+pub fn non_decreasing_digits<T: IntoDigits>(radix: T) -> Result<MonotonicDigits<T>, RadixError> {
+    MonotonicDigits::new(radix, false)
+}
+
+/// An iterator over the numbers whose digits are increasing in a radix, strictly or not, in
+/// increasing order.
+///
+/// This struct is created by the [`increasing_digits`](fn.increasing_digits.html) and
+/// [`non_decreasing_digits`](fn.non_decreasing_digits.html) functions.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MonotonicDigits<T: IntoDigits> {
+    radix: T,
+    strict: bool,
+    // The digits of the next number, most significant first.
+    digits: [T; MAX_DIGITS],
+    len: usize,
+    done: bool,
+}
+
+impl<T: IntoDigits> MonotonicDigits<T> {
+    fn new(radix: T, strict: bool) -> Result<Self, RadixError> {
+        T::zero().nbr_digits(radix)?;
+
+        Ok(MonotonicDigits {
+            radix,
+            strict,
+            digits: [T::zero(); MAX_DIGITS],
+            len: 1,
+            done: false,
+        })
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    /// Returns `true` if the digits are strictly increasing.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Returns the difference between successive digits of the smallest numbers.
+    fn step(&self) -> T {
+        if self.strict {
+            T::one()
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Sets the digits after `idx` to the smallest allowed after it.
+    fn fill_from(&mut self, idx: usize) {
+        let step = self.step();
+        for idx in idx + 1..self.len {
+            self.digits[idx] = self.digits[idx - 1] + step;
+        }
+    }
+
+    /// Moves to the next combination of digits, of the same length if any.
+    fn advance(&mut self) {
+        let largest = self.radix - T::one();
+        let step = self.step();
+
+        // The digit at `idx` is at most `largest - step * (len - 1 - idx)`, so that the following
+        // ones fit, and the rightmost one below its bound is the one to increment.
+        let mut bound = largest;
+        for idx in (0..self.len).rev() {
+            if self.digits[idx] < bound {
+                self.digits[idx] = self.digits[idx] + T::one();
+                self.fill_from(idx);
+                return;
+            }
+            bound = bound - step;
+        }
+
+        // The `len + 1` smallest nonzero digits must fit for a strictly increasing number.
+        self.len += 1;
+        let too_long = self.strict && T::from_usize(self.len).is_none_or(|len| len >= self.radix);
+        if self.len > MAX_DIGITS || too_long {
+            self.done = true;
+            return;
+        }
+        self.digits[0] = T::one();
+        self.fill_from(0);
+    }
+}
+
+impl<T: IntoDigits> Iterator for MonotonicDigits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let radix = self.radix;
+        let number = self.digits[..self.len]
+            .iter()
+            .try_fold(T::zero(), |number, &digit| push_digit(number, radix, digit));
+        match number {
+            Some(number) => {
+                self.advance();
+                Some(number)
+            }
+            None => {
+                // The next numbers are only larger.
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for MonotonicDigits<T> {}
+
+/// Whether the digit 0 is one of the digits of pandigital numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ZeroDigit {
+    /// The `width` digits are `0` to `width - 1`, and 0 never leads.
+    Included,
+    /// The `width` digits are `1` to `width`.
+    Excluded,
+}
+
+/// Returns an iterator over the pandigital numbers with `width` digits in `radix`, which use
+/// each of the first `width` digits exactly once, in increasing order.
+///
+/// `zero` sets whether these digits start from 0 or 1. The numbers are built as the
+/// permutations of the digits in lexicographic order, and the iterator is double-ended, so that
+/// `rev` yields them in decreasing order. Only the numbers that fit in `T` are yielded.
+///
+/// The iterator is empty if `width` is 0, or larger than the number of digits of the radix.
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::{pandigitals, ZeroDigit};
+///
+/// let numbers = pandigitals(10_u32, 3, ZeroDigit::Excluded).unwrap();
+/// assert!(numbers.eq([123, 132, 213, 231, 312, 321]));
+///
+/// let numbers = pandigitals(10_u32, 3, ZeroDigit::Included).unwrap();
+/// assert!(numbers.rev().eq([210, 201, 120, 102]));
+///
+/// // The largest pandigital prime.
+/// fn is_prime(n: u32) -> bool {
+///     n > 1 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+/// }
+/// let largest = (1..=9)
+///     .rev()
+///     .find_map(|width| {
+///         pandigitals(10_u32, width, ZeroDigit::Excluded)
+///             .unwrap()
+///             .rev()
+///             .find(|&n| is_prime(n))
+///     });
+/// assert_eq!(largest, Some(7_652_413));
+/// ```
+pub fn pandigitals<T: IntoDigits>(
+    radix: T,
+    width: usize,
+    zero: ZeroDigit,
+) -> Result<Pandigitals<T>, RadixError> {
+    T::zero().nbr_digits(radix)?;
+
+    let mut pandigitals = Pandigitals {
+        radix,
+        front: [0; MAX_DIGITS],
+        back: [0; MAX_DIGITS],
+        len: width,
+        done: true,
+    };
+    let first = match zero {
+        ZeroDigit::Included => 0,
+        ZeroDigit::Excluded => 1,
+    };
+    // The digits must all be smaller than the radix, and more would not fit in `T` either.
+    if width == 0 || width > MAX_DIGITS {
+        return Ok(pandigitals);
+    }
+    if T::from_usize(width + first - 1).is_none_or(|largest| largest >= radix) {
+        return Ok(pandigitals);
+    }
+
+    // The smallest permutation without a leading zero.
+    for (idx, digit) in pandigitals.front[..width].iter_mut().enumerate() {
+        *digit = (idx + first) as u8;
+    }
+    if width > 1 && first == 0 {
+        pandigitals.front.swap(0, 1);
+    }
+    if let Some(back) = largest_fitting(&pandigitals.front[..width], radix) {
+        pandigitals.back = back;
+        pandigitals.done = false;
+    }
+    Ok(pandigitals)
+}
+
+/// Returns the number of `digits`, most significant first, or `None` if it does not fit in `T`.
+fn number_of<T: IntoDigits>(digits: &[u8], radix: T) -> Option<T> {
+    digits.iter().try_fold(T::zero(), |number, &digit| {
+        push_digit(number, radix, T::from_u128(digit.into())?)
+    })
+}
+
+/// Returns the largest permutation of `smallest`, the smallest one without a leading zero,
+/// whose number fits in `T`, or `None` if there is none.
+fn largest_fitting<T: IntoDigits>(smallest: &[u8], radix: T) -> Option<[u8; MAX_DIGITS]> {
+    let len = smallest.len();
+    number_of(smallest, radix)?;
+
+    // Each digit is the largest one left whose smallest completion still fits, which the
+    // digits chosen so far followed by the others in ascending order always are.
+    let mut digits = [0; MAX_DIGITS];
+    digits[..len].copy_from_slice(smallest);
+    for idx in 0..len {
+        digits[idx..len].sort_unstable();
+        for pick in (idx..len).rev() {
+            let mut candidate = digits;
+            // Moves the picked digit to `idx`, the others staying in ascending order.
+            candidate[idx..=pick].rotate_right(1);
+            if idx == 0 && len > 1 && candidate[0] == 0 {
+                continue;
+            }
+            if number_of(&candidate[..len], radix).is_some() {
+                digits = candidate;
+                break;
+            }
+        }
+    }
+    Some(digits)
+}
+
+/// Moves to the next permutation in lexicographic order, or returns `false` if it is the last.
+fn next_permutation<D: Ord + Copy>(digits: &mut [D]) -> bool {
+    let idx = match digits.windows(2).rposition(|pair| pair[0] < pair[1]) {
+        Some(idx) => idx,
+        None => return false,
+    };
+    // There is a larger digit after `idx`, at least the next one.
+    let swap = digits
+        .iter()
+        .rposition(|&digit| digit > digits[idx])
+        .unwrap_or(idx + 1);
+    digits.swap(idx, swap);
+    digits[idx + 1..].reverse();
+    true
+}
+
+/// Moves to the previous permutation in lexicographic order, or returns `false` if it is the
+/// first.
+fn prev_permutation<D: Ord + Copy>(digits: &mut [D]) -> bool {
+    let idx = match digits.windows(2).rposition(|pair| pair[0] > pair[1]) {
+        Some(idx) => idx,
+        None => return false,
+    };
+    let swap = digits
+        .iter()
+        .rposition(|&digit| digit < digits[idx])
+        .unwrap_or(idx + 1);
+    digits.swap(idx, swap);
+    digits[idx + 1..].reverse();
+    true
+}
+
+/// An iterator over the pandigital numbers of a width in a radix, in increasing order.
+///
+/// This struct is created by the [`pandigitals`](fn.pandigitals.html) function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pandigitals<T: IntoDigits> {
+    radix: T,
+    // The digits of the next numbers from both ends, which are at most 128 distinct ones.
+    front: [u8; MAX_DIGITS],
+    back: [u8; MAX_DIGITS],
+    len: usize,
+    done: bool,
+}
+
+impl<T: IntoDigits> Pandigitals<T> {
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    /// Returns the number of digits of the numbers.
+    pub fn width(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: IntoDigits> Iterator for Pandigitals<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let len = self.len;
+        let number = number_of(&self.front[..len], self.radix);
+        if self.front[..len] == self.back[..len] {
+            self.done = true;
+        } else {
+            next_permutation(&mut self.front[..len]);
+        }
+        number
+    }
+}
+
+impl<T: IntoDigits> DoubleEndedIterator for Pandigitals<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let len = self.len;
+        let number = number_of(&self.back[..len], self.radix);
+        if self.front[..len] == self.back[..len] {
+            self.done = true;
+        } else {
+            prev_permutation(&mut self.back[..len]);
+        }
+        number
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for Pandigitals<T> {}
+
+/// Whether the permutations of digits may start with a 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LeadingZero {
+    /// The permutations with leading zeroes are the numbers with fewer digits.
+    Allowed,
+    /// Only the permutations which start with a digit other than 0, or the single digit 0.
+    Skipped,
+}
+
+/// Returns an iterator over the distinct numbers made of the digits of `number` in `radix`, in
+/// increasing order, until they do not fit in `T`.
+///
+/// Each permutation of the multiset of the digits is yielded once, even if some digits are
+/// repeated. `leading_zero` sets whether the permutations starting with a 0 are yielded.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::{digit_permutations, LeadingZero};
+///
+/// let numbers = digit_permutations(1_100_u32, 10, LeadingZero::Skipped).unwrap();
+/// assert!(numbers.eq([1_001, 1_010, 1_100]));
+///
+/// let numbers = digit_permutations(1_100_u32, 10, LeadingZero::Allowed).unwrap();
+/// assert!(numbers.eq([11, 101, 110, 1_001, 1_010, 1_100]));
+///
+/// // The multiples of a cyclic number are permutations of its digits.
+/// let cyclic = 142_857_u32;
+/// assert!((1..=6).all(|k| {
+///     digit_permutations(cyclic, 10, LeadingZero::Skipped)
+///         .unwrap()
+///         .any(|n| n == k * cyclic)
+/// }));
+/// ```
+pub fn digit_permutations<T: IntoDigits>(
+    number: T,
+    radix: T,
+    leading_zero: LeadingZero,
+) -> Result<DigitPermutations<T>, RadixError> {
+    let mut permutations = DigitPermutations {
+        radix,
+        digits: [T::zero(); MAX_DIGITS],
+        len: 0,
+        done: false,
+    };
+    for digit in number.into_digits(radix)? {
+        permutations.digits[permutations.len] = digit;
+        permutations.len += 1;
+    }
+
+    // The smallest permutation, with the smallest digit other than 0 first if they are skipped.
+    let digits = &mut permutations.digits[..permutations.len];
+    digits.sort_unstable();
+    if leading_zero == LeadingZero::Skipped {
+        if let Some(idx) = digits.iter().position(|digit| !digit.is_zero()) {
+            digits[..=idx].rotate_right(1);
+        }
+    }
+    Ok(permutations)
+}
+
+/// An iterator over the distinct permutations of the digits of a number, in increasing order.
+///
+/// This struct is created by the [`digit_permutations`](fn.digit_permutations.html) function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DigitPermutations<T: IntoDigits> {
+    radix: T,
+    // The digits of the next number, which has at most 128 digits.
+    digits: [T; MAX_DIGITS],
+    len: usize,
+    done: bool,
+}
+
+impl<T: IntoDigits> DigitPermutations<T> {
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    /// Returns the number of digits of the permutations.
+    pub fn width(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: IntoDigits> Iterator for DigitPermutations<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let digits = &mut self.digits[..self.len];
+        let radix = self.radix;
+        let number = digits
+            .iter()
+            .try_fold(T::zero(), |number, &digit| push_digit(number, radix, digit));
+        // The next permutations are only larger, so that none fits after one which does not.
+        self.done = number.is_none() || !next_permutation(digits);
+        number
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for DigitPermutations<T> {}
+
+/// Returns an iterator over the distinct numbers whose digits in `radix` are a subsequence of
+/// the digits of `number`, i.e. are left once some digits are deleted, in increasing order.
+///
+/// At least one digit is kept. A subsequence starting with a 0 is the same number as a shorter
+/// one, so that only the single digit 0 is yielded among them.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::digit_subsequences;
+///
+/// let numbers = digit_subsequences(1_021_u32, 10).unwrap();
+/// assert!(numbers.eq([0, 1, 2, 10, 11, 12, 21, 101, 102, 121, 1_021]));
+/// ```
+pub fn digit_subsequences<T: IntoDigits>(
+    number: T,
+    radix: T,
+) -> Result<DigitSubsequences<T>, RadixError> {
+    let mut subsequences = DigitSubsequences::new(number, radix, 1)?;
+    subsequences.fixed = false;
+    Ok(subsequences)
+}
+
+/// Returns an iterator over the distinct numbers whose `len` digits in `radix`, with leading
+/// zeroes, are a subsequence of the digits of `number`, in increasing order.
+///
+/// These are the numbers left once `number.nbr_digits(radix) - len` digits are deleted. The
+/// iterator is empty if `len` is 0 or larger than the number of digits.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::digit_subsequences_of_len;
+///
+/// // The smallest and largest numbers left once 3 digits are deleted.
+/// let mut numbers = digit_subsequences_of_len(1_432_219_u32, 10, 4).unwrap();
+/// assert_eq!(numbers.next(), Some(1_219));
+/// assert_eq!(numbers.last(), Some(4_329));
+///
+/// let numbers = digit_subsequences_of_len(10_200_u32, 10, 4).unwrap();
+/// assert!(numbers.eq([200, 1_000, 1_020, 1_200]));
+/// ```
+pub fn digit_subsequences_of_len<T: IntoDigits>(
+    number: T,
+    radix: T,
+    len: usize,
+) -> Result<DigitSubsequences<T>, RadixError> {
+    DigitSubsequences::new(number, radix, len)
+}
+
+/// An iterator over the distinct subsequences of the digits of a number, in increasing order.
+///
+/// This struct is created by the [`digit_subsequences`](fn.digit_subsequences.html) and
+/// [`digit_subsequences_of_len`](fn.digit_subsequences_of_len.html) functions.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DigitSubsequences<T: IntoDigits> {
+    radix: T,
+    // The digits of the number and of the next subsequence, which have at most 128 digits.
+    digits: [T; MAX_DIGITS],
+    nbr_digits: usize,
+    subsequence: [T; MAX_DIGITS],
+    len: usize,
+    fixed: bool,
+    done: bool,
+}
+
+impl<T: IntoDigits> DigitSubsequences<T> {
+    fn new(number: T, radix: T, len: usize) -> Result<Self, RadixError> {
+        let mut subsequences = DigitSubsequences {
+            radix,
+            digits: [T::zero(); MAX_DIGITS],
+            nbr_digits: 0,
+            subsequence: [T::zero(); MAX_DIGITS],
+            len,
+            fixed: true,
+            done: true,
+        };
+        for digit in number.into_digits(radix)? {
+            subsequences.digits[subsequences.nbr_digits] = digit;
+            subsequences.nbr_digits += 1;
+        }
+        if len > 0 && len <= subsequences.nbr_digits {
+            subsequences.done = !subsequences.fill(0, 0);
+        }
+        Ok(subsequences)
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    // Whether a subsequence would be the same number as a shorter one.
+    fn skips(&self, idx: usize, digit: T) -> bool {
+        !self.fixed && idx == 0 && self.len > 1 && digit.is_zero()
+    }
+
+    // Sets the subsequence from `idx` to the smallest one of the digits from `from`, or returns
+    // `false` if there is none.
+    fn fill(&mut self, mut from: usize, idx: usize) -> bool {
+        for idx in idx..self.len {
+            // The digits after the pick must be enough for the rest of the subsequence.
+            let last = self.nbr_digits - (self.len - idx);
+            let pick = (from..=last)
+                .filter(|&pos| !self.skips(idx, self.digits[pos]))
+                .min_by_key(|&pos| self.digits[pos]);
+            match pick {
+                Some(pos) => {
+                    self.subsequence[idx] = self.digits[pos];
+                    from = pos + 1;
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+
+    // Moves to the next subsequence of the same length, or returns `false` if it is the last.
+    fn advance(&mut self) -> bool {
+        // The earliest positions at which the digits of the subsequence are matched, which
+        // leave the most digits for the rest.
+        let mut ends = [0; MAX_DIGITS];
+        let mut from = 0;
+        for (idx, end) in ends[..self.len].iter_mut().enumerate() {
+            // A subsequence is always matched.
+            from += self.digits[from..]
+                .iter()
+                .position(|&digit| digit == self.subsequence[idx])
+                .unwrap_or(0);
+            from += 1;
+            *end = from;
+        }
+
+        // The last digit which can be replaced by a larger one, followed by the smallest rest.
+        for idx in (0..self.len).rev() {
+            let from = if idx == 0 { 0 } else { ends[idx - 1] };
+            let last = self.nbr_digits - (self.len - idx);
+            let current = self.subsequence[idx];
+            let pick = (from..=last)
+                .filter(|&pos| self.digits[pos] > current)
+                .min_by_key(|&pos| self.digits[pos]);
+            if let Some(pos) = pick {
+                self.subsequence[idx] = self.digits[pos];
+                return self.fill(pos + 1, idx + 1);
+            }
+        }
+        false
+    }
+}
+
+impl<T: IntoDigits> Iterator for DigitSubsequences<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let radix = self.radix;
+        let number = self.subsequence[..self.len]
+            .iter()
+            .try_fold(T::zero(), |number, &digit| push_digit(number, radix, digit));
+        if !self.advance() {
+            // The longer subsequences are larger, as they do not start with a 0.
+            self.len += 1;
+            self.done = self.fixed || self.len > self.nbr_digits || !self.fill(0, 0);
+        }
+        // The subsequences are not larger than the number.
+        number
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for DigitSubsequences<T> {}
+
+/// Returns an iterator over the digits of the Champernowne sequence in `radix`, the digits of
+/// 1, 2, 3 and so on concatenated, until the numbers do not fit in `T`.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::champernowne;
+///
+/// let digits = champernowne(10_u32).unwrap();
+/// assert!(digits.skip(8).take(6).eq([9, 1, 0, 1, 1, 1]));
+///
+/// let digits = champernowne(2_u8).unwrap();
+/// assert!(digits.take(8).eq([1, 1, 0, 1, 1, 1, 0, 0]));
+/// ```
+pub fn champernowne<T: IntoDigits>(radix: T) -> Result<Champernowne<T>, RadixError> {
+    let digits = T::one().into_digits(radix)?;
+
+    Ok(Champernowne {
+        radix,
+        number: T::one(),
+        digits: Some(digits),
+    })
+}
+
+/// Returns the digit at `index`, from 0, of the Champernowne sequence in `radix`, the digits of
+/// 1, 2, 3 and so on concatenated.
+///
+/// The digit is found by skipping the blocks of the numbers with the same number of digits, of
+/// `k * (radix - 1) * radix^(k - 1)` digits each. There are at least as many digits as numbers,
+/// so that the number holding the digit always fits in `T`.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::champernowne_digit;
+///
+/// // The product of the 1st, 10th, 100th and so on decimal digits.
+/// let product: u32 = (0..7)
+///     .map(|exponent| champernowne_digit(10_u32.pow(exponent) - 1, 10).unwrap())
+///     .product();
+/// assert_eq!(product, 210);
+///
+/// assert_eq!(champernowne_digit(0x20_u8, 16), Ok(8));
+/// assert_eq!(champernowne_digit(u8::MAX, 10), Ok(1));
+/// ```
+pub fn champernowne_digit<T: IntoDigits>(index: T, radix: T) -> Result<T, RadixError> {
+    T::zero().nbr_digits(radix)?;
+
+    let largest = radix - T::one();
+    let (mut index, mut len, mut first) = (index, T::one(), T::one());
+    loop {
+        // There are `first * (radix - 1)` numbers with `len` digits, and the block holds the
+        // index if its length does not fit.
+        let block = match first.checked_mul(largest) {
+            Some(count) => count.checked_mul(len),
+            None => None,
+        };
+        let block = match block {
+            Some(block) if index >= block => block,
+            _ => break,
+        };
+
+        // The digits skipped so far, at most `index`, are more than the numbers, so that the
+        // first number of the next block fits.
+        index = index - block;
+        len = len + T::one();
+        first = first * radix;
+    }
+
+    // The number is `first + index / len`, and the digit is at `index % len` from its left.
+    let mut number = first + index / len;
+    let mut right = len - T::one() - index % len;
+    while !right.is_zero() {
+        number = number / radix;
+        right = right - T::one();
+    }
+    Ok(number % radix)
+}
+
+/// An iterator over the digits of the Champernowne sequence in a radix.
+///
+/// This struct is created by the [`champernowne`](fn.champernowne.html) function.
+#[derive(Clone, Debug)]
+pub struct Champernowne<T: IntoDigits> {
+    radix: T,
+    number: T,
+    // The digits left of `number`, or `None` once the next number does not fit.
+    digits: Option<DigitsIterator<T>>,
+}
+
+impl<T: IntoDigits> Champernowne<T> {
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    /// Returns the number whose digits are being yielded.
+    pub fn number(&self) -> T {
+        self.number
+    }
+}
+
+impl<T: IntoDigits> Iterator for Champernowne<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let digits = self.digits.as_mut()?;
+        if let Some(digit) = digits.next() {
+            return Some(digit);
+        }
+
+        self.number = match self.number.checked_add(T::one()) {
+            Some(number) => number,
+            None => {
+                self.digits = None;
+                return None;
+            }
+        };
+        let digits = self.number.into_digits(self.radix).ok()?;
+        self.digits = Some(digits);
+        self.digits.as_mut()?.next()
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for Champernowne<T> {}
+
+/// Returns an iterator over the terms of the look-and-say sequence from `seed`, each the run
+/// lengths of the previous one, written in its radix, followed by the digits of the runs.
+///
+/// The terms grow exponentially, so that they are kept as `Digits` rather than numbers. The
+/// iterator yields `seed` first, and ends after a term with a digit not smaller than the radix.
+///
+/// # Example
+///
+/// ```
+/// use radixal::digits::Digits;
+/// use radixal::generate::look_and_say;
+/// use radixal::IntoDigits;
+///
+/// let mut terms = look_and_say(Digits::from(1_u32.into_decimal_digits()));
+/// let numbers = terms.by_ref().take(6).map(|term| term.to_number().unwrap());
+/// assert!(numbers.eq([1, 11, 21, 1211, 111_221, 312_211]));
+///
+/// // The lengths grow by Conway's constant, about 1.3036 per term, to the 50th one.
+/// let term = terms.nth(43).unwrap();
+/// assert_eq!(term.len(), 894_810);
+/// ```
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+pub fn look_and_say<T: IntoDigits>(seed: Digits<T>) -> LookAndSay<T> {
+    LookAndSay { term: Some(seed) }
+}
+
+/// An iterator over the terms of the look-and-say sequence.
+///
+/// This struct is created by the [`look_and_say`](fn.look_and_say.html) function.
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+#[derive(Clone, Debug)]
+pub struct LookAndSay<T: IntoDigits> {
+    term: Option<Digits<T>>,
+}
+
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+impl<T: IntoDigits> Iterator for LookAndSay<T> {
+    type Item = Digits<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.term.as_ref()?.look_and_say().ok();
+        core::mem::replace(&mut self.term, next)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "smallvec"))]
+impl<T: IntoDigits> FusedIterator for LookAndSay<T> {}
+
+/// Returns an iterator over the palindromes in `radix`, in increasing order, until they do not
+/// fit in `T`.
+///
+/// Each palindrome is built by mirroring its first half, so that there are about
+/// `radix^(n / 2)` steps to reach the `n`-digit ones.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::palindromes;
+///
+/// let decimal = palindromes(10_u32).unwrap();
+/// assert!(decimal.clone().skip(8).take(5).eq([8, 9, 11, 22, 33]));
+/// assert!(decimal.skip_while(|&p| p < 990).take(3).eq([999, 1001, 1111]));
+///
+/// // The palindromic squares.
+/// let squares = palindromes(10_u64)
+///     .unwrap()
+///     .take_while(|&p| p < 100_000)
+///     .filter(|&p| ((p as f64).sqrt() as u64).pow(2) == p);
+/// assert!(squares.eq([
+///     0, 1, 4, 9, 121, 484, 676, 10_201, 12_321, 14_641, 40_804, 44_944, 69_696, 94_249,
+/// ]));
+/// ```
+pub fn palindromes<T: IntoDigits>(radix: T) -> Result<Palindromes<T>, RadixError> {
+    palindromes_from_len(radix, 1)
+}
+
+/// Returns an iterator over the palindromes in `radix` with at least `len` digits, in increasing
+/// order, until they do not fit in `T`.
+///
+/// The 1-digit palindromes include 0, and a `len` of 0 is the same as 1.
+///
+/// Returns `Err(RadixError)` if the radix is 0 or 1.
+///
+/// # Example
+///
+/// ```
+/// use radixal::generate::palindromes_from_len;
+///
+/// let mut palindromes = palindromes_from_len(10_u128, 39).unwrap();
+/// assert_eq!(palindromes.next(), Some(10_u128.pow(38) + 1));
+/// assert_eq!(palindromes.next(), Some(10_u128.pow(38) + 10_u128.pow(19) + 1));
+/// assert_eq!(palindromes_from_len(10_u8, 4).unwrap().next(), None);
+/// ```
+pub fn palindromes_from_len<T: IntoDigits>(
+    radix: T,
+    len: usize,
+) -> Result<Palindromes<T>, RadixError> {
+    let powers = RadixPowers::new(radix)?;
+
+    let len = len.max(1);
+    let mut palindromes = Palindromes {
+        radix,
+        half: T::zero(),
+        half_end: radix,
+        power: T::one(),
+        len,
+        done: false,
+    };
+
+    // The first half has `ceil(len / 2)` digits, and is placed above the `len / 2` others.
+    match (powers.get(len.div_ceil(2) - 1), powers.get(len / 2)) {
+        (Some(half), Some(place)) => {
+            palindromes.half = if len == 1 { T::zero() } else { half };
+            palindromes.half_end = half.checked_mul(radix).unwrap_or_else(|| half.max_above());
+            palindromes.power = place;
+        }
+        _ => palindromes.done = true,
+    }
+    Ok(palindromes)
+}
+
+/// An iterator over the palindromes in a radix, in increasing order.
+///
+/// This struct is created by the [`palindromes`](fn.palindromes.html) function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Palindromes<T: IntoDigits> {
+    radix: T,
+    // The first `(len + 1) / 2` digits, below `half_end`, which saturates at the largest number.
+    half: T,
+    half_end: T,
+    // `radix^(len / 2)`, the place of the first half.
+    power: T,
+    len: usize,
+    done: bool,
+}
+
+impl<T: IntoDigits> Palindromes<T> {
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    /// Returns the palindrome of the current half, or `None` if it does not fit in `T`.
+    fn palindrome(&self) -> Option<T> {
+        let radix = self.radix;
+        let high = self.half.checked_mul(self.power)?;
+
+        // The middle digit of odd lengths is not mirrored.
+        let mut mirrored = if !self.len.is_multiple_of(2) {
+            self.half / radix
+        } else {
+            self.half
+        };
+        let mut reversed = T::zero();
+        for _ in 0..self.len / 2 {
+            reversed = reversed * radix + mirrored % radix;
+            mirrored = mirrored / radix;
+        }
+
+        high.checked_add(reversed)
+    }
+}
+
+impl<T: IntoDigits> Iterator for Palindromes<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let palindrome = self.palindrome();
+        if palindrome.is_none() {
+            self.done = true;
+            return None;
+        }
+
+        self.half = self.half + T::one();
+        if self.half == self.half_end {
+            let radix = self.radix;
+            self.len += 1;
+            if self.len.is_multiple_of(2) {
+                // The same half is mirrored once more.
+                self.half = self.half_end / radix;
+                match self.power.checked_mul(radix) {
+                    Some(power) => self.power = power,
+                    None => self.done = true,
+                }
+            } else {
+                self.half = self.half_end;
+                self.half_end = self
+                    .half_end
+                    .checked_mul(radix)
+                    .unwrap_or_else(|| self.half_end.max_above());
+            }
+        }
+        palindrome
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for Palindromes<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_n_digit_numbers() {
+        for radix in 2..=u8::MAX {
+            let mut expected = (0..=u8::MAX).map(|number| (number, number.nbr_digits(radix)));
+            for n in 0..10 {
+                let numbers = n_digit_numbers(radix, n).unwrap();
+                let digits: Vec<_> = expected
+                    .clone()
+                    .filter(|&(_, len)| len == Ok(n))
+                    .map(|(number, _)| number)
+                    .collect();
+                assert_eq!(numbers.len(), digits.len());
+                assert_eq!(numbers.size_hint(), (digits.len(), Some(digits.len())));
+                assert!(numbers.clone().eq(digits.iter().copied()));
+                assert!(numbers.rev().eq(digits.iter().rev().copied()));
+            }
+            assert!(expected.all(|(_, len)| len.unwrap() < 10));
+        }
+
+        assert_eq!(n_digit_numbers(0_u8, 1), Err(RadixError::Radix0));
+        assert_eq!(n_digit_numbers(1_u8, 1), Err(RadixError::Radix1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_repunits_and_repdigits() {
+        for radix in (2..=u16::MAX).step_by(997).chain(2..40) {
+            let expected = (1..=u16::MAX)
+                .filter(|number| number.digit_runs(radix).unwrap().count() == 1)
+                .collect::<Vec<_>>();
+            assert!(repdigits(radix).unwrap().eq(expected.iter().copied()));
+
+            let expected = expected
+                .iter()
+                .copied()
+                .filter(|number| number.into_digits(radix).unwrap().all(|digit| digit == 1));
+            assert!(repunits(radix).unwrap().eq(expected), "{}", radix);
+        }
+
+        assert_eq!(repunits(0_u8), Err(RadixError::Radix0));
+        assert_eq!(repdigits(1_u8), Err(RadixError::Radix1));
+    }
+
+    #[test]
+    fn test_repunits_at_the_limit() {
+        fn last<T: IntoDigits>(radix: T) -> Option<T> {
+            repunits(radix).unwrap().last()
+        }
+        assert_eq!(last(2_u128), Some(u128::MAX));
+        assert_eq!(last(10_u64), Some(11_111_111_111_111_111_111));
+        assert_eq!(last(10_u128), Some((10_u128.pow(38) - 1) / 9 * 10 + 1));
+        assert_eq!(last(u64::MAX), Some(1));
+        assert_eq!(repunits(1_u64 << 32).unwrap().count(), 2);
+
+        let mut hex = repunits(16_u32).unwrap();
+        assert_eq!(hex.nth(7), Some(0x1111_1111));
+        assert_eq!(hex.next(), None);
+        assert_eq!(hex.next(), None);
+
+        fn last_repdigit<T: IntoDigits>(radix: T) -> Option<T> {
+            repdigits(radix).unwrap().last()
+        }
+        assert_eq!(last_repdigit(16_u32), Some(0xffff_ffff));
+        assert_eq!(last_repdigit(10_u64), Some(11_111_111_111_111_111_111));
+        assert_eq!(
+            last_repdigit(10_u128),
+            Some(((10_u128.pow(38) - 1) / 9 * 10 + 1) * 3)
+        );
+        assert_eq!(last_repdigit(u8::MAX), Some(254));
+        assert_eq!(
+            repdigits(1_u64 << 16).unwrap().nth((1 << 16) - 1),
+            Some((1 << 16) + 1)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_monotonic_digits() {
+        for radix in (2..=u16::MAX).step_by(4999).chain(2..40) {
+            let non_decreasing = (0..=u16::MAX)
+                .filter(|&number| number.sort_digits_ascending(radix) == Ok(number))
+                .collect::<Vec<_>>();
+            let numbers = non_decreasing_digits(radix).unwrap();
+            assert!(!numbers.is_strict());
+            assert!(numbers.eq(non_decreasing.iter().copied()), "{}", radix);
+
+            let increasing = non_decreasing
+                .iter()
+                .copied()
+                .filter(|number| number.digit_runs(radix).unwrap().all(|(_, len)| len == 1));
+            let numbers = increasing_digits(radix).unwrap();
+            assert!(numbers.is_strict());
+            assert!(numbers.eq(increasing), "{}", radix);
+        }
+
+        assert_eq!(increasing_digits(0_u8), Err(RadixError::Radix0));
+        assert_eq!(non_decreasing_digits(1_u8), Err(RadixError::Radix1));
+    }
+
+    #[test]
+    fn test_monotonic_digits_at_the_limit() {
+        assert!(increasing_digits(2_u8).unwrap().eq([0, 1]));
+        assert!(non_decreasing_digits(2_u128)
+            .unwrap()
+            .skip(1)
+            .eq(repunits(2_u128).unwrap()));
+        assert_eq!(
+            non_decreasing_digits(10_u32).unwrap().last(),
+            Some(3_999_999_999)
+        );
+        assert_eq!(
+            increasing_digits(16_u64).unwrap().last(),
+            Some(0x0123_4567_89ab_cdef)
+        );
+        assert_eq!(increasing_digits(16_u64).unwrap().count(), 1 << 15);
+        // The 9-digit one does not fit.
+        assert_eq!(increasing_digits(16_u32).unwrap().last(), Some(0x89ab_cdef));
+        assert_eq!(increasing_digits(16_u32).unwrap().count(), 22819);
+
+        let mut numbers = increasing_digits(u8::MAX).unwrap();
+        assert_eq!(numbers.nth(u8::MAX as usize), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_pandigitals() {
+        for radix in 2..=7_u32 {
+            for width in 0..=radix as usize {
+                for &zero in &[ZeroDigit::Included, ZeroDigit::Excluded] {
+                    let first = (zero == ZeroDigit::Excluded) as u32;
+                    let expected = n_digit_numbers(radix, width)
+                        .unwrap()
+                        .filter(|&number| {
+                            let mut digits = number.into_digits(radix).unwrap().collect::<Vec<_>>();
+                            digits.sort_unstable();
+                            digits.iter().copied().eq(first..first + width as u32)
+                        })
+                        .collect::<Vec<_>>();
+                    let numbers = pandigitals(radix, width, zero).unwrap();
+                    assert_eq!(numbers.width(), width);
+                    assert!(numbers.clone().eq(expected.iter().copied()));
+                    assert!(numbers.rev().eq(expected.iter().rev().copied()));
+                }
+            }
+        }
+
+        assert_eq!(
+            pandigitals(0_u8, 1, ZeroDigit::Included),
+            Err(RadixError::Radix0)
+        );
+        assert!(pandigitals(10_u8, 1, ZeroDigit::Included).unwrap().eq([0]));
+        assert!(pandigitals(10_u64, 11, ZeroDigit::Included)
+            .unwrap()
+            .next()
+            .is_none());
+        assert!(pandigitals(10_u8, 10, ZeroDigit::Excluded)
+            .unwrap()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_pandigitals_at_the_limit() {
+        let numbers = pandigitals(10_u8, 3, ZeroDigit::Excluded).unwrap();
+        assert!(numbers.clone().eq([123, 132, 213, 231]));
+        assert!(numbers.rev().eq([231, 213, 132, 123]));
+
+        let mut numbers = pandigitals(10_u32, 10, ZeroDigit::Included).unwrap();
+        assert_eq!(numbers.next(), Some(1_023_456_789));
+        assert_eq!(numbers.next_back(), Some(4_293_876_510));
+        assert_eq!(numbers.count(), 1_206_720 - 2);
+
+        let mut numbers = pandigitals(16_u64, 16, ZeroDigit::Included).unwrap();
+        assert_eq!(numbers.next(), Some(0x1023_4567_89ab_cdef));
+        assert_eq!(numbers.next_back(), Some(0xfedc_ba98_7654_3210));
+
+        // Even the smallest does not fit.
+        assert!(pandigitals(10_u32, 11, ZeroDigit::Included)
+            .unwrap()
+            .next()
+            .is_none());
+        assert!(pandigitals(16_u8, 3, ZeroDigit::Excluded)
+            .unwrap()
+            .next_back()
+            .is_none());
+        assert!(pandigitals(2_u128, 128, ZeroDigit::Included)
+            .unwrap()
+            .next()
+            .is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_digit_permutations() {
+        for radix in 2..=6_u32 {
+            for number in 0..radix.pow(4) {
+                fn sorted(number: u32, radix: u32) -> Vec<u32> {
+                    let mut digits = number.into_digits(radix).unwrap().collect::<Vec<_>>();
+                    digits.sort_unstable();
+                    digits
+                }
+                let width = number.nbr_digits(radix).unwrap();
+                let digits = sorted(number, radix);
+
+                let expected = n_digit_numbers(radix, width)
+                    .unwrap()
+                    .filter(|&n| sorted(n, radix) == digits)
+                    .collect::<Vec<_>>();
+                let numbers = digit_permutations(number, radix, LeadingZero::Skipped).unwrap();
+                assert_eq!(numbers.width(), width);
+                assert!(numbers.eq(expected.iter().copied()));
+
+                // With leading zeroes, the numbers have as many digits once padded.
+                let expected = (0..radix.pow(width as u32))
+                    .filter(|&n| {
+                        let mut padded = sorted(n, radix);
+                        padded.resize(width.max(padded.len()), 0);
+                        padded.sort_unstable();
+                        padded == digits
+                    })
+                    .collect::<Vec<_>>();
+                let numbers = digit_permutations(number, radix, LeadingZero::Allowed).unwrap();
+                assert!(numbers.eq(expected.iter().copied()));
+            }
+        }
+
+        assert_eq!(
+            digit_permutations(12_u8, 1, LeadingZero::Allowed),
+            Err(RadixError::Radix1)
+        );
+        assert!(digit_permutations(0_u8, 10, LeadingZero::Skipped)
+            .unwrap()
+            .eq([0]));
+    }
+
+    #[test]
+    fn test_digit_permutations_at_the_limit() {
+        let numbers = digit_permutations(251_u8, 10, LeadingZero::Skipped).unwrap();
+        assert!(numbers.eq([125, 152, 215, 251]));
+
+        let numbers = digit_permutations(u128::MAX, 2, LeadingZero::Allowed).unwrap();
+        assert_eq!(numbers.width(), 128);
+        assert!(numbers.eq([u128::MAX]));
+
+        let numbers = digit_permutations(u16::MAX, 10, LeadingZero::Skipped).unwrap();
+        assert_eq!(numbers.clone().next(), Some(35_556));
+        assert_eq!(numbers.last(), Some(u16::MAX));
+
+        let mut numbers = digit_permutations(u64::MAX, 10, LeadingZero::Skipped).unwrap();
+        assert_eq!(numbers.next(), Some(10_011_344_445_556_677_789));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_digit_subsequences() {
+        for radix in 2..=5_u32 {
+            for number in 0..radix.pow(4) {
+                let digits = number.into_digits(radix).unwrap().collect::<Vec<_>>();
+                let with_len = |len: usize| {
+                    let mut numbers = (0..1_u32 << digits.len())
+                        .filter(|mask| mask.count_ones() as usize == len)
+                        .map(|mask| {
+                            digits
+                                .iter()
+                                .enumerate()
+                                .filter(|(idx, _)| mask & (1 << idx) != 0)
+                                .fold(0, |n, (_, &digit)| n * radix + digit)
+                        })
+                        .collect::<Vec<_>>();
+                    numbers.sort_unstable();
+                    numbers.dedup();
+                    numbers
+                };
+
+                let mut none = digit_subsequences_of_len(number, radix, 0).unwrap();
+                assert_eq!(none.next(), None);
+                let mut all = Vec::new();
+                for len in 1..=digits.len() + 1 {
+                    let expected = with_len(len);
+                    let numbers = digit_subsequences_of_len(number, radix, len).unwrap();
+                    assert!(numbers.eq(expected.iter().copied()));
+                    all.extend(expected);
+                }
+                all.sort_unstable();
+                all.dedup();
+                let numbers = digit_subsequences(number, radix).unwrap();
+                assert!(numbers.eq(all.iter().copied()));
+            }
+        }
+
+        assert_eq!(digit_subsequences(12_u8, 0), Err(RadixError::Radix0));
+        assert!(digit_subsequences(0_u8, 10).unwrap().eq([0]));
+    }
+
+    #[test]
+    fn test_digit_subsequences_at_the_limit() {
+        let numbers = digit_subsequences(u128::MAX, 2).unwrap();
+        assert!(numbers.eq((1..=128).map(|len| u128::MAX >> (128 - len))));
+
+        let numbers = digit_subsequences_of_len(u64::MAX, 10, 20).unwrap();
+        assert!(numbers.eq([u64::MAX]));
+        let numbers = digit_subsequences_of_len(u64::MAX, 10, 19).unwrap();
+        assert_eq!(numbers.count(), 17);
+    }
+
+    #[test]
+    fn test_champernowne() {
+        for radix in (2..=u16::MAX).step_by(4999).chain(2..20) {
+            let expected = (1..=u16::MAX).flat_map(|number| number.into_digits(radix).unwrap());
+            let digits = champernowne(radix).unwrap();
+            assert_eq!(digits.radix(), radix);
+            assert!(digits.clone().eq(expected.clone()), "{}", radix);
+            for (index, digit) in expected
+                .enumerate()
+                .step_by(13)
+                .take_while(|&(index, _)| index <= u16::MAX as usize)
+            {
+                let index = index as u16;
+                assert_eq!(champernowne_digit(index, radix), Ok(digit));
+            }
+        }
+
+        assert!(champernowne(0_u8).is_err());
+        assert_eq!(champernowne_digit(0_u8, 1), Err(RadixError::Radix1));
+    }
+
+    #[test]
+    fn test_champernowne_at_the_limit() {
+        // 9 digits, 180 for 10 to 99, and 468 for 100 to 255.
+        let mut digits = champernowne(10_u8).unwrap();
+        assert_eq!(digits.by_ref().count(), 657);
+        assert_eq!(digits.number(), u8::MAX);
+        assert_eq!(digits.next(), None);
+        assert_eq!(champernowne_digit(0_u16, 10), Ok(1));
+        assert_eq!(champernowne_digit(656_u16, 10), Ok(5));
+        assert_eq!(champernowne_digit(200_u8, 10), Ok(3));
+        assert_eq!(champernowne_digit(657_u16, 10), Ok(2));
+        assert_eq!(champernowne_digit(u8::MAX, 2), Ok(1));
+        // The digits of the largest radix itself, written `10`.
+        assert_eq!(champernowne_digit(u8::MAX - 1, u8::MAX), Ok(1));
+        assert_eq!(champernowne_digit(u8::MAX, u8::MAX), Ok(0));
+
+        assert_eq!(champernowne(u8::MAX).unwrap().count(), 256);
+        assert_eq!(champernowne_digit(u64::MAX, u64::MAX), Ok(0));
+        assert_eq!(champernowne_digit(u128::MAX, 2), Ok(0));
+        // Within the numbers of 2 digits, which do not all have digits indexed by a `u8`.
+        assert_eq!(champernowne_digit(u8::MAX, 16), Ok(8));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_look_and_say() {
+        use crate::digits::Digits;
+
+        // The lengths of the decimal terms from 1.
+        let lengths = look_and_say(Digits::from(1_u8.into_decimal_digits()))
+            .take(20)
+            .map(|term| term.len());
+        assert!(lengths
+            .eq([1, 2, 2, 4, 6, 6, 8, 10, 14, 20, 26, 34, 46, 62, 78, 102, 134, 176, 226, 302]));
+
+        // 22 describes itself.
+        let mut terms = look_and_say(Digits::from(22_u8.into_decimal_digits()));
+        assert!(terms
+            .by_ref()
+            .take(10)
+            .all(|term| term.as_slice() == [2, 2]));
+
+        // The digits stay below 4 once the lengths are single digits, as there are at most 3
+        // equal digits in a row.
+        for radix in 4..=16_u8 {
+            let seed = Digits::from(radix.into_digits(radix).unwrap());
+            for term in look_and_say(seed).skip(1).take(12) {
+                assert!(term
+                    .windows(4)
+                    .all(|run| run.iter().any(|&digit| digit != run[0])));
+                assert!(term.iter().all(|&digit| digit < 4));
+            }
+        }
+
+        let mut invalid = Digits::new(10_u8).unwrap();
+        invalid.extend([1, 2]);
+        invalid[1] = 10;
+        let mut terms = look_and_say(invalid.clone());
+        assert_eq!(terms.next(), Some(invalid));
+        assert_eq!(terms.next(), None);
+    }
+
+    #[test]
+    fn test_palindromes() {
+        for radix in 2..=40 {
+            let expected = (0..=u16::MAX).filter(|number| number.is_palindrome(radix).unwrap());
+            assert!(palindromes(radix).unwrap().eq(expected), "{}", radix);
+        }
+        for radix in (2..=u8::MAX).step_by(7) {
+            let expected = (0..=u8::MAX).filter(|number| number.is_palindrome(radix).unwrap());
+            assert!(palindromes(radix).unwrap().eq(expected), "{}", radix);
+        }
+
+        assert_eq!(palindromes(0_u8), Err(RadixError::Radix0));
+        assert_eq!(palindromes(1_u8), Err(RadixError::Radix1));
+    }
+
+    #[test]
+    fn test_large_palindromes() {
+        let largest = |radix| palindromes(radix).unwrap().last().unwrap();
+        assert_eq!(largest(2_u32), u32::MAX);
+        assert_eq!(largest(10_u32), 4_294_884_924);
+        assert_eq!(palindromes(2_u32).unwrap().count(), (1 << 17) - 1);
+        assert_eq!(largest(65_536_u32), 0xffff_ffff);
+
+        for radix in 2..=12_u32 {
+            for len in 0..=6 {
+                let expected = palindromes(radix)
+                    .unwrap()
+                    .filter(|p| p.nbr_digits(radix).unwrap() >= len);
+                assert!(palindromes_from_len(radix, len).unwrap().eq(expected));
+            }
+        }
+        assert_eq!(palindromes_from_len(2_u32, 33).unwrap().next(), None);
+        assert_eq!(palindromes_from_len(2_u32, 32).unwrap().count(), 1 << 15);
+        assert_eq!(palindromes_from_len(10_u32, 1000).unwrap().next(), None);
+        assert_eq!(palindromes_from_len(16_u8, 2).unwrap().last(), Some(0xff));
+        assert_eq!(palindromes_from_len(u128::MAX, 2).unwrap().next(), None);
+    }
+
+    #[test]
+    fn test_bounds() {
+        // The largest radix, with a single 2-digit number.
+        let mut numbers = n_digit_numbers(usize::MAX, 2).unwrap();
+        assert_eq!(numbers.len(), 1);
+        assert_eq!(numbers.next(), Some(usize::MAX));
+
+        let mut numbers = n_digit_numbers(10_u128, 39).unwrap();
+        assert_eq!(numbers.size_hint(), (usize::MAX, None));
+        assert_eq!(numbers.next_back(), Some(u128::MAX));
+        assert_eq!(numbers.next(), Some(10_u128.pow(38)));
+        assert_eq!(n_digit_numbers(10_u128, 40).unwrap().next(), None);
+
+        let mut numbers = n_digit_numbers(2_u64, 64).unwrap();
+        assert_eq!(numbers.size_hint(), (1 << 63, Some(1 << 63)));
+        assert_eq!(numbers.nth(1 << 62), Some(3 << 62));
+        assert_eq!(numbers.size_hint().0, (1 << 62) - 1);
+        assert_eq!(numbers.nth((1 << 62) - 2), Some(u64::MAX));
+        assert_eq!(numbers.next(), None);
+
+        let mut numbers = n_digit_numbers(10_u8, 2).unwrap();
+        assert_eq!(numbers.nth(90), None);
+        assert_eq!(numbers.next_back(), None);
+    }
+}
@@ -2,20 +2,156 @@
 //! integer types as a sequence of digits under a specified radix.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Calls `$body` with `$t`, the narrowest primitive type holding the `$bits` bits of a number,
+/// or evaluates `$wider` if there is none.
+macro_rules! with_primitive {
+    ( $bits:expr, |$t:ident| $body:expr, $wider:expr ) => {
+        match $bits {
+            Some(bits) if bits <= u8::BITS => {
+                type $t = u8;
+                $body
+            }
+            Some(bits) if bits <= u16::BITS => {
+                type $t = u16;
+                $body
+            }
+            Some(bits) if bits <= u32::BITS => {
+                type $t = u32;
+                $body
+            }
+            Some(bits) if bits <= u64::BITS => {
+                type $t = u64;
+                $body
+            }
+            Some(_) => {
+                type $t = u128;
+                $body
+            }
+            None => $wider,
+        }
+    };
+}
+
+pub mod alphabet;
+#[cfg(feature = "numeration")]
+pub mod balanced;
+#[cfg(all(feature = "alloc", feature = "alphabets"))]
+pub mod base58;
+#[cfg(feature = "alphabets")]
+pub mod base85;
+pub mod batch;
+#[cfg(feature = "bigint")]
+pub mod bigint;
+#[cfg(feature = "checksum")]
+pub mod checksum;
+pub mod complement;
+#[cfg(feature = "alphabets")]
+pub mod crockford;
+pub mod digital;
+pub mod digits;
 pub mod digits_iterator;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "fixed")]
+pub mod fixed_point;
+pub mod fixed_width;
+#[cfg(feature = "float")]
+pub mod float;
+pub mod format;
+pub mod fraction;
+pub mod generate;
+pub mod lookup;
+pub mod matrix;
+pub mod nonzero;
+#[cfg(feature = "ux")]
+pub mod odd_width;
+pub mod odometer;
+pub mod packed;
+pub mod parse;
+pub mod powers;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod rational;
+mod reciprocal;
+pub mod reversed_digits;
+#[cfg(feature = "numeration")]
+pub mod roman;
+pub mod sign_digit;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+pub mod testing;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
 use core::num::Wrapping;
-use digits_iterator::{DigitsIterator, RadixError};
-use num_traits::{Unsigned, WrappingAdd, WrappingMul};
+use core::ops::{Bound, RangeBounds};
+use digits_iterator::{DigitRuns, DigitsIterator, RadixError, Strategy};
+use num_traits::{Bounded, NumCast, Unsigned, WrappingAdd, WrappingMul};
+use powers::RadixPowers;
+use reversed_digits::{ReversedDigits, ZipDigits};
 
 /// An extension trait on unsigned integer types (`u8`, `u16`, `u32`, `u64`, `u128` and `usize`)
 /// and the corresponding `Wrapping` type.
+///
+/// Other unsigned numeric types, such as checked wrappers or big numbers, implement the
+/// [`Digital`](digital/trait.Digital.html) trait instead, and are wrapped in a
+/// [`DigitalNumber`](digital/struct.DigitalNumber.html) to work with every part of the crate
+/// taking `IntoDigits` numbers, splitting their digits with plain divisions.
 pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsigned {
     #[doc(hidden)]
-    const BINARY_RADIX: Self;
+    #[inline]
+    fn binary_radix() -> Self {
+        Self::one() + Self::one()
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn decimal_radix() -> Self {
+        let two = Self::binary_radix();
+        (two * two + Self::one()) * two
+    }
+
+    /// The number of bits of the primitive types, whose numbers convert to and from a `u128`.
+    #[doc(hidden)]
+    const PRIMITIVE_BITS: Option<u32> = None;
 
+    /// Converts a number of a primitive type to a `u128`.
     #[doc(hidden)]
-    const DECIMAL_RADIX: Self;
+    #[inline]
+    fn to_primitive(self) -> u128 {
+        unreachable!("not a primitive type")
+    }
+
+    /// Converts a `u128` to a primitive type, keeping its low bits.
+    #[doc(hidden)]
+    #[inline]
+    fn from_primitive(number: u128) -> Self {
+        let _ = number;
+        unreachable!("not a primitive type")
+    }
+
+    /// Returns `self * factor + addend`, or `None` if it overflows, for the types other than the
+    /// primitive ones, checking their wrapping operations by default.
+    #[doc(hidden)]
+    #[inline]
+    fn try_mul_add(self, factor: Self, addend: Self) -> Option<Self> {
+        let product = self.wrapping_mul(&factor);
+        if !self.is_zero() && product / self != factor {
+            return None;
+        }
+
+        let sum = product.wrapping_add(&addend);
+        if sum < product {
+            None
+        } else {
+            Some(sum)
+        }
+    }
 
     /// Creates a `DigitsIterator` with a given `radix`.
     ///
@@ -53,7 +189,7 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert_eq!(digits.next(), None);
     /// ```
     fn into_binary_digits(self) -> DigitsIterator<Self> {
-        self.into_digits(Self::BINARY_RADIX).unwrap()
+        self.into_digits(Self::binary_radix()).unwrap()
     }
 
     /// Creates a `DigitsIterator` with a decimal radix.
@@ -70,7 +206,88 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert_eq!(digits.next(), None);
     /// ```
     fn into_decimal_digits(self) -> DigitsIterator<Self> {
-        self.into_digits(Self::DECIMAL_RADIX).unwrap()
+        self.into_digits(Self::decimal_radix()).unwrap()
+    }
+
+    /// Creates a `ReversedDigits`, iterating over the digits from the least significant, with a
+    /// given `radix`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// let mut digits = 12_u32.into_reversed_digits(10).unwrap();
+    ///
+    /// assert_eq!(digits.next(), Some(2));
+    /// assert_eq!(digits.next(), Some(1));
+    /// assert_eq!(digits.next(), None);
+    /// ```
+    fn into_reversed_digits(self, radix: Self) -> Result<ReversedDigits<Self>, RadixError> {
+        ReversedDigits::new(self, radix)
+    }
+
+    /// Creates a `DigitRuns` over the runs of equal digits with a given `radix`, most significant
+    /// first.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// // The next term of the look-and-say sequence.
+    /// let next = 1211_u32
+    ///     .digit_runs(10)
+    ///     .unwrap()
+    ///     .fold(0, |next, (digit, len)| next * 100 + len as u32 * 10 + digit);
+    /// assert_eq!(next, 111_221);
+    /// ```
+    fn digit_runs(self, radix: Self) -> Result<DigitRuns<Self>, RadixError> {
+        self.into_digits(radix).map(DigitsIterator::runs)
+    }
+
+    /// Creates a `ZipDigits` over the pairs of digits of `self` and `other` with a given `radix`,
+    /// least significant first, the shorter number being padded with zeroes.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// // Adding without carries.
+    /// let (mut sum, mut power) = (0, 1);
+    /// for (a, b) in 1234_u32.zip_digits(987, 10).unwrap() {
+    ///     sum += (a + b) % 10 * power;
+    ///     power *= 10;
+    /// }
+    /// assert_eq!(sum, 1111);
+    /// ```
+    fn zip_digits(self, other: Self, radix: Self) -> Result<ZipDigits<Self>, RadixError> {
+        ZipDigits::new(self, other, radix)
+    }
+
+    /// Sums the digits for a given `radix`.
+    ///
+    /// The sum is never larger than the number itself. Returns `Err(RadixError)` if the radix is
+    /// 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(1234_u32.digit_sum(10).unwrap(), 10);
+    /// assert_eq!(255_u8.digit_sum(2).unwrap(), 8);
+    /// ```
+    fn digit_sum(self, radix: Self) -> Result<Self, RadixError> {
+        self.into_reversed_digits(radix)
+            .map(ReversedDigits::digit_sum)
     }
 
     /// Counts the number of digits for a given `radix`.
@@ -100,7 +317,7 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert_eq!(n.nbr_binary_digits(), 4);
     /// ```
     fn nbr_binary_digits(self) -> usize {
-        self.nbr_digits(Self::BINARY_RADIX).unwrap()
+        self.nbr_digits(Self::binary_radix()).unwrap()
     }
 
     /// Counts the number of decimal digits.
@@ -115,11 +332,14 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert_eq!(n.nbr_decimal_digits(), 3);
     /// ```
     fn nbr_decimal_digits(self) -> usize {
-        self.nbr_digits(Self::DECIMAL_RADIX).unwrap()
+        self.nbr_digits(Self::decimal_radix()).unwrap()
     }
 
     /// Checks if it is a palindrome for a given `radix`.
     ///
+    /// Only the low half of the digits is reversed and compared with the high half, so that
+    /// nothing can overflow.
+    ///
     /// Returns `Err(RadixError)` if the radix is 0 or 1.
     ///
     /// # Example
@@ -133,15 +353,50 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert!(n.is_palindrome(10).unwrap());
     /// ```
     fn is_palindrome(self, radix: Self) -> Result<bool, RadixError> {
-        let mut iter = self.into_digits(radix)?;
+        if radix == Self::zero() {
+            return Err(RadixError::Radix0);
+        } else if radix == Self::one() {
+            return Err(RadixError::Radix1);
+        }
 
-        while iter.len() > 1 {
-            if iter.next() != iter.next_back() {
-                return Ok(false);
-            }
+        if self < radix {
+            return Ok(true);
+        } else if self % radix == Self::zero() {
+            // The leading digit is never 0.
+            return Ok(false);
+        }
+
+        let strategy = Strategy::new(radix);
+        let (mut high, mut low) = (self, Self::zero());
+        while high > low {
+            let quotient = strategy.div(high, radix);
+            low = low * radix + (high - quotient * radix);
+            high = quotient;
         }
 
-        Ok(true)
+        // With an odd number of digits, the middle one ends up in `low`.
+        Ok(high == low || high == strategy.div(low, radix))
+    }
+
+    /// Checks if the least significant digits, read backwards, are the digits of `other` for a
+    /// given `radix`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert!(4321_u32.ends_with_reverse_of(12, 10).unwrap());
+    /// assert!(!4321_u32.ends_with_reverse_of(21, 10).unwrap());
+    /// assert!(!21_u32.ends_with_reverse_of(120, 10).unwrap());
+    /// ```
+    fn ends_with_reverse_of(self, other: Self, radix: Self) -> Result<bool, RadixError> {
+        let mut low = self.into_reversed_digits(radix)?;
+        Ok(other
+            .into_digits(radix)?
+            .all(|digit| low.next() == Some(digit)))
     }
 
     /// Checks if it is a palindrome under a binary number system.
@@ -157,7 +412,7 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert!(n.is_binary_palindrome());
     /// ```
     fn is_binary_palindrome(self) -> bool {
-        self.is_palindrome(Self::BINARY_RADIX).unwrap()
+        self.is_palindrome(Self::binary_radix()).unwrap()
     }
 
     /// Checks if it is a palindrome under a decimal number system.
@@ -173,7 +428,7 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert!(n.is_decimal_palindrome());
     /// ```
     fn is_decimal_palindrome(self) -> bool {
-        self.is_palindrome(Self::DECIMAL_RADIX).unwrap()
+        self.is_palindrome(Self::decimal_radix()).unwrap()
     }
 
     /// Reverses the digits, returning a new number with the digits reversed, using wrapping
@@ -192,7 +447,7 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// let reversed = n.reverse_digits(10).unwrap();
     /// assert_eq!(reversed, 321);
     ///
-    /// /// Wrapping on overflow.
+    /// // Wrapping on overflow.
     /// let n = 255_u8;
     /// let reversed = n.reverse_digits(10).unwrap();
     /// assert_ne!(reversed, n);
@@ -237,7 +492,7 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert_eq!(m, 0b11);
     /// ```
     fn reverse_binary_digits(self) -> Self {
-        self.reverse_digits(Self::BINARY_RADIX).unwrap()
+        self.reverse_digits(Self::binary_radix()).unwrap()
     }
 
     /// Reverses the digits under a decimal number system, using wrapping semantics if necessary.
@@ -254,7 +509,521 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     /// assert_eq!(reversed, 321);
     /// ```
     fn reverse_decimal_digits(self) -> Self {
-        self.reverse_digits(Self::DECIMAL_RADIX).unwrap()
+        self.reverse_digits(Self::decimal_radix()).unwrap()
+    }
+
+    /// Sorts the digits in ascending order under a given `radix`, returning the smallest number
+    /// made of them; zeroes become leading zeroes, and are dropped.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(3102_u32.sort_digits_ascending(10).unwrap(), 123);
+    /// ```
+    fn sort_digits_ascending(self, radix: Self) -> Result<Self, RadixError> {
+        digits::sorted_number(self, radix, false)
+    }
+
+    /// Sorts the digits in descending order under a given `radix`, returning the largest number
+    /// made of them, using wrapping semantics if necessary.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// // One step of the Kaprekar routine.
+    /// let n = 3524_u32;
+    /// let step = n.sort_digits_descending(10).unwrap() - n.sort_digits_ascending(10).unwrap();
+    /// assert_eq!(step, 5432 - 2345);
+    ///
+    /// // Wrapping on overflow.
+    /// assert_eq!(129_u8.sort_digits_descending(10).unwrap(), (921 % 256) as u8);
+    /// ```
+    fn sort_digits_descending(self, radix: Self) -> Result<Self, RadixError> {
+        digits::sorted_number(self, radix, true)
+    }
+
+    /// Rotates the digits to the left by `k` digits under a given `radix`, modulo their number:
+    /// the `k` most significant digits become the least significant ones.
+    ///
+    /// Only the digits of the number are rotated, so that zeroes moving to the front are dropped:
+    /// 100 rotated to the left by one digit is 001, that is 1. Use a
+    /// [`Digits`](digits/struct.Digits.html) to keep the width.
+    ///
+    /// Returns `Ok(None)` if the result does not fit in `Self`, and `Err(RadixError)` if the radix
+    /// is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(1234_u32.rotate_digits_left(1, 10), Ok(Some(2341)));
+    /// assert_eq!(100_u32.rotate_digits_left(1, 10), Ok(Some(1)));
+    /// assert_eq!(0b100_u8.rotate_digits_left(2, 2), Ok(Some(0b10)));
+    /// assert_eq!(129_u8.rotate_digits_left(2, 10), Ok(None));
+    /// ```
+    fn rotate_digits_left(self, k: usize, radix: Self) -> Result<Option<Self>, RadixError> {
+        digits::edited_number(self, radix, |digits| {
+            digits.rotate_digits_left(k);
+            Ok(())
+        })
+    }
+
+    /// Rotates the digits to the right by `k` digits under a given `radix`, modulo their number:
+    /// the `k` least significant digits become the most significant ones.
+    ///
+    /// Only the digits of the number are rotated, so that zeroes moving to the front are dropped:
+    /// 120 rotated to the right by one digit is 012, that is 12.
+    ///
+    /// Returns `Ok(None)` if the result does not fit in `Self`, and `Err(RadixError)` if the radix
+    /// is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// // 197 is a circular prime: 971 and 719 are primes too.
+    /// assert_eq!(197_u32.rotate_digits_right(1, 10), Ok(Some(719)));
+    /// assert_eq!(197_u32.rotate_digits_right(2, 10), Ok(Some(971)));
+    /// assert_eq!(120_u32.rotate_digits_right(1, 10), Ok(Some(12)));
+    /// assert_eq!(129_u8.rotate_digits_right(1, 10), Ok(None));
+    /// ```
+    fn rotate_digits_right(self, k: usize, radix: Self) -> Result<Option<Self>, RadixError> {
+        digits::edited_number(self, radix, |digits| {
+            digits.rotate_digits_right(k);
+            Ok(())
+        })
+    }
+
+    /// Inserts `digit` at `pos` under a given `radix`, counting the positions from the most
+    /// significant digit, and shifting the less significant digits.
+    ///
+    /// Inserting a zero at the front leaves the number unchanged, as leading zeroes are dropped.
+    ///
+    /// Returns `Ok(None)` if the digit is not smaller than the radix or if the result does not
+    /// fit in `Self`, and `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is larger than the number of digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(1234_u32.insert_digit(2, 9, 10), Ok(Some(12_934)));
+    /// assert_eq!(1234_u32.insert_digit(4, 5, 10), Ok(Some(12_345)));
+    /// assert_eq!(25_u8.insert_digit(0, 3, 10), Ok(None));
+    /// ```
+    fn insert_digit(
+        self,
+        pos: usize,
+        digit: Self,
+        radix: Self,
+    ) -> Result<Option<Self>, RadixError> {
+        digits::edited_number(self, radix, |digits| digits.insert(pos, digit))
+    }
+
+    /// Removes the digit at `pos` under a given `radix`, counting the positions from the most
+    /// significant digit, and shifting the less significant digits.
+    ///
+    /// The zeroes that become leading zeroes are dropped, and removing the only digit gives 0.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is not smaller than the number of digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(12_934_u32.remove_digit(2, 10), Ok(1234));
+    /// assert_eq!(1034_u32.remove_digit(0, 10), Ok(34));
+    /// ```
+    fn remove_digit(self, pos: usize, radix: Self) -> Result<Self, RadixError> {
+        digits::edited_number(self, radix, |digits| {
+            digits.remove(pos);
+            Ok(())
+        })
+        // Removing a digit never makes a number larger.
+        .map(Option::unwrap)
+    }
+
+    /// Replaces the digit at `pos` by `digit` under a given `radix`, counting the positions from
+    /// the most significant digit.
+    ///
+    /// Replacing the most significant digit by a zero drops it, along with the zeroes following
+    /// it.
+    ///
+    /// Returns `Ok(None)` if the digit is not smaller than the radix or if the result does not
+    /// fit in `Self`, and `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is not smaller than the number of digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(1234_u32.replace_digit(1, 9, 10), Ok(Some(1934)));
+    /// assert_eq!(1034_u32.replace_digit(0, 0, 10), Ok(Some(34)));
+    /// assert_eq!(255_u8.replace_digit(0, 3, 10), Ok(None));
+    /// ```
+    fn replace_digit(
+        self,
+        pos: usize,
+        digit: Self,
+        radix: Self,
+    ) -> Result<Option<Self>, RadixError> {
+        digits::edited_number(self, radix, |digits| digits.set_digit(pos, digit).map(drop))
+    }
+
+    /// Collapses the runs of equal digits under a given `radix` into a single digit.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(1_223_111_u32.dedup_digits(10), Ok(1231));
+    /// assert_eq!(0b1100_u8.dedup_digits(2), Ok(0b10));
+    /// ```
+    fn dedup_digits(self, radix: Self) -> Result<Self, RadixError> {
+        digits::edited_number(self, radix, |digits| {
+            digits.dedup_digits();
+            Ok(())
+        })
+        // Removing digits never makes a number larger.
+        .map(Option::unwrap)
+    }
+
+    /// Keeps the first occurrence of each digit under a given `radix`, removing the others.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(1_223_111_u32.unique_digits(10), Ok(123));
+    /// assert_eq!(3_141_592_u32.unique_digits(10), Ok(314_592));
+    /// ```
+    fn unique_digits(self, radix: Self) -> Result<Self, RadixError> {
+        digits::edited_number(self, radix, |digits| {
+            digits.unique_digits();
+            Ok(())
+        })
+        // Removing digits never makes a number larger.
+        .map(Option::unwrap)
+    }
+
+    /// Returns the number made of the digits at the positions of `range` under a given `radix`,
+    /// counting the positions from the most significant digit, or `None` if the range is out of
+    /// the digits.
+    ///
+    /// The digits are extracted with a division and a modulo; an empty range gives 0.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::ops::Bound;
+    /// use radixal::IntoDigits;
+    ///
+    /// // The date and the sequence number of an identifier.
+    /// let id = 202_610_140_042_u64;
+    /// assert_eq!(id.sub_number(..8, 10), Ok(Some(20_261_014)));
+    /// assert_eq!(id.sub_number(4..6, 10), Ok(Some(10)));
+    /// assert_eq!(id.sub_number(8.., 10), Ok(Some(42)));
+    /// assert_eq!(id.sub_number(8..13, 10), Ok(None));
+    /// assert_eq!(id.sub_number(..=usize::MAX, 10), Ok(None));
+    ///
+    /// let after_all = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+    /// assert_eq!(id.sub_number(after_all, 10), Ok(None));
+    /// ```
+    fn sub_number<R: RangeBounds<usize>>(
+        self,
+        range: R,
+        radix: Self,
+    ) -> Result<Option<Self>, RadixError> {
+        let powers = RadixPowers::new(radix)?;
+        let len = powers.nbr_digits(self);
+        // A bound past `usize::MAX` is out of the digits.
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => Some(len),
+        };
+        let (start, end) = match (start, end) {
+            (Some(start), Some(end)) if start <= end && end <= len => (start, end),
+            _ => return Ok(None),
+        };
+        if start == end {
+            return Ok(Some(Self::zero()));
+        }
+
+        // `radix^(len - end) <= self` as `end > 0`, and `radix^(end - start)` fits unless every
+        // digit is kept.
+        let powers = powers.as_slice();
+        let high = self / powers[len - end];
+        Ok(Some(if end - start < len {
+            high % powers[end - start]
+        } else {
+            high
+        }))
+    }
+
+    /// Concatenates the digits of `self` and `other` under a given `radix`, the digits of
+    /// `other` being the least significant ones.
+    ///
+    /// A zero `other` still appends its digit, so that `12` followed by `0` is `120`, while a
+    /// zero `self` is a leading zero, and is dropped.
+    ///
+    /// Returns `Ok(None)` if the result does not fit in `Self`, and `Err(RadixError)` if the radix
+    /// is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// // The concatenated product of 192 by 1, 2 and 3 is pandigital.
+    /// let n = 192_u32.concat_digits(384, 10).unwrap().unwrap();
+    /// assert_eq!(n.concat_digits(576, 10), Ok(Some(192_384_576)));
+    ///
+    /// assert_eq!(12_u32.concat_digits(0, 10), Ok(Some(120)));
+    /// assert_eq!(0b11_u8.concat_digits(0b101, 2), Ok(Some(0b11101)));
+    /// assert_eq!(25_u8.concat_digits(6, 10), Ok(None));
+    /// ```
+    fn concat_digits(self, other: Self, radix: Self) -> Result<Option<Self>, RadixError> {
+        Ok(other.into_digits(radix)?.try_fold(self, |number, digit| {
+            parse::push_digit(number, radix, digit)
+        }))
+    }
+
+    /// Interleaves the digits of `self` and `other` under a given `radix`, starting with a digit
+    /// of `self`, and handling numbers of different lengths as set by `mode`.
+    ///
+    /// Returns `Ok(None)` if the result does not fit in `Self`, and `Err(RadixError)` if the radix
+    /// is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::digits::Interleave;
+    /// use radixal::IntoDigits;
+    ///
+    /// // A Morton code, the bits of `x` and `y` alternating.
+    /// let (x, y) = (0b11_u32, 0b101);
+    /// assert_eq!(x.interleave_digits(y, Interleave::Pad, 2), Ok(Some(0b01_10_11)));
+    ///
+    /// assert_eq!(123_u32.interleave_digits(45, Interleave::Pad, 10), Ok(Some(102_435)));
+    /// assert_eq!(123_u32.interleave_digits(45, Interleave::Append, 10), Ok(Some(14_253)));
+    /// assert_eq!(99_u8.interleave_digits(9, Interleave::Append, 10), Ok(None));
+    /// ```
+    fn interleave_digits(
+        self,
+        other: Self,
+        mode: digits::Interleave,
+        radix: Self,
+    ) -> Result<Option<Self>, RadixError> {
+        digits::interleaved_number(self, other, radix, mode)
+    }
+
+    /// Rounds the number to `significant` digits (at least one) under a given `radix`,
+    /// following `mode`, replacing the other digits with zeroes.
+    ///
+    /// The mantissa and the exponent of the rounded number are given by
+    /// [`format::round_significant`](format/fn.round_significant.html).
+    ///
+    /// Returns `Ok(None)` if rounding up does not fit in `Self`, and `Err(RadixError)` if the
+    /// radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::format::RoundingMode;
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(1250_u32.round_to_significant(2, RoundingMode::HalfUp, 10), Ok(Some(1300)));
+    /// assert_eq!(1250_u32.round_to_significant(2, RoundingMode::HalfEven, 10), Ok(Some(1200)));
+    /// assert_eq!(0x1234_u32.round_to_significant(1, RoundingMode::Ceil, 16), Ok(Some(0x2000)));
+    /// assert_eq!(
+    ///     0b1011_u8.round_to_significant(2, RoundingMode::TowardZero, 2),
+    ///     Ok(Some(0b1000))
+    /// );
+    /// assert_eq!(250_u8.round_to_significant(1, RoundingMode::HalfUp, 10), Ok(None));
+    /// ```
+    fn round_to_significant(
+        self,
+        significant: usize,
+        mode: format::RoundingMode,
+        radix: Self,
+    ) -> Result<Option<Self>, RadixError> {
+        Ok(format::round_significant(self, radix, significant, mode)?.value())
+    }
+
+    /// Returns the largest power of `radix` not above the number.
+    ///
+    /// Returns `Ok(None)` if the number is 0, and `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(4242_u32.floor_to_power(10), Ok(Some(1000)));
+    /// assert_eq!(1000_u32.floor_to_power(10), Ok(Some(1000)));
+    /// assert_eq!(0x1ff_u16.floor_to_power(16), Ok(Some(0x100)));
+    /// assert_eq!(1_u8.floor_to_power(3), Ok(Some(1)));
+    /// assert_eq!(0_u8.floor_to_power(3), Ok(None));
+    /// ```
+    fn floor_to_power(self, radix: Self) -> Result<Option<Self>, RadixError> {
+        let powers = RadixPowers::new(radix)?;
+        Ok(if self == Self::zero() {
+            None
+        } else {
+            Some(powers.as_slice()[powers.nbr_digits(self) - 1])
+        })
+    }
+
+    /// Returns the smallest power of `radix` not below the number, 1 for 0.
+    ///
+    /// Returns `Ok(None)` if the power does not fit in `Self`, and `Err(RadixError)` if the
+    /// radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(4242_u32.ceil_to_power(10), Ok(Some(10_000)));
+    /// assert_eq!(1000_u32.ceil_to_power(10), Ok(Some(1000)));
+    /// assert_eq!(0_u8.ceil_to_power(10), Ok(Some(1)));
+    /// assert_eq!(129_u8.ceil_to_power(2), Ok(None));
+    /// ```
+    fn ceil_to_power(self, radix: Self) -> Result<Option<Self>, RadixError> {
+        match self.floor_to_power(radix)? {
+            None => Ok(Some(Self::one())),
+            Some(power) if power == self => Ok(Some(power)),
+            Some(power) => Ok(power.checked_mul(radix)),
+        }
+    }
+
+    /// Returns the power of `radix` nearest to the number, the larger one on ties, 1 for 0.
+    ///
+    /// Returns `Ok(None)` if the power does not fit in `Self`, and `Err(RadixError)` if the
+    /// radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(54_u32.round_to_power(10), Ok(Some(10)));
+    /// assert_eq!(55_u32.round_to_power(10), Ok(Some(100)));
+    /// assert_eq!(0xa0_u32.round_to_power(16), Ok(Some(0x100)));
+    /// assert_eq!(6_u8.round_to_power(2), Ok(Some(8)));
+    /// assert_eq!(200_u8.round_to_power(2), Ok(None));
+    /// ```
+    fn round_to_power(self, radix: Self) -> Result<Option<Self>, RadixError> {
+        let power = match self.floor_to_power(radix)? {
+            None => return Ok(Some(Self::one())),
+            Some(power) => power,
+        };
+
+        // With `self = q * power + r`, the distances to `power` and to `power * radix` are
+        // `(q - 1) * power + r` and `(radix - q) * power - r`, so that the upper one is nearer
+        // or as near if `q > radix - q`, or if `q = radix - q` and `r >= power - r`.
+        let (q, r) = (self / power, self % power);
+        let rest = radix - q;
+        let round_up = q > rest || (q == rest && r >= power - r);
+        if round_up {
+            Ok(power.checked_mul(radix))
+        } else {
+            Ok(Some(power))
+        }
+    }
+
+    /// Decomposes the number into `(m, e)` with `self = m * radix^e` and `m` not divisible by
+    /// `radix`, i.e. strips and counts its trailing zeroes. 0 is decomposed into `(0, 0)`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(4_200_u32.normalize(10), Ok((42, 2)));
+    /// assert_eq!(42_u32.normalize(10), Ok((42, 0)));
+    /// assert_eq!(0x3000_u16.normalize(16), Ok((3, 3)));
+    /// assert_eq!(0_u8.normalize(2), Ok((0, 0)));
+    /// ```
+    fn normalize(self, radix: Self) -> Result<(Self, u32), RadixError> {
+        self.nbr_digits(radix)?;
+        let (mut mantissa, mut exponent) = (self, 0);
+        while !mantissa.is_zero() && (mantissa % radix).is_zero() {
+            mantissa = mantissa / radix;
+            exponent += 1;
+        }
+        Ok((mantissa, exponent))
+    }
+
+    /// Returns `self * radix^exponent`, the inverse of [`normalize`](#method.normalize).
+    ///
+    /// Returns `Ok(None)` if it does not fit in `Self`, and `Err(RadixError)` if the radix is 0
+    /// or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::IntoDigits;
+    ///
+    /// assert_eq!(42_u32.denormalize(2, 10), Ok(Some(4_200)));
+    /// assert_eq!(3_u16.denormalize(3, 16), Ok(Some(0x3000)));
+    /// assert_eq!(3_u8.denormalize(2, 10), Ok(None));
+    /// assert_eq!(1_u8.denormalize(2, 1), Err(radixal::digits_iterator::RadixError::Radix1));
+    /// assert_eq!(0_u8.denormalize(u32::MAX, 10), Ok(Some(0)));
+    /// ```
+    fn denormalize(self, exponent: u32, radix: Self) -> Result<Option<Self>, RadixError> {
+        self.nbr_digits(radix)?;
+        if self.is_zero() {
+            return Ok(Some(self));
+        }
+
+        let mut number = self;
+        for _ in 0..exponent {
+            number = match number.checked_mul(radix) {
+                Some(number) => number,
+                None => return Ok(None),
+            };
+        }
+        Ok(Some(number))
     }
 
     /// Tests if `self` and `other` are composed of the same digits under a given radix.
@@ -277,7 +1046,7 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     ///
     /// assert!(!n.is_permutation(m, 10).unwrap());
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn is_permutation(self, other: Self, radix: Self) -> Result<bool, RadixError> {
         // This is reasonably efficient, but can be improved by short-circuiting.
         let mut a: Vec<Self> = self.into_digits(radix)?.filter(|&n| !n.is_zero()).collect();
@@ -315,9 +1084,9 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     ///
     /// assert!(!n.is_decimal_permutation(m));
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn is_decimal_permutation(self, other: Self) -> bool {
-        self.is_permutation(other, Self::DECIMAL_RADIX).unwrap()
+        self.is_permutation(other, Self::decimal_radix()).unwrap()
     }
 
     /// Tests if `self` and `other` are composed of the same digits under a binary radix.
@@ -343,30 +1112,384 @@ pub trait IntoDigits: Copy + PartialOrd + Ord + WrappingAdd + WrappingMul + Unsi
     ///
     /// assert!(!n.is_binary_permutation(m));
     /// ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn is_binary_permutation(self, other: Self) -> bool {
-        self.is_permutation(other, Self::BINARY_RADIX).unwrap()
+        self.is_permutation(other, Self::binary_radix()).unwrap()
+    }
+}
+
+/// The conversions, the checked arithmetic, the logarithms and the divisions by an invariant
+/// radix that the digits are computed with.
+///
+/// The primitive types convert their numbers to the narrowest primitive type holding them, where
+/// they take the same instructions as the primitive type itself, and have their digits split
+/// with shifts, masks and reciprocals. The other types fall back on plain divisions.
+pub(crate) trait RadixMath: IntoDigits {
+    /// Returns the number of bits of a primitive type, or `None` for the other types.
+    #[inline]
+    fn bits() -> Option<u32> {
+        Self::PRIMITIVE_BITS
+    }
+
+    /// Returns the largest number of a primitive type, or `None` for the other types.
+    #[inline]
+    fn max_number() -> Option<Self> {
+        Self::bits().map(|bits| Self::from_primitive(u128::MAX >> (u128::BITS - bits)))
+    }
+
+    /// Returns the largest number, which is searched for above `self` for the types other than
+    /// the primitive ones, so that a number must then be known to overflow.
+    fn max_above(self) -> Self {
+        if let Some(max) = Self::max_number() {
+            return max;
+        }
+
+        // Adds the largest powers of two that still fit, until even 1 does not.
+        let (mut max, mut step) = (self, Self::one());
+        loop {
+            match max.checked_add(step) {
+                Some(next) => {
+                    max = next;
+                    step = step.checked_add(step).unwrap_or(step);
+                }
+                None if step == Self::one() => return max,
+                None => step = step / Self::binary_radix(),
+            }
+        }
+    }
+
+    /// Returns `self + other`, or `None` if it overflows.
+    #[inline]
+    fn checked_add(self, other: Self) -> Option<Self> {
+        if Self::bits().is_none() {
+            return self.try_mul_add(Self::one(), other);
+        }
+
+        let sum = self.wrapping_add(&other);
+        if sum < self {
+            None
+        } else {
+            Some(sum)
+        }
+    }
+
+    /// Returns `self * other`, or `None` if it overflows.
+    // The conversions to `u128` are only needed for the narrower primitive types.
+    #[allow(clippy::useless_conversion)]
+    #[inline]
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        with_primitive!(
+            Self::bits(),
+            |P| (self.to_primitive() as P)
+                .checked_mul(other.to_primitive() as P)
+                .map(|product| Self::from_primitive(product.into())),
+            self.try_mul_add(other, Self::zero())
+        )
+    }
+
+    /// Converts the number to a `u128`, or returns `None` if it does not fit.
+    #[inline]
+    fn to_u128(self) -> Option<u128> {
+        if Self::bits().is_some() {
+            return Some(self.to_primitive());
+        }
+
+        let (mut number, mut value, mut bit) = (self, 0_u128, 0);
+        while !number.is_zero() {
+            if bit == u128::BITS {
+                return None;
+            } else if !(number % Self::binary_radix()).is_zero() {
+                value |= 1 << bit;
+            }
+            number = number / Self::binary_radix();
+            bit += 1;
+        }
+        Some(value)
+    }
+
+    /// Converts the number to a `usize`, or returns `None` if it does not fit.
+    #[inline]
+    fn to_usize(self) -> Option<usize> {
+        self.to_u128()
+            .and_then(|number| usize::try_from(number).ok())
+    }
+
+    /// Converts a `u128` to a number, or returns `None` if it does not fit.
+    #[inline]
+    fn from_u128(number: u128) -> Option<Self> {
+        match Self::bits() {
+            Some(bits) if bits < u128::BITS && number >> bits != 0 => None,
+            Some(_) => Some(Self::from_primitive(number)),
+            None => {
+                let mut result = Self::zero();
+                for bit in (0..u128::BITS - number.leading_zeros()).rev() {
+                    result = result.checked_mul(Self::binary_radix())?;
+                    if number >> bit & 1 == 1 {
+                        result = result.checked_add(Self::one())?;
+                    }
+                }
+                Some(result)
+            }
+        }
+    }
+
+    /// Converts a `usize` to a number, or returns `None` if it does not fit.
+    #[inline]
+    fn from_usize(number: usize) -> Option<Self> {
+        Self::from_u128(number as u128)
+    }
+
+    /// Returns the base 2 logarithm of a power of two of a primitive type, or `None` for the
+    /// other numbers and types.
+    #[inline]
+    fn power_of_two_log(self) -> Option<usize> {
+        with_primitive!(
+            Self::bits(),
+            |P| {
+                let number = self.to_primitive() as P;
+                if number.is_power_of_two() {
+                    Some(number.trailing_zeros() as usize)
+                } else {
+                    None
+                }
+            },
+            None
+        )
+    }
+
+    /// Shifts a number of a primitive type to the left, dropping the overflowing bits.
+    // The conversions to `u128` are only needed for the narrower primitive types.
+    #[allow(clippy::useless_conversion)]
+    #[inline]
+    fn shift_left(self, shift: usize) -> Self {
+        with_primitive!(
+            Self::bits(),
+            |P| Self::from_primitive(((self.to_primitive() as P) << shift).into()),
+            unreachable!("not a primitive type")
+        )
+    }
+
+    /// Shifts a number of a primitive type to the right.
+    // The conversions to `u128` are only needed for the narrower primitive types.
+    #[allow(clippy::useless_conversion)]
+    #[inline]
+    fn shift_right(self, shift: usize) -> Self {
+        with_primitive!(
+            Self::bits(),
+            |P| Self::from_primitive(((self.to_primitive() as P) >> shift).into()),
+            unreachable!("not a primitive type")
+        )
+    }
+
+    /// Keeps the `bits` low bits, fewer than the bits of the type, of a number of a primitive
+    /// type.
+    // The conversions to `u128` are only needed for the narrower primitive types.
+    #[allow(clippy::useless_conversion)]
+    #[inline]
+    fn low_bits(self, bits: usize) -> Self {
+        with_primitive!(
+            Self::bits(),
+            |P| {
+                let mask: P = (1 << bits) - 1;
+                Self::from_primitive(((self.to_primitive() as P) & mask).into())
+            },
+            unreachable!("not a primitive type")
+        )
+    }
+
+    /// Returns the logarithm in the given `radix`, rounded down, or `None` if the number is 0.
+    ///
+    /// The radix must be at least 2.
+    #[inline]
+    fn checked_ilog_radix(self, radix: Self) -> Option<usize> {
+        with_primitive!(
+            Self::bits(),
+            |P| {
+                let (number, radix) = (self.to_primitive() as P, radix.to_primitive() as P);
+                if radix.is_power_of_two() {
+                    number
+                        .checked_ilog2()
+                        .map(|log| (log / radix.trailing_zeros()) as usize)
+                } else if radix == 10 {
+                    number.checked_ilog10().map(|log| log as usize)
+                } else {
+                    number.checked_ilog(radix).map(|log| log as usize)
+                }
+            },
+            {
+                if self.is_zero() {
+                    return None;
+                }
+
+                let (mut number, mut log) = (self, 0);
+                while number >= radix {
+                    number = number / radix;
+                    log += 1;
+                }
+                Some(log)
+            }
+        )
+    }
+
+    /// Returns the logarithm in the given `radix`, rounded down, of a number which is known not
+    /// to be 0.
+    ///
+    /// The radix must be at least 2. Panics if the number is 0.
+    #[inline]
+    fn ilog_radix(self, radix: Self) -> usize {
+        self.checked_ilog_radix(radix)
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    /// Returns the multiplier and the shift dividing by `self`, which must be at least 2, or
+    /// `None` if dividing is faster.
+    #[inline]
+    fn reciprocal(self) -> Option<(Self, usize)> {
+        let bits = Self::bits().filter(|&bits| bits <= u64::BITS)?;
+        let (multiplier, shift) = reciprocal::reciprocal(self.to_primitive() as u64, bits);
+        // The actual multiplier is `2^bits + multiplier`, so that only the low bits are kept.
+        let multiplier = multiplier & (u64::MAX >> (u64::BITS - bits));
+        Some((Self::from_primitive(multiplier.into()), shift))
+    }
+
+    /// Divides by the divisor of the multiplier and the shift returned by `reciprocal`.
+    // The casts to `u64` and the conversion to `u128` are only needed for the narrower primitive
+    // types.
+    #[allow(clippy::unnecessary_cast, clippy::useless_conversion)]
+    #[inline]
+    fn div_reciprocal(self, multiplier: Self, shift: usize) -> Self {
+        let bits = Self::bits();
+        with_primitive!(
+            bits,
+            |P| {
+                // `reciprocal` only returns a multiplier for the types of at most 64 bits, and
+                // the quotient is at most the number.
+                let (n, multiplier) = (self.to_primitive() as P, multiplier.to_primitive() as P);
+                let quotient = reciprocal::divide(
+                    n as u64,
+                    multiplier as u64,
+                    shift,
+                    bits.unwrap_or_default(),
+                );
+                Self::from_primitive(quotient.into())
+            },
+            unreachable!("not a primitive type")
+        )
     }
 }
 
+impl<T: IntoDigits> RadixMath for T {}
+
 macro_rules! impl_digits {
     ( $($t:ty)* ) => {
         $(
             impl IntoDigits for $t {
-                const BINARY_RADIX: Self = 2;
-                const DECIMAL_RADIX: Self = 10;
+                const PRIMITIVE_BITS: Option<u32> = Some(<$t>::BITS);
 
-                #[cfg(feature = "std")]
+                #[inline]
+                fn binary_radix() -> Self {
+                    2
+                }
+
+                #[inline]
+                fn decimal_radix() -> Self {
+                    10
+                }
+
+                #[inline]
+                fn to_primitive(self) -> u128 {
+                    self as u128
+                }
+
+                #[inline]
+                fn from_primitive(number: u128) -> Self {
+                    number as $t
+                }
+
+                #[cfg(feature = "alloc")]
                 fn is_binary_permutation(self, other: Self) -> bool {
                     self.count_ones() == other.count_ones()
                 }
             }
+
             impl IntoDigits for Wrapping<$t> {
-                const BINARY_RADIX: Self = Wrapping(2);
-                const DECIMAL_RADIX: Self = Wrapping(10);
+                const PRIMITIVE_BITS: Option<u32> = Some(<$t>::BITS);
+
+                #[inline]
+                fn binary_radix() -> Self {
+                    Wrapping(2)
+                }
+
+                #[inline]
+                fn decimal_radix() -> Self {
+                    Wrapping(10)
+                }
+
+                #[inline]
+                fn to_primitive(self) -> u128 {
+                    self.0 as u128
+                }
+
+                #[inline]
+                fn from_primitive(number: u128) -> Self {
+                    Wrapping(number as $t)
+                }
             }
         )*
     };
 }
 
 impl_digits!(u8 u16 u32 u64 u128 usize);
+
+/// An extension trait on signed integer types (`i8`, `i16`, `i32`, `i64`, `i128` and `isize`)
+/// and the corresponding `Wrapping` type, splitting them into a sign and an unsigned magnitude.
+///
+/// # Example
+///
+/// ```
+/// use radixal::SignedInteger;
+///
+/// assert!(SignedInteger::is_negative(-128_i8));
+/// assert_eq!(SignedInteger::magnitude(-128_i8), 128_u8);
+/// ```
+pub trait SignedInteger: Copy + NumCast {
+    /// The unsigned type of the same width.
+    type Magnitude: IntoDigits + Bounded;
+
+    /// Returns `true` if the number is strictly negative.
+    fn is_negative(self) -> bool;
+
+    /// Returns the absolute value, which fits in `Magnitude` even for the smallest number.
+    fn magnitude(self) -> Self::Magnitude;
+}
+
+macro_rules! impl_signed {
+    ( $($t:ty => $u:ty)* ) => {
+        $(
+            impl SignedInteger for $t {
+                type Magnitude = $u;
+
+                fn is_negative(self) -> bool {
+                    self < 0
+                }
+
+                fn magnitude(self) -> $u {
+                    self.unsigned_abs()
+                }
+            }
+            impl SignedInteger for Wrapping<$t> {
+                type Magnitude = Wrapping<$u>;
+
+                fn is_negative(self) -> bool {
+                    self.0 < 0
+                }
+
+                fn magnitude(self) -> Wrapping<$u> {
+                    Wrapping(self.0.unsigned_abs())
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8 => u8 i16 => u16 i32 => u32 i64 => u64 i128 => u128 isize => usize);
@@ -0,0 +1,167 @@
+//! Decimal digits of `u8` and `u16` read from precomputed tables.
+//!
+//! The digits of every `u8` are stored in a table of 768 bytes, and a `u16` is split in pairs of
+//! digits read from the table of the 100 pairs, so that no division instruction is needed.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::lookup::LookupDigits;
+//!
+//! const DIGITS: LookupDigits = LookupDigits::from_u8(42);
+//! assert_eq!(DIGITS.as_slice(), [4, 2]);
+//! assert!(LookupDigits::from_u16(65_535).eq([6, 5, 5, 3, 5]));
+//! ```
+use crate::digits_iterator::DECIMAL_PAIRS;
+use core::iter::FusedIterator;
+
+/// The number of decimal digits of `u16::MAX`.
+const CAPACITY: usize = 5;
+
+/// The three decimal digits of every `u8`, with leading zeroes.
+const U8_DIGITS: [[u8; 3]; 256] = u8_digits();
+
+const fn u8_digits() -> [[u8; 3]; 256] {
+    let mut digits = [[0; 3]; 256];
+    let mut n = 0;
+    while n < 256 {
+        digits[n] = [(n / 100) as u8, (n / 10 % 10) as u8, (n % 10) as u8];
+        n += 1;
+    }
+    digits
+}
+
+/// An iterator over the decimal digits of a `u8` or a `u16`, most significant first, like
+/// `into_decimal_digits`.
+///
+/// The digits are looked up when the iterator is created.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LookupDigits {
+    digits: [u8; CAPACITY],
+    start: usize,
+    end: usize,
+}
+
+impl LookupDigits {
+    /// Looks up the decimal digits of `number`.
+    pub const fn from_u8(number: u8) -> Self {
+        let [hundreds, tens, units] = U8_DIGITS[number as usize];
+        let len = if number >= 100 {
+            3
+        } else if number >= 10 {
+            2
+        } else {
+            1
+        };
+
+        LookupDigits {
+            digits: [0, 0, hundreds, tens, units],
+            start: CAPACITY - len,
+            end: CAPACITY,
+        }
+    }
+
+    /// Looks up the decimal digits of `number`.
+    pub const fn from_u16(number: u16) -> Self {
+        let number = number as usize;
+        let [tens, units] = DECIMAL_PAIRS[number % 100];
+        let [thousands, hundreds] = DECIMAL_PAIRS[number / 100 % 100];
+        let len = if number >= 10_000 {
+            5
+        } else if number >= 1000 {
+            4
+        } else if number >= 100 {
+            3
+        } else if number >= 10 {
+            2
+        } else {
+            1
+        };
+
+        LookupDigits {
+            digits: [(number / 10_000) as u8, thousands, hundreds, tens, units],
+            start: CAPACITY - len,
+            end: CAPACITY,
+        }
+    }
+
+    /// Returns the remaining digits.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.digits[self.start..self.end]
+    }
+}
+
+impl Iterator for LookupDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else {
+            self.start += 1;
+            Some(self.digits[self.start - 1])
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for LookupDigits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(self.digits[self.end])
+        }
+    }
+}
+
+impl ExactSizeIterator for LookupDigits {}
+
+impl FusedIterator for LookupDigits {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    #[test]
+    fn test_every_u8() {
+        for number in 0..=u8::MAX {
+            let digits = LookupDigits::from_u8(number);
+            assert!(
+                digits.clone().eq(number.into_decimal_digits()),
+                "{}",
+                number
+            );
+            assert!(digits.clone().rev().eq(number.into_decimal_digits().rev()));
+            assert_eq!(digits, LookupDigits::from_u16(u16::from(number)));
+        }
+    }
+
+    #[test]
+    fn test_every_u16() {
+        for number in 0..=u16::MAX {
+            let digits = LookupDigits::from_u16(number);
+            assert_eq!(digits.len(), number.nbr_decimal_digits());
+            assert!(
+                digits.map(u16::from).eq(number.into_decimal_digits()),
+                "{}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn test_mixed_directions() {
+        let mut digits = LookupDigits::from_u16(12_345);
+        assert_eq!(digits.next_back(), Some(5));
+        assert_eq!(digits.next(), Some(1));
+        assert_eq!(digits.as_slice(), [2, 3, 4]);
+        assert_eq!(digits.len(), 3);
+    }
+}
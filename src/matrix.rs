@@ -0,0 +1,248 @@
+//! The digits of several numbers, aligned in columns as when adding them by hand.
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::powers::RadixPowers;
+use crate::IntoDigits;
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::slice;
+
+/// The digits of several numbers under a radix, one number per row, aligned on the least
+/// significant digit and padded with leading zeroes.
+///
+/// The numbers are borrowed, and each digit is computed when it is read.
+///
+/// # Example
+///
+/// ```
+/// use radixal::matrix::DigitMatrix;
+///
+/// let numbers = [478_u32, 56, 1203];
+/// let matrix = DigitMatrix::new(&numbers, 10).unwrap();
+/// assert_eq!((matrix.height(), matrix.width()), (3, 4));
+/// assert!(matrix.row(1).unwrap().eq([0, 0, 5, 6]));
+/// assert!(matrix.column(2).unwrap().eq([7, 5, 0]));
+///
+/// // Column addition, from the least significant column.
+/// let mut carry = 0;
+/// let mut sum = Vec::new();
+/// for column in matrix.columns().rev() {
+///     let total = carry + column.sum::<u32>();
+///     sum.push(total % 10);
+///     carry = total / 10;
+/// }
+/// assert_eq!(sum, [7, 3, 7, 1]);
+/// assert_eq!(carry, 0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigitMatrix<'a, T: IntoDigits> {
+    numbers: &'a [T],
+    powers: RadixPowers<T>,
+    width: usize,
+}
+
+impl<'a, T: IntoDigits> DigitMatrix<'a, T> {
+    /// Creates a `DigitMatrix` of `numbers` using `radix`, as wide as the number with the most
+    /// digits.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is `0` or `1`.
+    pub fn new(numbers: &'a [T], radix: T) -> Result<Self, RadixError> {
+        let powers = RadixPowers::new(radix)?;
+        let width = numbers
+            .iter()
+            .map(|&number| powers.nbr_digits(number))
+            .max()
+            .unwrap_or(0);
+
+        Ok(DigitMatrix {
+            numbers,
+            powers,
+            width,
+        })
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.powers.radix()
+    }
+
+    /// Returns the numbers, one per row.
+    pub fn numbers(&self) -> &'a [T] {
+        self.numbers
+    }
+
+    /// Returns the number of columns, that is the number of digits of the largest number.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows, that is the number of numbers.
+    pub fn height(&self) -> usize {
+        self.numbers.len()
+    }
+
+    /// Returns the digit at `row` and `column`, counting the columns from the most significant
+    /// one, or `None` if it is out of the matrix.
+    pub fn get(&self, row: usize, column: usize) -> Option<T> {
+        let number = *self.numbers.get(row)?;
+        self.column(column).map(|column| column.digit(number))
+    }
+
+    /// Returns the digits of the number at `row`, including the leading zeroes, or `None` if it
+    /// is out of the matrix.
+    pub fn row(&self, row: usize) -> Option<DigitsIterator<T>> {
+        let number = *self.numbers.get(row)?;
+        // There is a row, so that the matrix is at least one digit wide.
+        let splitter = self.powers.as_slice()[self.width - 1];
+        Some(DigitsIterator::with_splitter(
+            number,
+            self.radix(),
+            splitter,
+            self.width,
+        ))
+    }
+
+    /// Returns the digits of every number at `column`, counting the columns from the most
+    /// significant one, or `None` if it is out of the matrix.
+    pub fn column(&self, column: usize) -> Option<Column<'a, T>> {
+        if column >= self.width {
+            return None;
+        }
+
+        Some(Column {
+            numbers: self.numbers.iter(),
+            radix: self.radix(),
+            power: self.powers.as_slice()[self.width - 1 - column],
+        })
+    }
+
+    /// Returns an iterator over the columns, from the most significant one.
+    pub fn columns(&self) -> Columns<'_, 'a, T> {
+        Columns {
+            matrix: self,
+            columns: 0..self.width,
+        }
+    }
+}
+
+/// An iterator over the digits of a column of a `DigitMatrix`, from the first row.
+#[derive(Clone, Debug)]
+pub struct Column<'a, T: IntoDigits> {
+    numbers: slice::Iter<'a, T>,
+    radix: T,
+    /// The power of the radix of the column.
+    power: T,
+}
+
+impl<'a, T: IntoDigits> Column<'a, T> {
+    fn digit(&self, number: T) -> T {
+        number / self.power % self.radix
+    }
+}
+
+impl<'a, T: IntoDigits> Iterator for Column<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let number = *self.numbers.next()?;
+        Some(self.digit(number))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.numbers.size_hint()
+    }
+}
+
+impl<'a, T: IntoDigits> DoubleEndedIterator for Column<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let number = *self.numbers.next_back()?;
+        Some(self.digit(number))
+    }
+}
+
+impl<'a, T: IntoDigits> ExactSizeIterator for Column<'a, T> {}
+
+impl<'a, T: IntoDigits> FusedIterator for Column<'a, T> {}
+
+/// An iterator over the columns of a `DigitMatrix`, from the most significant one.
+#[derive(Clone, Debug)]
+pub struct Columns<'m, 'a, T: IntoDigits> {
+    matrix: &'m DigitMatrix<'a, T>,
+    columns: Range<usize>,
+}
+
+impl<'m, 'a, T: IntoDigits> Iterator for Columns<'m, 'a, T> {
+    type Item = Column<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let column = self.columns.next()?;
+        self.matrix.column(column)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.columns.size_hint()
+    }
+}
+
+impl<'m, 'a, T: IntoDigits> DoubleEndedIterator for Columns<'m, 'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let column = self.columns.next_back()?;
+        self.matrix.column(column)
+    }
+}
+
+impl<'m, 'a, T: IntoDigits> ExactSizeIterator for Columns<'m, 'a, T> {}
+
+impl<'m, 'a, T: IntoDigits> FusedIterator for Columns<'m, 'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_matches_padded_digits() {
+        let numbers = [0_u64, 1, 9, 10, 255, 1 << 40, u64::MAX - 1, u64::MAX];
+        for &radix in &[2_u64, 3, 10, 16, u64::MAX] {
+            for len in 0..=numbers.len() {
+                let numbers = &numbers[..len];
+                let matrix = DigitMatrix::new(numbers, radix).unwrap();
+                let width = numbers
+                    .iter()
+                    .map(|number| number.nbr_digits(radix).unwrap())
+                    .max()
+                    .unwrap_or(0);
+                assert_eq!(matrix.width(), width);
+                assert_eq!(matrix.height(), len);
+
+                let rows: Vec<Vec<_>> = numbers
+                    .iter()
+                    .map(|&number| DigitsIterator::zero_padded(number, radix, width).collect())
+                    .collect();
+                for (row, digits) in rows.iter().enumerate() {
+                    assert!(matrix.row(row).unwrap().eq(digits.iter().copied()));
+                    for (column, &digit) in digits.iter().enumerate() {
+                        assert_eq!(matrix.get(row, column), Some(digit));
+                    }
+                    assert_eq!(matrix.get(row, width), None);
+                }
+                assert!(matrix.row(len).is_none());
+
+                assert_eq!(matrix.columns().len(), width);
+                for (column, digits) in matrix.columns().enumerate() {
+                    assert!(digits.eq(rows.iter().map(|row| row[column])));
+                }
+                assert!(matrix
+                    .columns()
+                    .rev()
+                    .map(Iterator::count)
+                    .eq(core::iter::repeat_n(len, width)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_radix() {
+        assert_eq!(DigitMatrix::new(&[1_u8], 0), Err(RadixError::Radix0));
+        assert_eq!(DigitMatrix::new(&[1_u8], 1), Err(RadixError::Radix1));
+    }
+}
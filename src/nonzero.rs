@@ -0,0 +1,166 @@
+//! Digits of the non-zero unsigned integers of `core::num`, such as `NonZeroU32`.
+//!
+//! A non-zero number has a non-zero leading digit, so that its number of digits and its digit
+//! sum are non-zero as well, and are returned as such. Its number of digits is the logarithm
+//! of the number plus one, without the special case of 0 that `IntoDigits` has to handle.
+//!
+//! # Example
+//!
+//! ```
+//! use core::num::{NonZeroU32, NonZeroUsize};
+//! use radixal::nonzero::NonZeroDigits;
+//!
+//! let n = NonZeroU32::new(1203).unwrap();
+//!
+//! assert!(n.into_digits(10).unwrap().eq([1, 2, 0, 3]));
+//! assert_eq!(n.nbr_digits(10), Ok(NonZeroUsize::new(4).unwrap()));
+//! assert_eq!(n.digit_sum(10).map(NonZeroU32::get), Ok(6));
+//! ```
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::reversed_digits::ReversedDigits;
+use crate::{IntoDigits, RadixMath};
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use num_traits::{One, Zero};
+
+/// An extension trait on the non-zero unsigned integer types, mirroring `IntoDigits`.
+pub trait NonZeroDigits: Copy {
+    /// The underlying unsigned integer type.
+    type Int: IntoDigits;
+
+    /// Returns the number as its underlying integer type.
+    fn get_int(self) -> Self::Int;
+
+    /// Returns the non-zero number, or `None` if `number` is 0.
+    fn from_int(number: Self::Int) -> Option<Self>;
+
+    /// Creates a `DigitsIterator` over the digits of the number, most significant first.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    fn into_digits(self, radix: Self::Int) -> Result<DigitsIterator<Self::Int>, RadixError> {
+        // The number of digits is known up front, without going through the single digit case.
+        let len = self.nbr_digits(radix)?;
+        Ok(DigitsIterator::with_len(self.get_int(), radix, len.get()))
+    }
+
+    /// Creates a `ReversedDigits` over the digits of the number, least significant first.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    fn into_reversed_digits(
+        self,
+        radix: Self::Int,
+    ) -> Result<ReversedDigits<Self::Int>, RadixError> {
+        self.get_int().into_reversed_digits(radix)
+    }
+
+    /// Counts the number of digits for a given `radix`, at least 1.
+    ///
+    /// Unlike `IntoDigits::nbr_digits`, only takes the logarithm of the number, which always
+    /// exists, 0 being ruled out.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    fn nbr_digits(self, radix: Self::Int) -> Result<NonZeroUsize, RadixError> {
+        if radix.is_zero() {
+            Err(RadixError::Radix0)
+        } else if radix.is_one() {
+            Err(RadixError::Radix1)
+        } else {
+            Ok(NonZeroUsize::MIN.saturating_add(self.get_int().ilog_radix(radix)))
+        }
+    }
+
+    /// Counts the number of binary digits.
+    fn nbr_binary_digits(self) -> NonZeroUsize {
+        self.nbr_digits(Self::Int::binary_radix()).unwrap()
+    }
+
+    /// Counts the number of decimal digits.
+    fn nbr_decimal_digits(self) -> NonZeroUsize {
+        self.nbr_digits(Self::Int::decimal_radix()).unwrap()
+    }
+
+    /// Sums the digits for a given `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    fn digit_sum(self, radix: Self::Int) -> Result<Self, RadixError> {
+        // The sum includes the non-zero leading digit, and is at most the number, so that it
+        // neither is 0 nor overflows.
+        self.get_int()
+            .digit_sum(radix)
+            .map(|sum| Self::from_int(sum).unwrap())
+    }
+
+    /// Tests if the number is a palindrome for a given `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    fn is_palindrome(self, radix: Self::Int) -> Result<bool, RadixError> {
+        self.get_int().is_palindrome(radix)
+    }
+}
+
+macro_rules! impl_nonzero_digits {
+    ( $($nonzero:ident($int:ty))* ) => {
+        $(
+            impl NonZeroDigits for $nonzero {
+                type Int = $int;
+
+                #[inline]
+                fn get_int(self) -> Self::Int {
+                    self.get()
+                }
+
+                #[inline]
+                fn from_int(number: Self::Int) -> Option<Self> {
+                    $nonzero::new(number)
+                }
+            }
+        )*
+    }
+}
+
+impl_nonzero_digits!(
+    NonZeroU8(u8) NonZeroU16(u16) NonZeroU32(u32) NonZeroU64(u64) NonZeroU128(u128)
+    NonZeroUsize(usize)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_into_digits() {
+        for &radix in &[2_u16, 3, 10, 16, 255, u16::MAX] {
+            for number in (1..=u16::MAX).step_by(7) {
+                let nonzero = NonZeroU16::new(number).unwrap();
+                assert_eq!(nonzero.into_digits(radix), number.into_digits(radix));
+                assert!(nonzero
+                    .into_reversed_digits(radix)
+                    .unwrap()
+                    .eq(number.into_reversed_digits(radix).unwrap()));
+                assert_eq!(
+                    nonzero.nbr_digits(radix).map(NonZeroUsize::get),
+                    number.nbr_digits(radix)
+                );
+                assert_eq!(
+                    nonzero.digit_sum(radix).map(NonZeroU16::get),
+                    number.digit_sum(radix)
+                );
+                assert_eq!(nonzero.is_palindrome(radix), number.is_palindrome(radix));
+            }
+        }
+    }
+
+    #[test]
+    fn test_digit_counts() {
+        let max = NonZeroU128::new(u128::MAX).unwrap();
+        assert_eq!(max.nbr_binary_digits().get(), 128);
+        assert_eq!(max.nbr_decimal_digits().get(), 39);
+        assert_eq!(
+            NonZeroU8::MIN.nbr_digits(u8::MAX).map(NonZeroUsize::get),
+            Ok(1)
+        );
+
+        assert_eq!(NonZeroU8::MIN.nbr_digits(0), Err(RadixError::Radix0));
+        assert_eq!(NonZeroU8::MIN.nbr_digits(1), Err(RadixError::Radix1));
+        assert_eq!(NonZeroUsize::MIN.into_digits(1), Err(RadixError::Radix1));
+    }
+}
@@ -0,0 +1,221 @@
+//! Digits of the odd-width unsigned integers of `ux`, such as the `u24` and `u48` of hardware
+//! registers.
+//!
+//! These types do not implement the numerical traits that `IntoDigits` builds on. Instead, the
+//! `OddWidth` trait, implemented for the types from `u1` to `u63` that `ux` converts to the
+//! primitive types, converts them, without loss, to the smallest primitive type holding all their
+//! values, whose digits are those of the number, and narrows the results back where they are
+//! numbers of the same width.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::odd_width::OddWidth;
+//! use ux::u24;
+//!
+//! let register = u24::new(0x12_3456);
+//! assert!(register.into_digits(16).unwrap().eq([1, 2, 3, 4, 5, 6]));
+//! assert_eq!(register.nbr_digits(10), Ok(7));
+//!
+//! // Reversing wraps around at `2^24`, like a `u24` would: `51_277_761 = 3 * 2^24 + 946_113`.
+//! assert_eq!(u24::new(16_777_215).reverse_digits(10), Ok(u24::new(946_113)));
+//! assert_eq!(u24::new(16_777_215).checked_reverse_digits(10), Ok(None));
+//! ```
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::parse::{self, DigitValue, ParseError};
+use crate::reversed_digits::ReversedDigits;
+use crate::IntoDigits;
+
+/// An unsigned integer of an odd width, represented as a wider primitive type.
+///
+/// The digits methods mirror those of [`IntoDigits`](../trait.IntoDigits.html), with a radix of
+/// the primitive type.
+pub trait OddWidth: Copy {
+    /// The smallest primitive type holding all the values.
+    type Repr: IntoDigits;
+
+    /// The number of bits.
+    const BITS: u32;
+
+    /// Converts the number to the primitive type.
+    fn to_repr(self) -> Self::Repr;
+
+    /// Converts the low `BITS` bits of `repr` to a number.
+    fn wrapping_from_repr(repr: Self::Repr) -> Self;
+
+    /// Converts `repr` to a number, or returns `None` if it does not fit in `BITS` bits.
+    fn checked_from_repr(repr: Self::Repr) -> Option<Self> {
+        let number = Self::wrapping_from_repr(repr);
+        if number.to_repr() == repr {
+            Some(number)
+        } else {
+            None
+        }
+    }
+
+    /// Creates a `DigitsIterator` with a given `radix`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn into_digits(self, radix: Self::Repr) -> Result<DigitsIterator<Self::Repr>, RadixError> {
+        self.to_repr().into_digits(radix)
+    }
+
+    /// Creates a `ReversedDigits` with a given `radix`, least significant digit first.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn into_reversed_digits(
+        self,
+        radix: Self::Repr,
+    ) -> Result<ReversedDigits<Self::Repr>, RadixError> {
+        self.to_repr().into_reversed_digits(radix)
+    }
+
+    /// Counts the number of digits for a given `radix`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn nbr_digits(self, radix: Self::Repr) -> Result<usize, RadixError> {
+        self.to_repr().nbr_digits(radix)
+    }
+
+    /// Sums the digits for a given `radix`, which cannot overflow the primitive type.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn digit_sum(self, radix: Self::Repr) -> Result<Self::Repr, RadixError> {
+        self.to_repr().digit_sum(radix)
+    }
+
+    /// Checks if the number is a palindrome under a given `radix`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn is_palindrome(self, radix: Self::Repr) -> Result<bool, RadixError> {
+        self.to_repr().is_palindrome(radix)
+    }
+
+    /// Reverses the digits, wrapping around at `2^BITS`.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn reverse_digits(self, radix: Self::Repr) -> Result<Self, RadixError> {
+        // `2^BITS` divides the modulus of the primitive type, so that wrapping around it first
+        // gives the same low bits.
+        let reversed = self.to_repr().reverse_digits(radix)?;
+        Ok(Self::wrapping_from_repr(reversed))
+    }
+
+    /// Reverses the digits, or returns `None` if the result does not fit in `BITS` bits.
+    ///
+    /// Returns `Err(RadixError)` if the radix is 0 or 1.
+    fn checked_reverse_digits(self, radix: Self::Repr) -> Result<Option<Self>, RadixError> {
+        let digits = self.into_digits(radix)?;
+        Ok(digits
+            .checked_reversed_number()
+            .and_then(Self::checked_from_repr))
+    }
+
+    /// Builds a number from its digits under `radix`, most significant first, like
+    /// [`parse::from_digits_be`](../parse/fn.from_digits_be.html).
+    ///
+    /// Returns an `Err(ParseError)` if there are no digits, if a digit is invalid or not smaller
+    /// than the radix, or if the number does not fit in `BITS` bits.
+    fn from_digits_be<I>(digits: I, radix: Self::Repr) -> Result<Self, ParseError>
+    where
+        I: IntoIterator,
+        I::Item: DigitValue,
+    {
+        let repr = parse::from_digits_be(digits, radix)?;
+        Self::checked_from_repr(repr).ok_or(ParseError::Overflow)
+    }
+}
+
+macro_rules! impl_odd_width {
+    ($repr:ident: $($name:ident),*) => {$(
+        impl OddWidth for ux::$name {
+            type Repr = $repr;
+
+            const BITS: u32 = ux::$name::BITS;
+
+            #[inline]
+            fn to_repr(self) -> $repr {
+                $repr::from(self)
+            }
+
+            #[inline]
+            fn wrapping_from_repr(repr: $repr) -> Self {
+                ux::$name::new(repr & $repr::from(ux::$name::MAX))
+            }
+        }
+    )*};
+}
+
+impl_odd_width!(u8: u1, u2, u3, u4, u5, u6, u7);
+impl_odd_width!(u16: u9, u10, u11, u12, u13, u14, u15);
+impl_odd_width!(u32: u17, u18, u19, u20, u21, u22, u23, u24, u25, u26, u27, u28, u29, u30, u31);
+impl_odd_width!(
+    u64: u33, u34, u35, u36, u37, u38, u39, u40, u41, u42, u43, u44, u45, u46, u47, u48,
+    u49, u50, u51, u52, u53, u54, u55, u56, u57, u58, u59, u60, u61, u62, u63
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::TryFrom;
+    use ux::{u1, u24, u48, u63, u7};
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_matches_primitives() {
+        for &value in &[0, 1, 9, 10, 255, 0xAB_CDEF, 0xFF_FFFF] {
+            let number = u24::new(value);
+            for &radix in &[2, 3, 10, 16, 255] {
+                assert!(number
+                    .into_digits(radix)
+                    .unwrap()
+                    .eq(value.into_digits(radix).unwrap()));
+                assert!(number
+                    .into_reversed_digits(radix)
+                    .unwrap()
+                    .eq(value.into_reversed_digits(radix).unwrap()));
+                assert_eq!(number.digit_sum(radix), value.digit_sum(radix));
+                assert_eq!(number.is_palindrome(radix), value.is_palindrome(radix));
+
+                let digits: Vec<_> = number.into_digits(radix).unwrap().collect();
+                assert_eq!(u24::from_digits_be(&digits, radix), Ok(number));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reverse_digits_wraps() {
+        for value in (0..1 << 20).step_by(997) {
+            let number = u24::new(value);
+            let reversed = value.reverse_digits(10).unwrap();
+            assert_eq!(
+                number.reverse_digits(10),
+                Ok(u24::new(reversed & 0xFF_FFFF))
+            );
+            assert_eq!(
+                number.checked_reverse_digits(10),
+                Ok(u24::try_from(reversed).ok())
+            );
+        }
+
+        assert_eq!(u7::new(19).reverse_digits(10), Ok(u7::new(91)));
+        // `901 = 7 * 128 + 5`
+        assert_eq!(u7::new(109).reverse_digits(10), Ok(u7::new(5)));
+        assert_eq!(u7::new(109).checked_reverse_digits(10), Ok(None));
+        assert_eq!(u1::new(1).reverse_digits(2), Ok(u1::new(1)));
+    }
+
+    #[test]
+    fn test_widths() {
+        assert_eq!(<u48 as OddWidth>::BITS, 48);
+        assert_eq!(u48::MAX.nbr_digits(16), Ok(12));
+        assert_eq!(u63::MAX.nbr_digits(2), Ok(63));
+        assert_eq!(u48::checked_from_repr(1 << 48), None);
+        assert_eq!(u48::wrapping_from_repr((1 << 48) + 5), u48::new(5));
+        assert_eq!(
+            u24::from_digits_be([1_u8, 6, 7, 7, 7, 2, 1, 6], 10),
+            Err(ParseError::Overflow)
+        );
+        assert_eq!(u24::new(0).into_digits(1).unwrap_err(), RadixError::Radix1);
+    }
+}
@@ -0,0 +1,237 @@
+//! Counting with digits in place, with carries and borrows, in a radix or in a mixed radix.
+//!
+//! An `Odometer` enumerates the tuples of digits as nested loops would, without converting to
+//! and from numbers at each step.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::odometer::Odometer;
+//!
+//! // The times of a day, in hours, minutes and seconds.
+//! let mut clock = Odometer::with_radices([24_u32, 60, 60]).unwrap();
+//! clock.increment();
+//! assert_eq!(clock.digits(), &[0, 0, 1]);
+//!
+//! let times = Odometer::with_radices([24_u32, 60, 60]).unwrap();
+//! assert_eq!(times.last(), Some([23, 59, 59]));
+//! ```
+use crate::digits_iterator::RadixError;
+use crate::parse::push_digit;
+use crate::IntoDigits;
+use core::iter::FusedIterator;
+
+/// `N` digits, most significant first, each under its own radix, which count up or down in
+/// place.
+///
+/// As an iterator, it yields its digits and moves to the next ones, until it would wrap around
+/// to zeroes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Odometer<T: IntoDigits, const N: usize> {
+    digits: [T; N],
+    radices: [T; N],
+    done: bool,
+}
+
+impl<T: IntoDigits, const N: usize> Odometer<T, N> {
+    /// Creates an `Odometer` of `N` zeroes counting in `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::odometer::Odometer;
+    ///
+    /// let tuples = Odometer::<u8, 3>::new(2).unwrap();
+    /// assert!(tuples.map(|[a, b, c]| a * 4 + b * 2 + c).eq(0..8));
+    /// ```
+    pub fn new(radix: T) -> Result<Self, RadixError> {
+        Self::with_radices([radix; N])
+    }
+
+    /// Creates an `Odometer` of `N` zeroes, each digit counting in its radix, most significant
+    /// first.
+    ///
+    /// Returns an `Err(RadixError)` if a radix is 0 or 1.
+    pub fn with_radices(radices: [T; N]) -> Result<Self, RadixError> {
+        for &radix in &radices {
+            T::zero().nbr_digits(radix)?;
+        }
+
+        Ok(Odometer {
+            digits: [T::zero(); N],
+            radices,
+            done: false,
+        })
+    }
+
+    /// Returns the digits, most significant first.
+    pub fn digits(&self) -> &[T; N] {
+        &self.digits
+    }
+
+    /// Returns the radices of the digits, most significant first.
+    pub fn radices(&self) -> &[T; N] {
+        &self.radices
+    }
+
+    /// Adds one, carrying into the more significant digits.
+    ///
+    /// Returns `false` if all the digits were the largest ones and wrapped around to zeroes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::odometer::Odometer;
+    ///
+    /// let mut odometer = Odometer::<u32, 2>::with_radices([3, 2]).unwrap();
+    /// odometer.increment();
+    /// assert!(odometer.increment());
+    /// assert_eq!(odometer.digits(), &[1, 0]);
+    /// assert!(odometer.increment() && odometer.increment() && odometer.increment());
+    /// assert!(!odometer.increment());
+    /// assert_eq!(odometer.digits(), &[0, 0]);
+    /// ```
+    pub fn increment(&mut self) -> bool {
+        for (digit, &radix) in self.digits.iter_mut().zip(&self.radices).rev() {
+            *digit = *digit + T::one();
+            if *digit < radix {
+                return true;
+            }
+            *digit = T::zero();
+        }
+        false
+    }
+
+    /// Subtracts one, borrowing from the more significant digits.
+    ///
+    /// Returns `false` if all the digits were zeroes and wrapped around to the largest ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::odometer::Odometer;
+    ///
+    /// let mut odometer = Odometer::<u32, 2>::with_radices([3, 2]).unwrap();
+    /// assert!(!odometer.decrement());
+    /// assert_eq!(odometer.digits(), &[2, 1]);
+    /// assert!(odometer.decrement());
+    /// assert_eq!(odometer.digits(), &[2, 0]);
+    /// ```
+    pub fn decrement(&mut self) -> bool {
+        for (digit, &radix) in self.digits.iter_mut().zip(&self.radices).rev() {
+            if !digit.is_zero() {
+                *digit = *digit - T::one();
+                return true;
+            }
+            *digit = radix - T::one();
+        }
+        false
+    }
+
+    /// Returns the number of the digits in their mixed radix, or `None` if it does not fit in
+    /// `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::odometer::Odometer;
+    ///
+    /// let mut clock = Odometer::with_radices([24_u32, 60, 60]).unwrap();
+    /// (0..3_661).for_each(|_| {
+    ///     clock.increment();
+    /// });
+    /// assert_eq!(clock.digits(), &[1, 1, 1]);
+    /// assert_eq!(clock.to_number(), Some(3_661));
+    /// ```
+    pub fn to_number(&self) -> Option<T> {
+        self.digits
+            .iter()
+            .zip(&self.radices)
+            .try_fold(T::zero(), |number, (&digit, &radix)| {
+                push_digit(number, radix, digit)
+            })
+    }
+}
+
+impl<T: IntoDigits, const N: usize> Iterator for Odometer<T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let digits = self.digits;
+        self.done = !self.increment();
+        Some(digits)
+    }
+}
+
+impl<T: IntoDigits, const N: usize> FusedIterator for Odometer<T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting() {
+        for radix in 2..=7_u32 {
+            let mut odometer = Odometer::<u32, 4>::new(radix).unwrap();
+            for number in 0..radix.pow(4) {
+                assert_eq!(odometer.to_number(), Some(number));
+                for (i, &digit) in odometer.digits().iter().enumerate() {
+                    assert_eq!(digit, number / radix.pow(3 - i as u32) % radix);
+                }
+                assert_eq!(odometer.increment(), number + 1 < radix.pow(4));
+            }
+            assert_eq!(odometer.to_number(), Some(0));
+
+            assert_eq!(
+                Odometer::<u32, 4>::new(radix).unwrap().count(),
+                radix.pow(4) as usize
+            );
+        }
+    }
+
+    #[test]
+    fn test_mixed_radix() {
+        let mut clock = Odometer::with_radices([24_u32, 60, 60]).unwrap();
+        assert_eq!(clock.radices(), &[24, 60, 60]);
+        for second in 0..86_400 {
+            assert_eq!(
+                clock.next().and(clock.to_number()),
+                Some((second + 1) % 86_400)
+            );
+        }
+        assert_eq!(clock.next(), None);
+        assert_eq!(clock.digits(), &[0, 0, 0]);
+
+        let mut clock = Odometer::with_radices([24_u32, 60, 60]).unwrap();
+        assert!(!clock.decrement());
+        for second in (0..86_399).rev() {
+            assert!(clock.decrement());
+            assert_eq!(clock.to_number(), Some(second));
+        }
+        assert!(!clock.decrement());
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        assert_eq!(Odometer::<u8, 2>::new(1), Err(RadixError::Radix1));
+        assert_eq!(Odometer::with_radices([2_u8, 0]), Err(RadixError::Radix0));
+
+        let mut empty = Odometer::<u8, 0>::new(10).unwrap();
+        assert!(!empty.increment());
+        assert_eq!(empty.to_number(), Some(0));
+        assert_eq!(empty.count(), 1);
+
+        let mut large = Odometer::<u8, 2>::new(u8::MAX).unwrap();
+        assert!(!large.decrement());
+        assert_eq!(large.digits(), &[254, 254]);
+        assert_eq!(large.to_number(), None);
+        assert!(!large.increment());
+        assert_eq!(large.digits(), &[0, 0]);
+    }
+}
@@ -0,0 +1,585 @@
+//! Plain byte layouts of digits, which can be copied as is into DMA buffers, shared memory or
+//! network packets.
+//!
+//! `ByteDigits` stores the radix, the number of digits and the digits of an `ArrayDigits<u8, N>`
+//! as bytes only, and `PackedBcd` stores a fixed number of decimal digits in packed binary coded
+//! decimal, two per byte. Both are `repr(C)` without padding, and implement `bytemuck::Pod` with
+//! the `bytemuck` feature. Any bytes are a value of either type, so that conversions back to
+//! digits or numbers are validated.
+//!
+//! `ZonedDecimal` stores signed numbers one decimal digit per byte, with the sign in the zone
+//! nibble of the last byte, as in the records of EBCDIC mainframes.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::packed::PackedBcd;
+//!
+//! let bcd = PackedBcd::<3>::from_number(12_345_u32).unwrap();
+//!
+//! assert_eq!(bcd.as_bytes(), &[0x01, 0x23, 0x45]);
+//! assert!(bcd.digits().eq([0, 1, 2, 3, 4, 5]));
+//! assert_eq!(bcd.to_number(), Ok(12_345_u32));
+//! ```
+use crate::digits::{ArrayDigits, DigitsError};
+use crate::digits_iterator::RadixError;
+use crate::parse::push_digit;
+use crate::{IntoDigits, RadixMath, SignedInteger};
+use core::convert::TryFrom;
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Errors that can occur when converting packed digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PackedError {
+    /// The stored radix is 0 or 1.
+    Radix(RadixError),
+    /// The stored number of digits is larger than the capacity.
+    InvalidLength,
+    /// A digit is not smaller than the radix.
+    DigitTooLarge,
+    /// The number is too large for the integer type, or has more digits than the capacity.
+    Overflow,
+    /// A zone nibble is neither `0xF`, nor a sign in the last byte.
+    InvalidZone,
+}
+
+impl fmt::Display for PackedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PackedError::Radix(err) => err.fmt(f),
+            PackedError::InvalidLength => write!(f, "number of digits larger than the capacity"),
+            PackedError::DigitTooLarge => write!(f, "digit not smaller than the radix"),
+            PackedError::Overflow => write!(f, "number too large"),
+            PackedError::InvalidZone => write!(f, "invalid zone nibble"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackedError {}
+
+impl From<RadixError> for PackedError {
+    fn from(err: RadixError) -> Self {
+        PackedError::Radix(err)
+    }
+}
+
+impl From<DigitsError> for PackedError {
+    fn from(err: DigitsError) -> Self {
+        match err {
+            DigitsError::DigitTooLarge => PackedError::DigitTooLarge,
+            DigitsError::Overflow | DigitsError::CapacityExceeded => PackedError::Overflow,
+        }
+    }
+}
+
+/// The digits of an `ArrayDigits<u8, N>`, as bytes: the radix, the number of digits, then the
+/// digits, most significant first.
+///
+/// The bytes past the number of digits are zeroes when converted from an `ArrayDigits`, and are
+/// ignored when converting back.
+///
+/// # Example
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use radixal::digits::ArrayDigits;
+/// use radixal::packed::ByteDigits;
+/// use radixal::IntoDigits;
+///
+/// let digits = ArrayDigits::<u8, 4>::try_from(123_u8.into_digits(7).unwrap()).unwrap();
+/// let bytes = ByteDigits::try_from(digits).unwrap();
+///
+/// assert_eq!((bytes.radix(), bytes.as_slice()), (7, &[2, 3, 4][..]));
+/// assert_eq!(ArrayDigits::try_from(bytes), Ok(digits));
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ByteDigits<const N: usize> {
+    radix: u8,
+    len: u8,
+    digits: [u8; N],
+}
+
+impl<const N: usize> ByteDigits<N> {
+    /// Returns the stored radix, which may be invalid.
+    pub fn radix(&self) -> u8 {
+        self.radix
+    }
+
+    /// Returns the stored number of digits, which may be larger than `N`.
+    pub fn len(&self) -> usize {
+        usize::from(self.len)
+    }
+
+    /// Returns `true` if no digits are stored.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the stored digits, most significant first, which may not be smaller than the
+    /// radix.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.digits[..self.len().min(N)]
+    }
+}
+
+impl<const N: usize> TryFrom<ArrayDigits<u8, N>> for ByteDigits<N> {
+    type Error = PackedError;
+
+    /// Returns an `Err(PackedError::Overflow)` if there are more than 255 digits.
+    fn try_from(digits: ArrayDigits<u8, N>) -> Result<Self, Self::Error> {
+        let len = u8::try_from(digits.len()).map_err(|_| PackedError::Overflow)?;
+        let mut bytes = [0; N];
+        bytes[..digits.len()].copy_from_slice(&digits);
+
+        Ok(ByteDigits {
+            radix: digits.radix(),
+            len,
+            digits: bytes,
+        })
+    }
+}
+
+impl<const N: usize> TryFrom<ByteDigits<N>> for ArrayDigits<u8, N> {
+    type Error = PackedError;
+
+    /// Returns an `Err(PackedError)` if the radix is 0 or 1, if the number of digits is larger
+    /// than `N` or if a digit is not smaller than the radix.
+    fn try_from(bytes: ByteDigits<N>) -> Result<Self, Self::Error> {
+        let digits = bytes
+            .digits
+            .get(..bytes.len())
+            .ok_or(PackedError::InvalidLength)?;
+
+        let mut array = ArrayDigits::new(bytes.radix)?;
+        for &digit in digits {
+            array.push(digit)?;
+        }
+        Ok(array)
+    }
+}
+
+/// `2 * N` decimal digits in packed binary coded decimal, most significant first, the first digit
+/// of each byte in its high nibble.
+///
+/// Numbers with fewer digits are padded with leading zeroes, as in fixed-width BCD registers.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackedBcd<const N: usize>([u8; N]);
+
+impl<const N: usize> Default for PackedBcd<N> {
+    /// Returns 0.
+    fn default() -> Self {
+        PackedBcd([0; N])
+    }
+}
+
+impl<const N: usize> PackedBcd<N> {
+    /// Wraps `bytes`, which are validated when read.
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        PackedBcd(bytes)
+    }
+
+    /// Packs the decimal digits of `number`.
+    ///
+    /// Returns an `Err(PackedError::Overflow)` if it has more than `2 * N` digits.
+    pub fn from_number<T: IntoDigits>(number: T) -> Result<Self, PackedError> {
+        let mut bytes = [0; N];
+        let mut digits = number.into_decimal_digits().rev();
+        if digits.len() > 2 * N {
+            return Err(PackedError::Overflow);
+        }
+
+        for byte in bytes.iter_mut().rev() {
+            // The digits are smaller than 10.
+            let low = digits
+                .next()
+                .and_then(|digit| digit.to_usize())
+                .unwrap_or(0) as u8;
+            let high = digits
+                .next()
+                .and_then(|digit| digit.to_usize())
+                .unwrap_or(0) as u8;
+            *byte = high << 4 | low;
+        }
+        Ok(PackedBcd(bytes))
+    }
+
+    /// Returns the bytes.
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns the bytes.
+    pub fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Returns an iterator over the `2 * N` nibbles, most significant first, including the
+    /// leading zeroes and any invalid nibble larger than 9.
+    pub fn digits(&self) -> BcdDigits<'_> {
+        BcdDigits {
+            bytes: &self.0,
+            front: 0,
+            back: 2 * N,
+        }
+    }
+
+    /// Returns `true` if every nibble is a decimal digit.
+    pub fn is_valid(&self) -> bool {
+        self.0.iter().all(|&byte| byte >> 4 < 10 && byte & 0xf < 10)
+    }
+
+    /// Unpacks the number.
+    ///
+    /// Returns an `Err(PackedError)` if a nibble is larger than 9, or if the number is too large
+    /// for `T`.
+    pub fn to_number<T: IntoDigits>(&self) -> Result<T, PackedError> {
+        let radix = T::decimal_radix();
+        self.digits().try_fold(T::zero(), |number, digit| {
+            if digit >= 10 {
+                return Err(PackedError::DigitTooLarge);
+            }
+            let digit = T::from_usize(digit.into()).ok_or(PackedError::Overflow)?;
+            push_digit(number, radix, digit).ok_or(PackedError::Overflow)
+        })
+    }
+}
+
+/// An iterator over the nibbles of a `PackedBcd`, most significant first.
+#[derive(Clone, Debug)]
+pub struct BcdDigits<'a> {
+    bytes: &'a [u8],
+    front: usize,
+    back: usize,
+}
+
+impl<'a> BcdDigits<'a> {
+    fn nibble(&self, idx: usize) -> u8 {
+        // The high nibble comes first.
+        (self.bytes[idx / 2] >> (4 - 4 * (idx % 2))) & 0xf
+    }
+}
+
+impl<'a> Iterator for BcdDigits<'a> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(self.nibble(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator for BcdDigits<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.nibble(self.back))
+    }
+}
+
+impl<'a> ExactSizeIterator for BcdDigits<'a> {}
+
+impl<'a> FusedIterator for BcdDigits<'a> {}
+
+/// `N` decimal digits in zoned decimal, most significant first, one per byte in its low nibble.
+///
+/// The high nibble of each byte is the zone `0xF`, except in the last byte, where it is the sign:
+/// `0xC` for positive numbers and `0xD` for negative ones. As on EBCDIC mainframes, the zones
+/// `0xA`, `0xE` and `0xF` are also read as positive and `0xB` as negative. Numbers with fewer
+/// digits are padded with leading zeroes.
+///
+/// # Example
+///
+/// ```
+/// use radixal::packed::ZonedDecimal;
+///
+/// let zoned = ZonedDecimal::<4>::from_number(-123_i32).unwrap();
+/// assert_eq!(zoned.as_bytes(), &[0xF0, 0xF1, 0xF2, 0xD3]);
+/// assert_eq!(zoned.to_number(), Ok(-123_i32));
+/// assert_eq!(ZonedDecimal::from_bytes([0xF4, 0xF2]).to_number(), Ok(42_i8));
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ZonedDecimal<const N: usize>([u8; N]);
+
+const ZONE: u8 = 0xF0;
+const POSITIVE_ZONE: u8 = 0xC0;
+const NEGATIVE_ZONE: u8 = 0xD0;
+
+impl<const N: usize> Default for ZonedDecimal<N> {
+    /// Returns 0.
+    fn default() -> Self {
+        let mut bytes = [ZONE; N];
+        if let Some(last) = bytes.last_mut() {
+            *last = POSITIVE_ZONE;
+        }
+        ZonedDecimal(bytes)
+    }
+}
+
+impl<const N: usize> ZonedDecimal<N> {
+    /// Wraps `bytes`, which are validated when read.
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        ZonedDecimal(bytes)
+    }
+
+    /// Zones the decimal digits of `number`, with the preferred signs `0xC` and `0xD`.
+    ///
+    /// Returns an `Err(PackedError::Overflow)` if it has more than `N` digits, which is always
+    /// the case when `N` is 0.
+    pub fn from_number<S: SignedInteger>(number: S) -> Result<Self, PackedError> {
+        let mut bytes = [ZONE; N];
+        let mut digits = number.magnitude().into_decimal_digits().rev();
+        if N == 0 || digits.len() > N {
+            return Err(PackedError::Overflow);
+        }
+
+        for byte in bytes.iter_mut().rev() {
+            // The digits are smaller than 10.
+            *byte |= digits
+                .next()
+                .and_then(|digit| digit.to_usize())
+                .unwrap_or(0) as u8;
+        }
+        bytes[N - 1] &= 0x0f;
+        bytes[N - 1] |= if number.is_negative() {
+            NEGATIVE_ZONE
+        } else {
+            POSITIVE_ZONE
+        };
+        Ok(ZonedDecimal(bytes))
+    }
+
+    /// Returns the bytes.
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns the bytes.
+    pub fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Returns `true` if the sign of the last byte is negative, whether or not the other bytes
+    /// are valid.
+    pub fn is_negative(&self) -> bool {
+        matches!(
+            self.0.last().map(|byte| byte & 0xf0),
+            Some(0xB0) | Some(0xD0)
+        )
+    }
+
+    /// Unzones the number.
+    ///
+    /// Returns an `Err(PackedError)` if a zone is invalid, if a digit is larger than 9, or if
+    /// the number is out of the range of `S`.
+    pub fn to_number<S: SignedInteger>(&self) -> Result<S, PackedError> {
+        let (last, init) = self.0.split_last().ok_or(PackedError::InvalidLength)?;
+        if init.iter().any(|&byte| byte & 0xf0 != ZONE) {
+            return Err(PackedError::InvalidZone);
+        }
+        let negative = match last & 0xf0 {
+            0xA0 | 0xC0 | 0xE0 | 0xF0 => false,
+            0xB0 | 0xD0 => true,
+            _ => return Err(PackedError::InvalidZone),
+        };
+
+        let magnitude = self.0.iter().try_fold(0_u128, |number, &byte| {
+            let digit = byte & 0x0f;
+            if digit >= 10 {
+                return Err(PackedError::DigitTooLarge);
+            }
+            push_digit(number, 10, u128::from(digit)).ok_or(PackedError::Overflow)
+        })?;
+        let number = if negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        number
+            .and_then(|number| S::from(number))
+            .ok_or(PackedError::Overflow)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+// Only bytes, without padding, and any bytes are a value.
+unsafe impl<const N: usize> bytemuck::Zeroable for ByteDigits<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for ByteDigits<N> {}
+
+#[cfg(feature = "bytemuck")]
+// A transparent wrapper of bytes, and any bytes are a value.
+unsafe impl<const N: usize> bytemuck::Zeroable for PackedBcd<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for PackedBcd<N> {}
+
+#[cfg(feature = "bytemuck")]
+// A transparent wrapper of bytes, and any bytes are a value.
+unsafe impl<const N: usize> bytemuck::Zeroable for ZonedDecimal<N> {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize> bytemuck::Pod for ZonedDecimal<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_digits_round_trip() {
+        for number in (0..=u8::MAX).step_by(5) {
+            for radix in 2..=u8::MAX {
+                let digits =
+                    ArrayDigits::<u8, 8>::try_from(number.into_digits(radix).unwrap()).unwrap();
+                let bytes = ByteDigits::try_from(digits).unwrap();
+                assert_eq!(bytes.radix(), radix);
+                assert_eq!(bytes.as_slice(), digits.as_slice());
+                assert_eq!(ArrayDigits::try_from(bytes), Ok(digits));
+            }
+        }
+    }
+
+    #[test]
+    fn test_byte_digits_validation() {
+        let valid = ByteDigits {
+            radix: 10,
+            len: 2,
+            digits: [4, 2, 9],
+        };
+        assert_eq!(
+            ArrayDigits::try_from(valid).map(|digits| digits.to_number()),
+            Ok(Ok(42))
+        );
+
+        let invalid =
+            |radix, len, digits| ArrayDigits::<u8, 3>::try_from(ByteDigits { radix, len, digits });
+        assert_eq!(
+            invalid(0, 0, [0; 3]),
+            Err(PackedError::Radix(RadixError::Radix0))
+        );
+        assert_eq!(invalid(10, 4, [0; 3]), Err(PackedError::InvalidLength));
+        assert_eq!(invalid(10, 2, [1, 10, 0]), Err(PackedError::DigitTooLarge));
+
+        let mut digits = ArrayDigits::<u8, 300>::new(2).unwrap();
+        digits.extend(core::iter::repeat_n(1, 256));
+        assert_eq!(ByteDigits::try_from(digits), Err(PackedError::Overflow));
+    }
+
+    #[test]
+    fn test_packed_bcd() {
+        assert_eq!(
+            PackedBcd::<8>::from_number(u32::MAX).map(|bcd| bcd.into_bytes()),
+            Ok([0x00, 0x00, 0x00, 0x42, 0x94, 0x96, 0x72, 0x95])
+        );
+        assert_eq!(
+            PackedBcd::<2>::from_number(12_345_u32),
+            Err(PackedError::Overflow)
+        );
+        assert_eq!(PackedBcd::<2>::default().to_number(), Ok(0_u8));
+
+        for number in (0..=u16::MAX).step_by(3) {
+            let bcd = PackedBcd::<3>::from_number(number).unwrap();
+            assert!(bcd.is_valid());
+            assert_eq!(bcd.to_number(), Ok(number));
+            assert!(bcd.digits().rev().eq(number
+                .into_reversed_digits(10)
+                .unwrap()
+                .map(|digit| digit as u8)
+                .chain(core::iter::repeat(0))
+                .take(6)));
+        }
+
+        let bcd = PackedBcd::from_bytes([0x9a]);
+        assert!(!bcd.is_valid());
+        assert_eq!(bcd.to_number::<u8>(), Err(PackedError::DigitTooLarge));
+        assert_eq!(
+            PackedBcd::from_bytes([0x02, 0x56]).to_number::<u8>(),
+            Err(PackedError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_zoned_decimal() {
+        assert_eq!(
+            ZonedDecimal::<5>::from_number(i16::MIN).map(|zoned| zoned.into_bytes()),
+            Ok([0xF3, 0xF2, 0xF7, 0xF6, 0xD8])
+        );
+        assert_eq!(
+            ZonedDecimal::<2>::from_number(-123_i32),
+            Err(PackedError::Overflow)
+        );
+        assert_eq!(
+            ZonedDecimal::<0>::from_number(0_i32),
+            Err(PackedError::Overflow)
+        );
+        assert_eq!(ZonedDecimal::<3>::default().as_bytes(), &[0xF0, 0xF0, 0xC0]);
+        assert_eq!(ZonedDecimal::<3>::default().to_number(), Ok(0_i8));
+
+        for number in i16::MIN..=i16::MAX {
+            let zoned = ZonedDecimal::<6>::from_number(number).unwrap();
+            assert_eq!(zoned.is_negative(), number < 0);
+            assert_eq!(zoned.to_number(), Ok(number));
+            assert_eq!(zoned.to_number(), Ok(i64::from(number)));
+        }
+
+        // The alternative signs of the last zone.
+        assert_eq!(
+            ZonedDecimal::from_bytes([0xF1, 0xA2]).to_number(),
+            Ok(12_i8)
+        );
+        assert_eq!(
+            ZonedDecimal::from_bytes([0xF1, 0xE2]).to_number(),
+            Ok(12_i8)
+        );
+        assert_eq!(
+            ZonedDecimal::from_bytes([0xF1, 0xB2]).to_number(),
+            Ok(-12_i8)
+        );
+
+        let invalid = |bytes: [u8; 3]| ZonedDecimal::from_bytes(bytes).to_number::<i8>();
+        assert_eq!(invalid([0xF1, 0xF2, 0x33]), Err(PackedError::InvalidZone));
+        assert_eq!(invalid([0xC1, 0xF2, 0xC3]), Err(PackedError::InvalidZone));
+        assert_eq!(invalid([0xF1, 0xFA, 0xC3]), Err(PackedError::DigitTooLarge));
+        assert_eq!(invalid([0xF1, 0xF2, 0xC8]), Err(PackedError::Overflow));
+        assert_eq!(invalid([0xF1, 0xF2, 0xD8]), Ok(-128));
+        assert_eq!(
+            ZonedDecimal::from_bytes([]).to_number::<i8>(),
+            Err(PackedError::InvalidLength)
+        );
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_pod() {
+        let bcd = PackedBcd::<3>::from_number(123_456_u32).unwrap();
+        assert_eq!(bytemuck::bytes_of(&bcd), [0x12, 0x34, 0x56]);
+        assert_eq!(
+            bytemuck::cast::<_, PackedBcd<3>>([0x12_u8, 0x34, 0x56]),
+            bcd
+        );
+
+        let digits = ArrayDigits::<u8, 4>::try_from(200_u8.into_digits(16).unwrap()).unwrap();
+        let bytes = ByteDigits::try_from(digits).unwrap();
+        assert_eq!(bytemuck::bytes_of(&bytes), [16, 2, 12, 8, 0, 0]);
+        assert_eq!(<ByteDigits<4> as bytemuck::Zeroable>::zeroed().len(), 0);
+        let copy: ByteDigits<4> = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&bytes));
+        assert_eq!(ArrayDigits::try_from(copy), Ok(digits));
+    }
+}
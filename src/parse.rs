@@ -0,0 +1,602 @@
+//! Parsing of numbers written with the symbols of an `Alphabet`.
+use crate::alphabet::Alphabet;
+use crate::digits_iterator::RadixError;
+use crate::{IntoDigits, RadixMath};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::str::Chars;
+
+/// Errors that can occur when parsing a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string holds no digits.
+    Empty,
+    /// A symbol is not part of the alphabet.
+    InvalidDigit(char),
+    /// The number is too large for the target type.
+    Overflow,
+    /// The number of symbols is not valid for the encoding.
+    InvalidLength,
+    /// The symbols are valid, but not in an order allowed by the numeral system.
+    Malformed,
+    /// A digit value is not smaller than the radix.
+    DigitTooLarge(usize),
+    /// The radix is invalid for the digits.
+    Radix(RadixError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse a number from an empty string"),
+            ParseError::InvalidDigit(c) => write!(f, "invalid digit {:?}", c),
+            ParseError::Overflow => write!(f, "number too large for the target type"),
+            ParseError::InvalidLength => write!(f, "invalid number of symbols"),
+            ParseError::Malformed => write!(f, "malformed numeral"),
+            ParseError::DigitTooLarge(digit) => write!(f, "digit {} too large", digit),
+            ParseError::Radix(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Parses `s` as a number written with `alphabet`, most significant digit first.
+///
+/// Returns an `Err(ParseError)` if the string is empty, contains a symbol outside of the
+/// alphabet or represents a number too large for `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::Alphabet;
+/// use radixal::parse::{parse, ParseError};
+///
+/// assert_eq!(parse::<u32>("10", &Alphabet::BASE36), Ok(36));
+/// assert_eq!(parse::<u32>("", &Alphabet::BASE36), Err(ParseError::Empty));
+/// ```
+pub fn parse<T: IntoDigits>(s: &str, alphabet: &Alphabet) -> Result<T, ParseError> {
+    parse_symbols(s.chars(), alphabet)
+}
+
+/// Parses a sequence of symbols, most significant digit first.
+pub(crate) fn parse_symbols<T, I>(symbols: I, alphabet: &Alphabet) -> Result<T, ParseError>
+where
+    T: IntoDigits,
+    I: IntoIterator<Item = char>,
+{
+    parse_with(symbols, alphabet.radix(), |symbol| alphabet.digit(symbol))
+}
+
+/// Parses a sequence of symbols, most significant digit first, looking up each digit with
+/// `digit`.
+pub(crate) fn parse_with<T, I, F>(symbols: I, radix: usize, digit: F) -> Result<T, ParseError>
+where
+    T: IntoDigits,
+    I: IntoIterator<Item = char>,
+    F: Fn(char) -> Option<usize>,
+{
+    let mut symbols = symbols.into_iter().peekable();
+    if symbols.peek().is_none() {
+        return Err(ParseError::Empty);
+    }
+
+    // Alphabets hold at most `u8::MAX` symbols, so the radix fits in any `T`.
+    let radix = T::from_usize(radix).ok_or(ParseError::Overflow)?;
+    let mut number = T::zero();
+
+    for symbol in symbols {
+        let digit = digit(symbol)
+            .and_then(T::from_usize)
+            .ok_or(ParseError::InvalidDigit(symbol))?;
+        number = push_digit(number, radix, digit).ok_or(ParseError::Overflow)?;
+    }
+
+    Ok(number)
+}
+
+/// Options for a lenient parser, for input written by people rather than programs.
+///
+/// By default, the options are as strict as [`parse`](fn.parse.html).
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::Alphabet;
+/// use radixal::parse::ParseOptions;
+///
+/// let options = ParseOptions::new()
+///     .ignore_case()
+///     .with_separators(&['_', ' '])
+///     .with_prefixes(ParseOptions::RUST_PREFIXES);
+///
+/// assert_eq!(options.parse::<u32>("1_000 000", &Alphabet::DECIMAL), Ok(1_000_000));
+/// assert_eq!(options.parse::<u32>("0xDEAD_beef", &Alphabet::DECIMAL), Ok(0xdead_beef));
+/// assert_eq!(options.parse::<u32>("0B1010", &Alphabet::DECIMAL), Ok(10));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions<'a> {
+    ignore_case: bool,
+    separators: &'a [char],
+    prefixes: &'a [(&'a str, Alphabet<'a>)],
+}
+
+impl<'a> ParseOptions<'a> {
+    /// The prefixes of Rust integer literals: `0x` for hexadecimal, `0o` for octal and `0b` for
+    /// binary.
+    pub const RUST_PREFIXES: &'static [(&'static str, Alphabet<'static>)] = &[
+        ("0x", Alphabet::LOWER_HEX),
+        ("0o", Alphabet::OCTAL),
+        ("0b", Alphabet::BINARY),
+    ];
+
+    /// Creates strict options.
+    pub fn new() -> Self {
+        ParseOptions::default()
+    }
+
+    /// Ignores the ASCII case of symbols and prefixes.
+    ///
+    /// A symbol matching a digit exactly always takes precedence, so that alphabets with both
+    /// cases (such as `Alphabet::BASE62`) keep their meaning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::parse::ParseOptions;
+    ///
+    /// let options = ParseOptions::new().ignore_case();
+    /// assert_eq!(options.parse::<u32>("FF", &Alphabet::LOWER_HEX), Ok(255));
+    /// # #[cfg(feature = "alphabets")]
+    /// assert_eq!(options.parse::<u32>("A", &Alphabet::BASE62), Ok(10));
+    /// # #[cfg(feature = "alphabets")]
+    /// assert_eq!(options.parse::<u32>("a", &Alphabet::BASE62), Ok(36));
+    /// ```
+    pub fn ignore_case(self) -> Self {
+        ParseOptions {
+            ignore_case: true,
+            ..self
+        }
+    }
+
+    /// Skips `separators` wherever they appear, such as `_` or spaces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::parse::{ParseError, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().with_separators(&[',']);
+    /// assert_eq!(options.parse::<u32>("1,234,567", &Alphabet::DECIMAL), Ok(1_234_567));
+    /// assert_eq!(options.parse::<u32>(",,", &Alphabet::DECIMAL), Err(ParseError::Empty));
+    /// ```
+    pub fn with_separators(self, separators: &'a [char]) -> Self {
+        ParseOptions { separators, ..self }
+    }
+
+    /// Accepts an optional prefix, each with the alphabet used for the digits that follow it.
+    ///
+    /// The first matching prefix is used; without a prefix, the digits are parsed with the
+    /// alphabet given to [`parse`](#method.parse).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::Alphabet;
+    /// use radixal::parse::ParseOptions;
+    ///
+    /// let prefixes = [("#", Alphabet::LOWER_HEX), ("id:", Alphabet::BASE36)];
+    /// let options = ParseOptions::new().with_prefixes(&prefixes);
+    ///
+    /// assert_eq!(options.parse::<u32>("#ff", &Alphabet::DECIMAL), Ok(255));
+    /// assert_eq!(options.parse::<u32>("id:zz", &Alphabet::DECIMAL), Ok(1295));
+    /// assert_eq!(options.parse::<u32>("99", &Alphabet::DECIMAL), Ok(99));
+    /// ```
+    pub fn with_prefixes(self, prefixes: &'a [(&'a str, Alphabet<'a>)]) -> Self {
+        ParseOptions { prefixes, ..self }
+    }
+
+    /// Parses `s` as a number written with `alphabet` (or the alphabet of its prefix), most
+    /// significant digit first.
+    ///
+    /// Returns an `Err(ParseError)` if there are no digits, if a symbol is neither a digit nor a
+    /// separator, or if the number is too large for `T`.
+    pub fn parse<T: IntoDigits>(&self, s: &str, alphabet: &Alphabet) -> Result<T, ParseError> {
+        let (s, alphabet) = self
+            .prefixes
+            .iter()
+            .find_map(|(prefix, alphabet)| self.strip_prefix(s, prefix).map(|s| (s, alphabet)))
+            .unwrap_or((s, alphabet));
+
+        let symbols = s.chars().filter(|c| !self.separators.contains(c));
+        parse_with(symbols, alphabet.radix(), |symbol| {
+            alphabet.digit(symbol).or_else(|| {
+                if self.ignore_case {
+                    alphabet
+                        .digit(symbol.to_ascii_lowercase())
+                        .or_else(|| alphabet.digit(symbol.to_ascii_uppercase()))
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    fn strip_prefix<'s>(&self, s: &'s str, prefix: &str) -> Option<&'s str> {
+        if self.ignore_case {
+            let head = s.get(..prefix.len())?;
+            if head.eq_ignore_ascii_case(prefix) {
+                s.get(prefix.len()..)
+            } else {
+                None
+            }
+        } else {
+            s.strip_prefix(prefix)
+        }
+    }
+}
+
+/// An iterator over the digits of a string, most significant first, validated one symbol at a
+/// time.
+///
+/// Each item is the value of a digit, or an `Err(ParseError::InvalidDigit)` for a symbol that is
+/// not a digit. Nothing is accumulated, so the digits can be fed to a number of any size, or to a
+/// checksum.
+///
+/// # Example
+///
+/// ```
+/// use radixal::alphabet::Alphabet;
+/// use radixal::parse::{from_digits_be, CharDigits, ParseError};
+///
+/// let digits = CharDigits::new("zz", Alphabet::BASE36);
+/// assert_eq!(digits.collect::<Vec<_>>(), vec![Ok(35), Ok(35)]);
+///
+/// assert_eq!(from_digits_be(CharDigits::new("zz", Alphabet::BASE36), 36_u32), Ok(1295));
+/// assert_eq!(
+///     from_digits_be(CharDigits::new("z!", Alphabet::BASE36), 36_u32),
+///     Err(ParseError::InvalidDigit('!'))
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct CharDigits<'a, 'b> {
+    symbols: Chars<'a>,
+    lookup: Lookup<'b>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Lookup<'a> {
+    Alphabet(Alphabet<'a>),
+    Radix(u32),
+}
+
+impl<'a, 'b> CharDigits<'a, 'b> {
+    /// Creates a new `CharDigits` over the symbols of `s`, looked up in `alphabet`.
+    pub fn new(s: &'a str, alphabet: Alphabet<'b>) -> Self {
+        CharDigits {
+            symbols: s.chars(),
+            lookup: Lookup::Alphabet(alphabet),
+        }
+    }
+
+    /// Creates a new `CharDigits` over the symbols of `s` under `radix`, with the digits `0-9`
+    /// followed by the letters `a-z` in any case, like `u32::from_str_radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0, 1 or larger than 36.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::parse::{CharDigits, ParseError};
+    ///
+    /// let mut digits = CharDigits::with_radix("fF8", 16).unwrap();
+    /// assert_eq!(digits.next(), Some(Ok(15)));
+    /// assert_eq!(digits.next_back(), Some(Ok(8)));
+    /// assert_eq!(digits.next(), Some(Ok(15)));
+    /// assert_eq!(digits.next(), None);
+    ///
+    /// let mut digits = CharDigits::with_radix("18", 8).unwrap();
+    /// assert_eq!(digits.nth(1), Some(Err(ParseError::InvalidDigit('8'))));
+    /// ```
+    pub fn with_radix(s: &'a str, radix: usize) -> Result<Self, RadixError> {
+        match radix {
+            0 => Err(RadixError::Radix0),
+            1 => Err(RadixError::Radix1),
+            2..=36 => Ok(CharDigits {
+                symbols: s.chars(),
+                lookup: Lookup::Radix(radix as u32),
+            }),
+            _ => Err(RadixError::RadixTooLarge),
+        }
+    }
+
+    /// Returns the radix, the number of symbols of the alphabet.
+    pub fn radix(&self) -> usize {
+        match self.lookup {
+            Lookup::Alphabet(alphabet) => alphabet.radix(),
+            Lookup::Radix(radix) => radix as usize,
+        }
+    }
+
+    /// Returns the part of the string which has not been read yet.
+    pub fn as_str(&self) -> &'a str {
+        self.symbols.as_str()
+    }
+
+    fn digit(&self, symbol: char) -> Result<usize, ParseError> {
+        let digit = match self.lookup {
+            Lookup::Alphabet(alphabet) => alphabet.digit(symbol),
+            Lookup::Radix(radix) => symbol.to_digit(radix).map(|digit| digit as usize),
+        };
+
+        digit.ok_or(ParseError::InvalidDigit(symbol))
+    }
+}
+
+impl<'a, 'b> Iterator for CharDigits<'a, 'b> {
+    type Item = Result<usize, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let symbol = self.symbols.next()?;
+        Some(self.digit(symbol))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.symbols.size_hint()
+    }
+}
+
+impl<'a, 'b> DoubleEndedIterator for CharDigits<'a, 'b> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let symbol = self.symbols.next_back()?;
+        Some(self.digit(symbol))
+    }
+}
+
+impl<'a, 'b> FusedIterator for CharDigits<'a, 'b> {}
+
+/// A digit given to [`from_digits_be`](fn.from_digits_be.html): either its value, or the error
+/// met while reading it.
+pub trait DigitValue {
+    /// Returns the value of the digit.
+    fn into_value(self) -> Result<usize, ParseError>;
+}
+
+impl<T: IntoDigits> DigitValue for T {
+    fn into_value(self) -> Result<usize, ParseError> {
+        self.to_usize().ok_or(ParseError::DigitTooLarge(usize::MAX))
+    }
+}
+
+macro_rules! impl_digit_value {
+    ( $($t:ty)* ) => {
+        $(
+            impl DigitValue for &$t {
+                fn into_value(self) -> Result<usize, ParseError> {
+                    (*self).into_value()
+                }
+            }
+        )*
+    };
+}
+
+impl_digit_value!(u8 u16 u32 u64 u128 usize);
+
+impl DigitValue for Result<usize, ParseError> {
+    fn into_value(self) -> Result<usize, ParseError> {
+        self
+    }
+}
+
+/// Builds a number from its digits under `radix`, most significant first.
+///
+/// The digits can be plain values, or the items of a [`CharDigits`](struct.CharDigits.html).
+///
+/// Returns an `Err(ParseError)` if the radix is 0 or 1, if there are no digits, if a digit is
+/// invalid or not smaller than the radix, or if the number is too large for `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::parse::{from_digits_be, ParseError};
+///
+/// assert_eq!(from_digits_be(&[1_u8, 2, 3], 10_u32), Ok(123));
+/// assert_eq!(from_digits_be(vec![1_usize, 0], 2_u8), Ok(2));
+/// assert_eq!(from_digits_be(&[1_u8, 10], 10_u32), Err(ParseError::DigitTooLarge(10)));
+/// assert_eq!(from_digits_be(&[2_u8, 5, 6], 10_u8), Err(ParseError::Overflow));
+/// ```
+pub fn from_digits_be<T, I>(digits: I, radix: T) -> Result<T, ParseError>
+where
+    T: IntoDigits,
+    I: IntoIterator,
+    I::Item: DigitValue,
+{
+    if radix == T::zero() {
+        return Err(ParseError::Radix(RadixError::Radix0));
+    } else if radix == T::one() {
+        return Err(ParseError::Radix(RadixError::Radix1));
+    }
+
+    let mut number = None;
+    for digit in digits {
+        let digit = checked_digit(digit, radix)?;
+        number = Some(
+            push_digit(number.unwrap_or_else(T::zero), radix, digit).ok_or(ParseError::Overflow)?,
+        );
+    }
+
+    number.ok_or(ParseError::Empty)
+}
+
+/// Builds a number from the runs of its digits under `radix`, most significant first, each run
+/// being a digit and the number of times it is repeated, as yielded by a
+/// [`DigitRuns`](../digits_iterator/struct.DigitRuns.html).
+///
+/// Returns an `Err(ParseError)` if there are no digits, if a digit is invalid or not smaller than
+/// the radix, or if the number is too large for `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::parse::{from_digit_runs, ParseError};
+///
+/// assert_eq!(from_digit_runs([(1_u8, 3), (0, 2)], 10_u32), Ok(11_100));
+/// assert_eq!(from_digit_runs([(0_u8, 1_000_000)], 10_u32), Ok(0));
+/// assert_eq!(from_digit_runs([(1_u8, 11)], 10_u32), Err(ParseError::Overflow));
+/// assert_eq!(from_digit_runs([(1_u8, 0)], 10_u32), Err(ParseError::Empty));
+/// ```
+pub fn from_digit_runs<T, D, I>(runs: I, radix: T) -> Result<T, ParseError>
+where
+    T: IntoDigits,
+    I: IntoIterator<Item = (D, usize)>,
+    D: DigitValue,
+{
+    let mut number = None;
+    for (digit, len) in runs {
+        let digit = checked_digit(digit, radix)?;
+        if len == 0 {
+            continue;
+        }
+
+        let mut current = number.unwrap_or_else(T::zero);
+        // Leading zeroes do not change the number, however long the run.
+        if current != T::zero() || digit != T::zero() {
+            for _ in 0..len {
+                current = push_digit(current, radix, digit).ok_or(ParseError::Overflow)?;
+            }
+        }
+        number = Some(current);
+    }
+
+    number.ok_or(ParseError::Empty)
+}
+
+/// Reads the value of `digit`, checking it against `radix`.
+fn checked_digit<T: IntoDigits, D: DigitValue>(digit: D, radix: T) -> Result<T, ParseError> {
+    let value = digit.into_value()?;
+    T::from_usize(value)
+        .filter(|&digit| digit < radix)
+        .ok_or(ParseError::DigitTooLarge(value))
+}
+
+/// Appends `digit` to `number` (i.e. `number * radix + digit`), or returns `None` on overflow.
+pub(crate) fn push_digit<T: IntoDigits>(number: T, radix: T, digit: T) -> Option<T> {
+    number.checked_mul(radix)?.checked_add(digit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::num::Wrapping;
+
+    #[test]
+    fn test_char_digits_agree_with_parse() {
+        for &s in &["0", "zz", "000z", "73", "74", "z!z", ""] {
+            assert_eq!(
+                from_digits_be(CharDigits::new(s, Alphabet::BASE36), 36_u8),
+                parse::<u8>(s, &Alphabet::BASE36)
+            );
+        }
+    }
+
+    #[test]
+    fn test_char_digits_stream_beyond_type() {
+        // A number far larger than any supported type, reduced modulo 97 as it streams.
+        let s = "123456789012345678901234567890123456789012345678901234567890";
+        let remainder = CharDigits::with_radix(s, 10)
+            .unwrap()
+            .try_fold(0, |acc, digit| digit.map(|digit| (acc * 10 + digit) % 97));
+        assert_eq!(remainder, Ok(10));
+    }
+
+    #[cfg(feature = "alphabets")]
+    #[test]
+    fn test_char_digits_rest() {
+        let mut digits = CharDigits::new("١٢٣", Alphabet::EASTERN_ARABIC);
+        assert_eq!(digits.next(), Some(Ok(1)));
+        assert_eq!(digits.as_str(), "٢٣");
+        assert_eq!(
+            CharDigits::with_radix("z", 37).err(),
+            Some(RadixError::RadixTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_from_digits_empty_and_radix_1() {
+        assert_eq!(
+            from_digits_be(core::iter::empty::<u8>(), 10_u32),
+            Err(ParseError::Empty)
+        );
+        assert_eq!(
+            from_digits_be([0_u8, 0], 1_u32),
+            Err(ParseError::Radix(RadixError::Radix1))
+        );
+        assert_eq!(
+            from_digits_be([0_u8, 0], 0_u32),
+            Err(ParseError::Radix(RadixError::Radix0))
+        );
+    }
+
+    #[test]
+    fn test_parse_leading_zeroes() {
+        assert_eq!(parse::<u8>("000000000z", &Alphabet::BASE36), Ok(35));
+    }
+
+    #[test]
+    fn test_parse_max() {
+        assert_eq!(parse::<u8>("73", &Alphabet::BASE36), Ok(255));
+        assert_eq!(
+            parse::<u8>("74", &Alphabet::BASE36),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_lenient_prefix_without_digits() {
+        let options = ParseOptions::new().with_prefixes(ParseOptions::RUST_PREFIXES);
+        assert_eq!(
+            options.parse::<u32>("0x", &Alphabet::DECIMAL),
+            Err(ParseError::Empty)
+        );
+        // Prefixes are case sensitive unless the case is ignored.
+        assert_eq!(
+            options.parse::<u32>("0XFF", &Alphabet::DECIMAL),
+            Err(ParseError::InvalidDigit('X'))
+        );
+    }
+
+    #[test]
+    fn test_lenient_multibyte_prefix() {
+        let prefixes = [("№", Alphabet::DECIMAL)];
+        let options = ParseOptions::new().ignore_case().with_prefixes(&prefixes);
+        assert_eq!(options.parse::<u32>("№42", &Alphabet::BINARY), Ok(42));
+        assert_eq!(
+            options.parse::<u32>("4", &Alphabet::BINARY),
+            Err(ParseError::InvalidDigit('4'))
+        );
+    }
+
+    #[test]
+    fn test_lenient_overflow() {
+        let options = ParseOptions::new().with_separators(&['_']);
+        assert_eq!(
+            options.parse::<u8>("2_5_6", &Alphabet::DECIMAL),
+            Err(ParseError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_wrapping_overflows() {
+        assert_eq!(
+            parse::<Wrapping<u8>>("73", &Alphabet::BASE36),
+            Ok(Wrapping(255))
+        );
+        assert_eq!(
+            parse::<Wrapping<u8>>("74", &Alphabet::BASE36),
+            Err(ParseError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! A table of the powers of a radix, computed once and shared by the conversions of many numbers.
+use crate::digits_iterator::{DigitsIterator, RadixError};
+use crate::{IntoDigits, RadixMath};
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// The largest number of powers, those of 2 in a `u128`.
+const MAX_POWERS: usize = 128;
+
+/// All the powers of a radix that fit in `T`, from `radix^0`.
+///
+/// Creating a `DigitsIterator` from the table only needs a binary search, instead of computing
+/// the number of digits and the matching power.
+///
+/// # Example
+///
+/// ```
+/// use radixal::powers::RadixPowers;
+///
+/// let powers = RadixPowers::new(10_u16).unwrap();
+/// assert_eq!(powers.as_slice(), [1, 10, 100, 1000, 10_000]);
+/// assert_eq!(powers.nbr_digits(4321), 4);
+/// assert!(powers.digits(4321).eq([4, 3, 2, 1]));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct RadixPowers<T: IntoDigits> {
+    powers: [T; MAX_POWERS],
+    len: usize,
+}
+
+impl<T: IntoDigits> RadixPowers<T> {
+    /// Computes the powers of `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is 0 or 1, or if more than 128 of its powers fit
+    /// in `T`, which only happens for types wider than 128 bits.
+    pub fn new(radix: T) -> Result<Self, RadixError> {
+        if radix == T::zero() {
+            return Err(RadixError::Radix0);
+        } else if radix == T::one() {
+            return Err(RadixError::Radix1);
+        }
+
+        let mut powers = [T::zero(); MAX_POWERS];
+        let mut power = T::one();
+        let mut len = 0;
+        loop {
+            if len == MAX_POWERS {
+                return Err(RadixError::RadixTooSmall);
+            }
+            powers[len] = power;
+            len += 1;
+            power = match power.checked_mul(radix) {
+                Some(power) => power,
+                None => break,
+            };
+        }
+
+        Ok(RadixPowers { powers, len })
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.powers[1]
+    }
+
+    /// Returns the powers, from `radix^0`.
+    pub fn as_slice(&self) -> &[T] {
+        &self.powers[..self.len]
+    }
+
+    /// Returns `radix^exponent`, or `None` if it does not fit in `T`.
+    pub fn get(&self, exponent: usize) -> Option<T> {
+        self.as_slice().get(exponent).copied()
+    }
+
+    /// Counts the number of digits of `number`.
+    pub fn nbr_digits(&self, number: T) -> usize {
+        self.as_slice()
+            .partition_point(|&power| power <= number)
+            .max(1)
+    }
+
+    /// Creates a `DigitsIterator` over the digits of `number`.
+    pub fn digits(&self, number: T) -> DigitsIterator<T> {
+        let len = self.nbr_digits(number);
+        DigitsIterator::with_splitter(number, self.radix(), self.powers[len - 1], len)
+    }
+
+    /// Creates an `IndexedDigits` over the digits of `number`, borrowing the table.
+    pub fn indexed_digits(&self, number: T) -> IndexedDigits<'_, T> {
+        IndexedDigits {
+            powers: self,
+            current: number,
+            len: self.nbr_digits(number),
+        }
+    }
+}
+
+impl<T: IntoDigits + fmt::Debug> fmt::Debug for RadixPowers<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RadixPowers")
+            .field("powers", &self.as_slice())
+            .finish()
+    }
+}
+
+/// An iterator over the digits of a number, most significant first, reading the powers of the
+/// radix from a shared `RadixPowers`.
+///
+/// Skipping digits with `nth`, `nth_back` or `step_by`, or splitting the digits with `split_at`,
+/// takes a single division, where a `DigitsIterator` would split off the digits one by one.
+///
+/// # Example
+///
+/// ```
+/// use radixal::powers::RadixPowers;
+///
+/// let powers = RadixPowers::new(10_u32).unwrap();
+///
+/// assert!(powers.indexed_digits(123_456).step_by(2).eq([1, 3, 5]));
+/// assert_eq!(powers.indexed_digits(123_456).nth(4), Some(5));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexedDigits<'a, T: IntoDigits> {
+    powers: &'a RadixPowers<T>,
+    current: T,
+    len: usize,
+}
+
+impl<'a, T: IntoDigits> IndexedDigits<'a, T> {
+    /// Returns the number made of the remaining digits.
+    pub fn to_number(&self) -> T {
+        self.current
+    }
+
+    /// Splits the remaining digits in the `mid` most significant ones and the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is larger than the number of remaining digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::powers::RadixPowers;
+    ///
+    /// let powers = RadixPowers::new(10_u32).unwrap();
+    /// let (high, low) = powers.indexed_digits(12_034).split_at(2);
+    ///
+    /// assert_eq!(high.to_number(), 12);
+    /// assert!(low.eq([0, 3, 4]));
+    /// ```
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        assert!(mid <= self.len, "mid > len");
+
+        let (high, low) = match self.powers.get(self.len - mid) {
+            Some(power) => {
+                let high = self.current / power;
+                (high, self.current - high * power)
+            }
+            // `radix^len` does not fit, so that every digit is a low one.
+            None => (T::zero(), self.current),
+        };
+        (
+            IndexedDigits {
+                current: high,
+                len: mid,
+                ..self
+            },
+            IndexedDigits {
+                current: low,
+                len: self.len - mid,
+                ..self
+            },
+        )
+    }
+}
+
+impl<'a, T: IntoDigits> Iterator for IndexedDigits<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let power = self.powers.powers[self.len];
+        let digit = self.current / power;
+        self.current = self.current - digit * power;
+        Some(digit)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.current = T::zero();
+            self.len = 0;
+            return None;
+        }
+
+        if n > 0 {
+            self.len -= n;
+            self.current = self.current % self.powers.powers[self.len];
+        }
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T: IntoDigits> DoubleEndedIterator for IndexedDigits<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.current = T::zero();
+            self.len = 0;
+            return None;
+        }
+
+        self.len -= n + 1;
+        let radix = self.powers.radix();
+        let rest = self.current / self.powers.powers[n];
+        self.current = rest / radix;
+        Some(rest - self.current * radix)
+    }
+}
+
+impl<'a, T: IntoDigits> ExactSizeIterator for IndexedDigits<'a, T> {}
+
+impl<'a, T: IntoDigits> FusedIterator for IndexedDigits<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::digital::DigitalNumber;
+    use crate::digits_iterator::tests::Wide256;
+
+    #[test]
+    fn test_powers() {
+        let powers = RadixPowers::new(2_u128).unwrap();
+        assert_eq!(powers.as_slice().len(), 128);
+        assert_eq!(powers.get(127), Some(1 << 127));
+        assert_eq!(powers.get(128), None);
+
+        let powers = RadixPowers::new(u8::MAX).unwrap();
+        assert_eq!(powers.as_slice(), [1, 255]);
+        assert_eq!(powers.radix(), 255);
+
+        assert_eq!(RadixPowers::new(0_u8), Err(RadixError::Radix0));
+        assert_eq!(RadixPowers::new(1_u8), Err(RadixError::Radix1));
+    }
+
+    #[test]
+    fn test_wider_than_u128() {
+        let radix = |radix| DigitalNumber(Wide256(0, radix));
+        assert_eq!(RadixPowers::new(radix(2)), Err(RadixError::RadixTooSmall));
+        assert_eq!(RadixPowers::new(radix(3)), Err(RadixError::RadixTooSmall));
+
+        let powers = RadixPowers::new(radix(4)).unwrap();
+        assert_eq!(powers.as_slice().len(), 128);
+        assert_eq!(powers.nbr_digits(Wide256::MAX), 128);
+        assert!(powers
+            .digits(Wide256::MAX)
+            .eq(Wide256::MAX.into_digits(radix(4)).unwrap()));
+    }
+
+    #[test]
+    fn test_digits_match_new() {
+        for radix in 2..=40_u64 {
+            let powers = RadixPowers::new(radix).unwrap();
+            assert_eq!(powers.radix(), radix);
+
+            for &power in powers.as_slice() {
+                for &number in &[0, power - 1, power, power + 1, u64::MAX] {
+                    let digits = DigitsIterator::new(number, radix).unwrap();
+                    assert_eq!(powers.nbr_digits(number), digits.len());
+                    assert_eq!(powers.digits(number), digits);
+                    assert!(powers.digits(number).eq(digits.clone()));
+                    assert!(powers.digits(number).rev().eq(digits.rev()));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_indexed_digits_match_iteration() {
+        for &radix in &[2_u64, 3, 10, 16, 255, u64::MAX] {
+            let powers = RadixPowers::new(radix).unwrap();
+            for &number in &[0, 1, 9, 10, 255, 1 << 40, u64::MAX - 1, u64::MAX] {
+                let expected: Vec<_> = number.into_digits(radix).unwrap().collect();
+                let digits = powers.indexed_digits(number);
+                assert_eq!(digits.len(), expected.len());
+                assert!(digits.eq(expected.iter().copied()));
+                assert!(digits.rev().eq(expected.iter().rev().copied()));
+
+                for step in 1..=expected.len() + 1 {
+                    assert!(digits
+                        .step_by(step)
+                        .eq(expected.iter().step_by(step).copied()));
+                    assert!(digits.rev().step_by(step).eq(expected
+                        .iter()
+                        .rev()
+                        .step_by(step)
+                        .copied()));
+                }
+
+                for mid in 0..=expected.len() {
+                    let (high, low) = digits.split_at(mid);
+                    assert!(high.eq(expected[..mid].iter().copied()));
+                    assert!(low.eq(expected[mid..].iter().copied()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_indexed_digits_past_the_end() {
+        let powers = RadixPowers::new(10_u8).unwrap();
+        let mut digits = powers.indexed_digits(123);
+        assert_eq!(digits.nth(3), None);
+        assert_eq!(digits.len(), 0);
+
+        let mut digits = powers.indexed_digits(123);
+        assert_eq!(digits.nth_back(1), Some(2));
+        assert_eq!(digits.nth_back(1), None);
+        assert_eq!(digits.next(), None);
+    }
+}
@@ -0,0 +1,487 @@
+//! Random numbers constrained by their digits, for generating test data.
+//!
+//! Each generator is a `Distribution` of `rand`, sampling uniformly among the numbers that match
+//! its constraints: ranges are sampled by `rand`, without modulo bias, and the digits are drawn
+//! so that every matching number is equally likely.
+//!
+//! # Example
+//!
+//! ```
+//! use rand::distributions::Distribution;
+//! use radixal::random::{DistinctDigits, UniformDigits};
+//! use radixal::IntoDigits;
+//!
+//! let mut rng = rand::thread_rng();
+//!
+//! let n = UniformDigits::new(6, 10_u32).unwrap().sample(&mut rng);
+//! assert_eq!(n.nbr_decimal_digits(), 6);
+//!
+//! let n = DistinctDigits::new(10, 10_u64).unwrap().sample(&mut rng);
+//! assert!(n.is_decimal_permutation(1_234_567_890));
+//! ```
+use crate::digits_iterator::RadixError;
+use crate::{IntoDigits, RadixMath};
+use core::fmt;
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::Distribution;
+use rand::Rng;
+
+/// Errors that can occur when creating a generator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RandomError {
+    /// The radix is 0 or 1.
+    Radix(RadixError),
+    /// Some numbers of this width do not fit in the integer type.
+    WidthTooLarge,
+    /// No number matches the constraints.
+    Empty,
+}
+
+impl fmt::Display for RandomError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RandomError::Radix(error) => error.fmt(f),
+            RandomError::WidthTooLarge => write!(f, "width too large for the integer type"),
+            RandomError::Empty => write!(f, "no number matches the constraints"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RandomError {}
+
+impl From<RadixError> for RandomError {
+    fn from(error: RadixError) -> Self {
+        RandomError::Radix(error)
+    }
+}
+
+fn check_radix<T: IntoDigits>(radix: T) -> Result<(), RadixError> {
+    if radix == T::zero() {
+        Err(RadixError::Radix0)
+    } else if radix == T::one() {
+        Err(RadixError::Radix1)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns `radix^(width - 1)` and `radix^width - 1`, the bounds of the numbers of `width`
+/// digits, or `None` if no such number fits in `T`; the upper bound is `None` if it does not fit.
+fn bounds<T: IntoDigits>(width: usize, radix: T) -> Option<(T, Option<T>)> {
+    let mut low = T::one();
+    for _ in 1..width {
+        low = low.checked_mul(radix)?;
+    }
+
+    // `radix^width - 1 = radix^(width - 1) * (radix - 1) + radix^(width - 1) - 1`
+    let high = low
+        .checked_mul(radix - T::one())
+        .and_then(|high| high.checked_add(low - T::one()));
+    Some((low, high))
+}
+
+/// Returns the bounds of the numbers of `width` digits, checking that they all fit in `T`.
+fn checked_bounds<T: IntoDigits>(width: usize, radix: T) -> Result<(T, T), RandomError> {
+    check_radix(radix)?;
+    if width == 0 {
+        return Err(RandomError::Empty);
+    }
+
+    match bounds(width, radix) {
+        Some((low, Some(high))) => Ok((low, high)),
+        Some((_, None)) => Err(RandomError::WidthTooLarge),
+        None => Err(RandomError::Empty),
+    }
+}
+
+/// Samples uniformly the numbers of exactly `width` digits under a radix.
+///
+/// The numbers of one digit include 0. When the largest numbers of that width do not fit in
+/// `T`, the numbers sampled stop at `T::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::Distribution;
+/// use radixal::random::{RandomError, UniformDigits};
+///
+/// let three_digits = UniformDigits::new(3, 10_u16).unwrap();
+/// let n = three_digits.sample(&mut rand::thread_rng());
+/// assert!((100..1000).contains(&n));
+///
+/// assert!(UniformDigits::new(20, 10_u64).is_ok());
+/// assert_eq!(UniformDigits::new(21, 10_u64), Err(RandomError::Empty));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UniformDigits<T: IntoDigits> {
+    low: T,
+    high: T,
+}
+
+impl<T: IntoDigits> UniformDigits<T> {
+    /// Creates a new `UniformDigits` for the numbers of `width` digits using `radix`.
+    ///
+    /// Returns an `Err(RandomError)` if the radix is 0 or 1, or if no number of that width fits
+    /// in `T`.
+    pub fn new(width: usize, radix: T) -> Result<Self, RandomError> {
+        check_radix(radix)?;
+        if width == 0 {
+            return Err(RandomError::Empty);
+        }
+
+        let (low, high) = bounds(width, radix).ok_or(RandomError::Empty)?;
+        Ok(UniformDigits {
+            low: if width == 1 { T::zero() } else { low },
+            high: high.unwrap_or_else(|| low.max_above()),
+        })
+    }
+}
+
+impl<T: IntoDigits + SampleUniform> Distribution<T> for UniformDigits<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        rng.gen_range(self.low..=self.high)
+    }
+}
+
+/// Samples uniformly the palindromes of exactly `width` digits under a radix.
+///
+/// The most significant half of the digits is sampled, and mirrored.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::Distribution;
+/// use radixal::random::Palindromes;
+/// use radixal::IntoDigits;
+///
+/// let palindromes = Palindromes::new(7, 16_u32).unwrap();
+/// let n = palindromes.sample(&mut rand::thread_rng());
+/// assert!(n.is_palindrome(16).unwrap());
+/// assert_eq!(n.nbr_digits(16).unwrap(), 7);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Palindromes<T: IntoDigits> {
+    /// The bounds of the most significant half, including the middle digit.
+    low: T,
+    high: T,
+    width: usize,
+    radix: T,
+}
+
+impl<T: IntoDigits> Palindromes<T> {
+    /// Creates a new `Palindromes` for the palindromes of `width` digits using `radix`.
+    ///
+    /// Returns an `Err(RandomError)` if the radix is 0 or 1, if the width is 0, or if some
+    /// numbers of that width do not fit in `T`.
+    pub fn new(width: usize, radix: T) -> Result<Self, RandomError> {
+        checked_bounds(width, radix)?;
+        let (low, high) = checked_bounds(width.div_ceil(2), radix)?;
+        Ok(Palindromes {
+            low: if width == 1 { T::zero() } else { low },
+            high,
+            width,
+            radix,
+        })
+    }
+}
+
+impl<T: IntoDigits + SampleUniform> Distribution<T> for Palindromes<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        let half = rng.gen_range(self.low..=self.high);
+
+        // The middle digit of an odd width is not repeated.
+        let mut mirrored = if self.width % 2 == 1 {
+            half / self.radix
+        } else {
+            half
+        };
+        let mut number = half;
+        for _ in 0..self.width / 2 {
+            number = number * self.radix + mirrored % self.radix;
+            mirrored = mirrored / self.radix;
+        }
+        number
+    }
+}
+
+/// Samples uniformly the numbers of exactly `width` digits under a radix whose digits are all
+/// distinct.
+///
+/// The most significant digit is sampled first, among the nonzero digits unless the width is 1,
+/// and each following digit among the digits not sampled yet.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::Distribution;
+/// use radixal::random::{DistinctDigits, RandomError};
+/// use radixal::IntoDigits;
+///
+/// let distinct = DistinctDigits::new(4, 10_u32).unwrap();
+/// let n = distinct.sample(&mut rand::thread_rng());
+/// assert_eq!(n.unique_digits(10), Ok(n));
+///
+/// assert_eq!(DistinctDigits::new(11, 10_u64), Err(RandomError::Empty));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DistinctDigits<T: IntoDigits> {
+    width: usize,
+    radix: T,
+}
+
+impl<T: IntoDigits> DistinctDigits<T> {
+    /// Creates a new `DistinctDigits` for the numbers of `width` distinct digits using `radix`.
+    ///
+    /// Returns an `Err(RandomError)` if the radix is 0 or 1, if the width is 0 or larger than the
+    /// radix, or if some numbers of that width do not fit in `T`.
+    pub fn new(width: usize, radix: T) -> Result<Self, RandomError> {
+        checked_bounds(width, radix)?;
+        if radix.to_usize().is_some_and(|radix| width > radix) {
+            return Err(RandomError::Empty);
+        }
+
+        Ok(DistinctDigits { width, radix })
+    }
+}
+
+impl<T: IntoDigits + SampleUniform> Distribution<T> for DistinctDigits<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        // The width fits in `T`, being at most the radix, and is at most 128.
+        let mut used = [T::zero(); 128];
+        let mut number = T::zero();
+        for len in 0..self.width {
+            let low = if len == 0 && self.width > 1 {
+                T::one()
+            } else {
+                T::zero()
+            };
+
+            // The index of the next digit among the unused ones, which is shifted past the used
+            // digits in increasing order.
+            let available = self.radix - T::from_usize(len).unwrap();
+            let mut digit = rng.gen_range(low..available);
+            let mut idx = 0;
+            while idx < len && used[idx] <= digit {
+                digit = digit + T::one();
+                idx += 1;
+            }
+
+            used.copy_within(idx..len, idx + 1);
+            used[idx] = digit;
+            number = number * self.radix + digit;
+        }
+        number
+    }
+}
+
+/// Samples uniformly the numbers of at most `width` digits under a radix whose digits add up to
+/// a given sum.
+///
+/// A table of the number of sequences of digits for each length and each sum is computed when
+/// the generator is created, so that each digit is drawn with the probability of the numbers it
+/// leads to. The table holds `(width + 1) * (sum + 1)` counts.
+///
+/// # Example
+///
+/// ```
+/// use rand::distributions::Distribution;
+/// use radixal::random::{DigitSum, RandomError};
+/// use radixal::IntoDigits;
+///
+/// let digit_sum = DigitSum::new(6, 27, 10_u32).unwrap();
+/// let n = digit_sum.sample(&mut rand::thread_rng());
+/// assert!(n < 1_000_000);
+/// assert_eq!(n.digit_sum(10), Ok(27));
+///
+/// assert_eq!(DigitSum::new(2, 19, 10_u32).unwrap_err(), RandomError::Empty);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigitSum<T: IntoDigits> {
+    /// The number of sequences of `len` digits adding up to `sum`, at `len * (self.sum + 1) +
+    /// sum`.
+    counts: Vec<u128>,
+    width: usize,
+    sum: usize,
+    radix: T,
+}
+
+impl<T: IntoDigits> DigitSum<T> {
+    /// Creates a new `DigitSum` for the numbers of at most `width` digits using `radix`, whose
+    /// digits add up to `sum`.
+    ///
+    /// Returns an `Err(RandomError)` if the radix is 0 or 1, if some numbers of that width do
+    /// not fit in `T`, or if no number of that width has digits adding up to `sum`.
+    pub fn new(width: usize, sum: usize, radix: T) -> Result<Self, RandomError> {
+        checked_bounds(width, radix)?;
+        let digits = radix.to_usize().unwrap_or(usize::MAX);
+        if sum.div_ceil(width) >= digits {
+            return Err(RandomError::Empty);
+        }
+
+        // `count(len, sum) = count(len, sum - 1) + count(len - 1, sum)
+        //     - count(len - 1, sum - radix)`
+        let columns = sum + 1;
+        let mut counts = vec![0; (width + 1) * columns];
+        counts[0] = 1;
+        for len in 1..=width {
+            for partial in 0..columns {
+                let mut count = counts[(len - 1) * columns + partial];
+                if partial > 0 {
+                    count += counts[len * columns + partial - 1];
+                }
+                if partial >= digits {
+                    count -= counts[(len - 1) * columns + partial - digits];
+                }
+                counts[len * columns + partial] = count;
+            }
+        }
+
+        Ok(DigitSum {
+            counts,
+            width,
+            sum,
+            radix,
+        })
+    }
+
+    fn count(&self, len: usize, sum: usize) -> u128 {
+        self.counts[len * (self.sum + 1) + sum]
+    }
+}
+
+impl<T: IntoDigits> Distribution<T> for DigitSum<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        let mut remaining = self.sum;
+        let mut number = T::zero();
+        for len in (0..self.width).rev() {
+            // Picks one of the sequences of the remaining digits, and the digit it starts with.
+            let mut target = rng.gen_range(0..self.count(len + 1, remaining));
+            let mut digit = 0;
+            while target >= self.count(len, remaining - digit) {
+                target -= self.count(len, remaining - digit);
+                digit += 1;
+            }
+
+            number = number * self.radix + T::from_usize(digit).unwrap();
+            remaining -= digit;
+        }
+        number
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    /// Checks that sampling `distribution` gives each of the `expected` numbers about as often,
+    /// and nothing else.
+    fn check_uniform<T, D>(distribution: D, expected: &[T])
+    where
+        T: IntoDigits + core::hash::Hash + fmt::Debug,
+        D: Distribution<T>,
+    {
+        let mut rng = StdRng::seed_from_u64(0x5EED);
+        let samples = 1000 * expected.len();
+        let mut counts = HashMap::new();
+        for n in distribution.sample_iter(&mut rng).take(samples) {
+            *counts.entry(n).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts.len(), expected.len(), "{:?}", counts);
+        for n in expected {
+            let count = counts[n];
+            assert!((800..1200).contains(&count), "{:?} {}", n, count);
+        }
+    }
+
+    #[test]
+    fn test_uniform_digits() {
+        check_uniform(UniformDigits::new(1, 3_u8).unwrap(), &[0, 1, 2]);
+        check_uniform(
+            UniformDigits::new(2, 3_u8).unwrap(),
+            &(3..9).collect::<Vec<_>>(),
+        );
+        check_uniform(
+            UniformDigits::new(2, 16_u8).unwrap(),
+            &(16..=255).collect::<Vec<_>>(),
+        );
+        assert_eq!(UniformDigits::new(3, 16_u8), Err(RandomError::Empty));
+        assert_eq!(UniformDigits::new(0, 16_u8), Err(RandomError::Empty));
+        assert_eq!(
+            UniformDigits::new(1, 1_u8),
+            Err(RandomError::Radix(RadixError::Radix1))
+        );
+
+        // The widest numbers stop at `u128::MAX`.
+        let mut rng = StdRng::seed_from_u64(1);
+        let widest = UniformDigits::new(39, 10_u128).unwrap();
+        for n in widest.sample_iter(&mut rng).take(100) {
+            assert_eq!(n.nbr_decimal_digits(), 39);
+        }
+    }
+
+    #[test]
+    fn test_palindromes() {
+        check_uniform(
+            Palindromes::new(1, 10_u16).unwrap(),
+            &(0..10).collect::<Vec<_>>(),
+        );
+        let expected: Vec<u16> = (10..10_000)
+            .filter(|n: &u16| n.nbr_decimal_digits() == 3 && n.is_decimal_palindrome())
+            .collect();
+        assert_eq!(expected.len(), 90);
+        check_uniform(Palindromes::new(3, 10_u16).unwrap(), &expected);
+        let expected: Vec<u16> = (0..=u16::MAX)
+            .filter(|n| n.nbr_digits(3).unwrap() == 4 && n.is_palindrome(3).unwrap())
+            .collect();
+        check_uniform(Palindromes::new(4, 3_u16).unwrap(), &expected);
+
+        assert_eq!(Palindromes::new(3, 10_u8), Err(RandomError::WidthTooLarge));
+        let mut rng = StdRng::seed_from_u64(2);
+        let widest = Palindromes::new(128, 2_u128).unwrap();
+        for n in widest.sample_iter(&mut rng).take(100) {
+            assert!(n.is_binary_palindrome());
+            assert_eq!(n.nbr_binary_digits(), 128);
+        }
+    }
+
+    #[test]
+    fn test_distinct_digits() {
+        let expected: Vec<u16> = (0..=u16::MAX)
+            .filter(|n| n.nbr_digits(5).unwrap() == 3 && n.unique_digits(5) == Ok(*n))
+            .collect();
+        assert_eq!(expected.len(), 4 * 4 * 3);
+        check_uniform(DistinctDigits::new(3, 5_u16).unwrap(), &expected);
+        check_uniform(DistinctDigits::new(1, 4_u16).unwrap(), &[0, 1, 2, 3]);
+
+        assert_eq!(DistinctDigits::new(6, 5_u32), Err(RandomError::Empty));
+        let mut rng = StdRng::seed_from_u64(3);
+        let widest = DistinctDigits::new(16, 16_u64).unwrap();
+        for n in widest.sample_iter(&mut rng).take(100) {
+            assert!(n.is_permutation(0xFEDC_BA98_7654_3210, 16).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_digit_sum() {
+        let expected: Vec<u16> = (0..27).filter(|n| n.digit_sum(3) == Ok(2)).collect();
+        assert_eq!(expected.len(), 6);
+        check_uniform(DigitSum::new(3, 2, 3_u16).unwrap(), &expected);
+
+        let expected: Vec<u16> = (0..10_000).filter(|n| n.digit_sum(10) == Ok(30)).collect();
+        check_uniform(DigitSum::new(4, 30, 10_u16).unwrap(), &expected);
+        check_uniform(DigitSum::new(4, 0, 10_u16).unwrap(), &[0]);
+
+        assert_eq!(DigitSum::new(4, 37, 10_u16), Err(RandomError::Empty));
+        assert_eq!(DigitSum::new(5, 1, 10_u16), Err(RandomError::WidthTooLarge));
+        let mut rng = StdRng::seed_from_u64(4);
+        let widest = DigitSum::new(128, 64, 2_u128).unwrap();
+        for n in widest.sample_iter(&mut rng).take(100) {
+            assert_eq!(n.count_ones(), 64);
+        }
+    }
+}
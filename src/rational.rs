@@ -0,0 +1,550 @@
+//! Digits of the fractional part of rational numbers, with their preperiod and their repetend.
+//!
+//! The expansion of `n / d` under a radix is made by long division: each digit multiplies the
+//! remainder by the radix and divides it by `d`. The remainders are below `d`, so that one of
+//! them eventually comes back, and the digits repeat from there. The expansion ends when the
+//! remainder is 0, which happens if and only if every prime factor of the reduced denominator
+//! divides the radix.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::rational::RationalDigits;
+//!
+//! // 1/6 = 0.1666…
+//! let digits = RationalDigits::new(1_u32, 6, 10).unwrap();
+//! assert_eq!((digits.preperiod(), digits.period()), (1, Some(1)));
+//! assert!(digits.take(4).eq([1, 6, 6, 6]));
+//!
+//! // 1/7 = 0.(142857)
+//! let digits = RationalDigits::new(1_u32, 7, 10).unwrap();
+//! assert!(digits.repetend().unwrap().eq([1, 4, 2, 8, 5, 7]));
+//! ```
+//!
+//! The other expansion of rational numbers, their continued fraction, comes from the steps of
+//! Euclid's algorithm, and its convergents are the best rational approximations of the number.
+//!
+//! ```
+//! use radixal::rational::ContinuedFraction;
+//!
+//! // 415/93 = 4 + 1/(2 + 1/(6 + 1/7))
+//! let terms = ContinuedFraction::new(415_u32, 93).unwrap();
+//! assert!(terms.eq([4, 2, 6, 7]));
+//! assert!(terms.convergents().eq([(4, 1), (9, 2), (58, 13), (415, 93)]));
+//! ```
+use crate::digits_iterator::RadixError;
+use crate::{IntoDigits, RadixMath};
+use core::fmt;
+use core::iter::{FusedIterator, Take};
+
+/// Errors that can occur when creating a `RationalDigits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RationalError {
+    /// The radix is 0 or 1.
+    Radix(RadixError),
+    /// The denominator is 0.
+    ZeroDenominator,
+}
+
+impl fmt::Display for RationalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RationalError::Radix(err) => err.fmt(f),
+            RationalError::ZeroDenominator => write!(f, "denominator cannot be 0"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RationalError {}
+
+impl From<RadixError> for RationalError {
+    fn from(err: RadixError) -> Self {
+        RationalError::Radix(err)
+    }
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+pub(crate) fn gcd<T: IntoDigits>(mut a: T, mut b: T) -> T {
+    while !b.is_zero() {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Returns the quotient and the remainder of `a * b` divided by `d`, with `a < d`, so that the
+/// quotient is below `b`, even if the product does not fit.
+fn mul_div_rem<T: IntoDigits>(a: T, b: T, d: T) -> (T, T) {
+    if let Some(product) = a.checked_mul(b) {
+        return (product / d, product % d);
+    }
+
+    // Long multiplication, one bit of `b` at a time, keeping `q * d + s` with `s < d`.
+    let (mut q, mut s) = (T::zero(), T::zero());
+    for bit in b.into_digits(T::binary_radix()).into_iter().flatten() {
+        q = q + q;
+        if s >= d - s {
+            s = s - (d - s);
+            q = q + T::one();
+        } else {
+            s = s + s;
+        }
+
+        if bit == T::one() {
+            if s >= d - a {
+                s = s - (d - a);
+                q = q + T::one();
+            } else {
+                s = s + a;
+            }
+        }
+    }
+    (q, s)
+}
+
+/// An iterator over the digits of the fractional part of a rational number, most significant
+/// first.
+///
+/// The iterator ends when the remaining fraction is 0, and never ends if the expansion repeats,
+/// other than with 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RationalDigits<T: IntoDigits> {
+    remainder: T,
+    denominator: T,
+    radix: T,
+}
+
+impl<T: IntoDigits> RationalDigits<T> {
+    /// Creates a new `RationalDigits` over the digits of the fractional part of
+    /// `numerator / denominator`, with a given `radix`.
+    ///
+    /// The integer part, `numerator / denominator`, is left out.
+    ///
+    /// Returns an `Err(RationalError)` if the radix is 0 or 1, or if the denominator is 0.
+    pub fn new(numerator: T, denominator: T, radix: T) -> Result<Self, RationalError> {
+        if radix.is_zero() {
+            return Err(RadixError::Radix0.into());
+        } else if radix.is_one() {
+            return Err(RadixError::Radix1.into());
+        } else if denominator.is_zero() {
+            return Err(RationalError::ZeroDenominator);
+        }
+
+        Ok(RationalDigits {
+            remainder: numerator % denominator,
+            denominator,
+            radix,
+        })
+    }
+
+    /// Returns the radix.
+    pub fn radix(&self) -> T {
+        self.radix
+    }
+
+    /// Returns the remaining fraction, as its numerator over the denominator.
+    pub fn remainder(&self) -> T {
+        self.remainder
+    }
+
+    /// Returns the number of remaining digits, or `None` if they never end.
+    ///
+    /// The remaining digits end if the reduced denominator only has prime factors of the radix,
+    /// and are then at most as many as its number of bits.
+    pub fn exact_len(&self) -> Option<usize> {
+        let mut denominator = self.denominator / gcd(self.remainder, self.denominator);
+        loop {
+            let common = gcd(denominator, self.radix);
+            if common.is_one() {
+                break;
+            }
+            denominator = denominator / common;
+        }
+
+        if denominator.is_one() {
+            Some(self.count())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of remaining digits before the repetend, or all of them if they end.
+    ///
+    /// Finding the repetend takes as many steps as the digits up to its second occurrence, which
+    /// can be as many as the denominator.
+    pub fn preperiod(&self) -> usize {
+        self.cycle().0
+    }
+
+    /// Returns the length of the repetend, or `None` if the remaining digits end.
+    ///
+    /// Finding the repetend takes as many steps as the digits up to its second occurrence, which
+    /// can be as many as the denominator.
+    pub fn period(&self) -> Option<usize> {
+        self.cycle().1
+    }
+
+    /// Returns an iterator over the digits of the repetend, or `None` if the remaining digits
+    /// end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::rational::RationalDigits;
+    ///
+    /// // 1/12 = 0.08(3)
+    /// let digits = RationalDigits::new(1_u8, 12, 10).unwrap();
+    /// assert!(digits.repetend().unwrap().eq([3]));
+    /// // 1/12 = 0.1 in radix 12
+    /// assert!(RationalDigits::new(1_u8, 12, 12).unwrap().repetend().is_none());
+    /// ```
+    pub fn repetend(&self) -> Option<Take<Self>> {
+        let (preperiod, period) = self.cycle();
+        let mut digits = *self;
+        for _ in 0..preperiod {
+            digits.remainder = digits.step(digits.remainder).1;
+        }
+        period.map(|period| digits.take(period))
+    }
+
+    /// Returns the next digit and remainder after `remainder`.
+    fn step(&self, remainder: T) -> (T, T) {
+        mul_div_rem(remainder, self.radix, self.denominator)
+    }
+
+    /// Returns the preperiod and the period of the remainders, with Brent's algorithm.
+    fn cycle(&self) -> (usize, Option<usize>) {
+        let next = |remainder| self.step(remainder).1;
+
+        let (mut power, mut period) = (1, 1);
+        let mut tortoise = self.remainder;
+        let mut hare = next(tortoise);
+        while tortoise != hare {
+            if power == period {
+                tortoise = hare;
+                power *= 2;
+                period = 0;
+            }
+            hare = next(hare);
+            period += 1;
+        }
+
+        let mut preperiod = 0;
+        tortoise = self.remainder;
+        hare = self.remainder;
+        for _ in 0..period {
+            hare = next(hare);
+        }
+        while tortoise != hare {
+            tortoise = next(tortoise);
+            hare = next(hare);
+            preperiod += 1;
+        }
+
+        // Reaching a remainder of 0 ends the digits, rather than repeating zeroes.
+        if tortoise.is_zero() {
+            (preperiod, None)
+        } else {
+            (preperiod, Some(period))
+        }
+    }
+}
+
+impl<T: IntoDigits> Iterator for RationalDigits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_zero() {
+            return None;
+        }
+
+        let (digit, remainder) = self.step(self.remainder);
+        self.remainder = remainder;
+        Some(digit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.exact_len() {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for RationalDigits<T> {}
+
+/// An iterator over the terms of the continued fraction of a rational number, the integer part
+/// first.
+///
+/// Each term is a quotient of Euclid's algorithm on the numerator and the denominator, so that
+/// there are about as many terms as the digits of the denominator, and the last one is at least
+/// 2 unless it is the only one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContinuedFraction<T: IntoDigits> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: IntoDigits> ContinuedFraction<T> {
+    /// Creates a new `ContinuedFraction` over the terms of `numerator / denominator`.
+    ///
+    /// Returns an `Err(RationalError)` if the denominator is 0.
+    pub fn new(numerator: T, denominator: T) -> Result<Self, RationalError> {
+        if denominator.is_zero() {
+            return Err(RationalError::ZeroDenominator);
+        }
+
+        Ok(ContinuedFraction {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Returns an iterator over the convergents of the remaining terms, as their numerators and
+    /// denominators in lowest terms.
+    ///
+    /// The convergents grow up to the number itself in lowest terms, so that they always fit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::rational::ContinuedFraction;
+    ///
+    /// // The best approximations of 355/113 are 3/1, 22/7 and itself.
+    /// let convergents = ContinuedFraction::new(710_u16, 226).unwrap().convergents();
+    /// assert!(convergents.eq([(3, 1), (22, 7), (355, 113)]));
+    /// ```
+    pub fn convergents(self) -> Convergents<T> {
+        Convergents {
+            terms: self,
+            previous: (T::one(), T::zero()),
+            before: (T::zero(), T::one()),
+        }
+    }
+}
+
+impl<T: IntoDigits> Iterator for ContinuedFraction<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.denominator.is_zero() {
+            return None;
+        }
+
+        let term = self.numerator / self.denominator;
+        let remainder = self.numerator % self.denominator;
+        self.numerator = self.denominator;
+        self.denominator = remainder;
+        Some(term)
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for ContinuedFraction<T> {}
+
+/// An iterator over the convergents of a continued fraction, as their numerators and
+/// denominators.
+///
+/// This struct is created by the [`convergents`](struct.ContinuedFraction.html#method.convergents)
+/// method on `ContinuedFraction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Convergents<T: IntoDigits> {
+    terms: ContinuedFraction<T>,
+    /// The last convergent.
+    previous: (T, T),
+    /// The convergent before the last one.
+    before: (T, T),
+}
+
+impl<T: IntoDigits> Iterator for Convergents<T> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let term = self.terms.next()?;
+        let (h, k) = self.previous;
+        let (h_before, k_before) = self.before;
+        let convergent = (term * h + h_before, term * k + k_before);
+
+        self.before = self.previous;
+        self.previous = convergent;
+        Some(convergent)
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for Convergents<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
+
+    #[cfg(feature = "std")]
+    /// Expands `numerator / denominator` keeping every remainder seen, as by hand.
+    fn expand(numerator: u32, denominator: u32, radix: u32) -> (Vec<u32>, Option<usize>) {
+        let mut seen = HashMap::new();
+        let mut digits = Vec::new();
+        let mut remainder = numerator % denominator;
+        while remainder != 0 {
+            if let Some(&start) = seen.get(&remainder) {
+                return (digits, Some(start));
+            }
+            seen.insert(remainder, digits.len());
+            digits.push(remainder * radix / denominator);
+            remainder = remainder * radix % denominator;
+        }
+        (digits, None)
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_matches_long_division() {
+        for radix in [2_u8, 3, 10, 12, 16, 255] {
+            for denominator in 1..=u8::MAX {
+                for numerator in (0..=u8::MAX).step_by(13) {
+                    let digits = RationalDigits::new(numerator, denominator, radix).unwrap();
+                    let (expected, start) = expand(
+                        u32::from(numerator),
+                        u32::from(denominator),
+                        u32::from(radix),
+                    );
+
+                    let period = digits.period();
+                    assert_eq!(period, start.map(|start| expected.len() - start));
+                    assert_eq!(digits.preperiod(), start.unwrap_or(expected.len()));
+                    let len = expected.len() + period.unwrap_or(0);
+                    let repeated = expected
+                        .iter()
+                        .chain(start.map_or(&[][..], |start| &expected[start..]));
+                    assert!(digits.take(len).map(u32::from).eq(repeated.copied()));
+
+                    match start {
+                        Some(start) => {
+                            assert_eq!(digits.exact_len(), None);
+                            assert!(digits
+                                .repetend()
+                                .unwrap()
+                                .map(u32::from)
+                                .eq(expected[start..].iter().copied()));
+                        }
+                        None => {
+                            assert_eq!(digits.exact_len(), Some(expected.len()));
+                            assert_eq!(digits.size_hint(), (expected.len(), Some(expected.len())));
+                            assert!(digits.repetend().is_none());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_div_rem() {
+        assert_eq!(mul_div_rem(2_u128, u128::MAX, 3), (u128::MAX / 3 * 2, 0));
+        assert_eq!(mul_div_rem(1_u128, 1 << 127, 3), ((1 << 127) / 3, 2));
+        assert_eq!(
+            mul_div_rem(u64::MAX - 1, u64::MAX, u64::MAX),
+            (u64::MAX - 1, 0)
+        );
+        let (a, b, d) = (0xfedc_ba98_u32, 0x1234_5678_u32, 0xfedc_ba99_u32);
+        let product = u64::from(a) * u64::from(b);
+        assert_eq!(
+            mul_div_rem(a, b, d),
+            (
+                (product / u64::from(d)) as u32,
+                (product % u64::from(d)) as u32
+            )
+        );
+    }
+
+    #[test]
+    fn test_wide_radices() {
+        let digits = RationalDigits::new(2_u128, 3, u128::MAX).unwrap();
+        assert_eq!(digits.exact_len(), Some(1));
+        assert!(digits.eq([u128::MAX / 3 * 2]));
+
+        let digits = RationalDigits::new(1_u64, u64::MAX - 1, u64::MAX).unwrap();
+        assert_eq!((digits.preperiod(), digits.period()), (0, Some(1)));
+        assert!(digits.take(3).eq([1; 3]));
+
+        assert_eq!(RationalDigits::new(7_u16, 2, 10).unwrap().remainder(), 1);
+        assert_eq!(RationalDigits::new(6_u16, 3, 10).unwrap().count(), 0);
+        assert_eq!(RationalDigits::new(6_u16, 3, 10).unwrap().radix(), 10);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_continued_fractions() {
+        for numerator in 0..=u8::MAX {
+            for denominator in 1..=u8::MAX {
+                let terms = ContinuedFraction::new(numerator, denominator).unwrap();
+                let common = gcd(numerator, denominator);
+                let reduced = (numerator / common, denominator / common);
+
+                let convergents: Vec<_> = terms.convergents().collect();
+                assert_eq!(convergents.len(), terms.count());
+                assert_eq!(convergents.last(), Some(&reduced));
+                for pair in convergents.windows(2) {
+                    let ((h0, k0), (h1, k1)) = (pair[0], pair[1]);
+                    let cross = i32::from(h1) * i32::from(k0) - i32::from(h0) * i32::from(k1);
+                    assert_eq!(cross.abs(), 1);
+                }
+
+                // Folding the terms back from the last one gives the reduced fraction.
+                let rebuilt = terms
+                    .collect::<Vec<_>>()
+                    .iter()
+                    .rev()
+                    .fold(None, |acc, &term| match acc {
+                        None => Some((u32::from(term), 1)),
+                        Some((h, k)) => Some((u32::from(term) * h + k, h)),
+                    });
+                assert_eq!(rebuilt, Some((u32::from(reduced.0), u32::from(reduced.1))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_continued_fraction_limits() {
+        // Consecutive Fibonacci numbers have the longest expansions, of ones only.
+        let (mut a, mut b) = (1_u128, 1_u128);
+        while let Some(c) = a.checked_add(b) {
+            a = b;
+            b = c;
+        }
+        let terms = ContinuedFraction::new(b, a).unwrap();
+        assert_eq!(terms.count(), 184);
+        assert!(terms.take(183).all(|term| term == 1));
+        assert_eq!(terms.last(), Some(2));
+        assert_eq!(terms.convergents().last(), Some((b, a)));
+
+        assert!(ContinuedFraction::new(0_u8, 7).unwrap().eq([0]));
+        assert!(ContinuedFraction::new(u8::MAX, 1)
+            .unwrap()
+            .convergents()
+            .eq([(u8::MAX, 1)]));
+        assert_eq!(
+            ContinuedFraction::new(1_u8, 0),
+            Err(RationalError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            RationalDigits::new(1_u8, 3, 0),
+            Err(RationalError::Radix(RadixError::Radix0))
+        );
+        assert_eq!(
+            RationalDigits::new(1_u8, 3, 1),
+            Err(RationalError::Radix(RadixError::Radix1))
+        );
+        assert_eq!(
+            RationalDigits::new(1_u8, 0, 10),
+            Err(RationalError::ZeroDenominator)
+        );
+        assert_eq!(gcd(12_u8, 18), 6);
+        assert_eq!(gcd(0_u8, 7), 7);
+    }
+}
@@ -0,0 +1,117 @@
+//! Division by an invariant divisor with a multiplication, as described by Granlund and
+//! Montgomery in "Division by Invariant Integers using Multiplication" (figure 4.1).
+//!
+//! The numbers are given as `u64`, along with the width in bits of their actual type, so that
+//! the same functions serve every primitive type up to 64 bits, including in constant
+//! expressions. Wider types keep dividing: the high half of their product with the multiplier
+//! would take four multiplications.
+
+/// Returns the multiplier and the shift to divide by `divisor`, at least 2, numbers of `bits`
+/// bits.
+#[inline]
+pub(crate) const fn reciprocal(divisor: u64, bits: u32) -> (u64, usize) {
+    // `2^(shift - 1) < divisor <= 2^shift`
+    let shift = u64::BITS - (divisor - 1).leading_zeros();
+    let excess = if shift == u64::BITS {
+        divisor.wrapping_neg()
+    } else {
+        (1 << shift) - divisor
+    };
+
+    // `excess < divisor`, so that the quotient fits in `bits` bits.
+    let quotient = if bits <= u32::BITS {
+        (excess << bits) / divisor
+    } else if divisor <= u32::MAX as u64 {
+        // `2^64 = high * divisor + low`, and `excess * low < divisor^2` fits in 64 bits, so that
+        // no 128 bits division is needed.
+        let (mut high, mut low) = (u64::MAX / divisor, u64::MAX % divisor + 1);
+        if low == divisor {
+            high += 1;
+            low = 0;
+        }
+        excess * high + excess * low / divisor
+    } else {
+        (((excess as u128) << bits) / divisor as u128) as u64
+    };
+    (quotient + 1, shift as usize)
+}
+
+/// Returns `n / divisor` for a number of `bits` bits, with the multiplier and the shift of the
+/// divisor.
+#[inline]
+pub(crate) const fn divide(n: u64, multiplier: u64, shift: usize, bits: u32) -> u64 {
+    // The actual multiplier is `2^bits + multiplier`.
+    if bits <= u32::BITS {
+        // `n + high` has at most 33 bits.
+        return (n + ((n * multiplier) >> bits)) >> shift;
+    }
+
+    // `high <= n`, so that the sum fits in 64 bits.
+    let high = ((n as u128 * multiplier as u128) >> bits) as u64;
+    (high + ((n - high) >> 1)) >> (shift - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(n: u64, divisor: u64, bits: u32) {
+        let (multiplier, shift) = reciprocal(divisor, bits);
+        assert_eq!(
+            divide(n, multiplier, shift, bits),
+            n / divisor,
+            "{} {} {}",
+            n,
+            divisor,
+            bits
+        );
+    }
+
+    #[test]
+    fn test_every_u8() {
+        for divisor in 2..=u8::MAX {
+            for n in 0..=u8::MAX {
+                check(n.into(), divisor.into(), u8::BITS);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wide_types() {
+        let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10_000 {
+            let (a, b) = (random(), random());
+            let divisor = (a >> (b % 64)).max(2);
+            let small = (divisor >> 32).max(2);
+
+            check(b, divisor, u64::BITS);
+            check(u64::MAX, divisor, u64::BITS);
+            check(b, small, u64::BITS);
+            check(b >> 32, small, u32::BITS);
+            check(u64::from(u32::MAX), small, u32::BITS);
+            check(b >> 48, (small >> 16).max(2), u16::BITS);
+        }
+
+        for &divisor in &[
+            3,
+            7,
+            10,
+            u32::MAX.into(),
+            1 << 32,
+            u64::MAX,
+            u64::MAX - 1,
+            1 << 63,
+        ] {
+            check(u64::MAX, divisor, u64::BITS);
+            check(divisor - 1, divisor, u64::BITS);
+            check(divisor, divisor, u64::BITS);
+        }
+    }
+}
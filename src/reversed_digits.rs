@@ -0,0 +1,429 @@
+//! Digits iteration from the least significant digit, without computing the number of digits.
+use crate::digits_iterator::{RadixError, Strategy};
+use crate::{IntoDigits, RadixMath};
+use core::iter::FusedIterator;
+
+/// An iterator over the digits of a number, least significant first.
+///
+/// Unlike a reversed `DigitsIterator`, nothing is computed up front: each digit is split off
+/// the number with a single division, which suits callers that neither need the number of digits
+/// nor the most significant digits first, such as when summing the digits.
+///
+/// # Example
+///
+/// ```
+/// use radixal::reversed_digits::ReversedDigits;
+///
+/// let mut digits = ReversedDigits::new(123_u32, 10).unwrap();
+///
+/// assert_eq!(digits.next(), Some(3));
+/// assert_eq!(digits.next(), Some(2));
+/// assert_eq!(digits.next(), Some(1));
+/// assert_eq!(digits.next(), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReversedDigits<T: IntoDigits> {
+    current: T,
+    radix: T,
+    strategy: Strategy<T>,
+    /// The square of the radix, or 0 if it does not fit.
+    squared: T,
+    done: bool,
+}
+
+impl<T: IntoDigits> ReversedDigits<T> {
+    /// Creates a new `ReversedDigits` for `number` using `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is `0` or `1`.
+    #[inline]
+    pub fn new(number: T, radix: T) -> Result<Self, RadixError> {
+        if radix == T::zero() {
+            return Err(RadixError::Radix0);
+        } else if radix == T::one() {
+            return Err(RadixError::Radix1);
+        }
+
+        let squared = radix.checked_mul(radix).unwrap_or_else(T::zero);
+
+        Ok(ReversedDigits {
+            current: number,
+            radix,
+            strategy: Strategy::new(radix),
+            squared,
+            done: false,
+        })
+    }
+
+    /// Returns a `ReversedDigits` for `number`, with the same radix.
+    pub(crate) fn with_number(&self, number: T) -> Self {
+        ReversedDigits {
+            current: number,
+            done: false,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the sum of the remaining digits.
+    ///
+    /// In radix 2, 4 and 16, the digits are summed in parallel, adding neighbouring groups of
+    /// digits in lanes of doubling width.
+    pub(crate) fn digit_sum(self) -> T {
+        if self.done {
+            return T::zero();
+        }
+
+        // The width is spelled out in each arm, so that the masks are constants.
+        match self.strategy {
+            Strategy::Shift(1) => sum_lanes(self.current, 1),
+            Strategy::Shift(2) => sum_lanes(self.current, 2),
+            Strategy::Shift(4) => sum_lanes(self.current, 4),
+            _ => self.fold(T::zero(), |sum, digit| sum + digit),
+        }
+    }
+
+    /// Returns the number made of the remaining digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::reversed_digits::ReversedDigits;
+    ///
+    /// let mut digits = ReversedDigits::new(123_u32, 10).unwrap();
+    /// digits.next();
+    ///
+    /// assert_eq!(digits.to_number(), 12);
+    /// ```
+    pub fn to_number(&self) -> T {
+        if self.done {
+            T::zero()
+        } else {
+            self.current
+        }
+    }
+}
+
+/// Sums the digits of `width` bits of `n`, of a primitive type whose bits `width` must evenly
+/// divide.
+// The conversion to `u128` is only needed for the narrower primitive types.
+#[allow(clippy::useless_conversion)]
+#[inline]
+fn sum_lanes<T: IntoDigits>(n: T, mut width: usize) -> T {
+    with_primitive!(
+        T::bits(),
+        |P| {
+            let mut n = n.to_primitive() as P;
+            while width < P::BITS as usize {
+                // The low half of every lane of `2 * width` bits, which holds the sum of two
+                // lanes of `width` bits without overflowing.
+                let mask = P::MAX / ((1 << width) + 1);
+                n = (n & mask) + ((n >> width) & mask);
+                width *= 2;
+            }
+            T::from_primitive(n.into())
+        },
+        unreachable!("not a primitive type")
+    )
+}
+
+impl<T: IntoDigits> Iterator for ReversedDigits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let quotient = self.strategy.div(self.current, self.radix);
+        let digit = self.current - quotient * self.radix;
+        self.current = quotient;
+        self.done = quotient == T::zero();
+        Some(digit)
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // Splitting off two digits at once halves the chain of dependent divisions.
+        let mut acc = init;
+        if !self.done && self.squared != T::zero() {
+            while self.current >= self.squared {
+                let quotient = self.strategy.div_squared(self.current, self.squared);
+                let pair = self.current - quotient * self.squared;
+                let high = self.strategy.div(pair, self.radix);
+                acc = f(acc, pair - high * self.radix);
+                acc = f(acc, high);
+                self.current = quotient;
+            }
+        }
+
+        for digit in self {
+            acc = f(acc, digit);
+        }
+        acc
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (1, None)
+        }
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for ReversedDigits<T> {}
+
+/// A smaller `ReversedDigits`, holding only the number and the radix: it is as large as two `T`.
+///
+/// The radix is set to 0 once the last digit has been returned, and every digit costs a division.
+///
+/// # Example
+///
+/// ```
+/// use radixal::reversed_digits::LsdDigits;
+///
+/// assert_eq!(core::mem::size_of::<LsdDigits<u8>>(), 2);
+/// assert!(LsdDigits::new(123_u8, 10).unwrap().eq([3, 2, 1]));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LsdDigits<T: IntoDigits> {
+    current: T,
+    radix: T,
+}
+
+impl<T: IntoDigits> LsdDigits<T> {
+    /// Creates a new `LsdDigits` for `number` using `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is `0` or `1`.
+    pub fn new(number: T, radix: T) -> Result<Self, RadixError> {
+        if radix == T::zero() {
+            Err(RadixError::Radix0)
+        } else if radix == T::one() {
+            Err(RadixError::Radix1)
+        } else {
+            Ok(LsdDigits {
+                current: number,
+                radix,
+            })
+        }
+    }
+}
+
+impl<T: IntoDigits> Iterator for LsdDigits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.radix == T::zero() {
+            return None;
+        }
+
+        let quotient = self.current / self.radix;
+        let digit = self.current - quotient * self.radix;
+        self.current = quotient;
+        if quotient == T::zero() {
+            self.radix = T::zero();
+        }
+        Some(digit)
+    }
+}
+
+/// An iterator over the pairs of digits of two numbers of the same position, least significant
+/// first, the shorter number being padded with zeroes.
+///
+/// # Example
+///
+/// ```
+/// use radixal::IntoDigits;
+///
+/// let pairs = 1234_u32.zip_digits(56, 10).unwrap();
+/// assert!(pairs.eq([(4, 6), (3, 5), (2, 0), (1, 0)]));
+///
+/// // The number of positions whose digits differ.
+/// let distance = 1234_u32.zip_digits(1334, 10).unwrap().filter(|(a, b)| a != b).count();
+/// assert_eq!(distance, 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZipDigits<T: IntoDigits> {
+    a: T,
+    b: T,
+    radix: T,
+    strategy: Strategy<T>,
+    done: bool,
+}
+
+impl<T: IntoDigits> ZipDigits<T> {
+    /// Creates a new `ZipDigits` for `a` and `b` using `radix`.
+    ///
+    /// Returns an `Err(RadixError)` if the radix is `0` or `1`.
+    pub fn new(a: T, b: T, radix: T) -> Result<Self, RadixError> {
+        if radix == T::zero() {
+            return Err(RadixError::Radix0);
+        } else if radix == T::one() {
+            return Err(RadixError::Radix1);
+        }
+
+        Ok(ZipDigits {
+            a,
+            b,
+            radix,
+            strategy: Strategy::new(radix),
+            done: false,
+        })
+    }
+}
+
+impl<T: IntoDigits> Iterator for ZipDigits<T> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let (a, b) = (
+            self.strategy.div(self.a, self.radix),
+            self.strategy.div(self.b, self.radix),
+        );
+        let digits = (self.a - a * self.radix, self.b - b * self.radix);
+        self.a = a;
+        self.b = b;
+        self.done = a == T::zero() && b == T::zero();
+        Some(digits)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (1, None)
+        }
+    }
+}
+
+impl<T: IntoDigits> FusedIterator for ZipDigits<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_reversed_iterator() {
+        for &radix in &[2_u64, 3, 8, 10, 16, 36, u64::MAX] {
+            for &number in &[0, 1, 9, 10, 255, 1 << 40, u64::MAX - 1, u64::MAX] {
+                let digits = ReversedDigits::new(number, radix).unwrap();
+                assert!(digits.eq(number.into_digits(radix).unwrap().rev()));
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_fold_matches_next() {
+        for &radix in &[2_u64, 3, 10, 16, 255, 1 << 32, (1 << 32) + 1, u64::MAX] {
+            for &number in &[0, 1, 99, 100, 101, 1 << 40, u64::MAX - 1, u64::MAX] {
+                let mut digits = ReversedDigits::new(number, radix).unwrap();
+                let folded = digits.clone().fold(Vec::new(), |mut digits, digit| {
+                    digits.push(digit);
+                    digits
+                });
+                assert_eq!(folded, digits.by_ref().collect::<Vec<_>>());
+
+                assert_eq!(
+                    digits.with_number(number).fold(0, |count, _| count + 1),
+                    number.nbr_digits(radix).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sum_matches_fold() {
+        for &radix in &[2_u64, 4, 8, 16, 32, 1 << 32] {
+            for &number in &[0, 1, 15, 16, 0xF0F0, 1 << 63, u64::MAX - 1, u64::MAX] {
+                let digits = ReversedDigits::new(number, radix).unwrap();
+                assert_eq!(
+                    digits.clone().digit_sum(),
+                    digits.sum::<u64>(),
+                    "{} {}",
+                    number,
+                    radix
+                );
+            }
+        }
+
+        for number in 0..=u8::MAX {
+            for &radix in &[2, 4, 16] {
+                let mut digits = ReversedDigits::new(number, radix).unwrap();
+                digits.next();
+                assert_eq!(digits.clone().digit_sum(), digits.sum::<u8>());
+            }
+        }
+        assert_eq!(u128::MAX.digit_sum(16), Ok(15 * 32));
+    }
+
+    #[test]
+    fn test_zero_has_one_digit() {
+        let mut digits = ReversedDigits::new(0_u8, 10).unwrap();
+        assert_eq!(digits.size_hint(), (1, None));
+        assert_eq!(digits.to_number(), 0);
+        assert_eq!(digits.next(), Some(0));
+        assert_eq!(digits.size_hint(), (0, Some(0)));
+        assert_eq!(digits.next(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_zip_digits() {
+        for &radix in &[2_u64, 3, 10, 16, u64::MAX] {
+            for &a in &[0, 1, 9, 10, 255, 1 << 40, u64::MAX] {
+                for &b in &[0, 1, 10, 1 << 20, u64::MAX] {
+                    let (a_digits, b_digits): (Vec<_>, Vec<_>) =
+                        ZipDigits::new(a, b, radix).unwrap().unzip();
+                    let len = a
+                        .nbr_digits(radix)
+                        .unwrap()
+                        .max(b.nbr_digits(radix).unwrap());
+                    let padded = |number: u64| {
+                        ReversedDigits::new(number, radix)
+                            .unwrap()
+                            .chain(core::iter::repeat(0))
+                            .take(len)
+                            .collect::<Vec<_>>()
+                    };
+                    assert_eq!(a_digits, padded(a));
+                    assert_eq!(b_digits, padded(b));
+                }
+            }
+        }
+
+        assert!(0_u8.zip_digits(0, 10).unwrap().eq([(0, 0)]));
+        assert_eq!(ZipDigits::new(1_u8, 1, 0), Err(RadixError::Radix0));
+    }
+
+    #[test]
+    fn test_invalid_radix() {
+        assert_eq!(ReversedDigits::new(1_u8, 0), Err(RadixError::Radix0));
+        assert_eq!(ReversedDigits::new(1_u8, 1), Err(RadixError::Radix1));
+        assert_eq!(LsdDigits::new(1_u8, 0), Err(RadixError::Radix0));
+        assert_eq!(LsdDigits::new(1_u8, 1), Err(RadixError::Radix1));
+    }
+
+    #[test]
+    fn test_lsd_digits() {
+        for &radix in &[2_u64, 3, 10, 16, u64::MAX] {
+            for &number in &[0, 1, 9, 10, 255, 1 << 40, u64::MAX - 1, u64::MAX] {
+                let mut digits = LsdDigits::new(number, radix).unwrap();
+                assert!(digits.eq(ReversedDigits::new(number, radix).unwrap()));
+
+                for _ in digits.by_ref() {}
+                assert_eq!(digits.next(), None);
+            }
+        }
+
+        assert_eq!(
+            core::mem::size_of::<LsdDigits<u64>>(),
+            2 * core::mem::size_of::<u64>()
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Roman numerals.
+//!
+//! Numbers from 1 to 3999 are written with the symbols `I`, `V`, `X`, `L`, `C`, `D` and `M`,
+//! using the subtractive forms `IV`, `IX`, `XL`, `XC`, `CD` and `CM`. Larger numbers, up to
+//! 3,999,999, can be written with an overline (a vinculum), which multiplies a symbol by 1000;
+//! it is encoded as the combining overline U+0305 after the symbol.
+use crate::parse::ParseError;
+use crate::{IntoDigits, RadixMath};
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+
+const NUMERALS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+const OVERLINE: char = '\u{0305}';
+const OVERLINE_FACTOR: u32 = 1000;
+const MAX: u32 = 3999;
+const MAX_OVERLINE: u32 = MAX * OVERLINE_FACTOR + MAX;
+
+/// Errors that can occur when writing a number as a Roman numeral.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RomanError {
+    /// There is no Roman numeral for 0.
+    Zero,
+    /// The number is too large to be written.
+    TooLarge,
+}
+
+impl fmt::Display for RomanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RomanError::Zero => write!(f, "there is no roman numeral for 0"),
+            RomanError::TooLarge => write!(f, "number too large for a roman numeral"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RomanError {}
+
+/// How strictly Roman numerals are parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    /// Only accepts uppercase numerals, in the form written by this module.
+    Strict,
+    /// Ignores the ASCII case, and accepts additive forms such as `IIII` and any smaller symbol
+    /// before a larger one as a subtraction, such as `IIX` for 8.
+    Lenient,
+}
+
+/// Displays a number as a Roman numeral.
+///
+/// This struct is created by the [`display`](fn.display.html) and
+/// [`display_overline`](fn.display_overline.html) functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RomanDisplay {
+    number: u32,
+    overline: bool,
+}
+
+/// Displays `number` as a Roman numeral.
+///
+/// Returns an `Err(RomanError)` if `number` is 0 or larger than 3999.
+///
+/// # Example
+///
+/// ```
+/// use radixal::roman::{self, RomanError};
+///
+/// assert_eq!(roman::display(1994_u32).unwrap().to_string(), "MCMXCIV");
+/// assert_eq!(roman::display(0_u32), Err(RomanError::Zero));
+/// assert_eq!(roman::display(4000_u32), Err(RomanError::TooLarge));
+/// ```
+pub fn display<T: IntoDigits>(number: T) -> Result<RomanDisplay, RomanError> {
+    create(number, MAX, false)
+}
+
+/// Displays `number` as a Roman numeral, overlining its thousands from 4000 on.
+///
+/// Returns an `Err(RomanError)` if `number` is 0 or larger than 3,999,999.
+///
+/// # Example
+///
+/// ```
+/// use radixal::roman;
+///
+/// assert_eq!(roman::display_overline(3999_u32).unwrap().to_string(), "MMMCMXCIX");
+/// assert_eq!(roman::display_overline(4001_u32).unwrap().to_string(), "I\u{305}V\u{305}I");
+/// ```
+pub fn display_overline<T: IntoDigits>(number: T) -> Result<RomanDisplay, RomanError> {
+    create(number, MAX_OVERLINE, true)
+}
+
+fn create<T: IntoDigits>(number: T, max: u32, overline: bool) -> Result<RomanDisplay, RomanError> {
+    match number
+        .to_u128()
+        .and_then(|number| u32::try_from(number).ok())
+    {
+        Some(0) => Err(RomanError::Zero),
+        Some(number) if number <= max => Ok(RomanDisplay { number, overline }),
+        _ => Err(RomanError::TooLarge),
+    }
+}
+
+impl fmt::Display for RomanDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.overline && self.number > MAX {
+            write_numeral(f, self.number / OVERLINE_FACTOR, true)?;
+            write_numeral(f, self.number % OVERLINE_FACTOR, false)
+        } else {
+            write_numeral(f, self.number, false)
+        }
+    }
+}
+
+fn write_numeral<W: Write>(f: &mut W, mut number: u32, overline: bool) -> fmt::Result {
+    for &(value, symbols) in &NUMERALS {
+        while number >= value {
+            for symbol in symbols.chars() {
+                f.write_char(symbol)?;
+                if overline {
+                    f.write_char(OVERLINE)?;
+                }
+            }
+            number -= value;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a Roman numeral, with or without overlines.
+///
+/// Returns an `Err(ParseError)` if the string is empty, contains a symbol that is not a Roman
+/// numeral, if the symbols are not in a valid order for `mode`, or if the number is too large for
+/// `T`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::parse::ParseError;
+/// use radixal::roman::{self, ParseMode};
+///
+/// assert_eq!(roman::parse::<u32>("MCMXCIV", ParseMode::Strict), Ok(1994));
+/// assert_eq!(roman::parse::<u32>("V\u{305}", ParseMode::Strict), Ok(5000));
+/// assert_eq!(roman::parse::<u32>("IIII", ParseMode::Strict), Err(ParseError::Malformed));
+/// assert_eq!(roman::parse::<u32>("iiii", ParseMode::Lenient), Ok(4));
+/// assert_eq!(roman::parse::<u8>("CCLVI", ParseMode::Lenient), Err(ParseError::Overflow));
+/// ```
+pub fn parse<T: IntoDigits>(s: &str, mode: ParseMode) -> Result<T, ParseError> {
+    let number = parse_lenient(s, mode)?;
+
+    if mode == ParseMode::Strict {
+        let display = create(number, MAX_OVERLINE, true).map_err(|_| ParseError::Malformed)?;
+        let mut matcher = Matcher { rest: s };
+        if write!(matcher, "{}", display).is_err() || !matcher.rest.is_empty() {
+            return Err(ParseError::Malformed);
+        }
+    }
+
+    T::from_u128(number.into()).ok_or(ParseError::Overflow)
+}
+
+/// Parses the symbols from right to left, subtracting those smaller than a symbol after them.
+fn parse_lenient(s: &str, mode: ParseMode) -> Result<u32, ParseError> {
+    let mut number: i64 = 0;
+    let mut largest = 0;
+    let mut overline = false;
+    let mut empty = true;
+
+    for symbol in s.chars().rev() {
+        if symbol == OVERLINE && !overline {
+            overline = true;
+            continue;
+        }
+
+        let mut value = value(symbol, mode).ok_or(ParseError::InvalidDigit(symbol))?;
+        if overline {
+            value *= OVERLINE_FACTOR;
+            overline = false;
+        }
+
+        number = if value < largest {
+            number.checked_sub(i64::from(value))
+        } else {
+            largest = value;
+            number.checked_add(i64::from(value))
+        }
+        .ok_or(ParseError::Overflow)?;
+        empty = false;
+    }
+
+    if overline {
+        return Err(ParseError::InvalidDigit(OVERLINE));
+    }
+    if empty {
+        return Err(ParseError::Empty);
+    }
+    if number <= 0 {
+        return Err(ParseError::Malformed);
+    }
+    u32::try_from(number).map_err(|_| ParseError::Overflow)
+}
+
+/// Returns the value of a symbol.
+fn value(symbol: char, mode: ParseMode) -> Option<u32> {
+    let symbol = match mode {
+        ParseMode::Strict => symbol,
+        ParseMode::Lenient => symbol.to_ascii_uppercase(),
+    };
+
+    let value = match symbol {
+        'I' => 1,
+        'V' => 5,
+        'X' => 10,
+        'L' => 50,
+        'C' => 100,
+        'D' => 500,
+        'M' => 1000,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Consumes the start of a string as long as what is written matches it.
+struct Matcher<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Write for Matcher<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.rest = self.rest.strip_prefix(s).ok_or(fmt::Error)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_round_trip() {
+        for number in 1..=MAX {
+            let s = display(number).unwrap().to_string();
+            assert_eq!(parse::<u32>(&s, ParseMode::Strict), Ok(number));
+            assert_eq!(
+                parse::<u32>(&s.to_lowercase(), ParseMode::Lenient),
+                Ok(number)
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_overline_round_trip() {
+        for &number in &[4000, 4999, 10_000, 123_456, MAX_OVERLINE] {
+            let s = display_overline(number).unwrap().to_string();
+            assert_eq!(parse::<u32>(&s, ParseMode::Strict), Ok(number));
+        }
+        assert_eq!(
+            display_overline(MAX_OVERLINE + 1),
+            Err(RomanError::TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_strict_rejects_non_canonical() {
+        for &s in &["IIII", "VV", "IC", "IIX", "I\u{305}V", "M\u{305}M"] {
+            assert_eq!(
+                parse::<u32>(s, ParseMode::Strict),
+                Err(ParseError::Malformed)
+            );
+        }
+    }
+
+    #[test]
+    fn test_lenient_forms() {
+        assert_eq!(parse::<u32>("IC", ParseMode::Lenient), Ok(99));
+        assert_eq!(parse::<u32>("IIX", ParseMode::Lenient), Ok(8));
+        assert_eq!(parse::<u32>("MDCCCCX", ParseMode::Lenient), Ok(1910));
+        assert_eq!(parse::<u32>("M\u{305}", ParseMode::Lenient), Ok(1_000_000));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse::<u32>("", ParseMode::Lenient), Err(ParseError::Empty));
+        assert_eq!(
+            parse::<u32>("XIZ", ParseMode::Lenient),
+            Err(ParseError::InvalidDigit('Z'))
+        );
+        assert_eq!(
+            parse::<u32>("\u{305}I", ParseMode::Lenient),
+            Err(ParseError::InvalidDigit(OVERLINE))
+        );
+        assert_eq!(
+            parse::<u32>("x", ParseMode::Strict),
+            Err(ParseError::InvalidDigit('x'))
+        );
+    }
+}
@@ -0,0 +1,487 @@
+//! Representations of signed numbers where the sign is carried by a digit, as in the files of
+//! legacy systems, rather than by a separate `-` symbol.
+//!
+//! * A dedicated leading symbol can stand for the sign, before the digits of the magnitude.
+//! * Negative numbers can be written as the diminished radix complement of their magnitude, e.g.
+//!   the nines' complement, after a leading sign digit: the largest digit for negative numbers,
+//!   and 0 for the others.
+//! * The last digit can carry the sign in its symbol, as the trailing overpunch of zoned decimal
+//!   fields in COBOL records, where `{ABCDEFGHI` stand for the positive digits and `}JKLMNOPQR`
+//!   for the negative ones.
+//!
+//! The byte layout of zoned decimal itself, with its sign nibble, is
+//! [`ZonedDecimal`](../packed/struct.ZonedDecimal.html).
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::alphabet::Alphabet;
+//! use radixal::sign_digit::{SignDigit, SignDigitAlphabet};
+//!
+//! let nines = SignDigitAlphabet::new(Alphabet::DECIMAL, SignDigit::Complement).unwrap();
+//! assert_eq!(nines.display(-42_i32).to_string(), "957");
+//! assert_eq!(format!("{:06}", nines.display(-42_i32)), "999957");
+//! assert_eq!(nines.parse::<i32>("999957"), Ok(-42));
+//!
+//! let overpunch = SignDigitAlphabet::OVERPUNCH;
+//! assert_eq!(overpunch.display(-123_i32).to_string(), "12L");
+//! assert_eq!(overpunch.parse::<i32>("12C"), Ok(123));
+//! ```
+use crate::alphabet::{Alphabet, AlphabetError};
+use crate::parse::{push_digit, ParseError};
+use crate::{IntoDigits, RadixMath, SignedInteger};
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+
+/// How the sign of a number is carried by its digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SignDigit<'a> {
+    /// A leading symbol, `positive` or `negative`, followed by the digits of the magnitude.
+    Leading {
+        /// The symbol of the non-negative numbers.
+        positive: char,
+        /// The symbol of the negative numbers.
+        negative: char,
+    },
+    /// A leading sign digit, 0 or the largest digit, followed by the digits of the magnitude, or
+    /// their complement to the largest digit for negative numbers.
+    ///
+    /// Any digit in the upper half of the radix makes a number negative when parsing, so that
+    /// fixed-width fields of any length are read back.
+    Complement,
+    /// The last digit written with the symbols of `positive` or `negative`, which have as many
+    /// symbols as the alphabet.
+    ///
+    /// A last digit from the alphabet itself is read as unsigned, and thus positive.
+    Trailing {
+        /// The symbols of the last digit of the non-negative numbers.
+        positive: Alphabet<'a>,
+        /// The symbols of the last digit of the negative numbers.
+        negative: Alphabet<'a>,
+    },
+}
+
+/// An `Alphabet` along with the way the sign is carried by the digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SignDigitAlphabet<'a> {
+    alphabet: Alphabet<'a>,
+    sign: SignDigit<'a>,
+}
+
+impl<'a> SignDigitAlphabet<'a> {
+    /// Creates a new `SignDigitAlphabet` writing the digits with `alphabet` and the sign as set
+    /// by `sign`.
+    ///
+    /// Returns an `Err(AlphabetError)` if both leading symbols are the same, or if the trailing
+    /// alphabets do not have as many symbols as `alphabet` or share symbols with the negative
+    /// one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::alphabet::{Alphabet, AlphabetError};
+    /// use radixal::sign_digit::{SignDigit, SignDigitAlphabet};
+    ///
+    /// let sign = SignDigit::Leading { positive: '0', negative: '9' };
+    /// let leading = SignDigitAlphabet::new(Alphabet::DECIMAL, sign).unwrap();
+    /// assert_eq!(leading.display(-42_i32).to_string(), "942");
+    /// assert_eq!(format!("{:05}", leading.display(42_i32)), "00042");
+    ///
+    /// let sign = SignDigit::Leading { positive: '+', negative: '+' };
+    /// assert_eq!(
+    ///     SignDigitAlphabet::new(Alphabet::DECIMAL, sign),
+    ///     Err(AlphabetError::InvalidSign('+'))
+    /// );
+    /// ```
+    pub fn new(alphabet: Alphabet<'a>, sign: SignDigit<'a>) -> Result<Self, AlphabetError> {
+        match sign {
+            SignDigit::Leading { positive, negative } if positive == negative => {
+                return Err(AlphabetError::InvalidSign(positive));
+            }
+            SignDigit::Trailing { positive, negative } => {
+                for symbols in &[positive, negative] {
+                    if symbols.radix() < alphabet.radix() {
+                        return Err(AlphabetError::TooFewSymbols);
+                    } else if symbols.radix() > alphabet.radix() {
+                        return Err(AlphabetError::TooManySymbols);
+                    }
+                }
+
+                let shared = negative.symbols().iter().find(|&&symbol| {
+                    positive.digit(symbol).is_some() || alphabet.digit(symbol).is_some()
+                });
+                if let Some(&symbol) = shared {
+                    return Err(AlphabetError::DuplicateSymbol(symbol));
+                }
+            }
+            _ => {}
+        }
+
+        Ok(SignDigitAlphabet { alphabet, sign })
+    }
+
+    /// Returns the alphabet of the digits.
+    pub fn alphabet(&self) -> Alphabet<'a> {
+        self.alphabet
+    }
+
+    /// Returns how the sign is carried.
+    pub fn sign(&self) -> SignDigit<'a> {
+        self.sign
+    }
+
+    /// Wraps `number` so that it is displayed using this alphabet.
+    pub fn display<S: SignedInteger>(&self, number: S) -> SignDigitDisplay<'a, S> {
+        SignDigitDisplay {
+            number,
+            alphabet: *self,
+        }
+    }
+
+    /// Parses a number written with this alphabet.
+    ///
+    /// Returns an `Err(ParseError)` if the string holds no digits, contains a symbol outside of
+    /// the alphabet or at the place of the sign, or represents a number out of the range of `S`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::parse::ParseError;
+    /// use radixal::sign_digit::SignDigitAlphabet;
+    ///
+    /// let overpunch = SignDigitAlphabet::OVERPUNCH;
+    /// assert_eq!(overpunch.parse::<i16>("1}"), Ok(-10));
+    /// assert_eq!(overpunch.parse::<i16>("10"), Ok(10));
+    /// assert_eq!(overpunch.parse::<i16>("1L2"), Err(ParseError::InvalidDigit('L')));
+    /// assert_eq!(overpunch.parse::<i8>("12R"), Err(ParseError::Overflow));
+    /// ```
+    pub fn parse<S: SignedInteger>(&self, s: &str) -> Result<S, ParseError> {
+        let digit = |symbol| {
+            self.alphabet
+                .digit(symbol)
+                .ok_or(ParseError::InvalidDigit(symbol))
+        };
+        let radix = self.alphabet.radix() as u128;
+        let largest = self.alphabet.radix() - 1;
+        let push = |magnitude: Option<u128>, digit: usize| {
+            push_digit(magnitude.unwrap_or(0), radix, digit as u128).ok_or(ParseError::Overflow)
+        };
+
+        let mut magnitude = None;
+        let mut symbols = s.chars().peekable();
+        let negative = match self.sign {
+            SignDigit::Leading { positive, negative } => {
+                let sign = symbols.next().ok_or(ParseError::Empty)?;
+                if sign != positive && sign != negative {
+                    return Err(ParseError::InvalidDigit(sign));
+                }
+                for symbol in symbols {
+                    magnitude = Some(push(magnitude, digit(symbol)?)?);
+                }
+                sign == negative
+            }
+            SignDigit::Complement => {
+                let sign = digit(*symbols.peek().ok_or(ParseError::Empty)?)?;
+                let negative = 2 * sign >= self.alphabet.radix();
+                for symbol in symbols {
+                    let digit = digit(symbol)?;
+                    let digit = if negative { largest - digit } else { digit };
+                    magnitude = Some(push(magnitude, digit)?);
+                }
+                negative
+            }
+            SignDigit::Trailing { positive, negative } => {
+                let mut is_negative = false;
+                while let Some(symbol) = symbols.next() {
+                    let digit = if symbols.peek().is_some() {
+                        digit(symbol)?
+                    } else if let Some(digit) = negative.digit(symbol) {
+                        is_negative = true;
+                        digit
+                    } else {
+                        positive.digit(symbol).map_or_else(|| digit(symbol), Ok)?
+                    };
+                    magnitude = Some(push(magnitude, digit)?);
+                }
+                is_negative
+            }
+        };
+
+        let magnitude = magnitude.ok_or(ParseError::Empty)?;
+        let number = if negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        number
+            .and_then(|number| S::from(number))
+            .ok_or(ParseError::Overflow)
+    }
+}
+
+const OVERPUNCH_POSITIVE_SYMBOLS: [char; 10] = ['{', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I'];
+const OVERPUNCH_NEGATIVE_SYMBOLS: [char; 10] = ['}', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R'];
+
+impl SignDigitAlphabet<'static> {
+    /// Decimal digits with a trailing overpunch, as the signed zoned decimal fields of COBOL
+    /// records read as text: the last digit is one of `{ABCDEFGHI` for positive numbers, and
+    /// one of `}JKLMNOPQR` for negative numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use radixal::sign_digit::SignDigitAlphabet;
+    ///
+    /// assert_eq!(SignDigitAlphabet::OVERPUNCH.display(0_i32).to_string(), "{");
+    /// assert_eq!(SignDigitAlphabet::OVERPUNCH.display(-1050_i32).to_string(), "105}");
+    /// ```
+    pub const OVERPUNCH: SignDigitAlphabet<'static> = SignDigitAlphabet {
+        alphabet: Alphabet::DECIMAL,
+        sign: SignDigit::Trailing {
+            positive: Alphabet::from_symbols(&OVERPUNCH_POSITIVE_SYMBOLS),
+            negative: Alphabet::from_symbols(&OVERPUNCH_NEGATIVE_SYMBOLS),
+        },
+    };
+}
+
+/// Displays a signed number with its sign carried by a digit.
+///
+/// It honors the width, fill and alignment flags of the formatter. The `0` flag pads with zeroes
+/// after the leading symbol, before the digits with a trailing sign, and with the sign digit
+/// itself for complements, so that the number keeps its value.
+///
+/// This struct is created by the [`display`](struct.SignDigitAlphabet.html#method.display)
+/// method on `SignDigitAlphabet`.
+///
+/// # Example
+///
+/// ```
+/// use radixal::sign_digit::SignDigitAlphabet;
+///
+/// let display = SignDigitAlphabet::OVERPUNCH.display(-42_i32);
+/// assert_eq!(format!("{:>4}|{:04}", display, display), "  4K|004K");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SignDigitDisplay<'a, S: SignedInteger> {
+    number: S,
+    alphabet: SignDigitAlphabet<'a>,
+}
+
+impl<'a, S: SignedInteger> fmt::Display for SignDigitDisplay<'a, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let alphabet = self.alphabet.alphabet;
+        let symbol = |digit: usize| alphabet.symbol(digit).ok_or(fmt::Error);
+        let largest = alphabet.radix() - 1;
+
+        // Alphabets hold at most `u8::MAX` symbols, so the radix fits in any magnitude.
+        let radix: S::Magnitude = RadixMath::from_usize(alphabet.radix()).ok_or(fmt::Error)?;
+        let digits = self
+            .number
+            .magnitude()
+            .into_digits(radix)
+            .map_err(|_| fmt::Error)?;
+        let negative = self.number.is_negative();
+        let len = match self.alphabet.sign {
+            SignDigit::Trailing { .. } => digits.len(),
+            _ => digits.len() + 1,
+        };
+
+        let width = f.width().unwrap_or(0);
+        let padding = width.saturating_sub(len);
+        let (zeroes, before, after) = match f.align() {
+            _ if f.sign_aware_zero_pad() => (padding, 0, 0),
+            Some(fmt::Alignment::Left) => (0, 0, padding),
+            Some(fmt::Alignment::Center) => (0, padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (0, padding, 0),
+        };
+
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+
+        let last = digits.len() - 1;
+        for (idx, digit) in digits.enumerate() {
+            let digit = digit.to_usize().ok_or(fmt::Error)?;
+            let symbol = match self.alphabet.sign {
+                SignDigit::Leading {
+                    positive,
+                    negative: minus,
+                } => {
+                    if idx == 0 {
+                        f.write_char(if negative { minus } else { positive })?;
+                        for _ in 0..zeroes {
+                            f.write_char(symbol(0)?)?;
+                        }
+                    }
+                    symbol(digit)?
+                }
+                SignDigit::Complement => {
+                    let sign = if negative { largest } else { 0 };
+                    if idx == 0 {
+                        for _ in 0..=zeroes {
+                            f.write_char(symbol(sign)?)?;
+                        }
+                    }
+                    symbol(if negative { largest - digit } else { digit })?
+                }
+                SignDigit::Trailing {
+                    positive,
+                    negative: minus,
+                } => {
+                    if idx == 0 {
+                        for _ in 0..zeroes {
+                            f.write_char(symbol(0)?)?;
+                        }
+                    }
+                    if idx == last {
+                        let symbols = if negative { minus } else { positive };
+                        symbols.symbol(digit).ok_or(fmt::Error)?
+                    } else {
+                        symbol(digit)?
+                    }
+                }
+            };
+            f.write_char(symbol)?;
+        }
+
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    fn alphabets() -> Vec<SignDigitAlphabet<'static>> {
+        let leading = SignDigit::Leading {
+            positive: 'C',
+            negative: 'D',
+        };
+        vec![
+            SignDigitAlphabet::new(Alphabet::DECIMAL, leading).unwrap(),
+            SignDigitAlphabet::new(Alphabet::LOWER_HEX, leading).unwrap(),
+            SignDigitAlphabet::new(Alphabet::DECIMAL, SignDigit::Complement).unwrap(),
+            SignDigitAlphabet::new(Alphabet::BINARY, SignDigit::Complement).unwrap(),
+            SignDigitAlphabet::new(
+                Alphabet::new(&['0', '1', '2']).unwrap(),
+                SignDigit::Complement,
+            )
+            .unwrap(),
+            SignDigitAlphabet::OVERPUNCH,
+        ]
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_round_trip() {
+        for alphabet in alphabets() {
+            for number in i16::MIN..=i16::MAX {
+                let s = alphabet.display(number).to_string();
+                assert_eq!(alphabet.parse(&s), Ok(number), "{}", s);
+
+                let padded = format!("{:012}", alphabet.display(number));
+                assert_eq!(padded.chars().count(), 12.max(s.chars().count()));
+                assert_eq!(alphabet.parse(&padded), Ok(number), "{}", padded);
+            }
+
+            // The bounds of `i128` go through the magnitude.
+            for &number in &[i128::MIN, i128::MAX, 0] {
+                let s = alphabet.display(number).to_string();
+                assert_eq!(alphabet.parse(&s), Ok(number));
+            }
+            let s = alphabet.display(i16::MIN).to_string();
+            assert_eq!(alphabet.parse::<i8>(&s), Err(ParseError::Overflow));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_complement() {
+        let nines = SignDigitAlphabet::new(Alphabet::DECIMAL, SignDigit::Complement).unwrap();
+        assert_eq!(nines.display(0_i8).to_string(), "00");
+        assert_eq!(nines.display(-1_i8).to_string(), "98");
+        assert_eq!(nines.display(-128_i8).to_string(), "9871");
+        // Fixed-width fields of any length, as long as the upper half marks negative numbers.
+        assert_eq!(nines.parse::<i32>("5"), Ok(-4));
+        assert_eq!(nines.parse::<i32>("4"), Ok(4));
+        assert_eq!(nines.parse::<i32>("99"), Ok(0));
+        assert_eq!(nines.parse::<i32>(""), Err(ParseError::Empty));
+        assert_eq!(nines.parse::<i32>("9x"), Err(ParseError::InvalidDigit('x')));
+
+        let ones = SignDigitAlphabet::new(Alphabet::BINARY, SignDigit::Complement).unwrap();
+        assert_eq!(ones.display(-5_i32).to_string(), "1010");
+        assert_eq!(format!("{:<6}|", ones.display(5_i32)), "0101  |");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_leading_and_trailing() {
+        let sign = SignDigit::Leading {
+            positive: '0',
+            negative: '9',
+        };
+        let leading = SignDigitAlphabet::new(Alphabet::DECIMAL, sign).unwrap();
+        assert_eq!(leading.sign(), sign);
+        assert_eq!(leading.alphabet(), Alphabet::DECIMAL);
+        assert_eq!(leading.parse::<i32>("9"), Err(ParseError::Empty));
+        assert_eq!(
+            leading.parse::<i32>("5123"),
+            Err(ParseError::InvalidDigit('5'))
+        );
+        assert_eq!(leading.parse::<i32>("90042"), Ok(-42));
+
+        let overpunch = SignDigitAlphabet::OVERPUNCH;
+        assert_eq!(overpunch.display(i32::MIN).to_string(), "214748364Q");
+        assert_eq!(overpunch.display(7_i32).to_string(), "G");
+        assert_eq!(format!("{:^5}", overpunch.display(-7_i32)), "  P  ");
+        assert_eq!(overpunch.parse::<i32>("G"), Ok(7));
+        assert_eq!(overpunch.parse::<i32>("00I"), Ok(9));
+        assert_eq!(overpunch.parse::<i32>("0{"), Ok(0));
+        assert_eq!(overpunch.parse::<i32>("0}"), Ok(0));
+        assert_eq!(overpunch.parse::<i32>(""), Err(ParseError::Empty));
+        assert_eq!(
+            overpunch.parse::<i32>("1S"),
+            Err(ParseError::InvalidDigit('S'))
+        );
+    }
+
+    #[test]
+    fn test_invalid_alphabets() {
+        let positive = Alphabet::new(&['a', 'b']).unwrap();
+        let negative = Alphabet::new(&['c', 'd']).unwrap();
+        let sign = SignDigit::Trailing { positive, negative };
+        assert!(SignDigitAlphabet::new(Alphabet::BINARY, sign).is_ok());
+        assert_eq!(
+            SignDigitAlphabet::new(Alphabet::DECIMAL, sign),
+            Err(AlphabetError::TooFewSymbols)
+        );
+
+        let wide = Alphabet::new(&['c', 'd', 'e']).unwrap();
+        let sign = SignDigit::Trailing {
+            positive,
+            negative: wide,
+        };
+        assert_eq!(
+            SignDigitAlphabet::new(Alphabet::BINARY, sign),
+            Err(AlphabetError::TooManySymbols)
+        );
+
+        let shared = Alphabet::new(&['1', 'x']).unwrap();
+        let sign = SignDigit::Trailing {
+            positive,
+            negative: shared,
+        };
+        assert_eq!(
+            SignDigitAlphabet::new(Alphabet::BINARY, sign),
+            Err(AlphabetError::DuplicateSymbol('1'))
+        );
+
+        assert!(SignDigitAlphabet::new(Alphabet::BINARY, SignDigit::Complement).is_ok());
+    }
+}
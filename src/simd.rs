@@ -0,0 +1,257 @@
+//! A vectorized conversion of `u64` to its decimal digits.
+//!
+//! The digits are computed all at once into a buffer, eight at a time: with SSE2 on x86_64, and
+//! with arithmetic on the lanes of a `u64` elsewhere. The iterator then only reads the buffer,
+//! which is much faster than dividing once per digit when all the digits are needed.
+//!
+//! # Example
+//!
+//! ```
+//! use radixal::simd::DecimalDigits;
+//! use radixal::IntoDigits;
+//!
+//! let digits = DecimalDigits::new(9_876_543_210);
+//! assert_eq!(digits.as_slice(), [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+//! assert!(digits.eq(9_876_543_210_u64.into_decimal_digits()));
+//! ```
+use crate::digits_iterator::DECIMAL_PAIRS;
+use core::iter::FusedIterator;
+
+/// The number of decimal digits of `u64::MAX`.
+const CAPACITY: usize = 20;
+/// The digits converted by a single call to `convert16`.
+const CHUNK: u64 = 100_000_000;
+
+/// An iterator over the decimal digits of a `u64`, most significant first, like
+/// `u64::into_decimal_digits`.
+///
+/// The digits are computed when the iterator is created.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalDigits {
+    digits: [u8; CAPACITY],
+    start: usize,
+    end: usize,
+}
+
+impl DecimalDigits {
+    /// Converts `number` to its decimal digits.
+    pub fn new(number: u64) -> Self {
+        let mut digits = [0; CAPACITY];
+
+        let top = (number / (CHUNK * CHUNK)) as usize;
+        let rest = number % (CHUNK * CHUNK);
+        let [thousands, hundreds] = DECIMAL_PAIRS[top / 100];
+        let [tens, units] = DECIMAL_PAIRS[top % 100];
+        digits[..4].copy_from_slice(&[thousands, hundreds, tens, units]);
+        digits[4..].copy_from_slice(&convert16((rest / CHUNK) as u32, (rest % CHUNK) as u32));
+
+        let len = number.checked_ilog10().unwrap_or_default() as usize + 1;
+        DecimalDigits {
+            digits,
+            start: CAPACITY - len,
+            end: CAPACITY,
+        }
+    }
+
+    /// Returns the remaining digits.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.digits[self.start..self.end]
+    }
+}
+
+impl Iterator for DecimalDigits {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else {
+            self.start += 1;
+            Some(u64::from(self.digits[self.start - 1]))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DecimalDigits {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(u64::from(self.digits[self.end]))
+        }
+    }
+}
+
+impl ExactSizeIterator for DecimalDigits {}
+
+impl FusedIterator for DecimalDigits {}
+
+/// Returns the 16 digits of `high * 10^8 + low`, where both halves are below `10^8`.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+fn convert16(high: u32, low: u32) -> [u8; 16] {
+    // SAFETY: SSE2 is enabled at compile time.
+    unsafe { sse2::convert16(high, low) }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod sse2 {
+    use core::arch::x86_64::*;
+
+    #[target_feature(enable = "sse2")]
+    pub(super) fn convert16(high: u32, low: u32) -> [u8; 16] {
+        // Each 16 bits lane holds a digit, which fits in a byte.
+        let digits = _mm_packus_epi16(convert8(high), convert8(low));
+        let first = _mm_cvtsi128_si64(digits) as u64;
+        let second = _mm_cvtsi128_si64(_mm_srli_si128::<8>(digits)) as u64;
+
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&first.to_le_bytes());
+        bytes[8..].copy_from_slice(&second.to_le_bytes());
+        bytes
+    }
+
+    /// Returns the 8 digits of `value`, below `10^8`, in the 16 bits lanes of a vector.
+    ///
+    /// Both halves of 4 digits are copied to 4 lanes, which are divided by 1000, 100, 10 and 1
+    /// with multiplications; the tens of each quotient are then removed.
+    #[target_feature(enable = "sse2")]
+    fn convert8(value: u32) -> __m128i {
+        let (high, low) = (value / 10_000, value % 10_000);
+
+        // [high * 4, low * 4, 0, 0, 0, 0, 0, 0]
+        let halves = _mm_slli_epi64::<2>(_mm_cvtsi32_si128((high | (low << 16)) as i32));
+        // [high * 4, high * 4, high * 4, high * 4, low * 4, low * 4, low * 4, low * 4]
+        let halves = _mm_unpacklo_epi16(halves, halves);
+        let halves = _mm_unpacklo_epi32(halves, halves);
+
+        // The quotients, such as [1, 12, 123, 1234, 5, 56, 567, 5678].
+        let reciprocals = _mm_setr_epi16(8389, 5243, 13108, i16::MIN, 8389, 5243, 13108, i16::MIN);
+        let shifts = _mm_setr_epi16(
+            1 << 7,
+            1 << 11,
+            1 << 13,
+            i16::MIN,
+            1 << 7,
+            1 << 11,
+            1 << 13,
+            i16::MIN,
+        );
+        let quotients = _mm_mulhi_epu16(_mm_mulhi_epu16(halves, reciprocals), shifts);
+
+        // Subtracts ten times the previous quotient from each quotient.
+        let tens = _mm_slli_epi64::<16>(_mm_mullo_epi16(quotients, _mm_set1_epi16(10)));
+        _mm_sub_epi16(quotients, tens)
+    }
+}
+
+/// Returns the 16 digits of `high * 10^8 + low`, where both halves are below `10^8`.
+#[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+fn convert16(high: u32, low: u32) -> [u8; 16] {
+    let mut bytes = [0; 16];
+    bytes[..8].copy_from_slice(&swar::convert8(high).to_le_bytes());
+    bytes[8..].copy_from_slice(&swar::convert8(low).to_le_bytes());
+    bytes
+}
+
+#[cfg_attr(all(target_arch = "x86_64", target_feature = "sse2"), allow(dead_code))]
+mod swar {
+    /// Returns the 8 digits of `value`, below `10^8`, in the bytes of a `u64`, most significant
+    /// first in little endian order.
+    ///
+    /// The halves of 4 digits, then of 2 digits, are split in the lanes of the `u64` at once,
+    /// dividing with a multiplication and a shift.
+    pub(super) fn convert8(value: u32) -> u64 {
+        let halves = (u64::from(value % 10_000) << 32) | u64::from(value / 10_000);
+
+        let hundreds = ((halves * 10_486) >> 20) & 0x0000_007F_0000_007F;
+        let pairs = ((halves - hundreds * 100) << 16) | hundreds;
+
+        let tens = ((pairs * 103) >> 10) & 0x000F_000F_000F_000F;
+        ((pairs - tens * 10) << 8) | tens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntoDigits;
+
+    fn check(number: u64) {
+        let digits = DecimalDigits::new(number);
+        assert!(
+            digits.clone().eq(number.into_decimal_digits()),
+            "{}",
+            number
+        );
+        assert!(digits.rev().eq(number.into_decimal_digits().rev()));
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let mut power = 1_u64;
+        loop {
+            check(power - 1);
+            check(power);
+            check(power + 1);
+            match power.checked_mul(10) {
+                Some(next) => power = next,
+                None => break,
+            }
+        }
+
+        check(u64::MAX);
+        check(u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_many_numbers() {
+        let mut number = 0x9E37_79B9_7F4A_7C15_u64;
+        for _ in 0..10_000 {
+            number ^= number << 13;
+            number ^= number >> 7;
+            number ^= number << 17;
+            check(number);
+            check(number >> (number % 64));
+        }
+
+        for number in 0..100_000 {
+            check(number);
+        }
+    }
+
+    #[test]
+    fn test_swar_matches_sse2() {
+        for value in (0..100_000_000)
+            .step_by(9_973)
+            .chain(99_999_990..100_000_000)
+        {
+            let expected = value
+                .into_decimal_digits()
+                .rfold((0_u64, 0), |(bytes, shift), digit| {
+                    (bytes | (u64::from(digit) << shift), shift + 8)
+                })
+                .0
+                .swap_bytes();
+            assert_eq!(swar::convert8(value), expected, "{}", value);
+            assert_eq!(&convert16(value, value)[..8], expected.to_le_bytes());
+        }
+    }
+
+    #[test]
+    fn test_as_slice_after_iteration() {
+        let mut digits = DecimalDigits::new(1_234_567);
+        assert_eq!(digits.len(), 7);
+        assert_eq!(digits.next(), Some(1));
+        assert_eq!(digits.next_back(), Some(7));
+        assert_eq!(digits.as_slice(), [2, 3, 4, 5, 6]);
+
+        let zero = DecimalDigits::new(0);
+        assert_eq!(zero.as_slice(), [0]);
+    }
+}
@@ -0,0 +1,517 @@
+//! Generators of radices and digits for property based testing, with `proptest` or
+//! `quickcheck`, and for fuzzing, with `arbitrary`.
+//!
+//! The radices are at least 2, and half of them are at most 36, where the numbers have enough
+//! digits to reach the edge cases. Shrinking keeps the values valid: a radix never shrinks below
+//! 2, and a `Reversible` number stays reversible without overflow.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "proptest")]
+//! # {
+//! use proptest::prelude::*;
+//! use radixal::testing::{self, Reversible};
+//! use radixal::IntoDigits;
+//!
+//! proptest!(|(Reversible { number, radix } in testing::reversible::<u32>())| {
+//!     let digits = number.into_digits(radix).unwrap();
+//!     prop_assert_eq!(digits.checked_reversed_number(), number.reverse_digits(radix).ok());
+//! });
+//! # }
+//! ```
+#[cfg(feature = "arbitrary")]
+use crate::alphabet::Alphabet;
+#[cfg(feature = "arbitrary")]
+use crate::digits::ArrayDigits;
+use crate::digits::Digits;
+use crate::digits_iterator::DigitsIterator;
+#[cfg(feature = "arbitrary")]
+use crate::fixed_width::FixedWidthDigits;
+use crate::{IntoDigits, RadixMath};
+#[cfg(feature = "arbitrary")]
+use arbitrary::Unstructured;
+#[cfg(feature = "proptest")]
+use proptest::prelude::{any, prop_oneof, BoxedStrategy, Strategy};
+
+/// The largest radix of the small radices, which are favoured.
+const SMALL_RADIX: usize = 36;
+
+/// The largest number of digits of the `Digits` generated by `proptest` and `arbitrary`.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+const MAX_DIGITS: usize = 64;
+
+/// The alphabets generated by `arbitrary`, before taking their first symbols.
+#[cfg(feature = "arbitrary")]
+const ALPHABETS: [Alphabet<'static>; 20] = [
+    Alphabet::BINARY,
+    Alphabet::OCTAL,
+    Alphabet::DECIMAL,
+    Alphabet::LOWER_HEX,
+    Alphabet::UPPER_HEX,
+    Alphabet::BASE36,
+    Alphabet::BASE62,
+    Alphabet::BASE58,
+    Alphabet::BASE32,
+    Alphabet::BASE32HEX,
+    Alphabet::Z85,
+    Alphabet::ASCII85,
+    Alphabet::CROCKFORD,
+    Alphabet::EASTERN_ARABIC,
+    Alphabet::PERSIAN,
+    Alphabet::DEVANAGARI,
+    Alphabet::BENGALI,
+    Alphabet::THAI,
+    Alphabet::FULLWIDTH,
+    Alphabet::SUPERSCRIPT,
+];
+
+/// A valid radix, at least 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Radix<T: IntoDigits>(pub T);
+
+/// A number and a radix such that reversing the digits of the number does not overflow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Reversible<T: IntoDigits> {
+    pub number: T,
+    pub radix: T,
+}
+
+impl<T: IntoDigits> Reversible<T> {
+    /// Drops the least significant digit of `number` if reversing it overflows, so that it has
+    /// fewer digits than `T::MAX`.
+    fn fit(number: T, radix: T) -> Self {
+        let digits = DigitsIterator::new(number, radix).unwrap();
+        let number = if digits.checked_reversed_number().is_some() {
+            number
+        } else {
+            number / radix
+        };
+        Reversible { number, radix }
+    }
+
+    /// Returns the number with its digits reversed.
+    pub fn reversed(&self) -> T {
+        self.number.reverse_digits(self.radix).unwrap()
+    }
+}
+
+fn two<T: IntoDigits>() -> T {
+    T::one() + T::one()
+}
+
+/// Collects `digits` in a `Digits`, reducing them modulo `radix`.
+fn new_digits<T: IntoDigits, I: IntoIterator<Item = T>>(radix: T, digits: I) -> Digits<T> {
+    let mut buffer = Digits::new(radix).unwrap();
+    buffer
+        .try_extend(digits.into_iter().map(|digit| digit % radix))
+        .unwrap();
+    buffer
+}
+
+/// Generates valid radices, half of them at most 36.
+///
+/// The radices shrink towards 2.
+#[cfg(feature = "proptest")]
+pub fn radix<T: IntoDigits + proptest::arbitrary::Arbitrary>() -> impl Strategy<Value = T> {
+    prop_oneof![
+        (2..=SMALL_RADIX).prop_map(|radix| T::from_usize(radix).unwrap()),
+        any::<T>().prop_map(|radix| radix.max(two())),
+    ]
+}
+
+/// Generates numbers and radices whose digits do not overflow when reversed.
+///
+/// The numbers shrink towards 0 and the radices towards 2.
+#[cfg(feature = "proptest")]
+pub fn reversible<T: IntoDigits + proptest::arbitrary::Arbitrary>(
+) -> impl Strategy<Value = Reversible<T>> {
+    (any::<T>(), radix()).prop_map(|(number, radix)| Reversible::fit(number, radix))
+}
+
+/// Generates the digits of any number under a valid radix.
+#[cfg(feature = "proptest")]
+pub fn digits_iterator<T: IntoDigits + proptest::arbitrary::Arbitrary>(
+) -> impl Strategy<Value = DigitsIterator<T>> {
+    (any::<T>(), radix()).prop_map(|(number, radix)| DigitsIterator::new(number, radix).unwrap())
+}
+
+/// Generates up to 64 digits under a valid radix, leading zeroes included.
+///
+/// The digits shrink towards fewer and smaller digits.
+#[cfg(feature = "proptest")]
+pub fn digits<T: IntoDigits + proptest::arbitrary::Arbitrary>() -> impl Strategy<Value = Digits<T>>
+{
+    radix().prop_flat_map(|radix| {
+        proptest::collection::vec(any::<T>(), 0..=MAX_DIGITS)
+            .prop_map(move |digits| new_digits(radix, digits))
+    })
+}
+
+#[cfg(feature = "proptest")]
+impl<T: IntoDigits + proptest::arbitrary::Arbitrary + 'static> proptest::arbitrary::Arbitrary
+    for Radix<T>
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        radix().prop_map(Radix).boxed()
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T: IntoDigits + proptest::arbitrary::Arbitrary + 'static> proptest::arbitrary::Arbitrary
+    for Reversible<T>
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        reversible().boxed()
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T: IntoDigits + proptest::arbitrary::Arbitrary + 'static> proptest::arbitrary::Arbitrary
+    for DigitsIterator<T>
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        digits_iterator().boxed()
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T: IntoDigits + proptest::arbitrary::Arbitrary + 'static> proptest::arbitrary::Arbitrary
+    for Digits<T>
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        digits().boxed()
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: IntoDigits + quickcheck::Arbitrary> quickcheck::Arbitrary for Radix<T> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let radix = if bool::arbitrary(g) {
+            T::from_usize(usize::arbitrary(g) % (SMALL_RADIX - 1) + 2).unwrap()
+        } else {
+            T::arbitrary(g)
+        };
+        Radix(radix.max(two()))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().filter(|&radix| radix >= two()).map(Radix))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: IntoDigits + quickcheck::Arbitrary> quickcheck::Arbitrary for Reversible<T> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Reversible::fit(T::arbitrary(g), Radix::arbitrary(g).0)
+    }
+
+    /// Shrinks the number, then the radix, dropping the least significant digit of the number
+    /// where it stops being reversible.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let Reversible { number, radix } = *self;
+        let numbers = number
+            .shrink()
+            .map(move |number| Reversible::fit(number, radix));
+        let radices = Radix(radix)
+            .shrink()
+            .map(move |radix| Reversible::fit(number, radix.0));
+        Box::new(numbers.chain(radices))
+    }
+}
+
+/// Shrinks the remaining digits, as the digits of a new number.
+#[cfg(feature = "quickcheck")]
+impl<T: IntoDigits + quickcheck::Arbitrary> quickcheck::Arbitrary for DigitsIterator<T> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        DigitsIterator::new(T::arbitrary(g), Radix::arbitrary(g).0).unwrap()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let (number, radix) = (self.to_number(), self.radix());
+        let numbers = number
+            .shrink()
+            .map(move |number| DigitsIterator::new(number, radix).unwrap());
+        let radices = Radix(radix)
+            .shrink()
+            .map(move |radix| DigitsIterator::new(number, radix.0).unwrap());
+        Box::new(numbers.chain(radices))
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: IntoDigits + quickcheck::Arbitrary> quickcheck::Arbitrary for Digits<T> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let radix = Radix::arbitrary(g).0;
+        new_digits(radix, Vec::arbitrary(g))
+    }
+
+    /// Shrinks the digits, then the radix, reducing the digits modulo the smaller radix.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let radix = self.radix();
+        let digits = self.as_slice().to_vec();
+        let radices = Radix(radix).shrink().map({
+            let digits = digits.clone();
+            move |radix| new_digits(radix.0, digits.clone())
+        });
+        Box::new(
+            digits
+                .shrink()
+                .map(move |digits| new_digits(radix, digits))
+                .chain(radices),
+        )
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: IntoDigits + arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Radix<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let radix = if u.arbitrary()? {
+            T::from_usize(u.int_in_range(2..=SMALL_RADIX)?).unwrap()
+        } else {
+            u.arbitrary::<T>()?
+        };
+        Ok(Radix(radix.max(two())))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: IntoDigits + arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Reversible<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let number = u.arbitrary::<T>()?;
+        Ok(Reversible::fit(number, u.arbitrary::<Radix<T>>()?.0))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: IntoDigits + arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for DigitsIterator<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let number = u.arbitrary::<T>()?;
+        Ok(DigitsIterator::new(number, u.arbitrary::<Radix<T>>()?.0).unwrap())
+    }
+}
+
+/// Takes up to 64 digits, leading zeroes included, reduced modulo the radix.
+#[cfg(feature = "arbitrary")]
+impl<'a, T: IntoDigits + arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Digits<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let radix = u.arbitrary::<Radix<T>>()?.0;
+        let len = u.arbitrary_len::<T>()?.min(MAX_DIGITS);
+        let digits = (0..len)
+            .map(|_| u.arbitrary::<T>())
+            .collect::<arbitrary::Result<Vec<_>>>()?;
+        Ok(new_digits(radix, digits))
+    }
+}
+
+/// Takes up to `N` digits, leading zeroes included, reduced modulo the radix.
+#[cfg(feature = "arbitrary")]
+impl<'a, T: IntoDigits + arbitrary::Arbitrary<'a>, const N: usize> arbitrary::Arbitrary<'a>
+    for ArrayDigits<T, N>
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut digits = ArrayDigits::new(u.arbitrary::<Radix<T>>()?.0).unwrap();
+        let len = u.arbitrary_len::<T>()?.min(N);
+        for _ in 0..len {
+            digits.push(u.arbitrary::<T>()? % digits.radix()).unwrap();
+        }
+        Ok(digits)
+    }
+}
+
+/// Picks one of the built-in alphabets, or its first symbols as an alphabet of a smaller radix,
+/// without aliases.
+#[cfg(feature = "arbitrary")]
+impl<'a, 'b> arbitrary::Arbitrary<'a> for Alphabet<'b> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let alphabet = *u.choose(&ALPHABETS)?;
+        if u.arbitrary()? {
+            return Ok(alphabet);
+        }
+
+        let radix = u.int_in_range(2..=alphabet.radix())?;
+        Ok(Alphabet::new(&alphabet.symbols()[..radix]).unwrap())
+    }
+}
+
+/// Picks an alphabet, then a width whose numbers all fit in `T`, and reduces the value modulo
+/// `radix^width`.
+#[cfg(feature = "arbitrary")]
+impl<'a, 'b, T: IntoDigits + arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a>
+    for FixedWidthDigits<'b, T>
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let alphabet = u.arbitrary::<Alphabet>()?;
+        let max_width = (1..)
+            .take_while(|&width| FixedWidthDigits::new(T::zero(), width, alphabet).is_ok())
+            .last()
+            .unwrap();
+        let width = u.int_in_range(1..=max_width)?;
+        let zero = FixedWidthDigits::new(T::zero(), width, alphabet).unwrap();
+        Ok(zero.wrapping_add(u.arbitrary::<T>()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_reversible<T: IntoDigits>(reversible: Reversible<T>) -> bool {
+        let digits = reversible.number.into_digits(reversible.radix).unwrap();
+        digits.checked_reversed_number() == Some(reversible.reversed()) && reversible.radix >= two()
+    }
+
+    fn check_digits<T: IntoDigits>(digits: &Digits<T>) -> bool {
+        digits.iter().all(|&digit| digit < digits.radix()) && digits.radix() >= two()
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(Reversible::fit(152_u8, 10).number, 152);
+        assert_eq!(Reversible::fit(159_u8, 10).number, 15);
+        assert_eq!(Reversible::fit(u128::MAX, 2).number, u128::MAX);
+        assert_eq!(Reversible::fit(u64::MAX, 10).number, u64::MAX / 10);
+        assert!(check_reversible(Reversible::fit(u64::MAX - 1, u64::MAX)));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    mod with_arbitrary {
+        use super::*;
+        use arbitrary::Arbitrary;
+
+        /// Generates values from random bytes of random lengths, including none at all.
+        fn check_bytes(mut check: impl FnMut(&mut Unstructured) -> arbitrary::Result<()>) {
+            let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+            let mut random = move || {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state
+            };
+
+            for len in 0..1000 {
+                let bytes: Vec<u8> = (0..len % 64).map(|_| random() as u8).collect();
+                check(&mut Unstructured::new(&bytes)).unwrap();
+            }
+        }
+
+        #[test]
+        fn test_valid_values() {
+            check_bytes(|u| {
+                assert!(Radix::<u64>::arbitrary(u)?.0 >= 2);
+                assert!(check_reversible(Reversible::<u16>::arbitrary(u)?));
+                assert!(check_reversible(Reversible::<u128>::arbitrary(u)?));
+                assert!(check_digits(&Digits::<u8>::arbitrary(u)?));
+
+                let digits = DigitsIterator::<u32>::arbitrary(u)?;
+                assert_eq!(
+                    digits,
+                    DigitsIterator::new(digits.to_number(), digits.radix()).unwrap()
+                );
+
+                let digits = ArrayDigits::<u16, 4>::arbitrary(u)?;
+                assert!(digits.len() <= 4);
+                assert!(digits.iter().all(|&digit| digit < digits.radix()));
+                Ok(())
+            });
+        }
+
+        #[test]
+        fn test_alphabets() {
+            let mut radices = std::collections::HashSet::new();
+            check_bytes(|u| {
+                let alphabet = Alphabet::arbitrary(u)?;
+                radices.insert(alphabet.radix());
+                let symbols = alphabet.symbols();
+                assert_eq!(Alphabet::new(symbols).map(|a| a.radix()), Ok(symbols.len()));
+
+                let fixed = FixedWidthDigits::<u8>::arbitrary(u)?;
+                let expected =
+                    FixedWidthDigits::new(fixed.value(), fixed.width(), fixed.alphabet());
+                assert_eq!(expected, Ok(fixed));
+                Ok(())
+            });
+            assert!(radices.contains(&2) && radices.contains(&85));
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    mod with_proptest {
+        use super::*;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn test_radix(radix in radix::<u16>()) {
+                prop_assert!(radix >= 2);
+            }
+
+            #[test]
+            fn test_reversible_u8(reversible in any::<Reversible<u8>>()) {
+                prop_assert!(check_reversible(reversible));
+            }
+
+            #[test]
+            fn test_reversible_u64(reversible in reversible::<u64>()) {
+                prop_assert!(check_reversible(reversible));
+            }
+
+            #[test]
+            fn test_digits_iterator(digits in any::<DigitsIterator<u32>>()) {
+                let expected = DigitsIterator::new(digits.to_number(), digits.radix()).unwrap();
+                prop_assert_eq!(digits, expected);
+            }
+
+            #[test]
+            fn test_digits(digits in any::<Digits<u8>>()) {
+                prop_assert!(check_digits(&digits));
+                prop_assert!(digits.len() <= MAX_DIGITS);
+            }
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    mod with_quickcheck {
+        use super::*;
+        use quickcheck::{quickcheck, Arbitrary, Gen};
+
+        #[test]
+        fn test_arbitrary() {
+            quickcheck(check_reversible as fn(Reversible<u8>) -> bool);
+            quickcheck(check_reversible as fn(Reversible<u64>) -> bool);
+            quickcheck((|digits| check_digits(&digits)) as fn(Digits<u16>) -> bool);
+            quickcheck((|Radix(radix)| radix >= 2) as fn(Radix<u128>) -> bool);
+        }
+
+        #[test]
+        fn test_shrink_stays_valid() {
+            let mut g = Gen::new(100);
+            for _ in 0..100 {
+                let reversible = Reversible::<u16>::arbitrary(&mut g);
+                assert!(reversible.shrink().all(check_reversible));
+
+                let digits = Digits::<u8>::arbitrary(&mut g);
+                assert!(digits.shrink().all(|digits| check_digits(&digits)));
+
+                let iterator = DigitsIterator::<u32>::arbitrary(&mut g);
+                assert!(iterator.shrink().all(|digits| digits.radix() >= 2));
+            }
+
+            let shrunk: Vec<_> = Radix(10_u8).shrink().collect();
+            assert!(shrunk.contains(&Radix(5)));
+            assert!(shrunk.iter().all(|&Radix(radix)| (2..10).contains(&radix)));
+        }
+    }
+}